@@ -16,6 +16,32 @@ pub const BUILTIN_MAP_ID: u8 = 11;
 pub const BUILTIN_FILTER_ID: u8 = 12;
 pub const BUILTIN_SET_ID: u8 = 13;
 pub const BUILTIN_TREESET_ID: u8 = 14;
+pub const BUILTIN_FORMAT_ID: u8 = 15;
+pub const BUILTIN_ABS_ID: u8 = 16;
+pub const BUILTIN_MIN_ID: u8 = 17;
+pub const BUILTIN_MAX_ID: u8 = 18;
+pub const BUILTIN_SUM_ID: u8 = 19;
+pub const BUILTIN_SORTED_ID: u8 = 20;
+pub const BUILTIN_REVERSED_ID: u8 = 21;
+pub const BUILTIN_ENUMERATE_ID: u8 = 22;
+pub const BUILTIN_ZIP_ID: u8 = 23;
+pub const BUILTIN_ANY_ID: u8 = 24;
+pub const BUILTIN_ALL_ID: u8 = 25;
+pub const BUILTIN_ROUND_ID: u8 = 26;
+pub const BUILTIN_POW_ID: u8 = 27;
+pub const BUILTIN_DIVMOD_ID: u8 = 28;
+pub const BUILTIN_CHR_ID: u8 = 29;
+pub const BUILTIN_ORD_ID: u8 = 30;
+pub const BUILTIN_HEX_ID: u8 = 31;
+pub const BUILTIN_BIN_ID: u8 = 32;
+pub const BUILTIN_TYPE_ID: u8 = 33;
+pub const BUILTIN_REPR_ID: u8 = 34;
+pub const BUILTIN_HASH_ID: u8 = 35;
+pub const BUILTIN_ID_ID: u8 = 36;
+pub const BUILTIN_ISINSTANCE_ID: u8 = 37;
+pub const BUILTIN_TUPLE_ID: u8 = 38;
+pub const BUILTIN_ITER_ID: u8 = 39;
+pub const BUILTIN_NEXT_ID: u8 = 40;
 
 // ========== 빌트인 타입 ID ==========
 // 0-99는 builtin 타입, 100+는 사용자 정의 타입 (TYPE_USER_START는 type_def.rs에 정의)
@@ -33,9 +59,20 @@ pub const TYPE_FILTER_ITER: u16 = 10;
 pub const TYPE_TUPLE: u16 = 11;
 pub const TYPE_SET: u16 = 12;
 pub const TYPE_TREESET: u16 = 13;
+pub const TYPE_ENUMERATE: u16 = 14;
+pub const TYPE_ZIP: u16 = 15;
+pub const TYPE_TYPE: u16 = 16;
+/// `import`한 내장 모듈 객체 (`sys`)
+pub const TYPE_MODULE: u16 = 17;
+/// `sys.stdin` / `sys.stdout` 스트림
+pub const TYPE_TEXT_IO: u16 = 18;
+/// `iter(xs)`, `reversed(xs)`가 돌려주는 list iterator (str, tuple 등의 `__iter__`도 사용)
+pub const TYPE_LIST_ITERATOR: u16 = 19;
+/// 호스트가 등록한 네이티브 클래스의 인스턴스 (타입 테이블 항목 없음, vm::native 참고)
+pub const TYPE_NATIVE: u16 = 99;
 
 // ========== 빌트인 클래스 타입 ==========
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum BuiltinClassType {
     /// `range(start, stop, step)` 타입
     Range,
@@ -53,6 +90,32 @@ pub enum BuiltinClassType {
     Set,
     /// `t{1, 2, 3}` 타입 (BTreeSet)
     TreeSet,
+    /// `enumerate(iterable, start)` 타입
+    Enumerate,
+    /// `zip(a, b, ...)` 타입
+    Zip,
+    /// `int` 타입 객체 (`type(1)`)
+    Int,
+    /// `float` 타입 객체
+    Float,
+    /// `bool` 타입 객체
+    Bool,
+    /// `str` 타입 객체
+    Str,
+    /// `NoneType` 타입 객체
+    NoneType,
+    /// `function` 타입 객체 (`type(lambda x: x)`)
+    Function,
+    /// `type` 타입 객체 (`type(int)`)
+    Type,
+    /// `module` 타입 객체 (`type(sys)`)
+    Module,
+    /// `TextIOWrapper` 타입 객체 (`type(sys.stdin)`)
+    TextIO,
+    /// `builtin_function_or_method` 타입 객체 (`type(len)`)
+    BuiltinFunction,
+    /// `list_iterator` 타입 (`type(iter([1, 2]))`)
+    ListIterator,
 }
 
 impl BuiltinClassType {
@@ -67,6 +130,66 @@ impl BuiltinClassType {
             Self::FilterIter => "filter_iter",
             Self::Set => "set",
             Self::TreeSet => "treeset",
+            Self::Enumerate => "enumerate",
+            Self::Zip => "zip",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Bool => "bool",
+            Self::Str => "str",
+            Self::NoneType => "NoneType",
+            Self::Function => "function",
+            Self::Type => "type",
+            Self::Module => "module",
+            Self::TextIO => "TextIOWrapper",
+            Self::BuiltinFunction => "builtin_function_or_method",
+            Self::ListIterator => "list_iterator",
+        }
+    }
+
+    /// 이름으로 값처럼 쓸 수 있는 타입 객체 조회 (`isinstance(x, int)`의 `int`)
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "int" => Self::Int,
+            "float" => Self::Float,
+            "bool" => Self::Bool,
+            "str" => Self::Str,
+            "list" => Self::List,
+            "dict" => Self::Dict,
+            "tuple" => Self::Tuple,
+            "set" => Self::Set,
+            "treeset" => Self::TreeSet,
+            "range" => Self::Range,
+            "enumerate" => Self::Enumerate,
+            "zip" => Self::Zip,
+            "type" => Self::Type,
+            _ => return None,
+        })
+    }
+
+    /// 타입 객체를 호출했을 때 대신 실행할 builtin 함수 (`t = int; t("3")`)
+    pub const fn constructor_id(&self) -> Option<u8> {
+        match self {
+            Self::Int => Some(BUILTIN_INT_ID),
+            Self::Float => Some(BUILTIN_FLOAT_ID),
+            Self::Bool => Some(BUILTIN_BOOL_ID),
+            Self::Str => Some(BUILTIN_STR_ID),
+            Self::List => Some(BUILTIN_LIST_ID),
+            Self::Dict => Some(BUILTIN_DICT_ID),
+            Self::Tuple => Some(BUILTIN_TUPLE_ID),
+            Self::Set => Some(BUILTIN_SET_ID),
+            Self::TreeSet => Some(BUILTIN_TREESET_ID),
+            Self::Range => Some(BUILTIN_RANGE_ID),
+            Self::Enumerate => Some(BUILTIN_ENUMERATE_ID),
+            Self::Zip => Some(BUILTIN_ZIP_ID),
+            Self::Type => Some(BUILTIN_TYPE_ID),
+            Self::MapIter => Some(BUILTIN_MAP_ID),
+            Self::FilterIter => Some(BUILTIN_FILTER_ID),
+            Self::NoneType
+            | Self::Function
+            | Self::Module
+            | Self::TextIO
+            | Self::BuiltinFunction
+            | Self::ListIterator => None,
         }
     }
 }
@@ -82,6 +205,11 @@ impl BuiltinFunction {
     pub fn check_arity(&self, got: usize) -> bool {
         self.arity.check(got)
     }
+
+    /// 키워드 인자를 받는 builtin인지 (`sorted(xs, key=f, reverse=True)`)
+    pub fn accepts_keyword(&self, name: &str) -> bool {
+        matches!((self.builtin_id, name), (BUILTIN_SORTED_ID, "key" | "reverse"))
+    }
 }
 
 const PRINT: BuiltinFunction = BuiltinFunction {
@@ -134,7 +262,7 @@ const FLOAT: BuiltinFunction = BuiltinFunction {
 
 const ASSERT: BuiltinFunction = BuiltinFunction {
     name: "assert",
-    arity: Arity::Exact(1), // assert(condition)
+    arity: Arity::Range(1, 2), // assert(condition) or assert(condition, message)
     builtin_id: BUILTIN_ASSERT_ID,
 };
 
@@ -162,22 +290,179 @@ const TREESET: BuiltinFunction = BuiltinFunction {
     builtin_id: BUILTIN_TREESET_ID,
 };
 
-// TODO: Uncomment when list() and dict() constructors are implemented
-// const LIST: Builtin = Builtin {
-//     name: "list",
-//     arity: Arity::Range(0, 1), // list() or list(iterable)
-//     builtin_id: BUILTIN_LIST_ID,
-// };
-//
-// const DICT: Builtin = Builtin {
-//     name: "dict",
-//     arity: Arity::Exact(0), // dict() - no args initially
-//     builtin_id: BUILTIN_DICT_ID,
-// };
+const FORMAT: BuiltinFunction = BuiltinFunction {
+    name: "format",
+    arity: Arity::Range(1, 2), // format(value) or format(value, spec)
+    builtin_id: BUILTIN_FORMAT_ID,
+};
+
+const LIST: BuiltinFunction = BuiltinFunction {
+    name: "list",
+    arity: Arity::Range(0, 1), // list() or list(iterable)
+    builtin_id: BUILTIN_LIST_ID,
+};
+
+const DICT: BuiltinFunction = BuiltinFunction {
+    name: "dict",
+    arity: Arity::Range(0, 1), // dict() or dict(mapping / iterable of pairs)
+    builtin_id: BUILTIN_DICT_ID,
+};
+
+const ABS: BuiltinFunction = BuiltinFunction {
+    name: "abs",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_ABS_ID,
+};
+
+const MIN: BuiltinFunction = BuiltinFunction {
+    name: "min",
+    arity: Arity::Variadic, // min(iterable) or min(a, b, ...)
+    builtin_id: BUILTIN_MIN_ID,
+};
+
+const MAX: BuiltinFunction = BuiltinFunction {
+    name: "max",
+    arity: Arity::Variadic, // max(iterable) or max(a, b, ...)
+    builtin_id: BUILTIN_MAX_ID,
+};
+
+const SUM: BuiltinFunction = BuiltinFunction {
+    name: "sum",
+    arity: Arity::Range(1, 2), // sum(iterable) or sum(iterable, start)
+    builtin_id: BUILTIN_SUM_ID,
+};
+
+const SORTED: BuiltinFunction = BuiltinFunction {
+    name: "sorted",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_SORTED_ID,
+};
+
+const REVERSED: BuiltinFunction = BuiltinFunction {
+    name: "reversed",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_REVERSED_ID,
+};
+
+const ENUMERATE: BuiltinFunction = BuiltinFunction {
+    name: "enumerate",
+    arity: Arity::Range(1, 2), // enumerate(iterable) or enumerate(iterable, start)
+    builtin_id: BUILTIN_ENUMERATE_ID,
+};
+
+const ZIP: BuiltinFunction = BuiltinFunction {
+    name: "zip",
+    arity: Arity::Variadic, // zip(a, b, ...)
+    builtin_id: BUILTIN_ZIP_ID,
+};
+
+const ANY: BuiltinFunction = BuiltinFunction {
+    name: "any",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_ANY_ID,
+};
+
+const ALL: BuiltinFunction = BuiltinFunction {
+    name: "all",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_ALL_ID,
+};
+
+const ROUND: BuiltinFunction = BuiltinFunction {
+    name: "round",
+    arity: Arity::Range(1, 2), // round(x) or round(x, ndigits)
+    builtin_id: BUILTIN_ROUND_ID,
+};
+
+const POW: BuiltinFunction = BuiltinFunction {
+    name: "pow",
+    arity: Arity::Range(2, 3), // pow(base, exp) or pow(base, exp, mod)
+    builtin_id: BUILTIN_POW_ID,
+};
+
+const DIVMOD: BuiltinFunction = BuiltinFunction {
+    name: "divmod",
+    arity: Arity::Exact(2),
+    builtin_id: BUILTIN_DIVMOD_ID,
+};
+
+const CHR: BuiltinFunction = BuiltinFunction {
+    name: "chr",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_CHR_ID,
+};
+
+const ORD: BuiltinFunction = BuiltinFunction {
+    name: "ord",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_ORD_ID,
+};
+
+const HEX: BuiltinFunction = BuiltinFunction {
+    name: "hex",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_HEX_ID,
+};
+
+const BIN: BuiltinFunction = BuiltinFunction {
+    name: "bin",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_BIN_ID,
+};
 
-static REGISTRY: &[BuiltinFunction] = &[PRINT, INPUT, INT, BOOL, STR, LEN, RANGE, FLOAT, ASSERT, MAP, FILTER, SET, TREESET];
-// TODO: Add LIST and DICT to registry when implemented
-// static REGISTRY: &[Builtin] = &[PRINT, INPUT, INT, BOOL, STR, LEN, RANGE, FLOAT, LIST, DICT, ASSERT];
+const TYPE: BuiltinFunction = BuiltinFunction {
+    name: "type",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_TYPE_ID,
+};
+
+const REPR: BuiltinFunction = BuiltinFunction {
+    name: "repr",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_REPR_ID,
+};
+
+const HASH: BuiltinFunction = BuiltinFunction {
+    name: "hash",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_HASH_ID,
+};
+
+const ID: BuiltinFunction = BuiltinFunction {
+    name: "id",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_ID_ID,
+};
+
+const ISINSTANCE: BuiltinFunction = BuiltinFunction {
+    name: "isinstance",
+    arity: Arity::Exact(2), // isinstance(obj, type) or isinstance(obj, (type, ...))
+    builtin_id: BUILTIN_ISINSTANCE_ID,
+};
+
+const TUPLE: BuiltinFunction = BuiltinFunction {
+    name: "tuple",
+    arity: Arity::Range(0, 1), // tuple() or tuple(iterable)
+    builtin_id: BUILTIN_TUPLE_ID,
+};
+
+const ITER: BuiltinFunction = BuiltinFunction {
+    name: "iter",
+    arity: Arity::Exact(1),
+    builtin_id: BUILTIN_ITER_ID,
+};
+
+const NEXT: BuiltinFunction = BuiltinFunction {
+    name: "next",
+    arity: Arity::Range(1, 2), // next(iterator) or next(iterator, default)
+    builtin_id: BUILTIN_NEXT_ID,
+};
+
+static REGISTRY: &[BuiltinFunction] = &[
+    PRINT, INPUT, INT, BOOL, STR, LEN, RANGE, FLOAT, LIST, DICT, ASSERT, MAP, FILTER, SET, TREESET,
+    FORMAT, ABS, MIN, MAX, SUM, SORTED, REVERSED, ENUMERATE, ZIP, ANY, ALL, ROUND, POW, DIVMOD, CHR,
+    ORD, HEX, BIN, TYPE, REPR, HASH, ID, ISINSTANCE, TUPLE, ITER, NEXT,
+];
 
 pub fn all() -> &'static [BuiltinFunction] {
     REGISTRY
@@ -190,3 +475,33 @@ pub fn lookup(name: &str) -> Option<&'static BuiltinFunction> {
 pub fn lookup_by_id(id: u8) -> Option<&'static BuiltinFunction> {
     REGISTRY.iter().find(|&b| b.builtin_id == id)
 }
+
+// ========== 빌트인 모듈 ==========
+
+/// `import`로 가져오는 내장 모듈
+///
+/// 모듈 함수는 모듈 타입(TYPE_MODULE)의 Native 메서드로 실행됩니다.
+#[derive(Debug)]
+pub struct BuiltinModule {
+    pub name: &'static str,
+    /// 값 속성 (`sys.argv`, `sys.stdin`)
+    pub attributes: &'static [&'static str],
+    /// 함수 (`sys.exit`)
+    pub functions: &'static [&'static str],
+}
+
+const SYS: BuiltinModule = BuiltinModule {
+    name: "sys",
+    attributes: &["argv", "stdin", "stdout"],
+    functions: &["exit"],
+};
+
+static MODULES: &[BuiltinModule] = &[SYS];
+
+pub fn lookup_module(name: &str) -> Option<&'static BuiltinModule> {
+    MODULES.iter().find(|m| m.name == name)
+}
+
+pub fn all_modules() -> &'static [BuiltinModule] {
+    MODULES
+}
@@ -0,0 +1,654 @@
+//! 컴파일 단계(렉서/파서/의미 분석)의 진단 모델
+//!
+//! 진단은 심각도, 안정적인 에러 코드, 주 라벨 메시지와 보조 라벨, 노트, 도움말로
+//! 구성됩니다. `pyhc explain E0xxx`는 [`ErrorCode::explanation`]을 출력합니다.
+
+use std::ops::Range;
+
+use ariadne::{Color, Label, Report, ReportKind, Source};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Severity::Error => Color::Red,
+            Severity::Warning => Color::Yellow,
+            Severity::Info => Color::Cyan,
+        }
+    }
+}
+
+macro_rules! error_codes {
+    ($($variant:ident = $code:literal, $title:literal;)*) => {
+        /// 안정적인 진단 코드 (한 번 배정된 코드는 의미를 바꾸지 않습니다)
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum ErrorCode {
+            $($variant,)*
+        }
+
+        impl ErrorCode {
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$variant,)*];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(ErrorCode::$variant => $code,)*
+                }
+            }
+
+            /// 리포트 헤더에 쓰이는 한 줄 제목
+            pub fn title(&self) -> &'static str {
+                match self {
+                    $(ErrorCode::$variant => $title,)*
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    InvalidToken = "E0001", "invalid token";
+    InvalidIndentation = "E0002", "invalid indentation";
+    UnexpectedToken = "E0101", "unexpected token";
+    OutsideLoop = "E0102", "statement outside loop";
+    InvalidAssignTarget = "E0103", "invalid assignment target";
+    MissingSelf = "E0104", "method without self";
+    UndefinedName = "E0201", "undefined name";
+    UnboundLocal = "E0202", "unbound local variable";
+    UnknownModule = "E0203", "unknown module";
+    OperandType = "E0301", "unsupported operand types";
+    ConditionType = "E0302", "non-boolean condition";
+    IncompatibleTypes = "E0303", "incompatible types";
+    NotIterable = "E0304", "object is not iterable";
+    IndexType = "E0305", "invalid index";
+    ArgumentType = "E0306", "invalid argument type";
+    InvalidFormatSpec = "E0307", "invalid format specifier";
+    ArityMismatch = "E0401", "wrong number of arguments";
+    UnknownAttribute = "E0402", "unknown attribute";
+    UnexpectedKeyword = "E0403", "unexpected keyword argument";
+    UnreachableCode = "W0001", "unreachable code";
+    AttributeOutsideClass = "W0002", "attribute defined outside class";
+}
+
+impl ErrorCode {
+    /// `"E0201"`처럼 코드 문자열로 찾기 (대소문자 무시)
+    pub fn from_code(code: &str) -> Option<ErrorCode> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|c| c.as_str().eq_ignore_ascii_case(code))
+    }
+
+    /// 코드의 기본 심각도 (`W` 코드는 경고)
+    pub fn severity(&self) -> Severity {
+        if self.as_str().starts_with('W') {
+            Severity::Warning
+        } else {
+            Severity::Error
+        }
+    }
+
+    /// `pyhc explain`이 출력하는 긴 설명
+    ///
+    /// 예제 블록은 두 칸 들여쓰기이고, `# error`/`# warning` 표시가 없는 블록은
+    /// 그대로 컴파일되는 올바른 프로그램입니다 (테스트가 확인).
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidToken => {
+                "The lexer found a character sequence that does not form a valid token,
+such as a stray `$` or a string literal that is never closed.
+
+  name = \"Kim      # error: unterminated string literal
+
+Close the string on the same line, or remove the unexpected character.
+
+  name = \"Kim\""
+            }
+            ErrorCode::InvalidIndentation => {
+                "Blocks are delimited by indentation. A nested block is indented exactly
+two spaces deeper than its header, tabs are not allowed, and a dedent
+must return to a level that was used before.
+
+  x = 1
+  if x > 0:
+      y = 1    # error: indented four spaces instead of two
+
+Indent with spaces and keep the lines of a block aligned.
+
+  x = 1
+  if x > 0:
+    y = 1
+  z = 2"
+            }
+            ErrorCode::UnexpectedToken => {
+                "The parser found a token where the grammar does not allow it, for example
+a missing `:` after an `if` condition or an unbalanced parenthesis.
+
+  x = 1
+  if x > 0     # error: expected `:`
+    print(x)
+
+The label shows what was found; the message lists what was expected.
+
+  x = 1
+  if x > 0:
+    print(x)"
+            }
+            ErrorCode::OutsideLoop => {
+                "`break` and `continue` only make sense inside a `for` or `while` loop.
+A function body does not inherit the loop around its definition.
+
+  def f():
+    break    # error: 'break' outside loop
+
+Move the statement into a loop, or use `return` to leave a function.
+
+  def f():
+    while True:
+      break
+    return 0"
+            }
+            ErrorCode::InvalidAssignTarget => {
+                "The left-hand side of `=` must be a name, an attribute (`obj.x`), an
+index (`xs[0]`) or a tuple of those for unpacking. Literals, calls and
+operators cannot be assigned to.
+
+  a = 1
+  b = 2
+  a + b = 3    # error
+
+  a, b = 1, 2
+  xs = [0]
+  xs[0] = a + b"
+            }
+            ErrorCode::MissingSelf => {
+                "Every method, including `__init__`, receives the instance as its first
+parameter, which must be named `self`.
+
+  class Point:
+    def norm():          # error: missing self
+      return 0
+
+  class Point:
+    def norm(self):
+      return 0"
+            }
+            ErrorCode::UndefinedName => {
+                "A name was used that is not a builtin and is not defined in any
+enclosing scope. This is usually a typo or a variable used before the
+statement that assigns it.
+
+  length = 3
+  print(lenght)    # error: did you mean `length`?
+
+When a similar name exists, the diagnostic suggests it.
+
+  length = 3
+  print(length)"
+            }
+            ErrorCode::UnboundLocal => {
+                "A name that is assigned anywhere in a function is local to the whole
+function. Reading it before the first assignment is an error, even if a
+global with the same name exists.
+
+  x = 1
+  def f():
+    print(x)     # error: x is local to f but not yet assigned
+    x = 2
+
+Assign the variable first, or use a different name for the local.
+
+  x = 1
+  def f():
+    y = 2
+    print(x, y)"
+            }
+            ErrorCode::UnknownModule => {
+                "`import` only loads the builtin modules; there are no user modules or
+packages. The only builtin module is `sys`.
+
+  import math      # error: no module named 'math'
+
+Define the helpers you need in the same file instead.
+
+  import sys
+  print(sys.argv)"
+            }
+            ErrorCode::OperandType => {
+                "An operator was applied to operand types that do not support it, such
+as adding a string and an integer.
+
+  print(\"age: \" + 3)        # error
+
+Convert one operand explicitly so both sides have compatible types.
+
+  print(\"age: \" + str(3))"
+            }
+            ErrorCode::ConditionType => {
+                "Conditions of `if`, `elif` and `while` must be booleans. Values such as
+integers are not implicitly truthy in conditions.
+
+  n = 3
+  if n:            # error: n is an int
+    print(n)
+
+Write the comparison you mean explicitly.
+
+  n = 3
+  if n != 0:
+    print(n)"
+            }
+            ErrorCode::IncompatibleTypes => {
+                "Two values that must share a type do not, for example the return values
+of a function or a variable assigned in both branches of an `if`.
+
+  def f(x):
+    if x > 0:
+      return 1
+    return \"negative\"   # error: int vs str
+
+Return or assign values of a single type.
+
+  def f(x):
+    if x > 0:
+      return 1
+    return -1"
+            }
+            ErrorCode::NotIterable => {
+                "A `for` loop (or a builtin that consumes an iterable) was given a value
+that cannot be iterated, such as an integer.
+
+  for i in 10:         # error
+    print(i)
+
+  for i in range(10):
+    print(i)"
+            }
+            ErrorCode::IndexType => {
+                "An index expression used a value that cannot be indexed, or an index of
+the wrong type, such as indexing a list with a string.
+
+  xs = [1, 2, 3]
+  print(xs[\"0\"])     # error: list indices must be integers
+
+  xs = [1, 2, 3]
+  print(xs[0])"
+            }
+            ErrorCode::ArgumentType => {
+                "A builtin function was called with an argument of a type it does not
+accept.
+
+  for i in range(\"3\"):      # error: range() arguments must be integers
+    print(i)
+
+  for i in range(3):
+    print(i)"
+            }
+            ErrorCode::InvalidFormatSpec => {
+                "A format specifier in an f-string field or a `format()` call does not
+follow the mini-language `[[fill]align][sign][#][0][width][,][.precision][type]`.
+Literal specifiers are checked at compile time.
+
+  x = 3.14159
+  print(f\"{x:.2q}\")        # error: unknown format code 'q'
+
+Supported types are d, f, e, g, x, o, b, c, s and %.
+
+  x = 3.14159
+  print(f\"{x:>8.2f}\")"
+            }
+            ErrorCode::ArityMismatch => {
+                "A function, method or constructor was called with a different number of
+positional arguments than it declares. For methods, `self` is not
+counted.
+
+  def add(a, b):
+    return a + b
+  add(1)           # error: takes 2 positional arguments but 1 was given
+
+  def add(a, b):
+    return a + b
+  add(1, 2)"
+            }
+            ErrorCode::UnknownAttribute => {
+                "An attribute or method was accessed that the object's type does not
+have. Instance attributes are those assigned as `self.x = ...` in a
+method or declared in the class body; builtin types have a fixed set of
+methods.
+
+  s = \"abc\"
+  s.uper()     # error: did you mean `upper`?
+
+  s = \"abc\"
+  s.upper()"
+            }
+            ErrorCode::UnexpectedKeyword => {
+                "A keyword argument (`name=value`) was passed to a callable that does not
+accept it. User-defined functions and most builtins take positional
+arguments only; `str.format` accepts named fields.
+
+  def add(a, b):
+    return a + b
+  add(1, b=2)                      # error
+
+  print(\"{x}\".format(x=1))"
+            }
+            ErrorCode::UnreachableCode => {
+                "Statements that follow `return`, `break` or `continue` in the same
+block can never run. This is a warning: the program still compiles.
+
+  def f():
+    return 1
+    print(\"done\")    # warning: unreachable
+
+Remove the dead code or move it before the jump.
+
+  def f():
+    print(\"done\")
+    return 1"
+            }
+            ErrorCode::AttributeOutsideClass => {
+                "An attribute assigned on an instance outside its class, which no
+method sets with `self.x = ...`. Python allows this, so it is only a
+warning, and later reads of the attribute are accepted. Often it is a
+typo or a field that only some objects get.
+
+  class P:
+    def __init__(self):
+      self.x = 1
+  p = P()
+  p.y = 2                          # warning
+  print(p.y)
+
+Set the attribute in `__init__` so every object has it.
+
+  class P:
+    def __init__(self):
+      self.x = 1
+      self.y = 0
+  p = P()
+  p.y = 2
+  print(p.y)"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: ErrorCode,
+    /// 주 라벨 메시지
+    pub message: String,
+    pub span: Range<usize>,
+    /// 보조 라벨 (예: "first defined here")
+    pub labels: Vec<(Range<usize>, String)>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+/// 시작이 끝보다 뒤인 범위를 바로잡습니다.
+///
+/// 파서는 입력 끝(EOI)의 범위와 앞쪽 토큰의 범위를 합쳐 `11..6` 같은 범위를 만들 수 있는데,
+/// ariadne는 이런 라벨에서 패닉하므로 진단을 만들 때 정규화합니다.
+fn ordered(span: Range<usize>) -> Range<usize> {
+    span.start.min(span.end)..span.start.max(span.end)
+}
+
+impl Diagnostic {
+    /// 코드의 기본 심각도로 진단을 만듭니다.
+    pub fn new(code: ErrorCode, message: impl Into<String>, span: Range<usize>) -> Self {
+        Self {
+            severity: code.severity(),
+            code,
+            message: message.into(),
+            span: ordered(span),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+        }
+    }
+
+    pub fn with_label(mut self, span: Range<usize>, message: impl Into<String>) -> Self {
+        self.labels.push((ordered(span), message.into()));
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    pub fn format(&self, path: &str, src: &str) -> String {
+        let kind = match self.severity {
+            Severity::Error => ReportKind::Error,
+            Severity::Warning => ReportKind::Warning,
+            Severity::Info => ReportKind::Advice,
+        };
+        let mut report = Report::build(kind, (path, self.span.clone()))
+            .with_config(ariadne::Config::new().with_index_type(ariadne::IndexType::Byte))
+            .with_code(self.code)
+            .with_message(self.code.title())
+            .with_label(
+                Label::new((path, self.span.clone()))
+                    .with_message(&self.message)
+                    .with_color(self.severity.color()),
+            );
+        for (span, message) in &self.labels {
+            report = report.with_label(
+                Label::new((path, span.clone()))
+                    .with_message(message)
+                    .with_color(Color::Blue),
+            );
+        }
+        for note in &self.notes {
+            report = report.with_note(note);
+        }
+        if let Some(help) = &self.help {
+            report = report.with_help(help);
+        }
+
+        let mut buffer = Vec::new();
+        report
+            .finish()
+            .write((path, Source::from(src)), &mut buffer)
+            .ok();
+        String::from_utf8_lossy(&buffer).to_string()
+    }
+}
+
+/// 후보 중 `name`과 가장 비슷한 이름을 찾습니다 ("did you mean ...?" 용).
+///
+/// 편집 거리가 이름 길이의 1/3 이하(최소 1)인 후보만 제안합니다.
+pub fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|c| *c != name)
+        .map(|c| (edit_distance(name, c), c))
+        .filter(|(d, _)| *d <= max_distance)
+        .min_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)))
+        .map(|(_, c)| c)
+}
+
+/// `suggest` 결과를 도움말 문장으로
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    suggest(name, candidates).map(|s| format!("did you mean `{}`?", s))
+}
+
+/// 인접 문자 교환을 1로 세는 편집 거리 (optimal string alignment)
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_lookup() {
+        assert_eq!(ErrorCode::from_code("E0201"), Some(ErrorCode::UndefinedName));
+        assert_eq!(ErrorCode::from_code("e0402"), Some(ErrorCode::UnknownAttribute));
+        assert_eq!(ErrorCode::from_code("E9999"), None);
+        assert_eq!(ErrorCode::UnreachableCode.severity(), Severity::Warning);
+        assert_eq!(ErrorCode::AttributeOutsideClass.severity(), Severity::Warning);
+        assert_eq!(ErrorCode::UndefinedName.severity(), Severity::Error);
+    }
+
+    #[test]
+    fn test_error_codes_are_unique() {
+        let mut codes: Vec<&str> = ErrorCode::ALL.iter().map(|c| c.as_str()).collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), ErrorCode::ALL.len());
+    }
+
+    /// 설명의 예제 블록 (두 칸 들여쓴 줄들, 빈 줄로 구분)
+    fn examples(text: &str) -> Vec<String> {
+        let mut blocks = vec![String::new()];
+        for line in text.lines() {
+            match line.strip_prefix("  ") {
+                Some(code) => {
+                    let block = blocks.last_mut().unwrap();
+                    block.push_str(code);
+                    block.push('\n');
+                }
+                None if !blocks.last().unwrap().is_empty() => blocks.push(String::new()),
+                None => {}
+            }
+        }
+        blocks.retain(|b| !b.is_empty());
+        blocks
+    }
+
+    #[test]
+    fn test_explanation_examples_compile() {
+        for code in ErrorCode::ALL {
+            let blocks = examples(code.explanation());
+            let (wrong, correct): (Vec<_>, Vec<_>) = blocks
+                .iter()
+                .partition(|b| b.contains("# error") || b.contains("# warning"));
+            assert!(!correct.is_empty(), "{} has no correct example", code);
+            for src in correct {
+                let program = crate::parse_source(src)
+                    .unwrap_or_else(|d| panic!("{} example does not parse: {:?}\n{}", code, d, src));
+                crate::analyze(&program)
+                    .unwrap_or_else(|d| panic!("{} example does not analyze: {:?}\n{}", code, d, src));
+            }
+            // 틀린 예제는 바로 그 코드의 진단을 냄
+            for src in wrong {
+                let diagnostics = match crate::parse_source(src) {
+                    Ok(program) => match crate::analyze(&program) {
+                        Ok(warnings) => warnings,
+                        Err(diagnostics) => diagnostics,
+                    },
+                    Err(diagnostics) => diagnostics,
+                };
+                assert!(
+                    diagnostics.iter().any(|d| d.code == *code),
+                    "{} example reports {:?}\n{}",
+                    code,
+                    diagnostics.iter().map(|d| d.code).collect::<Vec<_>>(),
+                    src
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_suggest() {
+        let names = ["length", "print", "len", "total"];
+        assert_eq!(suggest("lenght", names), Some("length"));
+        assert_eq!(suggest("pritn", names), Some("print"));
+        assert_eq!(suggest("xyz", names), None);
+        assert_eq!(suggest("len", names), None);
+    }
+
+    #[test]
+    fn test_format_includes_code_labels_notes_and_help() {
+        let src = "x = 1\nprint(y)\n";
+        let diag = Diagnostic::new(ErrorCode::UndefinedName, "Undefined variable: y", 12..13)
+            .with_label(0..1, "similar name defined here")
+            .with_note("names must be defined before use")
+            .with_help("did you mean `x`?");
+        let out = diag.format("test.pyh", src);
+        assert!(out.contains("E0201"));
+        assert!(out.contains("undefined name"));
+        assert!(out.contains("similar name defined here"));
+        assert!(out.contains("names must be defined before use"));
+        assert!(out.contains("did you mean `x`?"));
+    }
+
+    #[test]
+    fn test_unclosed_input_at_eof_formats() {
+        // 입력 끝에서 닫히지 않은 괄호/문자열은 시작이 끝보다 뒤인 범위를 만들 수 있음
+        for src in ["print(1\n", "print(\"abc\n", "x = (1,\n", "print('''x\n"] {
+            let diagnostics = crate::parse_source(src).unwrap_err();
+            for d in &diagnostics {
+                assert!(d.span.start <= d.span.end, "{:?} in {:?}", d.span, src);
+                d.format("test.pyh", src);
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)] // 파서가 만드는 뒤집힌 범위를 그대로 재현
+    fn test_reversed_spans_are_ordered() {
+        let diag = Diagnostic::new(ErrorCode::UnexpectedToken, "unexpected end of input", 11..6)
+            .with_label(9..3, "opened here");
+        assert_eq!(diag.span, 6..11);
+        assert_eq!(diag.labels[0].0, 3..9);
+        diag.format("test.pyh", "print(1, 2)");
+    }
+}
@@ -0,0 +1,304 @@
+//! 임베딩용 고수준 인터프리터
+//!
+//! 파싱 → 의미 분석 → 컴파일 → 실행을 한 번에 처리하고, REPL처럼 호출 사이에
+//! 전역 변수, 함수, 클래스를 유지합니다.
+//!
+//! REPL 상태([`ReplState`])를 그대로 쓰므로 `repl`처럼 wasm32 빌드에서는 제외됩니다.
+//! 웹 플레이그라운드는 `wasm_api`를 씁니다.
+//!
+//! ```
+//! use pyhyeon::Interpreter;
+//! use pyhyeon::runtime_io::BufferIo;
+//! use pyhyeon::vm::Value;
+//!
+//! let mut interp = Interpreter::with_io(BufferIo::new());
+//! interp.exec("def square(x):\n  return x * x\n").unwrap();
+//! interp.set_global("n", 7);
+//! assert_eq!(interp.eval("square(n) + 1").unwrap(), Value::Int(50));
+//! assert_eq!(interp.call("square", vec![Value::Int(3)]).unwrap(), Value::Int(9));
+//! ```
+
+use std::fmt;
+
+use crate::diagnostic::Diagnostic;
+use crate::parser::ast::Stmt;
+use crate::repl::ReplState;
+use crate::runtime_io::{RuntimeIo, StdIo};
+use crate::semantic::analyze_with_natives;
+use crate::vm::native::{HostRequest, IntoValue, NativeRegistry};
+use crate::vm::{Value, VmError};
+
+/// 인터프리터 에러: 실행 전 진단, 실행 중 에러, 없는 전역 이름
+#[derive(Debug)]
+pub enum InterpreterError {
+    /// 파싱 또는 의미 분석 실패. 진단은 `source`의 위치를 가리킴 (경고 포함)
+    Compile {
+        source: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+    /// 실행 중 에러
+    Runtime(VmError),
+    /// `get_global`/`call`에 넘긴 이름이 정의되지 않음
+    UndefinedGlobal(String),
+}
+
+impl InterpreterError {
+    /// 실행 전 진단 (실행 중 에러면 빈 목록)
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        match self {
+            InterpreterError::Compile { diagnostics, .. } => diagnostics,
+            _ => &[],
+        }
+    }
+
+    /// 진단을 소스 코드와 함께 보기 좋게 출력한 문자열 (CLI의 `run`과 같은 형식)
+    pub fn format(&self, path: &str) -> String {
+        match self {
+            InterpreterError::Compile {
+                source,
+                diagnostics,
+            } => diagnostics.iter().map(|d| d.format(path, source)).collect(),
+            other => format!("{}\n", other),
+        }
+    }
+}
+
+impl fmt::Display for InterpreterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpreterError::Compile { diagnostics, .. } => {
+                let messages: Vec<String> = diagnostics
+                    .iter()
+                    .filter(|d| d.is_error())
+                    .map(|d| format!("[{}] {}", d.code, d.message))
+                    .collect();
+                write!(f, "{}", messages.join("\n"))
+            }
+            InterpreterError::Runtime(err) => write!(f, "Runtime Error: {}", err.message),
+            InterpreterError::UndefinedGlobal(name) => {
+                write!(f, "NameError: name '{}' is not defined", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InterpreterError {}
+
+impl From<VmError> for InterpreterError {
+    fn from(err: VmError) -> Self {
+        InterpreterError::Runtime(err)
+    }
+}
+
+pub type InterpreterResult<T> = Result<T, InterpreterError>;
+
+/// 상태를 유지하는 인터프리터
+///
+/// 출력과 `input()`은 `IO`를 거칩니다 (기본은 표준 입출력).
+pub struct Interpreter<IO: RuntimeIo = StdIo> {
+    state: ReplState,
+    io: IO,
+}
+
+impl Default for Interpreter<StdIo> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interpreter<StdIo> {
+    /// 표준 입출력을 쓰는 인터프리터
+    pub fn new() -> Self {
+        Self::with_io(StdIo::new())
+    }
+}
+
+impl<IO: RuntimeIo> Interpreter<IO> {
+    /// 출력을 `io`로 보내는 인터프리터 (테스트에서는 `BufferIo`)
+    pub fn with_io(io: IO) -> Self {
+        Self {
+            state: ReplState::new(),
+            io,
+        }
+    }
+
+    /// 네이티브 함수/클래스 등록 (이후의 `exec`/`eval`에서 쓸 수 있음)
+    pub fn with_natives(mut self, natives: NativeRegistry) -> Self {
+        self.state.vm.natives = natives;
+        self
+    }
+
+    pub fn io(&self) -> &IO {
+        &self.io
+    }
+
+    pub fn io_mut(&mut self) -> &mut IO {
+        &mut self.io
+    }
+
+    /// 프로그램 실행. 정의한 전역 이름은 다음 호출에서도 보입니다.
+    pub fn exec(&mut self, source: &str) -> InterpreterResult<()> {
+        self.run(source, false).map(|_| ())
+    }
+
+    /// 마지막 문장이 표현식이면 그 값을 반환합니다 (아니면 `None`).
+    ///
+    /// 앞의 문장들은 `exec`처럼 실행됩니다.
+    pub fn eval(&mut self, source: &str) -> InterpreterResult<Value> {
+        self.run(source, true)
+    }
+
+    /// 네이티브 함수가 호스트에 요청하면서 멈춘 경우 그 요청
+    ///
+    /// `exec`/`eval`은 멈춘 시점에 `Ok`로 돌아오므로(`eval`의 값은 `None`), 요청을 처리한 뒤
+    /// `resume`으로 이어서 실행합니다. 그 전에 다시 `exec`/`eval`/`call`을 부르면 멈춘 실행은 버려집니다.
+    pub fn pending_request(&self) -> Option<&HostRequest> {
+        self.state.vm.host_request()
+    }
+
+    /// 멈춘 실행을 호스트의 응답으로 이어감. 다시 멈추면 `pending_request`에 새 요청이 있습니다.
+    ///
+    /// `eval`이 멈췄던 경우 끝까지 실행되면 그 표현식의 값을 반환합니다.
+    pub fn resume(&mut self, response: Result<Value, String>) -> InterpreterResult<Value> {
+        if self.pending_request().is_none() {
+            return Ok(Value::None);
+        }
+        self.state.vm.resume_host(response)?;
+        let ret = self.state.vm.run_with_io(&mut self.state.module, &mut self.io)?;
+        Ok(ret.unwrap_or(Value::None))
+    }
+
+    /// 전역 변수 값 (모듈 수준 `def`로 정의한 함수 포함)
+    pub fn get_global(&self, name: &str) -> Option<Value> {
+        let idx = *self.state.symbols.get(name)?;
+        self.state.module.globals[idx as usize].clone()
+    }
+
+    /// 전역 변수 설정. 없는 이름이면 새로 만들고, 이후 스크립트에서 쓸 수 있습니다.
+    pub fn set_global(&mut self, name: &str, value: impl IntoValue) {
+        let idx = match self.state.symbols.get(name) {
+            Some(&idx) => idx,
+            None => {
+                let module = &mut self.state.module;
+                let idx = module.symbols.len() as u16;
+                module.symbols.push(name.to_string());
+                module.globals.push(None);
+                self.state.symbols.insert(name.to_string(), idx);
+                idx
+            }
+        };
+        self.state.module.globals[idx as usize] = Some(value.into_value());
+    }
+
+    /// 이름으로 함수 호출: `call("add", vec![Value::Int(1), Value::Int(2)])`
+    pub fn call(&mut self, name: &str, args: Vec<Value>) -> InterpreterResult<Value> {
+        let func = self
+            .get_global(name)
+            .ok_or_else(|| InterpreterError::UndefinedGlobal(name.to_string()))?;
+        self.call_value(&func, args)
+    }
+
+    /// 함수 값(`get_global`로 꺼낸 함수나 람다) 호출
+    pub fn call_value(&mut self, func: &Value, args: Vec<Value>) -> InterpreterResult<Value> {
+        self.state.reset_vm();
+        let state = &mut self.state;
+        let result = state
+            .vm
+            .call_function(func, args, &mut state.module, &mut self.io);
+        self.io.flush();
+        Ok(result?)
+    }
+
+    fn run(&mut self, source: &str, want_value: bool) -> InterpreterResult<Value> {
+        let compile_error = |diagnostics| InterpreterError::Compile {
+            source: source.to_string(),
+            diagnostics,
+        };
+        let mut program = crate::parse_source(source).map_err(compile_error)?;
+        analyze_with_natives(&program, &self.state.global_names(), &self.state.vm.natives)
+            .map_err(compile_error)?;
+
+        // 마지막 표현식을 main의 반환값으로 컴파일 (의미 분석 뒤라 모듈 수준 return 검사를 피함)
+        if want_value
+            && let Some(last) = program.last_mut()
+            && let Stmt::Expr(expr) = &last.0
+        {
+            last.0 = Stmt::Return(expr.clone());
+        }
+
+        let module = self
+            .state
+            .compile_with_context(&program)
+            .expect("compilation does not fail after analysis");
+        self.state
+            .merge_module(module)
+            .expect("merged module symbols are consistent");
+
+        self.state.reset_vm();
+        let ret = self.state.vm.run_with_io(&mut self.state.module, &mut self.io)?;
+        Ok(if want_value {
+            ret.unwrap_or(Value::None)
+        } else {
+            Value::None
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime_io::BufferIo;
+
+    fn interp() -> Interpreter<BufferIo> {
+        Interpreter::with_io(BufferIo::new())
+    }
+
+    #[test]
+    fn test_state_persists_between_calls() {
+        let mut interp = interp();
+        interp.exec("xs = [1, 2]\nclass P:\n  def __init__(self, v):\n    self.v = v\n").unwrap();
+        interp.exec("xs.append(P(3).v)\nprint(xs)").unwrap();
+        assert_eq!(interp.io_mut().drain_output(), "[1, 2, 3]\n");
+        assert_eq!(interp.eval("len(xs)").unwrap(), Value::Int(3));
+        assert_eq!(interp.eval("y = 1").unwrap(), Value::None);
+        assert_eq!(interp.get_global("y"), Some(Value::Int(1)));
+    }
+
+    #[test]
+    fn test_globals_and_calls_by_name() {
+        let mut interp = interp();
+        interp.set_global("greeting", "hi");
+        interp.exec("def greet(name):\n  return greeting + ' ' + name\n").unwrap();
+        let result = interp.call("greet", vec!["bob".into_value()]).unwrap();
+        assert_eq!(crate::vm::utils::display_value(&result), "hi bob");
+
+        let add = interp.eval("lambda a, b: a + b").unwrap();
+        assert_eq!(
+            interp.call_value(&add, vec![Value::Int(2), Value::Int(3)]).unwrap(),
+            Value::Int(5)
+        );
+        // 나중에 정의한 함수도 제 코드를 가리킴
+        interp.exec("def inc(x):\n  return x + 1\n").unwrap();
+        assert_eq!(interp.call("inc", vec![Value::Int(1)]).unwrap(), Value::Int(2));
+        assert_eq!(interp.eval("inc(len(greet('x')))").unwrap(), Value::Int(5));
+        assert!(matches!(
+            interp.call("missing", vec![]),
+            Err(InterpreterError::UndefinedGlobal(name)) if name == "missing"
+        ));
+    }
+
+    #[test]
+    fn test_errors_carry_diagnostics() {
+        let mut interp = interp();
+        let error = interp.exec("print(undefined_name)").unwrap_err();
+        assert_eq!(error.diagnostics().len(), 1);
+        assert!(error.format("<embed>").contains("undefined_name"));
+
+        let error = interp.eval("1 // 0").unwrap_err();
+        assert!(matches!(error, InterpreterError::Runtime(_)));
+        assert!(error.to_string().starts_with("Runtime Error:"), "{}", error);
+
+        // 실패한 실행 뒤에도 계속 쓸 수 있음
+        assert_eq!(interp.eval("2 + 2").unwrap(), Value::Int(4));
+    }
+}
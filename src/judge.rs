@@ -0,0 +1,431 @@
+//! 온라인 저지 모드 (`pyhc judge`)
+//!
+//! 풀이 프로그램을 테스트 디렉터리의 모든 `*.in`/`*.out` 쌍에 대해 실행하고 채점합니다.
+//! 각 테스트는 새 VM에서 시간/메모리 제한(`vm::limits`)을 걸고 실행하며, 출력은 공백으로
+//! 나눈 토큰끼리 비교합니다 (줄바꿈, 공백 개수, 끝의 공백은 상관없음).
+//!
+//! 시간은 CPU 시간이라 채점 머신이 바빠도 결과가 같습니다. `[0] * (10 ** 11)`처럼 한 번에
+//! 제한보다 크게 할당하려는 풀이는 할당하기 전에 MLE가 됩니다.
+//!
+//! ```
+//! use pyhyeon::judge::{Judge, Verdict};
+//! use pyhyeon::vm::Limits;
+//!
+//! let judge = Judge::new("a, b = input().split()\nprint(int(a) + int(b))\n", Limits::default()).unwrap();
+//! assert_eq!(judge.run("1 2\n", "3\n").verdict, Verdict::Accepted);
+//! assert_eq!(judge.run("1 2\n", "4").verdict, Verdict::WrongAnswer);
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::diagnostic::Diagnostic;
+use crate::runtime_io::BufferIo;
+use crate::vm::bytecode::Module;
+use crate::vm::limits::{self, Limits};
+use crate::vm::{Vm, VmErrorKind};
+
+/// 채점 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// AC: 맞음
+    Accepted,
+    /// WA: 출력이 다름
+    WrongAnswer,
+    /// TLE: 시간 제한 초과
+    TimeLimitExceeded,
+    /// MLE: 메모리 제한 초과
+    MemoryLimitExceeded,
+    /// RE: 런타임 에러, 입력 부족, 0이 아닌 `sys.exit`
+    RuntimeError,
+    /// CE: 파싱/의미 분석 에러
+    CompileError,
+}
+
+impl Verdict {
+    /// 약어 (`AC`, `WA`, `TLE`, ...)
+    pub fn code(self) -> &'static str {
+        match self {
+            Verdict::Accepted => "AC",
+            Verdict::WrongAnswer => "WA",
+            Verdict::TimeLimitExceeded => "TLE",
+            Verdict::MemoryLimitExceeded => "MLE",
+            Verdict::RuntimeError => "RE",
+            Verdict::CompileError => "CE",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// 테스트 하나의 채점 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaseResult {
+    pub verdict: Verdict,
+    /// 실행에 쓴 CPU 시간 (유닉스가 아니면 경과 시간)
+    pub time: Duration,
+    /// 실행 중 늘어난 최대 메모리 (바이트). 잴 수 없으면 `None`
+    pub memory: Option<usize>,
+    /// WA의 첫 번째 다른 토큰, RE의 에러 메시지 등
+    pub detail: Option<String>,
+}
+
+/// 한 번 컴파일한 풀이를 여러 입력으로 실행
+pub struct Judge {
+    source: String,
+    module: Module,
+    limits: Limits,
+    argv0: String,
+}
+
+impl Judge {
+    /// 풀이를 컴파일. 실패하면 (경고를 포함한) 진단 목록 - 모든 테스트가 CE
+    pub fn new(source: &str, limits: Limits) -> Result<Self, Vec<Diagnostic>> {
+        let source = if source.ends_with('\n') {
+            source.to_string()
+        } else {
+            format!("{}\n", source)
+        };
+        let program = crate::parse_source(&source)?;
+        crate::analyze(&program)?;
+        Ok(Self {
+            module: crate::compile_to_module(&program),
+            source,
+            limits,
+            argv0: String::new(),
+        })
+    }
+
+    /// 풀이가 `sys.argv[0]`으로 볼 이름
+    pub fn with_argv0(mut self, argv0: impl Into<String>) -> Self {
+        self.argv0 = argv0.into();
+        self
+    }
+
+    /// `input`을 표준 입력으로 실행하고 출력을 `expected`와 비교
+    pub fn run(&self, input: &str, expected: &str) -> CaseResult {
+        let mut module = self.module.clone();
+        // CLI와 같이 출력을 모아 두었다가 내보냄
+        let mut io = BufferIo::new().with_buffered_output();
+        io.push_input(input);
+        let mut vm = Vm::new();
+        vm.set_argv(vec![self.argv0.clone()]);
+
+        limits::enable_counting();
+        limits::reset_peak();
+        let base = limits::allocated_bytes();
+        let timer = limits::CpuTimer::start();
+        vm.set_limits(self.limits);
+        let result = vm.run_with_io(&mut module, &mut io);
+        let time = timer.elapsed();
+        let memory = limits::memory_tracking().then(|| limits::peak_bytes().saturating_sub(base));
+
+        let line = |vm: &Vm| {
+            vm.last_position(&module)
+                .map(|pos| self.source[..pos].matches('\n').count() + 1)
+        };
+        let (verdict, detail) = match result {
+            Err(error) => match error.kind {
+                VmErrorKind::TimeLimitExceeded => (Verdict::TimeLimitExceeded, None),
+                VmErrorKind::MemoryLimitExceeded => (Verdict::MemoryLimitExceeded, None),
+                _ => (Verdict::RuntimeError, Some(at_line(line(&vm), &error.message))),
+            },
+            Ok(_) if vm.is_waiting_for_input() => (
+                Verdict::RuntimeError,
+                Some(at_line(line(&vm), "EOFError: input() read past the end of the input")),
+            ),
+            Ok(_) if vm.exit_code().is_some_and(|code| code != 0) => (
+                Verdict::RuntimeError,
+                Some(format!("exit status {}", vm.exit_code().unwrap_or_default())),
+            ),
+            // 검사 간격 사이에 끝났어도 제한은 지켜야 함
+            Ok(_) if self.limits.time.is_some_and(|limit| time > limit) => {
+                (Verdict::TimeLimitExceeded, None)
+            }
+            Ok(_) if self.limits.memory.zip(memory).is_some_and(|(limit, used)| used > limit) => {
+                (Verdict::MemoryLimitExceeded, None)
+            }
+            Ok(_) => match first_difference(expected, io.get_output()) {
+                None => (Verdict::Accepted, None),
+                Some(difference) => (Verdict::WrongAnswer, Some(difference)),
+            },
+        };
+        CaseResult {
+            verdict,
+            time,
+            memory,
+            detail,
+        }
+    }
+}
+
+fn at_line(line: Option<usize>, message: &str) -> String {
+    match line {
+        Some(line) => format!("line {}: {}", line, message),
+        None => message.to_string(),
+    }
+}
+
+/// 공백으로 나눈 토큰을 비교해서 처음 다른 곳 설명 (같으면 `None`)
+pub fn first_difference(expected: &str, actual: &str) -> Option<String> {
+    let mut expected_tokens = expected.split_whitespace();
+    let mut actual_tokens = actual.split_whitespace();
+    for index in 1.. {
+        match (expected_tokens.next(), actual_tokens.next()) {
+            (None, None) => return None,
+            (e, a) if e == a => {}
+            (e, a) => {
+                let show = |t: Option<&str>| t.map_or("end of output".to_string(), |t| format!("{:?}", t));
+                return Some(format!("token {}: expected {}, got {}", index, show(e), show(a)));
+            }
+        }
+    }
+    unreachable!("token iterators are finite")
+}
+
+/// 테스트 디렉터리의 `NAME.in`/`NAME.out` 쌍
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestPair {
+    pub name: String,
+    pub input: PathBuf,
+    pub expected: PathBuf,
+}
+
+/// 테스트 쌍을 이름순으로 찾음. 이름의 숫자는 값으로 비교합니다 (`2` < `10`).
+///
+/// `.out`이 없는 `.in`은 두 번째 목록으로 돌려줍니다.
+pub fn discover_cases(dir: &Path) -> std::io::Result<(Vec<TestPair>, Vec<PathBuf>)> {
+    let mut cases = Vec::new();
+    let mut missing = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let input = entry?.path();
+        if input.extension().and_then(|e| e.to_str()) != Some("in") {
+            continue;
+        }
+        let expected = input.with_extension("out");
+        let name = input
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if expected.is_file() {
+            cases.push(TestPair {
+                name,
+                input,
+                expected,
+            });
+        } else {
+            missing.push(input);
+        }
+    }
+    cases.sort_by_cached_key(|case| natural_key(&case.name));
+    missing.sort();
+    Ok((cases, missing))
+}
+
+/// 자연 정렬 키: 숫자 부분은 값으로 비교
+fn natural_key(name: &str) -> Vec<(u8, u128, String)> {
+    let mut key = Vec::new();
+    let mut chars = name.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        let digits = c.is_ascii_digit();
+        let mut part = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() != digits {
+                break;
+            }
+            part.push(c);
+            chars.next();
+        }
+        key.push(match part.parse::<u128>() {
+            Ok(n) if digits => (0, n, part),
+            _ => (1, 0, part),
+        });
+    }
+    key
+}
+
+/// 여러 테스트의 결과
+#[derive(Debug, Clone, Default)]
+pub struct JudgeReport {
+    /// (테스트 이름, 결과)
+    pub results: Vec<(String, CaseResult)>,
+}
+
+impl JudgeReport {
+    /// 전체 결과: 처음으로 AC가 아닌 테스트의 결과, 모두 맞으면 AC
+    pub fn verdict(&self) -> Verdict {
+        self.results
+            .iter()
+            .map(|(_, r)| r.verdict)
+            .find(|v| *v != Verdict::Accepted)
+            .unwrap_or(Verdict::Accepted)
+    }
+
+    pub fn accepted(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, r)| r.verdict == Verdict::Accepted)
+            .count()
+    }
+
+    /// 테스트마다 한 줄인 표와 요약 줄
+    pub fn table(&self) -> String {
+        let width = self
+            .results
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .chain([4])
+            .max()
+            .unwrap_or(4);
+        let mut out = format!("{:<width$}  {:<7}  {:>9}  {:>9}\n", "Test", "Verdict", "Time", "Memory");
+        for (name, result) in &self.results {
+            let _ = write!(
+                out,
+                "{:<width$}  {:<7}  {:>9}  {:>9}",
+                name,
+                result.verdict.code(),
+                format_time(result.time),
+                result.memory.map_or("-".to_string(), format_memory)
+            );
+            if let Some(detail) = &result.detail {
+                let _ = write!(out, "  {}", detail);
+            }
+            out.push('\n');
+        }
+        let max_time = self.results.iter().map(|(_, r)| r.time).max().unwrap_or_default();
+        let max_memory = self.results.iter().filter_map(|(_, r)| r.memory).max();
+        let _ = writeln!(
+            out,
+            "\n{}  {}/{} passed  (max time {}, max memory {})",
+            self.verdict(),
+            self.accepted(),
+            self.results.len(),
+            format_time(max_time),
+            max_memory.map_or("-".to_string(), format_memory)
+        );
+        out
+    }
+}
+
+fn format_time(time: Duration) -> String {
+    format!("{:.1} ms", time.as_secs_f64() * 1000.0)
+}
+
+fn format_memory(bytes: usize) -> String {
+    format!("{} KB", bytes.div_ceil(1024))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn judge(source: &str) -> Judge {
+        Judge::new(source, Limits::default()).unwrap()
+    }
+
+    #[test]
+    fn test_tokens_ignore_whitespace() {
+        assert_eq!(first_difference("1 2\n3\n", "1\n2 3"), None);
+        assert_eq!(first_difference("", "  \n"), None);
+        assert_eq!(
+            first_difference("1 2 3", "1 2 4\n").as_deref(),
+            Some("token 3: expected \"3\", got \"4\"")
+        );
+        assert_eq!(
+            first_difference("1 2", "1").as_deref(),
+            Some("token 2: expected \"2\", got end of output")
+        );
+        assert_eq!(
+            first_difference("1", "1 2").as_deref(),
+            Some("token 2: expected end of output, got \"2\"")
+        );
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let sum = judge("n = int(input())\ntotal = 0\nfor _ in range(n):\n  total += int(input())\nprint(total)\n");
+        assert_eq!(sum.run("2\n3\n4\n", "7").verdict, Verdict::Accepted);
+        let wrong = sum.run("1\n5\n", "6\n");
+        assert_eq!(wrong.verdict, Verdict::WrongAnswer);
+        assert_eq!(wrong.detail.as_deref(), Some("token 1: expected \"6\", got \"5\""));
+        let eof = sum.run("3\n1\n", "1\n");
+        assert_eq!(eof.verdict, Verdict::RuntimeError);
+        assert_eq!(
+            eof.detail.as_deref(),
+            Some("line 4: EOFError: input() read past the end of the input")
+        );
+
+        let crash = judge("print(1)\nx = [1][5]\n").run("", "1");
+        assert_eq!(crash.verdict, Verdict::RuntimeError);
+        assert!(crash.detail.unwrap().starts_with("line 2: "));
+        let exit = judge("import sys\nsys.exit(3)\n").run("", "");
+        assert_eq!((exit.verdict, exit.detail.as_deref()), (Verdict::RuntimeError, Some("exit status 3")));
+        assert_eq!(judge("import sys\nprint(1)\nsys.exit(0)\n").run("", "1").verdict, Verdict::Accepted);
+
+        let limits = Limits {
+            time: Some(Duration::from_millis(20)),
+            memory: None,
+        };
+        let spin = Judge::new("while True:\n  pass\n", limits).unwrap();
+        assert_eq!(spin.run("", "").verdict, Verdict::TimeLimitExceeded);
+
+        // 할당하기 전에 크기를 검사하므로 프로세스가 죽지 않음
+        let limits = Limits {
+            time: None,
+            memory: Some(256 << 20),
+        };
+        for source in [
+            "x = [0] * (10 ** 11)\n",
+            "s = 'ab' * (10 ** 11)\n",
+            "t = (1, 2) * (10 ** 11)\n",
+            "xs = list(range(10 ** 11))\n",
+            "print('x'.ljust(10 ** 11))\n",
+            "print(f'{1:>99999999999}')\n",
+        ] {
+            let huge = Judge::new(source, limits).unwrap();
+            assert_eq!(huge.run("", "").verdict, Verdict::MemoryLimitExceeded, "{}", source);
+        }
+        assert_eq!(
+            Judge::new("x = [0] * 1000\nprint(len(x))\n", limits).unwrap().run("", "1000").verdict,
+            Verdict::Accepted
+        );
+
+        let diagnostics = Judge::new("print(undefined)\n", Limits::default()).err().unwrap();
+        assert_eq!(diagnostics[0].code.as_str(), "E0201");
+    }
+
+    #[test]
+    fn test_natural_order_and_report() {
+        let mut names = vec!["10", "2", "a1", "1", "a10", "a2"];
+        names.sort_by_key(|n| natural_key(n));
+        assert_eq!(names, ["1", "2", "10", "a1", "a2", "a10"]);
+
+        let result = |verdict, ms| CaseResult {
+            verdict,
+            time: Duration::from_millis(ms),
+            memory: None,
+            detail: None,
+        };
+        let mut report = JudgeReport::default();
+        report.results.push(("1".into(), result(Verdict::Accepted, 3)));
+        report.results.push(("big".into(), result(Verdict::TimeLimitExceeded, 2001)));
+        report.results.push(("3".into(), result(Verdict::WrongAnswer, 1)));
+        assert_eq!(report.verdict(), Verdict::TimeLimitExceeded);
+        assert_eq!(
+            report.table(),
+            "\
+Test  Verdict       Time     Memory
+1     AC          3.0 ms          -
+big   TLE      2001.0 ms          -
+3     WA          1.0 ms          -
+
+TLE  1/3 passed  (max time 2001.0 ms, max memory -)
+"
+        );
+    }
+}
@@ -12,6 +12,10 @@ pub struct Lexer<'source> {
     token_queue: VecDeque<(Token, Range<usize>)>,
     indent_stack: Vec<usize>,
     at_start_of_line: bool,
+    /// 아직 닫히지 않은 `(`, `[`, `{`의 위치. 비어 있지 않으면 줄바꿈과 들여쓰기를 무시 (암묵적 줄 이어짐)
+    open_brackets: Vec<Range<usize>>,
+    /// 마지막으로 읽은 것이 줄 끝 백슬래시인지
+    after_continuation: bool,
 }
 
 impl<'source> Lexer<'source> {
@@ -21,9 +25,16 @@ impl<'source> Lexer<'source> {
             token_queue: VecDeque::new(),
             indent_stack: vec![0],
             at_start_of_line: true,
+            open_brackets: Vec::new(),
+            after_continuation: false,
         }
     }
 
+    /// 입력이 줄 끝 백슬래시로 끝나 다음 줄이 이어져야 하는지
+    pub fn ends_with_continuation(&self) -> bool {
+        self.after_continuation
+    }
+
     pub fn next_token(&mut self) -> Token {
         let (tok, _) = self.next_token_with_span();
         tok
@@ -41,12 +52,37 @@ impl<'source> Lexer<'source> {
             }
         }
 
-        match self.inner.next() {
+        let next = self.inner.next();
+        if next.is_some() {
+            self.after_continuation = false;
+        }
+        match next {
+            Some(Ok(RawToken::LineContinuation)) => {
+                self.after_continuation = true;
+                self.next_token_with_span()
+            }
+            // 괄호 안의 줄바꿈은 문장의 끝이 아님
+            Some(Ok(RawToken::Newline)) if !self.open_brackets.is_empty() => {
+                self.next_token_with_span()
+            }
+            Some(Ok(RawToken::FString(raw))) => {
+                let span = self.inner.span();
+                self.token_queue.extend(expand_fstring(&raw, span));
+                self.token_queue.pop_front().expect("f-string yields at least one token")
+            }
             Some(Ok(raw_token)) => {
                 let span = self.inner.span();
                 let token = Self::convert_token(raw_token);
-                if token == Token::Newline {
-                    self.at_start_of_line = true;
+                match token {
+                    Token::Newline => self.at_start_of_line = true,
+                    Token::LParen | Token::LBracket | Token::LBrace => {
+                        self.open_brackets.push(span.clone());
+                    }
+                    // 짝이 맞지 않는 닫는 괄호는 파서가 보고
+                    Token::RParen | Token::RBracket | Token::RBrace => {
+                        self.open_brackets.pop();
+                    }
+                    _ => {}
                 }
                 (token, span)
             }
@@ -55,9 +91,20 @@ impl<'source> Lexer<'source> {
                 // Check if a specific error message was stored in extras
                 let error_msg = self.inner.extras.error_message.take()
                     .unwrap_or_else(|| format!("Invalid token '{}'", self.inner.slice()));
-                (Token::Error(error_msg, span.clone()), span)
+                // 리터럴의 일부(잘못된 숫자, 이스케이프)를 가리키는 위치가 있으면 그쪽을 보고
+                let error_span = self.inner.extras.error_span.take().unwrap_or_else(|| span.clone());
+                (Token::Error(error_msg, error_span), span)
             }
             None => {
+                // 입력이 끝났는데 괄호가 열려 있으면 가장 안쪽 괄호를 가리킴
+                if let Some(open) = self.open_brackets.pop() {
+                    let bracket = &self.inner.source()[open.clone()];
+                    self.open_brackets.clear();
+                    return (
+                        Token::Error(format!("'{}' was never closed", bracket), open.clone()),
+                        open,
+                    );
+                }
                 while self.indent_stack.len() > 1 {
                     self.indent_stack.pop();
                     // Use the current cursor position as zero-length span for dedent
@@ -161,6 +208,7 @@ impl<'source> Lexer<'source> {
             RawToken::While => Token::While,
             RawToken::For => Token::For,
             RawToken::In => Token::In,
+            RawToken::Is => Token::Is,
             RawToken::Def => Token::Def,
             RawToken::Return => Token::Return,
             RawToken::And => Token::And,
@@ -171,10 +219,12 @@ impl<'source> Lexer<'source> {
             RawToken::Continue => Token::Continue,
             RawToken::Pass => Token::Pass,
             RawToken::Lambda => Token::Lambda,
+            RawToken::Import => Token::Import,
 
             RawToken::Bool(b) => Token::Bool(b),
             RawToken::Int(i) => Token::Int(i),
             RawToken::String(s) => Token::String(s),
+            RawToken::FString(_) => unreachable!("f-strings are expanded by expand_fstring"),
             RawToken::Identifier(name) => Token::Identifier(name),
             RawToken::Float(f) => Token::Float(f),
             RawToken::Plus => Token::Plus,
@@ -183,6 +233,25 @@ impl<'source> Lexer<'source> {
             RawToken::SlashSlash => Token::SlashSlash,
             RawToken::Slash => Token::Slash,
             RawToken::Percent => Token::Percent,
+            RawToken::StarStar => Token::StarStar,
+            RawToken::Amp => Token::Amp,
+            RawToken::Pipe => Token::Pipe,
+            RawToken::Caret => Token::Caret,
+            RawToken::Tilde => Token::Tilde,
+            RawToken::LessLess => Token::LessLess,
+            RawToken::GreaterGreater => Token::GreaterGreater,
+            RawToken::PlusEqual => Token::PlusEqual,
+            RawToken::MinusEqual => Token::MinusEqual,
+            RawToken::StarEqual => Token::StarEqual,
+            RawToken::SlashEqual => Token::SlashEqual,
+            RawToken::SlashSlashEqual => Token::SlashSlashEqual,
+            RawToken::PercentEqual => Token::PercentEqual,
+            RawToken::StarStarEqual => Token::StarStarEqual,
+            RawToken::AmpEqual => Token::AmpEqual,
+            RawToken::PipeEqual => Token::PipeEqual,
+            RawToken::CaretEqual => Token::CaretEqual,
+            RawToken::LessLessEqual => Token::LessLessEqual,
+            RawToken::GreaterGreaterEqual => Token::GreaterGreaterEqual,
             RawToken::EqualEqual => Token::EqualEqual,
             RawToken::NotEqual => Token::NotEqual,
             RawToken::Less => Token::Less,
@@ -197,15 +266,278 @@ impl<'source> Lexer<'source> {
             RawToken::LBrace => Token::LBrace,
             RawToken::RBrace => Token::RBrace,
             RawToken::Colon => Token::Colon,
+            RawToken::ColonEqual => Token::ColonEqual,
             RawToken::Comma => Token::Comma,
             RawToken::Semicolon => Token::Semicolon,
             RawToken::Dot => Token::Dot,
 
             RawToken::Newline => Token::Newline,
+            RawToken::LineContinuation => {
+                unreachable!("line continuations are skipped by next_token_with_span")
+            }
         }
     }
 }
 
+/// f-string 원문(`f"` 와 `"` 사이)을 토큰들로 펼칩니다.
+///
+/// 리터럴 부분은 `FStringText`가 되고, `{...}` 안의 표현식은 일반 토큰으로 토큰화되어
+/// 파서가 보통의 표현식처럼 파싱합니다. 모든 span은 원본 소스 기준입니다.
+fn expand_fstring(raw: &str, span: Range<usize>) -> Vec<(Token, Range<usize>)> {
+    let base = span.start + 2; // `f"` 다음
+    let mut tokens = vec![(Token::FStringStart, span.start..base)];
+    let mut text = String::new();
+    let mut text_start = base;
+    let mut i = 0;
+
+    let flush = |text: &mut String, start: usize, end: usize, tokens: &mut Vec<_>| {
+        if !text.is_empty() {
+            match raw_token::process_string_escapes(text) {
+                Ok(processed) => tokens.push((Token::FStringText(processed), start..end)),
+                Err((message, at)) => {
+                    let at = start + at.start..start + at.end;
+                    tokens.push((Token::Error(message, at.clone()), at));
+                }
+            }
+            text.clear();
+        }
+    };
+
+    while let Some(ch) = raw[i..].chars().next() {
+        if text.is_empty() {
+            text_start = base + i;
+        }
+        let next = raw[i + ch.len_utf8()..].chars().next();
+        match ch {
+            '{' if next == Some('{') => {
+                text.push('{');
+                i += 2;
+            }
+            '}' if next == Some('}') => {
+                text.push('}');
+                i += 2;
+            }
+            '}' => {
+                let at = base + i;
+                tokens.push((
+                    Token::Error("f-string: single '}' is not allowed".to_string(), at..at + 1),
+                    at..at + 1,
+                ));
+                i += 1;
+            }
+            '{' => {
+                flush(&mut text, text_start, base + i, &mut tokens);
+                match scan_fstring_field(raw, i + 1) {
+                    Ok(field) => {
+                        if field.debug {
+                            // `{x=}` → "x=" 텍스트 + repr(x)
+                            let debug_text = &raw[i + 1..field.expr_end + 1];
+                            let debug_end = base + field.expr_end + 1;
+                            match tokens.last_mut() {
+                                Some((Token::FStringText(prev), prev_span)) => {
+                                    prev.push_str(debug_text);
+                                    prev_span.end = debug_end;
+                                }
+                                _ => tokens.push((
+                                    Token::FStringText(debug_text.to_string()),
+                                    base + i + 1..debug_end,
+                                )),
+                            }
+                        }
+                        tokens.push((Token::FStringExprStart, base + i..base + i + 1));
+                        tokens.extend(lex_embedded(
+                            &raw[i + 1..field.expr_end],
+                            base + i + 1,
+                        ));
+                        let conversion = match field.conversion {
+                            Some(c) => Some(c),
+                            None if field.debug && field.spec.is_none() => Some('r'),
+                            None => None,
+                        };
+                        if let Some(c) = conversion {
+                            tokens.push((Token::FStringConv(c), base + field.expr_end..base + field.close));
+                        }
+                        if let Some((spec, start)) = field.spec {
+                            tokens.push((Token::FStringSpec(spec), base + start..base + field.close));
+                        }
+                        tokens.push((Token::FStringExprEnd, base + field.close..base + field.close + 1));
+                        i = field.close + 1;
+                    }
+                    Err((message, at)) => {
+                        let at = base + at;
+                        tokens.push((Token::Error(message, at..at + 1), at..at + 1));
+                        // 필드를 닫을 수 없으면 나머지는 건너뜀
+                        i = raw.len();
+                    }
+                }
+            }
+            // `\N{...}`의 중괄호는 필드가 아니라 이스케이프의 일부
+            '\\' if raw[i + 1..].starts_with("N{") && raw[i..].contains('}') => {
+                let close = i + raw[i..].find('}').unwrap_or(0);
+                text.push_str(&raw[i..=close]);
+                i = close + 1;
+            }
+            '\\' => {
+                // 이스케이프는 flush 시 처리. `\{`의 중괄호는 필드 시작이므로 건드리지 않음
+                text.push('\\');
+                i += 1;
+                if let Some(escaped) = next
+                    && escaped != '{'
+                    && escaped != '}'
+                {
+                    text.push(escaped);
+                    i += escaped.len_utf8();
+                }
+            }
+            _ => {
+                text.push(ch);
+                i += ch.len_utf8();
+            }
+        }
+    }
+    flush(&mut text, text_start, base + i, &mut tokens);
+    tokens.push((Token::FStringEnd, span.end - 1..span.end));
+    tokens
+}
+
+/// f-string의 `{...}` 필드 하나의 위치 정보 (모두 원문 기준 바이트 오프셋)
+struct FStringField {
+    /// 표현식 끝 (`!`, `:`, `=`, `}` 중 하나의 위치)
+    expr_end: usize,
+    /// `{x=}` 디버그 표기
+    debug: bool,
+    conversion: Option<char>,
+    /// (spec 문자열, spec 시작 위치)
+    spec: Option<(String, usize)>,
+    /// 닫는 `}` 위치
+    close: usize,
+}
+
+/// `{` 다음 위치부터 필드를 스캔합니다. 괄호 깊이와 문자열을 고려합니다.
+fn scan_fstring_field(raw: &str, start: usize) -> Result<FStringField, (String, usize)> {
+    let unclosed = || ("f-string: expecting '}'".to_string(), start - 1);
+    let chars: Vec<(usize, char)> = raw[start..]
+        .char_indices()
+        .map(|(i, c)| (start + i, c))
+        .collect();
+    let char_at = |k: usize| chars.get(k).map(|&(_, c)| c);
+
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    let mut k = 0;
+    let mut debug = false;
+    let expr_end = loop {
+        let Some(&(pos, ch)) = chars.get(k) else {
+            return Err(unclosed());
+        };
+        if let Some(q) = quote {
+            if ch == q {
+                quote = None;
+            }
+            k += 1;
+            continue;
+        }
+        match ch {
+            '\'' | '"' => quote = Some(ch),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            '}' if depth > 0 => depth -= 1,
+            '}' => break pos,
+            '!' if depth == 0 && char_at(k + 1) != Some('=') => break pos,
+            ':' if depth == 0 => break pos,
+            '=' if depth == 0
+                && matches!(char_at(k + 1), Some('}' | '!' | ':'))
+                && !matches!(k.checked_sub(1).and_then(char_at), Some('=' | '!' | '<' | '>')) =>
+            {
+                debug = true;
+                break pos;
+            }
+            _ => {}
+        }
+        k += 1;
+    };
+
+    if raw[start..expr_end].trim().is_empty() {
+        return Err(("f-string: empty expression not allowed".to_string(), start - 1));
+    }
+
+    let mut k = chars.iter().position(|&(pos, _)| pos == expr_end).unwrap_or(chars.len());
+    if debug {
+        k += 1;
+    }
+    let mut conversion = None;
+    if char_at(k) == Some('!') {
+        match char_at(k + 1) {
+            Some(c @ ('r' | 's' | 'a')) => conversion = Some(c),
+            _ => {
+                return Err((
+                    "f-string: invalid conversion character: expected 's', 'r', or 'a'".to_string(),
+                    chars.get(k).map_or(start, |&(pos, _)| pos),
+                ));
+            }
+        }
+        k += 2;
+    }
+    let mut spec = None;
+    if char_at(k) == Some(':') {
+        let spec_start = chars.get(k + 1).map_or(raw.len(), |&(pos, _)| pos);
+        k += 1;
+        while let Some(c) = char_at(k) {
+            if c == '}' {
+                break;
+            }
+            if c == '{' {
+                return Err((
+                    "f-string: nested replacement fields in format spec are not supported"
+                        .to_string(),
+                    chars[k].0,
+                ));
+            }
+            k += 1;
+        }
+        let spec_end = chars.get(k).map_or(raw.len(), |&(pos, _)| pos);
+        spec = Some((raw[spec_start..spec_end].to_string(), spec_start));
+    }
+    match chars.get(k) {
+        Some(&(close, '}')) => Ok(FStringField {
+            expr_end,
+            debug,
+            conversion,
+            spec,
+            close,
+        }),
+        _ => Err(unclosed()),
+    }
+}
+
+/// f-string 안의 표현식을 토큰화합니다. span은 `offset`만큼 옮겨 원본 기준으로 맞춥니다.
+fn lex_embedded(src: &str, offset: usize) -> Vec<(Token, Range<usize>)> {
+    let mut inner = RawToken::lexer(src);
+    let mut tokens = Vec::new();
+    while let Some(result) = inner.next() {
+        let span = inner.span().start + offset..inner.span().end + offset;
+        match result {
+            Ok(RawToken::FString(raw)) => tokens.extend(expand_fstring(&raw, span)),
+            Ok(RawToken::LineContinuation) => {}
+            Ok(raw_token) => tokens.push((Lexer::convert_token(raw_token), span)),
+            Err(_) => {
+                let message = inner
+                    .extras
+                    .error_message
+                    .take()
+                    .unwrap_or_else(|| format!("Invalid token '{}'", inner.slice()));
+                let error_span = inner
+                    .extras
+                    .error_span
+                    .take()
+                    .map_or_else(|| span.clone(), |at| at.start + offset..at.end + offset);
+                tokens.push((Token::Error(message, error_span), span));
+            }
+        }
+    }
+    tokens
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -340,9 +672,14 @@ def sub(a, b):
 
     #[test]
     fn test_integer_overflow_edge_case() {
-        // i64::MAX + 1
+        // i64::MAX + 1은 `-`와 합쳐 i64::MIN이 될 수 있으므로 파서로 넘김 (범위 검사는 파서에서)
         let source = "9223372036854775808";
         let mut lexer = Lexer::new(source);
+        assert_eq!(lexer.next_token(), Token::Int(i64::MIN));
+
+        // i64::MAX + 2부터는 렉서에서 바로 에러
+        let source = "9223372036854775809";
+        let mut lexer = Lexer::new(source);
         let token = lexer.next_token();
         match token {
             Token::Error(msg, _) => {
@@ -368,6 +705,7 @@ def sub(a, b):
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_float_normal() {
         let source = "3.14159";
         let mut lexer = Lexer::new(source);
@@ -392,4 +730,282 @@ def sub(a, b):
             _ => panic!("Expected float token, got: {:?}", token),
         }
     }
+
+    #[test]
+    fn test_unterminated_string_stops_at_line_end() {
+        // 닫히지 않은 문자열은 에러가 되고, 다음 줄은 정상적으로 토큰화됨
+        let source = "x = \"abc\ny = 1\n";
+        let mut lexer = Lexer::new(source);
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token == Token::Eof {
+                break;
+            }
+            tokens.push(token);
+        }
+        assert!(matches!(&tokens[2], Token::Error(msg, _) if msg.contains("Unterminated string")));
+        assert!(tokens.contains(&Token::Identifier("y".to_string())));
+    }
+
+    fn collect(source: &str) -> Vec<Token> {
+        let mut lexer = Lexer::new(source);
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token == Token::Eof {
+                break;
+            }
+            tokens.push(token);
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_operator_tokens() {
+        assert_eq!(
+            collect("x **= 2 ** -1 >> 3 <<= ~y & z | w ^ v //= 1"),
+            [
+                Token::Identifier("x".to_string()),
+                Token::StarStarEqual,
+                Token::Int(2),
+                Token::StarStar,
+                Token::Minus,
+                Token::Int(1),
+                Token::GreaterGreater,
+                Token::Int(3),
+                Token::LessLessEqual,
+                Token::Tilde,
+                Token::Identifier("y".to_string()),
+                Token::Amp,
+                Token::Identifier("z".to_string()),
+                Token::Pipe,
+                Token::Identifier("w".to_string()),
+                Token::Caret,
+                Token::Identifier("v".to_string()),
+                Token::SlashSlashEqual,
+                Token::Int(1),
+            ]
+        );
+        assert_eq!(
+            collect("(n := 1):"),
+            [
+                Token::LParen,
+                Token::Identifier("n".to_string()),
+                Token::ColonEqual,
+                Token::Int(1),
+                Token::RParen,
+                Token::Colon,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fstring_tokens() {
+        let tokens = collect("f\"a{{b}} {x!r:>5} {y=}\"");
+        assert_eq!(
+            tokens,
+            [
+                Token::FStringStart,
+                Token::FStringText("a{b} ".to_string()),
+                Token::FStringExprStart,
+                Token::Identifier("x".to_string()),
+                Token::FStringConv('r'),
+                Token::FStringSpec(">5".to_string()),
+                Token::FStringExprEnd,
+                Token::FStringText(" y=".to_string()),
+                Token::FStringExprStart,
+                Token::Identifier("y".to_string()),
+                Token::FStringConv('r'),
+                Token::FStringExprEnd,
+                Token::FStringEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fstring_errors() {
+        let has_error = |src: &str, needle: &str| {
+            collect(src)
+                .iter()
+                .any(|t| matches!(t, Token::Error(msg, _) if msg.contains(needle)))
+        };
+        assert!(has_error("f\"{x\"", "expecting '}'"));
+        assert!(has_error("f\"}\"", "single '}'"));
+        assert!(has_error("f\"{}\"", "empty expression"));
+        assert!(has_error("f\"{x!z}\"", "conversion"));
+        assert!(has_error("f\"{x:{w}}\"", "nested replacement fields"));
+    }
+
+    #[test]
+    fn test_number_literal_forms() {
+        assert_eq!(
+            collect("0xFF 0o17 0b1010 1_000_000 0x_ff 0 00"),
+            [255, 15, 10, 1_000_000, 255, 0, 0].map(Token::Int)
+        );
+        assert_eq!(
+            collect("1e9 .5 1. 2.5e-3 1_0.0_1"),
+            [1e9, 0.5, 1.0, 2.5e-3, 10.01].map(Token::Float)
+        );
+    }
+
+    fn first_error(source: &str) -> (String, Range<usize>) {
+        match collect(source).into_iter().next() {
+            Some(Token::Error(msg, span)) => (msg, span),
+            other => panic!("expected error for {:?}, got {:?}", source, other),
+        }
+    }
+
+    #[test]
+    fn test_number_literal_errors_point_at_the_problem() {
+        let (msg, span) = first_error("0b102");
+        assert!(msg.contains("Invalid digit '2' in binary literal"), "{}", msg);
+        assert_eq!(span, 4..5);
+        assert_eq!(first_error("1__0").1, 1..2);
+        assert_eq!(first_error("1_").1, 1..2);
+        assert!(first_error("0x").0.contains("Invalid hexadecimal literal"));
+        assert!(first_error("012").0.contains("Leading zeros"));
+        assert!(first_error("1e+").0.contains("exponent has no digits"));
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        assert_eq!(
+            collect(r#""\x41\u00e9\U0001F600\101\a\N{bullet}\q""#),
+            [Token::String("Aé😀A\x07•\\q".to_string())]
+        );
+        assert_eq!(
+            first_error(r#""ab\x4""#),
+            ("truncated \\xXX escape".to_string(), 3..6)
+        );
+        assert_eq!(
+            collect(r#""\N{LATIN CAPITAL LETTER A}\N{hangul syllable ga}""#),
+            [Token::String("A가".to_string())]
+        );
+        assert_eq!(first_error(r#""\uD800""#).1, 1..7);
+        assert!(first_error(r#""\N{NO SUCH NAME}""#).0.contains("unknown Unicode character name"));
+        assert!(first_error(r#""\N""#).0.contains("malformed"));
+    }
+
+    #[test]
+    fn test_raw_and_triple_quoted_strings() {
+        assert_eq!(
+            collect(r#"r"a\nb" R'\d+' r"\"""#),
+            ["a\\nb", "\\d+", "\\\""].map(|s| Token::String(s.to_string()))
+        );
+        // 세 따옴표 문자열 안의 줄바꿈은 들여쓰기 토큰을 만들지 않음
+        assert_eq!(
+            collect("x = \"\"\"a\n  \"b\"\\\nc\"\"\"\ny = r'''\\n'''\n"),
+            [
+                Token::Identifier("x".to_string()),
+                Token::Equal,
+                Token::String("a\n  \"b\"c".to_string()),
+                Token::Newline,
+                Token::Identifier("y".to_string()),
+                Token::Equal,
+                Token::String("\\n".to_string()),
+                Token::Newline,
+            ]
+        );
+        match collect("x = '''abc\ny = 1\n").into_iter().nth(2) {
+            Some(Token::Error(msg, span)) => {
+                assert!(msg.contains("Unterminated triple-quoted"), "{}", msg);
+                assert_eq!(span, 4..7);
+            }
+            other => panic!("expected error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_fstring_unicode_name_escape() {
+        assert_eq!(
+            collect("f\"\\N{EM DASH}{x}\""),
+            [
+                Token::FStringStart,
+                Token::FStringText("—".to_string()),
+                Token::FStringExprStart,
+                Token::Identifier("x".to_string()),
+                Token::FStringExprEnd,
+                Token::FStringEnd,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_newlines_inside_brackets_are_ignored() {
+        let source = "xs = [\n  1,\n    2,  # note\n]\nf(a,\n\n  b)\n";
+        assert_eq!(
+            collect(source),
+            [
+                Token::Identifier("xs".to_string()),
+                Token::Equal,
+                Token::LBracket,
+                Token::Int(1),
+                Token::Comma,
+                Token::Int(2),
+                Token::Comma,
+                Token::RBracket,
+                Token::Newline,
+                Token::Identifier("f".to_string()),
+                Token::LParen,
+                Token::Identifier("a".to_string()),
+                Token::Comma,
+                Token::Identifier("b".to_string()),
+                Token::RParen,
+                Token::Newline,
+            ]
+        );
+        // 스팬은 원래 줄의 위치를 유지
+        let mut lexer = Lexer::new(source);
+        let spans: Vec<_> = std::iter::from_fn(|| match lexer.next_token_with_span() {
+            (Token::Eof, _) => None,
+            token => Some(token),
+        })
+        .collect();
+        assert_eq!(spans[5], (Token::Int(2), 16..17));
+    }
+
+    #[test]
+    fn test_backslash_continuation() {
+        let mut lexer = Lexer::new("if a and \\\n    b:\n  x = 1 + \\\n");
+        let mut tokens = Vec::new();
+        loop {
+            match lexer.next_token() {
+                Token::Eof => break,
+                token => tokens.push(token),
+            }
+        }
+        assert_eq!(tokens[..5], [
+            Token::If,
+            Token::Identifier("a".to_string()),
+            Token::And,
+            Token::Identifier("b".to_string()),
+            Token::Colon,
+        ]);
+        assert_eq!(tokens[5..7], [Token::Newline, Token::Indent]);
+        assert!(lexer.ends_with_continuation());
+
+        let (msg, span) = first_error("\\ x");
+        assert!(msg.contains("after line continuation"), "{}", msg);
+        assert_eq!(span, 0..1);
+    }
+
+    #[test]
+    fn test_unclosed_bracket_reported_at_eof() {
+        let (tokens, spans): (Vec<_>, Vec<_>) = {
+            let mut lexer = Lexer::new("f([1, {2: 3}
+x
+");
+            std::iter::from_fn(|| match lexer.next_token_with_span() {
+                (Token::Eof, _) => None,
+                token => Some(token),
+            })
+            .unzip()
+        };
+        let last = tokens.last().unwrap();
+        assert!(matches!(last, Token::Error(msg, _) if msg == "'[' was never closed"));
+        assert_eq!(spans.last(), Some(&(2..3)));
+        // 짝이 맞지 않는 닫는 괄호는 파서가 보고
+        assert!(!collect(")]\nx\n").iter().any(|t| matches!(t, Token::Error(..))));
+    }
 }
@@ -1,9 +1,12 @@
 use logos::Logos;
+use std::ops::Range;
 
 /// Stores error context during lexing to provide better error messages
 #[derive(Default, Debug, Clone)]
 pub struct LexerExtras {
     pub error_message: Option<String>,
+    /// 에러가 리터럴 전체가 아니라 일부(잘못된 숫자, 이스케이프)를 가리킬 때의 위치
+    pub error_span: Option<Range<usize>>,
 }
 
 #[derive(Logos, Debug, PartialEq, Clone)]
@@ -24,6 +27,8 @@ pub enum RawToken {
     For,
     #[token("in")]
     In,
+    #[token("is")]
+    Is,
     #[token("def")]
     Def,
     #[token("return")]
@@ -44,6 +49,8 @@ pub enum RawToken {
     Pass,
     #[token("lambda")]
     Lambda,
+    #[token("import")]
+    Import,
 
     // Identifiers and literals
     #[token("None")]
@@ -51,14 +58,36 @@ pub enum RawToken {
     #[token("True", |_| true)]
     #[token("False", |_| false)]
     Bool(bool),
-    // #[regex(r"[0-9]+", lex_integer)]
-    #[regex(r"[+-]?[0-9]+", lex_integer)]
+    // 부호는 단항 연산자로 파싱: `x -1`, `-2 ** 2 == -4`
+    // 진법 접두사 뒤는 넓게 받아 콜백에서 잘못된 숫자를 정확한 위치로 보고: `0b102`
+    #[regex(r"[0-9][0-9_]*", lex_integer)]
+    #[regex(r"0[xXoObB][0-9a-zA-Z_]*", lex_integer)]
     Int(i64),
-    #[regex(r#""([^"\\]|\\.)*""#, lex_string)]
-    #[regex(r#"'([^'\\]|\\.)*'"#, lex_string)]
+    #[regex(r#""([^"\\\n]|\\.)*""#, lex_string)]
+    #[regex(r#"'([^'\\\n]|\\.)*'"#, lex_string)]
+    // 줄 끝까지 닫히지 않은 문자열: 에러로 보고하고 다음 줄부터 계속 토큰화
+    #[regex(r#""([^"\\\n]|\\.)*"#, lex_unterminated_string)]
+    #[regex(r#"'([^'\\\n]|\\.)*"#, lex_unterminated_string)]
+    // raw 문자열: 이스케이프를 처리하지 않음 (`\"`는 닫는 따옴표가 아님)
+    #[regex(r#"[rR]"([^"\\\n]|\\.)*""#, lex_raw_string)]
+    #[regex(r#"[rR]'([^'\\\n]|\\.)*'"#, lex_raw_string)]
+    #[regex(r#"[rR]"([^"\\\n]|\\.)*"#, lex_unterminated_string)]
+    #[regex(r#"[rR]'([^'\\\n]|\\.)*"#, lex_unterminated_string)]
+    // 세 따옴표 문자열은 여러 줄에 걸칠 수 있어 닫는 따옴표까지 직접 스캔
+    #[regex(r#"[rR]?("""|''')"#, lex_triple_string)]
     String(String),
-    #[regex(r"[0-9]+\.[0-9]+", lex_float)]
-    #[regex(r"[0-9]+\.[0-9]+[eE][+-]?[0-9]+", lex_float)]
+    /// f-string 원문 (접두사와 따옴표 제외, 이스케이프 미처리). 렉서가 토큰들로 펼칩니다.
+    #[regex(r#"[fF]"([^"\\\n]|\\.)*""#, lex_fstring)]
+    #[regex(r#"[fF]'([^'\\\n]|\\.)*'"#, lex_fstring)]
+    #[regex(r#"[fF]"([^"\\\n]|\\.)*"#, lex_unterminated_string)]
+    #[regex(r#"[fF]'([^'\\\n]|\\.)*"#, lex_unterminated_string)]
+    FString(String),
+    // `1.5`, `1.`, `.5`, `1e9`, `1_000.5e-3`
+    #[regex(r"[0-9][0-9_]*\.([0-9][0-9_]*)?([eE][+-]?[0-9_]+)?", lex_float)]
+    #[regex(r"\.[0-9][0-9_]*([eE][+-]?[0-9_]+)?", lex_float)]
+    #[regex(r"[0-9][0-9_]*[eE][+-]?[0-9_]+", lex_float)]
+    // 지수 숫자가 빠진 `1e`, `2.5e+`
+    #[regex(r"([0-9][0-9_]*(\.([0-9][0-9_]*)?)?|\.[0-9][0-9_]*)[eE][+-]?", lex_missing_exponent)]
     Float(f64),
 
     #[regex(r"[_\p{XID_Start}]\p{XID_Continue}*", lex_identifier)]
@@ -77,6 +106,45 @@ pub enum RawToken {
     Slash,
     #[token("%")]
     Percent,
+    #[token("**")]
+    StarStar,
+    #[token("&")]
+    Amp,
+    #[token("|")]
+    Pipe,
+    #[token("^")]
+    Caret,
+    #[token("~")]
+    Tilde,
+    #[token("<<")]
+    LessLess,
+    #[token(">>")]
+    GreaterGreater,
+    // Augmented assignment
+    #[token("+=")]
+    PlusEqual,
+    #[token("-=")]
+    MinusEqual,
+    #[token("*=")]
+    StarEqual,
+    #[token("/=")]
+    SlashEqual,
+    #[token("//=")]
+    SlashSlashEqual,
+    #[token("%=")]
+    PercentEqual,
+    #[token("**=")]
+    StarStarEqual,
+    #[token("&=")]
+    AmpEqual,
+    #[token("|=")]
+    PipeEqual,
+    #[token("^=")]
+    CaretEqual,
+    #[token("<<=")]
+    LessLessEqual,
+    #[token(">>=")]
+    GreaterGreaterEqual,
     #[token("==")]
     EqualEqual,
     #[token("!=")]
@@ -105,6 +173,8 @@ pub enum RawToken {
     RBrace,
     #[token(":")]
     Colon,
+    #[token(":=")]
+    ColonEqual,
     #[token(",")]
     Comma,
     #[token(";")]
@@ -114,51 +184,207 @@ pub enum RawToken {
 
     #[token("\n")]
     Newline,
+    // 줄 끝의 백슬래시: 다음 줄을 같은 논리 줄로 이어 붙임
+    #[regex(r"\\\r?\n")]
+    #[token("\\", lex_stray_backslash)]
+    LineContinuation,
+}
+
+/// 에러 메시지(와 리터럴 일부를 가리키는 위치)를 남기고 토큰화를 실패시킵니다.
+fn fail<T>(
+    lexer: &mut logos::Lexer<RawToken>,
+    message: String,
+    span: Option<Range<usize>>,
+) -> Option<T> {
+    lexer.extras.error_message = Some(message);
+    lexer.extras.error_span = span;
+    None
+}
+
+/// `_`는 두 숫자 사이에만 올 수 있음 (`1_000`). 진법 접두사 바로 뒤도 허용 (`0x_ff`).
+/// 잘못 놓인 첫 `_`의 바이트 위치를 반환합니다.
+fn misplaced_underscore(digits: &str, radix: u32, after_prefix: bool) -> Option<usize> {
+    let bytes = digits.as_bytes();
+    let is_digit = |i: Option<usize>| {
+        i.and_then(|i| bytes.get(i))
+            .is_some_and(|&b| (b as char).is_digit(radix))
+    };
+    (0..bytes.len()).find(|&i| {
+        let before_ok = is_digit(i.checked_sub(1)) || (i == 0 && after_prefix);
+        bytes[i] == b'_' && !(before_ok && is_digit(Some(i + 1)))
+    })
 }
 
 fn lex_integer(lexer: &mut logos::Lexer<RawToken>) -> Option<i64> {
     let slice = lexer.slice();
-    match slice.parse::<i64>() {
+    let start = lexer.span().start;
+    let (radix, kind, prefix_len) = match slice.as_bytes().get(..2) {
+        Some([b'0', b'x' | b'X']) => (16, "hexadecimal", 2),
+        Some([b'0', b'o' | b'O']) => (8, "octal", 2),
+        Some([b'0', b'b' | b'B']) => (2, "binary", 2),
+        _ => (10, "decimal", 0),
+    };
+    let digits = &slice[prefix_len..];
+    if !digits.chars().any(|c| c.is_ascii_alphanumeric()) {
+        let message = format!("Invalid {} literal '{}'", kind, slice);
+        return fail(lexer, message, None);
+    }
+    if let Some((i, c)) = digits
+        .char_indices()
+        .find(|&(_, c)| c != '_' && !c.is_digit(radix))
+    {
+        let at = start + prefix_len + i;
+        let message = format!("Invalid digit '{}' in {} literal '{}'", c, kind, slice);
+        return fail(lexer, message, Some(at..at + c.len_utf8()));
+    }
+    if let Some(i) = misplaced_underscore(digits, radix, prefix_len > 0) {
+        let at = start + prefix_len + i;
+        let message = format!(
+            "Invalid {} literal '{}': '_' must separate digits",
+            kind, slice
+        );
+        return fail(lexer, message, Some(at..at + 1));
+    }
+    let cleaned: String = digits.chars().filter(|&c| c != '_').collect();
+    if radix == 10 && cleaned.starts_with('0') && cleaned.contains(|c| c != '0') {
+        let message = format!(
+            "Leading zeros in decimal integer literal '{}' are not permitted; use an 0o prefix for octal integers",
+            slice
+        );
+        return fail(lexer, message, None);
+    }
+    match i64::from_str_radix(&cleaned, radix) {
         Ok(value) => Some(value),
+        // 2^63은 단항 `-`와 합쳐야만 표현 가능: 파서가 `-9223372036854775808`로 접고,
+        // 홀로 쓰이면 범위 에러를 보고
+        Err(_) if u64::from_str_radix(&cleaned, radix) == Ok(1 << 63) => Some(i64::MIN),
         Err(_) => {
             // Overflow error
-            lexer.extras.error_message = Some(format!(
+            let message = format!(
                 "Integer literal '{}' is out of range (valid range: {} to {})",
                 slice,
                 i64::MIN,
                 i64::MAX
-            ));
-            None
+            );
+            fail(lexer, message, None)
         }
     }
 }
 
 fn lex_float(lexer: &mut logos::Lexer<RawToken>) -> Option<f64> {
     let slice = lexer.slice();
-    match slice.parse::<f64>() {
+    if let Some(i) = misplaced_underscore(slice, 10, false) {
+        let at = lexer.span().start + i;
+        let message = format!("Invalid float literal '{}': '_' must separate digits", slice);
+        return fail(lexer, message, Some(at..at + 1));
+    }
+    let cleaned: String = slice.chars().filter(|&c| c != '_').collect();
+    match cleaned.parse::<f64>() {
         Ok(f) if f.is_finite() => Some(f),
         Ok(_) => {
             // Infinite value
-            lexer.extras.error_message = Some(format!(
+            let message = format!(
                 "Float literal '{}' is out of range (max: {:.2e}, min: {:.2e})",
                 slice,
                 f64::MAX,
                 f64::MIN
-            ));
-            None
+            );
+            fail(lexer, message, None)
         }
         Err(_) => {
             // Parse error
-            lexer.extras.error_message = Some(format!("Invalid float literal '{}'", slice));
-            None
+            let message = format!("Invalid float literal '{}'", slice);
+            fail(lexer, message, None)
         }
     }
 }
 
+fn lex_missing_exponent(lexer: &mut logos::Lexer<RawToken>) -> Option<f64> {
+    let message = format!(
+        "Invalid float literal '{}': exponent has no digits",
+        lexer.slice()
+    );
+    fail(lexer, message, None)
+}
+
 fn lex_string(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
     let slice = lexer.slice();
     let unquoted = &slice[1..slice.len() - 1];
-    Some(process_string_escapes(unquoted))
+    let content_start = lexer.span().start + 1;
+    match process_string_escapes(unquoted) {
+        Ok(s) => Some(s),
+        Err((message, at)) => fail(
+            lexer,
+            message,
+            Some(content_start + at.start..content_start + at.end),
+        ),
+    }
+}
+
+fn lex_raw_string(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
+    let slice = lexer.slice();
+    Some(slice[2..slice.len() - 1].to_string())
+}
+
+/// 여는 따옴표 세 개까지만 매치된 상태에서 닫는 따옴표를 찾아 토큰을 늘립니다.
+/// 줄바꿈이 토큰 안에 들어가므로 들여쓰기 처리에 영향을 주지 않습니다.
+fn lex_triple_string(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
+    let opening = lexer.slice();
+    let raw = opening.len() == 4;
+    let quote = &opening[opening.len() - 3..];
+    let remainder = lexer.remainder();
+    let mut end = None;
+    let mut chars = remainder.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' {
+            // 이스케이프된 문자는 닫는 따옴표가 될 수 없음 (raw 문자열도 마찬가지)
+            chars.next();
+        } else if remainder[i..].starts_with(quote) {
+            end = Some(i);
+            break;
+        }
+    }
+    let Some(end) = end else {
+        let opening_span = lexer.span();
+        lexer.bump(remainder.len());
+        let message = "Unterminated triple-quoted string literal".to_string();
+        return fail(lexer, message, Some(opening_span));
+    };
+    let content = &remainder[..end];
+    let content_start = lexer.span().end;
+    let result = if raw {
+        Ok(content.to_string())
+    } else {
+        process_string_escapes(content)
+    };
+    lexer.bump(end + 3);
+    match result {
+        Ok(s) => Some(s),
+        Err((message, at)) => fail(
+            lexer,
+            message,
+            Some(content_start + at.start..content_start + at.end),
+        ),
+    }
+}
+
+fn lex_fstring(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
+    let slice = lexer.slice();
+    Some(slice[2..slice.len() - 1].to_string())
+}
+
+fn lex_unterminated_string(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
+    lexer.extras.error_message = Some(format!(
+        "Unterminated string literal {}",
+        lexer.slice()
+    ));
+    None
+}
+
+fn lex_stray_backslash(lexer: &mut logos::Lexer<RawToken>) -> bool {
+    lexer.extras.error_message =
+        Some("Unexpected character after line continuation character".to_string());
+    false
 }
 
 fn lex_identifier(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
@@ -166,31 +392,104 @@ fn lex_identifier(lexer: &mut logos::Lexer<RawToken>) -> Option<String> {
     Some(slice.to_string())
 }
 
-fn process_string_escapes(s: &str) -> String {
+/// 문자열 리터럴의 이스케이프를 처리합니다.
+///
+/// 잘못된 이스케이프는 (메시지, `s` 안의 바이트 범위)로 반환합니다.
+/// 알 수 없는 이스케이프(`\q`)는 Python처럼 백슬래시를 그대로 둡니다.
+pub(super) fn process_string_escapes(s: &str) -> Result<String, (String, Range<usize>)> {
     let mut result = String::new();
-    let mut chars = s.chars();
-    while let Some(ch) = chars.next() {
-        match ch {
-            '\\' => {
-                if let Some(escaped) = chars.next() {
-                    match escaped {
-                        'n' => result.push('\n'),
-                        't' => result.push('\t'),
-                        'r' => result.push('\r'),
-                        '\\' => result.push('\\'),
-                        '"' => result.push('"'),
-                        '\'' => result.push('\''),
-                        other => {
-                            result.push('\\');
-                            result.push(other);
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+        let Some((_, escaped)) = chars.next() else {
+            result.push('\\');
+            break;
+        };
+        match escaped {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            '\\' => result.push('\\'),
+            '"' => result.push('"'),
+            '\'' => result.push('\''),
+            'a' => result.push('\x07'),
+            'b' => result.push('\x08'),
+            'f' => result.push('\x0c'),
+            'v' => result.push('\x0b'),
+            // 줄 끝의 백슬래시는 줄 이어짐 (세 따옴표 문자열)
+            '\n' => {}
+            '0'..='7' => {
+                let mut value = escaped.to_digit(8).unwrap_or(0);
+                for _ in 0..2 {
+                    match chars.peek().and_then(|&(_, c)| c.to_digit(8)) {
+                        Some(d) => {
+                            value = value * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                result.push(char::from_u32(value).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            'x' | 'u' | 'U' => {
+                let (width, form) = match escaped {
+                    'x' => (2, "\\xXX"),
+                    'u' => (4, "\\uXXXX"),
+                    _ => (8, "\\UXXXXXXXX"),
+                };
+                let mut code = 0u32;
+                let mut len = 0;
+                while len < width {
+                    match chars.peek().and_then(|&(_, c)| c.to_digit(16)) {
+                        Some(d) => {
+                            code = code * 16 + d;
+                            len += 1;
+                            chars.next();
                         }
+                        None => break,
+                    }
+                }
+                let end = start + 2 + len;
+                if len < width {
+                    return Err((format!("truncated {} escape", form), start..end));
+                }
+                match char::from_u32(code) {
+                    Some(c) => result.push(c),
+                    None => {
+                        let message = format!("illegal Unicode character U+{:X} in escape", code);
+                        return Err((message, start..end));
                     }
-                } else {
-                    result.push('\\');
                 }
             }
-            other => result.push(other),
+            'N' => {
+                let name = s[start + 2..]
+                    .strip_prefix('{')
+                    .and_then(|rest| rest.find('}').map(|close| &rest[..close]));
+                let Some(name) = name else {
+                    let message = "malformed \\N character escape".to_string();
+                    return Err((message, start..start + 2));
+                };
+                let end = start + 2 + name.len() + 2;
+                // 유니코드 이름 데이터베이스 전체 (Python처럼 대소문자는 무시, 앞뒤 공백은 허용하지 않음)
+                match unicode_names2::character(name) {
+                    Some(c) => result.push(c),
+                    None => {
+                        let message = format!("unknown Unicode character name '{}'", name);
+                        return Err((message, start..end));
+                    }
+                }
+                while chars.peek().is_some_and(|&(i, _)| i < end) {
+                    chars.next();
+                }
+            }
+            other => {
+                result.push('\\');
+                result.push(other);
+            }
         }
     }
-    result
+    Ok(result)
 }
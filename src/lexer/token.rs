@@ -10,6 +10,7 @@ pub enum Token {
     While,
     For,
     In,
+    Is,
     Def,
     Return,
     And,
@@ -20,6 +21,7 @@ pub enum Token {
     Continue,
     Pass,
     Lambda,
+    Import,
     // Identifiers and literals
     None,
     Bool(bool),
@@ -27,6 +29,16 @@ pub enum Token {
     String(String),
     Identifier(String),
     Float(f64),
+    // f-string: `f"a{x!r:>5}b"` →
+    // FStringStart, FStringText("a"), FStringExprStart, <x 토큰들>, FStringConv('r'),
+    // FStringSpec(">5"), FStringExprEnd, FStringText("b"), FStringEnd
+    FStringStart,
+    FStringText(String),
+    FStringExprStart,
+    FStringConv(char),
+    FStringSpec(String),
+    FStringExprEnd,
+    FStringEnd,
     // Operators and punctuation
     Plus,
     Minus,
@@ -34,6 +46,26 @@ pub enum Token {
     SlashSlash,
     Slash,
     Percent,
+    StarStar,
+    Amp,
+    Pipe,
+    Caret,
+    Tilde,
+    LessLess,
+    GreaterGreater,
+    // Augmented assignment
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
+    SlashSlashEqual,
+    PercentEqual,
+    StarStarEqual,
+    AmpEqual,
+    PipeEqual,
+    CaretEqual,
+    LessLessEqual,
+    GreaterGreaterEqual,
     EqualEqual,
     NotEqual,
     Less,
@@ -48,6 +80,7 @@ pub enum Token {
     LBrace,
     RBrace,
     Colon,
+    ColonEqual,
     Comma,
     Semicolon,
     Dot,
@@ -70,6 +103,7 @@ impl Display for Token {
             Token::While => write!(f, "while"),
             Token::For => write!(f, "for"),
             Token::In => write!(f, "in"),
+            Token::Is => write!(f, "is"),
             Token::Def => write!(f, "def"),
             Token::Return => write!(f, "return"),
             Token::And => write!(f, "and"),
@@ -80,6 +114,7 @@ impl Display for Token {
             Token::Continue => write!(f, "continue"),
             Token::Pass => write!(f, "pass"),
             Token::Lambda => write!(f, "lambda"),
+            Token::Import => write!(f, "import"),
 
             // Identifiers and literals
             Token::None => write!(f, "None"),
@@ -89,6 +124,13 @@ impl Display for Token {
             Token::String(s) => write!(f, "\"{}\"", s),
             Token::Identifier(name) => write!(f, "{}", name),
             Token::Float(ff) => write!(f, "{}", ff),
+            Token::FStringStart => write!(f, "f\""),
+            Token::FStringText(s) => write!(f, "{}", s),
+            Token::FStringExprStart => write!(f, "{{"),
+            Token::FStringConv(c) => write!(f, "!{}", c),
+            Token::FStringSpec(s) => write!(f, ":{}", s),
+            Token::FStringExprEnd => write!(f, "}}"),
+            Token::FStringEnd => write!(f, "\""),
 
             // Operators and punctuation
             Token::Plus => write!(f, "+"),
@@ -97,6 +139,25 @@ impl Display for Token {
             Token::SlashSlash => write!(f, "//"),
             Token::Slash => write!(f, "/"),
             Token::Percent => write!(f, "%"),
+            Token::StarStar => write!(f, "**"),
+            Token::Amp => write!(f, "&"),
+            Token::Pipe => write!(f, "|"),
+            Token::Caret => write!(f, "^"),
+            Token::Tilde => write!(f, "~"),
+            Token::LessLess => write!(f, "<<"),
+            Token::GreaterGreater => write!(f, ">>"),
+            Token::PlusEqual => write!(f, "+="),
+            Token::MinusEqual => write!(f, "-="),
+            Token::StarEqual => write!(f, "*="),
+            Token::SlashEqual => write!(f, "/="),
+            Token::SlashSlashEqual => write!(f, "//="),
+            Token::PercentEqual => write!(f, "%="),
+            Token::StarStarEqual => write!(f, "**="),
+            Token::AmpEqual => write!(f, "&="),
+            Token::PipeEqual => write!(f, "|="),
+            Token::CaretEqual => write!(f, "^="),
+            Token::LessLessEqual => write!(f, "<<="),
+            Token::GreaterGreaterEqual => write!(f, ">>="),
             Token::EqualEqual => write!(f, "=="),
             Token::NotEqual => write!(f, "!="),
             Token::Less => write!(f, "<"),
@@ -111,6 +172,7 @@ impl Display for Token {
             Token::LBrace => write!(f, "{{"),
             Token::RBrace => write!(f, "}}"),
             Token::Colon => write!(f, ":"),
+            Token::ColonEqual => write!(f, ":="),
             Token::Comma => write!(f, ","),
             Token::Semicolon => write!(f, ";"),
             Token::Dot => write!(f, "."),
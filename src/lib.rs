@@ -1,46 +1,30 @@
 pub mod builtins;
+pub mod diagnostic;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod interpreter;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod judge;
 pub mod lexer;
 pub mod parser;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod repl;
 pub mod runtime_io;
 pub mod semantic;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod test_runner;
 pub mod types;
 pub mod vm;
 
+pub use diagnostic::{Diagnostic, ErrorCode, Severity};
+#[cfg(not(target_arch = "wasm32"))]
+pub use interpreter::{Interpreter, InterpreterError};
 pub use runtime_io::RuntimeIo;
 pub use vm::Vm;
 
-use ariadne::{Color, Label, Report, ReportKind, Source};
 use chumsky::Parser;
 use chumsky::input::{Input, Stream};
 use chumsky::span::SimpleSpan;
 
-#[derive(Debug, Clone)]
-pub struct Diagnostic {
-    pub message: String,
-    pub span: std::ops::Range<usize>,
-}
-
-impl Diagnostic {
-    pub fn format(&self, path: &str, src: &str, kind: &str, code: usize) -> String {
-        let mut buffer = Vec::new();
-        Report::build(ReportKind::Error, (path, self.span.clone()))
-            .with_config(ariadne::Config::new().with_index_type(ariadne::IndexType::Byte))
-            .with_code(code)
-            .with_message(kind)
-            .with_label(
-                Label::new((path, self.span.clone()))
-                    .with_message(&self.message)
-                    .with_color(Color::Red),
-            )
-            .finish()
-            .write((path, Source::from(src)), &mut buffer)
-            .ok();
-        String::from_utf8_lossy(&buffer).to_string()
-    }
-}
-
 pub fn parse_source(src: &str) -> Result<Vec<parser::ast::StmtS>, Vec<Diagnostic>> {
     let mut lexer = lexer::Lexer::new(src);
 
@@ -53,10 +37,7 @@ pub fn parse_source(src: &str) -> Result<Vec<parser::ast::StmtS>, Vec<Diagnostic
 
         // Check for lexer errors
         if let lexer::token::Token::Error(msg, error_span) = t {
-            lexer_errors.push(Diagnostic {
-                message: msg,
-                span: error_span,
-            });
+            lexer_errors.push(Diagnostic::new(lexer_error_code(&msg), msg, error_span));
             continue; // Skip error tokens
         }
 
@@ -67,55 +48,90 @@ pub fn parse_source(src: &str) -> Result<Vec<parser::ast::StmtS>, Vec<Diagnostic
         tokens.push((t, SimpleSpan::new(span.start, span.end)));
     }
 
-    // If there are lexer errors, return them immediately
-    if !lexer_errors.is_empty() {
-        return Err(lexer_errors);
-    }
+    // 렉서 에러가 있어도 파서를 실행하여 나머지 구문 에러까지 함께 보고
 
     let eoi_span = parser::SimpleSpan::new(src.len(), src.len());
     let token_stream = Stream::from_iter(tokens).map(eoi_span, |(t, s)| (t, s));
-    match parser::program_parser().parse(token_stream).into_result() {
-        Ok(program) => Ok(program),
-        Err(errors) => {
-            let mut errors = errors;
-            errors.sort_by(|x1, x2| {
-                let x1 = (x1.span().start, x1.span().end);
-                let x2 = (x2.span().start, x2.span().end);
-                x1.cmp(&x2)
-            });
-            let diagnostics = errors
-                .into_iter()
-                .map(|e| Diagnostic {
-                    message: e.reason().to_string(),
-                    span: e.span().into_range(),
-                })
-                .collect();
+    let (program, errors) = parser::program_parser()
+        .parse(token_stream)
+        .into_output_errors();
+
+    // 렉서 에러 토큰이 빠지면서 같은 줄에 생기는 파서 에러는 연쇄 에러이므로 생략
+    let error_lines: Vec<usize> = lexer_errors
+        .iter()
+        .map(|d| line_of(src, d.span.start))
+        .collect();
+    let mut diagnostics = lexer_errors;
+    diagnostics.extend(
+        errors
+            .into_iter()
+            .map(|e| {
+                Diagnostic::new(
+                    ErrorCode::UnexpectedToken,
+                    e.reason().to_string(),
+                    e.span().into_range(),
+                )
+            })
+            .filter(|d| !error_lines.contains(&line_of(src, d.span.start))),
+    );
+
+    match program {
+        Some(program) if diagnostics.is_empty() => Ok(program),
+        _ => {
+            diagnostics.sort_by_key(|d| (d.span.start, d.span.end));
             Err(diagnostics)
         }
     }
 }
 
-pub fn analyze(program: &[parser::ast::StmtS]) -> Result<(), Diagnostic> {
-    match semantic::analyze(program) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(Diagnostic {
-            message: e.message,
-            span: e.span,
-        }),
+/// 렉서 에러 메시지로 코드를 분류 (들여쓰기 관련 에러는 별도 코드)
+fn lexer_error_code(message: &str) -> ErrorCode {
+    let lower = message.to_lowercase();
+    if lower.contains("dentation") || lower.contains("tabs are not allowed") {
+        ErrorCode::InvalidIndentation
+    } else if lower.contains("never closed") {
+        ErrorCode::UnexpectedToken
+    } else {
+        ErrorCode::InvalidToken
     }
 }
 
+/// 바이트 오프셋이 속한 줄 번호 (0부터)
+fn line_of(src: &str, byte_idx: usize) -> usize {
+    src.as_bytes()[..byte_idx.min(src.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count()
+}
+
+/// 한 번에 보고하는 진단 개수의 기본 상한
+pub const DEFAULT_MAX_DIAGNOSTICS: usize = 20;
+
+/// 진단 목록을 `max`개로 자르고, 생략된 개수를 반환합니다.
+pub fn truncate_diagnostics(diagnostics: &mut Vec<Diagnostic>, max: usize) -> usize {
+    let omitted = diagnostics.len().saturating_sub(max);
+    diagnostics.truncate(max);
+    omitted
+}
+
+/// 의미 분석. 성공하면 경고 목록을, 실패하면 (경고를 포함한) 모든 진단을 반환합니다.
+pub fn analyze(program: &[parser::ast::StmtS]) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    analyze_with_globals(program, &[])
+}
+
 pub fn analyze_with_globals(
     program: &[parser::ast::StmtS],
     existing_globals: &[String],
-) -> Result<(), Diagnostic> {
-    match semantic::analyze_with_globals(program, existing_globals) {
-        Ok(_) => Ok(()),
-        Err(e) => Err(Diagnostic {
-            message: e.message,
-            span: e.span,
-        }),
-    }
+) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    semantic::analyze_with_globals(program, existing_globals)
+}
+
+/// 호스트가 등록한 네이티브 함수/클래스를 알고 있는 의미 분석
+pub fn analyze_with_natives(
+    program: &[parser::ast::StmtS],
+    natives: &vm::NativeRegistry,
+) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+    semantic::analyze_with_natives(program, &[], natives)
 }
 
 pub fn compile_to_module(program: &[parser::ast::StmtS]) -> vm::bytecode::Module {
@@ -123,11 +139,63 @@ pub fn compile_to_module(program: &[parser::ast::StmtS]) -> vm::bytecode::Module
     compiler.compile(program)
 }
 
-pub fn exec_vm_module(mut module: vm::bytecode::Module) {
+/// 네이티브 호출을 `CallNative`로 컴파일 (실행은 `Vm::with_natives`로)
+pub fn compile_with_natives(
+    program: &[parser::ast::StmtS],
+    natives: &vm::NativeRegistry,
+) -> vm::bytecode::Module {
+    vm::Compiler::new().with_natives(natives).compile(program)
+}
+
+// ===== CLI 종료 코드 =====
+/// 런타임 에러로 끝남 (`sys.exit("message")`도 1)
+pub const EXIT_RUNTIME_ERROR: i32 = 1;
+/// 파싱/시맨틱 에러로 실행하지 못함
+pub const EXIT_COMPILE_ERROR: i32 = 65;
+/// 소스나 바이트코드 파일을 읽지 못함
+pub const EXIT_NO_INPUT: i32 = 66;
+
+/// 모듈을 실행하고 프로세스 종료 코드를 돌려줌
+pub fn exec_vm_module(module: vm::bytecode::Module) -> i32 {
+    exec_vm_module_with(module, Vec::new(), None)
+}
+
+/// `argv`는 `sys.argv`가 됩니다. `tracer`가 있으면 실행한 명령어를 기록하고,
+/// 줄 수 제한으로 빠진 명령어가 있으면 끝에 알립니다.
+///
+/// 종료 코드: 정상 종료는 0, `sys.exit(n)`은 n, 런타임 에러는 [`EXIT_RUNTIME_ERROR`]
+pub fn exec_vm_module_with(
+    mut module: vm::bytecode::Module,
+    argv: Vec<String>,
+    tracer: Option<vm::trace::Tracer>,
+) -> i32 {
     let mut machine = vm::Vm::new();
-    if let Err(err) = machine.run(&mut module) {
-        eprintln!("VM Runtime Error: {:?}: {}", err.kind, err.message);
+    machine.set_argv(argv);
+    if let Some(tracer) = tracer {
+        machine.set_tracer(tracer);
     }
+    let status = match machine.run(&mut module) {
+        Ok(_) => {
+            if let Some(message) = machine.exit_message() {
+                eprintln!("{}", message);
+            }
+            machine.exit_code().unwrap_or(0)
+        }
+        Err(err) => {
+            eprintln!("VM Runtime Error: {:?}: {}", err.kind, err.message);
+            EXIT_RUNTIME_ERROR
+        }
+    };
+    if let Some(tracer) = machine.take_tracer()
+        && tracer.dropped() > 0
+    {
+        eprintln!(
+            "[trace] ... {} more instruction(s) after {} line(s)",
+            tracer.dropped(),
+            tracer.recorded()
+        );
+    }
+    status
 }
 
 pub fn save_module(module: &vm::bytecode::Module, path: &str) -> std::io::Result<()> {
@@ -140,7 +208,8 @@ pub fn load_module(path: &str) -> std::io::Result<vm::bytecode::Module> {
     let bytes = std::fs::read(path)?;
     let cfg = bincode::config::standard();
     let (module, _consumed): (vm::bytecode::Module, usize) =
-        bincode::serde::decode_from_slice(&bytes, cfg).expect("deserialize module");
+        bincode::serde::decode_from_slice(&bytes, cfg)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
     Ok(module)
 }
 
@@ -159,27 +228,39 @@ mod wasm_api {
         io: runtime_io::BufferIo,
         execution_timer: Option<instant::Instant>,
         accumulated_time: std::time::Duration,
+        /// 시각화용 소스 (줄 번호 계산)
+        source: String,
+        /// Some이면 한 번에 조금씩 실행하고 상태 스냅샷(view)을 함께 보냄
+        step_mode: Option<vm::StepMode>,
     }
 
     thread_local! {
         static ACTIVE_SESSION: RefCell<Option<VmSession>> = RefCell::new(None);
+        /// JS가 처리하는 함수들 (register_host_function)
+        static HOST_FUNCTIONS: RefCell<vm::NativeRegistry> = RefCell::new(vm::NativeRegistry::new());
     }
 
     #[derive(Serialize)]
     pub struct WasmDiagnostic {
         pub message: String,
+        pub code: String,
         pub start_line: u32,
         pub start_char: u32,
         pub end_line: u32,
         pub end_char: u32,
+        /// Monaco MarkerSeverity 값 (Error=8, Warning=4, Info=2)
         pub severity: u8,
     }
 
     #[derive(Serialize)]
     pub struct VmStateInfo {
-        pub state: String, // "running", "waiting_for_input", "finished", "error"
+        pub state: String, // "running", "waiting_for_input", "waiting_for_host", "finished", "error"
         pub output: String,
         pub execution_time_ms: Option<f64>,
+        /// state가 "waiting_for_host"일 때 JS가 처리할 요청
+        pub request: Option<vm::HostRequest>,
+        /// 한 단계씩 실행 중일 때의 프레임, 전역 변수, 힙 그래프 (start_stepping)
+        pub view: Option<vm::inspect::ExecutionView>,
     }
 
     fn byte_to_lc(src: &str, byte_idx: usize) -> (u32, u32) {
@@ -194,43 +275,43 @@ mod wasm_api {
 
     #[wasm_bindgen]
     pub fn analyze(src: &str) -> JsValue {
-        // parse
-        let program = match super::parse_source(src) {
-            Ok(p) => p,
-            Err(diagnostics) => {
-                let wasm_diags: Vec<WasmDiagnostic> = diagnostics
-                    .iter()
-                    .map(|d| {
-                        let (sl, sc) = byte_to_lc(src, d.span.start);
-                        let (el, ec) = byte_to_lc(src, d.span.end);
-                        WasmDiagnostic {
-                            message: d.message.clone(),
-                            start_line: sl,
-                            start_char: sc,
-                            end_line: el,
-                            end_char: ec,
-                            severity: 1,
-                        }
-                    })
-                    .collect();
-                return serde_wasm_bindgen::to_value(&wasm_diags).unwrap();
-            }
+        // parse, then semantic
+        let mut diagnostics = match super::parse_source(src) {
+            Ok(program) => match super::analyze_with_natives(&program, &host_functions()) {
+                Ok(warnings) => warnings,
+                Err(diagnostics) => diagnostics,
+            },
+            Err(diagnostics) => diagnostics,
         };
-        // semantic
-        if let Err(diag) = super::analyze(&program) {
-            let (sl, sc) = byte_to_lc(src, diag.span.start);
-            let (el, ec) = byte_to_lc(src, diag.span.end);
-            let wasm_diag = WasmDiagnostic {
-                message: diag.message,
-                start_line: sl,
-                start_char: sc,
-                end_line: el,
-                end_char: ec,
-                severity: 1,
-            };
-            return serde_wasm_bindgen::to_value(&vec![wasm_diag]).unwrap();
-        }
-        serde_wasm_bindgen::to_value(&Vec::<WasmDiagnostic>::new()).unwrap()
+        super::truncate_diagnostics(&mut diagnostics, super::DEFAULT_MAX_DIAGNOSTICS);
+        let wasm_diags: Vec<WasmDiagnostic> = diagnostics
+            .iter()
+            .map(|d| {
+                let (sl, sc) = byte_to_lc(src, d.span.start);
+                let (el, ec) = byte_to_lc(src, d.span.end);
+                let mut message = d.message.clone();
+                for note in &d.notes {
+                    message.push_str(&format!("\nnote: {}", note));
+                }
+                if let Some(help) = &d.help {
+                    message.push_str(&format!("\nhelp: {}", help));
+                }
+                WasmDiagnostic {
+                    message,
+                    code: d.code.as_str().to_string(),
+                    start_line: sl,
+                    start_char: sc,
+                    end_line: el,
+                    end_char: ec,
+                    severity: match d.severity {
+                        Severity::Error => 8,
+                        Severity::Warning => 4,
+                        Severity::Info => 2,
+                    },
+                }
+            })
+            .collect();
+        serde_wasm_bindgen::to_value(&wasm_diags).unwrap()
     }
 
     /// Compile and execute via VM, capturing output; return it as a string.
@@ -249,23 +330,34 @@ mod wasm_api {
             Err(diagnostics) => {
                 let mut output = String::new();
                 for diag in diagnostics {
-                    output.push_str(&diag.format("<mem>", &src, "Parsing failed", 3));
+                    output.push_str(&diag.format("<mem>", &src));
                 }
                 return output;
             }
         };
 
         // Semantic analysis
-        if let Err(diag) = super::analyze(&program) {
-            return diag.format("<mem>", &src, "Semantic Analyzing Failed", 4);
+        let natives = host_functions();
+        if let Err(diagnostics) = super::analyze_with_natives(&program, &natives) {
+            let mut output = String::new();
+            for diag in diagnostics {
+                output.push_str(&diag.format("<mem>", &src));
+            }
+            return output;
         }
 
         // Compile and run
-        let module = super::compile_to_module(&program);
+        let module = super::compile_with_natives(&program, &natives);
         let exec_start = instant::Instant::now();
-        let mut vm = super::vm::Vm::new();
+        let mut vm = super::vm::Vm::with_natives(natives);
         let mut io = super::runtime_io::BufferIo::new();
         let result = match vm.run_with_io(&mut module.clone(), &mut io) {
+            // 한 번에 실행하는 모드에서는 호스트 요청을 처리할 수 없음
+            Ok(_) if vm.host_request().is_some() && !vm.is_waiting_for_input() => format!(
+                "{}Runtime Error: host function '{}' needs interactive mode (start_program)",
+                io.take_output(),
+                vm.host_request().map(|r| r.kind.as_str()).unwrap_or_default()
+            ),
             Ok(_) => {
                 let exec_time = exec_start.elapsed();
                 let total_time = start_time.elapsed();
@@ -290,9 +382,65 @@ mod wasm_api {
         result
     }
 
+    /// Register a function the playground handles on the JS side (e.g. `sleep`, `fetch`).
+    ///
+    /// Calling it from a program suspends the VM with `state: "waiting_for_host"` and a
+    /// `request: { kind: name, payload: [args...] }`; answer with `resume_host`/`fail_host`.
+    /// Throws if `name` is a builtin function.
+    #[wasm_bindgen]
+    pub fn register_host_function(name: &str, min_args: usize, max_args: usize) -> Result<(), JsValue> {
+        let arity = if min_args == max_args {
+            vm::Arity::Exact(min_args)
+        } else {
+            vm::Arity::Range(min_args, max_args)
+        };
+        HOST_FUNCTIONS.with(|natives| {
+            natives
+                .borrow_mut()
+                .register_host_request(name, arity)
+                .map(|_| ())
+                .map_err(|e| JsValue::from_str(&e.message))
+        })
+    }
+
+    /// Forget all registered host functions
+    #[wasm_bindgen]
+    pub fn clear_host_functions() {
+        HOST_FUNCTIONS.with(|natives| *natives.borrow_mut() = vm::NativeRegistry::new());
+    }
+
+    fn host_functions() -> vm::NativeRegistry {
+        HOST_FUNCTIONS.with(|natives| natives.borrow().clone())
+    }
+
+    fn error_state(output: String) -> JsValue {
+        serde_wasm_bindgen::to_value(&VmStateInfo {
+            state: "error".to_string(),
+            output,
+            execution_time_ms: None,
+            request: None,
+            view: None,
+        })
+        .unwrap()
+    }
+
     /// Start a new program execution (interactive mode)
     #[wasm_bindgen]
     pub fn start_program(src: &str) -> JsValue {
+        start_session(src, None)
+    }
+
+    /// Start a program paused before its first line, for visualization
+    ///
+    /// Every result carries a `view` with frames (function, line, locals), globals and the heap
+    /// as objects with ids; advance with `step_line`/`step_instruction`. `provide_input`,
+    /// `resume_host` and `step_program` keep stepping at the last granularity.
+    #[wasm_bindgen]
+    pub fn start_stepping(src: &str) -> JsValue {
+        start_session(src, Some(vm::StepMode::Statement))
+    }
+
+    fn start_session(src: &str, step_mode: Option<vm::StepMode>) -> JsValue {
         let src = if src.ends_with('\n') {
             src.to_string()
         } else {
@@ -305,30 +453,25 @@ mod wasm_api {
             Err(diagnostics) => {
                 let mut output = String::new();
                 for diag in diagnostics {
-                    output.push_str(&diag.format("<mem>", &src, "Parsing failed", 3));
+                    output.push_str(&diag.format("<mem>", &src));
                 }
-                return serde_wasm_bindgen::to_value(&VmStateInfo {
-                    state: "error".to_string(),
-                    output,
-                    execution_time_ms: None,
-                })
-                .unwrap();
+                return error_state(output);
             }
         };
 
         // Semantic analysis
-        if let Err(diag) = super::analyze(&program) {
-            return serde_wasm_bindgen::to_value(&VmStateInfo {
-                state: "error".to_string(),
-                output: diag.format("<mem>", &src, "Semantic Analyzing Failed", 4),
-                execution_time_ms: None,
-            })
-            .unwrap();
+        let natives = host_functions();
+        if let Err(diagnostics) = super::analyze_with_natives(&program, &natives) {
+            let mut output = String::new();
+            for diag in diagnostics {
+                output.push_str(&diag.format("<mem>", &src));
+            }
+            return error_state(output);
         }
 
         // Compile
-        let module = super::compile_to_module(&program);
-        let vm = super::vm::Vm::new();
+        let module = super::compile_with_natives(&program, &natives);
+        let vm = super::vm::Vm::with_natives(natives);
         let io = super::runtime_io::BufferIo::new();
 
         // Start execution
@@ -338,6 +481,8 @@ mod wasm_api {
             io,
             execution_timer: Some(instant::Instant::now()),
             accumulated_time: std::time::Duration::from_secs(0),
+            source: src,
+            step_mode,
         };
         ACTIVE_SESSION.with(|s| {
             *s.borrow_mut() = Some(session);
@@ -347,166 +492,146 @@ mod wasm_api {
         step_program()
     }
 
-    /// Continue program execution (step)
-    #[wasm_bindgen]
-    pub fn step_program() -> JsValue {
+    /// Run the active session until it waits, finishes or fails.
+    /// `prepare` feeds the pending input/host response before execution resumes.
+    fn continue_session(prepare: impl FnOnce(&mut VmSession) -> Result<(), String>) -> JsValue {
         ACTIVE_SESSION.with(|s| {
             let mut session_opt = s.borrow_mut();
-            if let Some(ref mut session) = *session_opt {
-                // Start timer if not running
-                if session.execution_timer.is_none() {
-                    session.execution_timer = Some(instant::Instant::now());
-                }
+            let Some(session) = session_opt.as_mut() else {
+                return error_state("No active program".to_string());
+            };
+            if let Err(message) = prepare(session) {
+                return error_state(message);
+            }
 
-                // Execute
-                match session.vm.run_with_io(&mut session.module, &mut session.io) {
-                    Ok(_) => {
-                        let state = session.vm.get_state();
-
-                        // Stop timer and accumulate if waiting for input or finished
-                        let mut execution_time_ms = None;
-                        let is_waiting = state == vm::machine::VmState::WaitingForInput;
-                        let is_finished = state == vm::machine::VmState::Finished;
-                        let is_error = state == vm::machine::VmState::Error;
-
-                        if is_waiting || is_finished || is_error {
-                            if let Some(timer) = session.execution_timer.take() {
-                                session.accumulated_time += timer.elapsed();
-                            }
-
-                            // Set execution time for finished state
-                            if is_finished {
-                                execution_time_ms =
-                                    Some(session.accumulated_time.as_secs_f64() * 1000.0);
-                            }
-                        }
+            // Start timer if not running
+            if session.execution_timer.is_none() {
+                session.execution_timer = Some(instant::Instant::now());
+            }
 
-                        let state_str = vm_state_to_string(state);
+            // Execute
+            let result = match session.step_mode {
+                Some(mode) => session.vm.step_with_io(&mut session.module, &mut session.io, mode),
+                None => session.vm.run_with_io(&mut session.module, &mut session.io),
+            };
+            match result {
+                Ok(_) => {
+                    let state = session.vm.get_state();
 
-                        serde_wasm_bindgen::to_value(&VmStateInfo {
-                            state: state_str.to_string(),
-                            output: session.io.drain_output(),
-                            execution_time_ms,
-                        })
-                        .unwrap()
-                    }
-                    Err(err) => {
-                        // Stop timer on error
+                    // Stop timer and accumulate if waiting or finished
+                    let mut execution_time_ms = None;
+                    if state != vm::machine::VmState::Running {
                         if let Some(timer) = session.execution_timer.take() {
                             session.accumulated_time += timer.elapsed();
                         }
 
-                        // Get previous output and append error message with red color
-                        let previous_output = session.io.drain_output();
-                        let error_msg = format!(
-                            "\x1b[31mRuntime Error: {}\n{:?}\x1b[0m",
-                            err.message, err.kind
-                        );
-                        let combined_output = if previous_output.is_empty() {
-                            error_msg
-                        } else {
-                            format!("{}\n{}", previous_output, error_msg)
-                        };
-
-                        serde_wasm_bindgen::to_value(&VmStateInfo {
-                            state: "error".to_string(),
-                            output: combined_output,
-                            execution_time_ms: None,
-                        })
-                        .unwrap()
+                        // Set execution time for finished state
+                        if state == vm::machine::VmState::Finished {
+                            execution_time_ms =
+                                Some(session.accumulated_time.as_secs_f64() * 1000.0);
+                        }
+                    }
+
+                    serde_wasm_bindgen::to_value(&VmStateInfo {
+                        state: vm_state_to_string(&session.vm).to_string(),
+                        output: session.io.drain_output(),
+                        execution_time_ms,
+                        request: session.vm.host_request().cloned(),
+                        view: session_view(session),
+                    })
+                    .unwrap()
+                }
+                Err(err) => {
+                    // Stop timer on error
+                    if let Some(timer) = session.execution_timer.take() {
+                        session.accumulated_time += timer.elapsed();
                     }
+
+                    // Get previous output and append error message with red color
+                    let previous_output = session.io.drain_output();
+                    let error_msg = format!(
+                        "\x1b[31mRuntime Error: {}\n{:?}\x1b[0m",
+                        err.message, err.kind
+                    );
+                    let combined_output = if previous_output.is_empty() {
+                        error_msg
+                    } else {
+                        format!("{}\n{}", previous_output, error_msg)
+                    };
+                    error_state(combined_output)
                 }
-            } else {
-                serde_wasm_bindgen::to_value(&VmStateInfo {
-                    state: "error".to_string(),
-                    output: "No active program".to_string(),
-                    execution_time_ms: None,
-                })
-                .unwrap()
             }
         })
     }
 
-    /// Provide input to the running program
+    /// Continue program execution (step)
     #[wasm_bindgen]
-    pub fn provide_input(line: &str) -> JsValue {
-        ACTIVE_SESSION.with(|s| {
-            let mut session_opt = s.borrow_mut();
-            if let Some(ref mut session) = *session_opt {
-                // Add input to the buffer
-                session.io.push_input_line(line.to_string());
-                // Resume execution
-                session.vm.resume();
+    pub fn step_program() -> JsValue {
+        continue_session(|_| Ok(()))
+    }
 
-                // Restart timer before execution
-                session.execution_timer = Some(instant::Instant::now());
+    /// Run until the next source line (or function call/return) and report the `view`
+    #[wasm_bindgen]
+    pub fn step_line() -> JsValue {
+        continue_session(|session| {
+            session.step_mode = Some(vm::StepMode::Statement);
+            Ok(())
+        })
+    }
 
-                // Continue execution
-                match session.vm.run_with_io(&mut session.module, &mut session.io) {
-                    Ok(_) => {
-                        let state = session.vm.get_state();
-
-                        // Stop timer and accumulate if waiting for input or finished
-                        let mut execution_time_ms = None;
-                        let is_waiting = state == vm::machine::VmState::WaitingForInput;
-                        let is_finished = state == vm::machine::VmState::Finished;
-                        let is_error = state == vm::machine::VmState::Error;
-
-                        if is_waiting || is_finished || is_error {
-                            if let Some(timer) = session.execution_timer.take() {
-                                session.accumulated_time += timer.elapsed();
-                            }
-
-                            // Set execution time for finished state
-                            if is_finished {
-                                execution_time_ms =
-                                    Some(session.accumulated_time.as_secs_f64() * 1000.0);
-                            }
-                        }
+    /// Run a single bytecode instruction and report the `view`
+    #[wasm_bindgen]
+    pub fn step_instruction() -> JsValue {
+        continue_session(|session| {
+            session.step_mode = Some(vm::StepMode::Instruction);
+            Ok(())
+        })
+    }
 
-                        let state_str = vm_state_to_string(state);
+    fn session_view(session: &VmSession) -> Option<vm::inspect::ExecutionView> {
+        session
+            .step_mode
+            .map(|_| session.vm.inspect(&session.module, &session.source))
+    }
 
-                        serde_wasm_bindgen::to_value(&VmStateInfo {
-                            state: state_str.to_string(),
-                            output: session.io.drain_output(),
-                            execution_time_ms,
-                        })
-                        .unwrap()
-                    }
-                    Err(err) => {
-                        // Stop timer on error
-                        if let Some(timer) = session.execution_timer.take() {
-                            session.accumulated_time += timer.elapsed();
-                        }
+    /// Provide input to the running program
+    #[wasm_bindgen]
+    pub fn provide_input(line: &str) -> JsValue {
+        continue_session(|session| {
+            // 멈춘 input() 호출에 읽은 줄로 응답
+            session
+                .vm
+                .resume_host(Ok(vm::utils::make_string(line.to_string())))
+                .map_err(|e| e.message)?;
+            // Restart timer before execution
+            session.execution_timer = Some(instant::Instant::now());
+            Ok(())
+        })
+    }
 
-                        // Get previous output and append error message with red color
-                        let previous_output = session.io.drain_output();
-                        let error_msg = format!(
-                            "\x1b[31mRuntime Error: {}\n{:?}\x1b[0m",
-                            err.message, err.kind
-                        );
-                        let combined_output = if previous_output.is_empty() {
-                            error_msg
-                        } else {
-                            format!("{}\n{}", previous_output, error_msg)
-                        };
-
-                        serde_wasm_bindgen::to_value(&VmStateInfo {
-                            state: "error".to_string(),
-                            output: combined_output,
-                            execution_time_ms: None,
-                        })
-                        .unwrap()
-                    }
-                }
-            } else {
-                serde_wasm_bindgen::to_value(&VmStateInfo {
-                    state: "error".to_string(),
-                    output: "No active program".to_string(),
-                    execution_time_ms: None,
-                })
-                .unwrap()
-            }
+    /// Answer the pending host request with a value (null, bool, number, string, array or object)
+    #[wasm_bindgen]
+    pub fn resume_host(value: JsValue) -> JsValue {
+        continue_session(|session| {
+            let value: vm::HostValue = serde_wasm_bindgen::from_value(value)
+                .map_err(|e| format!("Invalid host response: {}", e))?;
+            session
+                .vm
+                .resume_host(Ok(vm::IntoValue::into_value(value)))
+                .map_err(|e| e.message)?;
+            session.execution_timer = Some(instant::Instant::now());
+            Ok(())
+        })
+    }
+
+    /// Fail the pending host request; the program stops with a runtime error
+    #[wasm_bindgen]
+    pub fn fail_host(message: &str) -> JsValue {
+        continue_session(|session| {
+            session
+                .vm
+                .resume_host(Err(message.to_string()))
+                .map_err(|e| e.message)
         })
     }
 
@@ -524,18 +649,15 @@ mod wasm_api {
                 };
 
                 serde_wasm_bindgen::to_value(&VmStateInfo {
-                    state: vm_state_to_string(state).to_string(),
+                    state: vm_state_to_string(&session.vm).to_string(),
                     output: session.io.drain_output(),
                     execution_time_ms,
+                    request: session.vm.host_request().cloned(),
+                    view: session_view(session),
                 })
                 .unwrap()
             } else {
-                serde_wasm_bindgen::to_value(&VmStateInfo {
-                    state: "error".to_string(),
-                    output: "No active program".to_string(),
-                    execution_time_ms: None,
-                })
-                .unwrap()
+                error_state("No active program".to_string())
             }
         })
     }
@@ -548,11 +670,61 @@ mod wasm_api {
         });
     }
 
+    /// Save the active program (for example while it waits for input) as bytes
+    ///
+    /// Pending input and output are not included; `restore_program` starts with empty buffers.
+    #[wasm_bindgen]
+    pub fn snapshot_program() -> Result<Vec<u8>, JsValue> {
+        ACTIVE_SESSION.with(|s| {
+            let session_opt = s.borrow();
+            let session = session_opt
+                .as_ref()
+                .ok_or_else(|| JsValue::from_str("No active program"))?;
+            session
+                .vm
+                .snapshot(&session.module)
+                .map_err(|e| JsValue::from_str(&e.message))
+        })
+    }
+
+    /// Replace the active program with one saved by `snapshot_program`
+    ///
+    /// Returns the restored state without running; continue with `provide_input`/`resume_host`.
+    #[wasm_bindgen]
+    pub fn restore_program(bytes: &[u8]) -> JsValue {
+        let (mut vm, module) = match vm::Vm::restore(bytes) {
+            Ok(restored) => restored,
+            Err(err) => return error_state(format!("\x1b[31mError: {}\x1b[0m", err.message)),
+        };
+        vm.natives = host_functions();
+        let info = VmStateInfo {
+            state: vm_state_to_string(&vm).to_string(),
+            output: String::new(),
+            execution_time_ms: None,
+            request: vm.host_request().cloned(),
+            view: None,
+        };
+        ACTIVE_SESSION.with(|s| {
+            *s.borrow_mut() = Some(VmSession {
+                vm,
+                module,
+                io: super::runtime_io::BufferIo::new(),
+                execution_timer: None,
+                accumulated_time: std::time::Duration::from_secs(0),
+                source: String::new(),
+                step_mode: None,
+            });
+        });
+        serde_wasm_bindgen::to_value(&info).unwrap()
+    }
+
     // Helper function to convert VmState to string
-    fn vm_state_to_string(state: vm::machine::VmState) -> &'static str {
-        match state {
+    // (input()도 호스트 요청이지만 웹 UI는 입력창을 띄우도록 따로 구분)
+    fn vm_state_to_string(vm: &vm::Vm) -> &'static str {
+        match vm.get_state() {
             vm::machine::VmState::Running => "running",
-            vm::machine::VmState::WaitingForInput => "waiting_for_input",
+            vm::machine::VmState::WaitingForHost if vm.is_waiting_for_input() => "waiting_for_input",
+            vm::machine::VmState::WaitingForHost => "waiting_for_host",
             vm::machine::VmState::Finished => "finished",
             vm::machine::VmState::Error => "error",
         }
@@ -1,156 +1,513 @@
+use clap::{Args, Parser, Subcommand};
 use pyhyeon as lib;
-use std::env;
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// `pyhc judge`의 메모리 제한과 사용량 측정 (judge나 메모리 제한을 걸 때만 세기 시작)
+#[global_allocator]
+static ALLOC: lib::vm::limits::CountingAlloc = lib::vm::limits::CountingAlloc;
 
 #[cfg(not(target_arch = "wasm32"))]
 use rustyline::DefaultEditor;
 #[cfg(not(target_arch = "wasm32"))]
 use rustyline::error::ReadlineError;
 
-fn main() {
-    // Subcommands: repl/run/compile/exec/disasm/dism
-    // repl: start the REPL (DEFAULT)
-    // run <file>: compile and execute the program
-    // compile <file> -o <output file>: compile the program to bytecode file
-    // exec <file>: execute the bytecode file
-    // disasm <file>: disassemble the bytecode file and print the result to the console
-    // dism <file>: compile source file and disassemble
-    let mut args = env::args().skip(1).collect::<Vec<String>>();
-    let mut subcmd = "repl".to_string();
-    let mut input_path = "./test.pyh".to_string();
-    let mut out_path: Option<String> = None;
-    if !args.is_empty() {
-        let first = &args[0];
-        if ["run", "compile", "exec", "repl", "disasm", "dism"].contains(&first.as_str()) {
-            subcmd = first.clone();
-            args.remove(0);
+const EXIT_STATUS_HELP: &str = "\
+Exit status:
+  0   success
+  1   runtime error (or sys.exit(\"message\"))
+  2   invalid command line
+  65  parse or semantic errors; the program did not run
+  66  the source or bytecode file could not be read
+  n   sys.exit(n)";
+
+const TEST_EXIT_STATUS_HELP: &str = "\
+Fixtures next to test_x.pyh:
+  test_x.in, test_x.NAME.in    stdin for every test / for test NAME
+  test_x.NAME.out              expected output of test NAME
+  test_x.out                   expected output of running the file as a program
+
+Exit status:
+  0   all tests passed
+  1   a test failed or had an error
+  66  a path could not be read, or no test files were found";
+
+const JUDGE_EXIT_STATUS_HELP: &str = "\
+Verdicts: AC accepted, WA wrong answer, TLE time limit exceeded,
+MLE memory limit exceeded, RE runtime error, CE compile error.
+Output is compared token by token, ignoring the amount of whitespace.
+
+Exit status:
+  0   every test was accepted
+  1   some test was not accepted
+  65  compile error
+  66  the solution or test directory could not be read, or it has no tests";
+
+/// Pyhyeon: a small Python-like language
+#[derive(Parser)]
+#[command(name = "pyhc", version, after_help = EXIT_STATUS_HELP)]
+struct Cli {
+    /// Defaults to `repl`
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Start the interactive REPL
+    Repl,
+    /// Compile and run a program
+    #[command(after_help = EXIT_STATUS_HELP)]
+    Run {
+        /// Run CODE instead of a file (sys.argv[0] is "-c")
+        #[arg(short = 'c', value_name = "CODE")]
+        code: Option<String>,
+        /// Source file, or `-` to read the program from stdin
+        #[arg(required_unless_present = "code")]
+        file: Option<String>,
+        /// Arguments for the program (sys.argv[1:]); put them after `--` if they start with `-`
+        args: Vec<String>,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+        #[command(flatten)]
+        trace: TraceArgs,
+    },
+    /// Compile a program to a bytecode file
+    Compile {
+        file: String,
+        /// Output bytecode file
+        #[arg(short = 'o', value_name = "FILE", default_value = "out.pyhb")]
+        output: String,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+    },
+    /// Run a compiled bytecode file
+    #[command(after_help = EXIT_STATUS_HELP)]
+    Exec {
+        file: String,
+        /// Arguments for the program (sys.argv[1:])
+        args: Vec<String>,
+        #[command(flatten)]
+        trace: TraceArgs,
+    },
+    /// Disassemble a bytecode file
+    Disasm { file: String },
+    /// Compile a program and print its disassembly
+    Dism {
+        file: String,
+        #[command(flatten)]
+        diagnostics: DiagnosticArgs,
+    },
+    /// Print the long-form explanation of a diagnostic code (e.g. E0201)
+    Explain { code: String },
+    /// Judge a solution against every NAME.in/NAME.out pair in a directory
+    #[command(after_help = JUDGE_EXIT_STATUS_HELP)]
+    Judge {
+        solution: String,
+        /// Directory with the NAME.in and NAME.out files
+        tests: PathBuf,
+        /// Time limit per test
+        #[arg(short = 't', long, value_name = "SECONDS", default_value_t = 2.0)]
+        time_limit: f64,
+        /// Memory limit per test
+        #[arg(short = 'm', long, value_name = "MB", default_value_t = 256)]
+        memory_limit: usize,
+    },
+    /// Run `test_*` functions in `test_*.pyh` files, each in a fresh VM
+    #[command(after_help = TEST_EXIT_STATUS_HELP)]
+    Test {
+        /// Test files or directories to search (default: current directory)
+        #[arg(default_value = ".")]
+        paths: Vec<PathBuf>,
+        /// Also write the results as JUnit XML to FILE
+        #[arg(long, value_name = "FILE")]
+        junit: Option<PathBuf>,
+        /// Print the output of failing tests
+        #[arg(short = 's', long)]
+        show_output: bool,
+    },
+}
+
+#[derive(Args)]
+struct DiagnosticArgs {
+    /// Report at most N diagnostics, N >= 1 (all errors are still collected)
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = lib::DEFAULT_MAX_DIAGNOSTICS,
+        value_parser = parse_max_errors
+    )]
+    max_errors: usize,
+}
+
+#[derive(Args)]
+struct TraceArgs {
+    /// Log every executed instruction to stderr
+    #[arg(long)]
+    trace: bool,
+    /// Only trace this function (repeatable, `<module>` for top level); implies --trace
+    #[arg(long = "trace-fn", value_name = "NAME")]
+    trace_fns: Vec<String>,
+    /// Stop logging after N lines; implies --trace
+    #[arg(long, value_name = "N")]
+    trace_max: Option<usize>,
+}
+
+impl TraceArgs {
+    fn tracer(&self, source: Option<&str>) -> Option<lib::vm::trace::Tracer> {
+        if !self.trace && self.trace_fns.is_empty() && self.trace_max.is_none() {
+            return None;
         }
+        let mut tracer = lib::vm::trace::Tracer::new(lib::vm::trace::StderrSink);
+        if let Some(source) = source {
+            tracer = tracer.with_source(source);
+        }
+        for name in &self.trace_fns {
+            tracer = tracer.only_function(name.as_str());
+        }
+        if let Some(max) = self.trace_max {
+            tracer = tracer.max_lines(max);
+        }
+        Some(tracer)
     }
-    let mut i = 0;
-    while i < args.len() {
-        match args[i].as_str() {
-            "-o" => {
-                if i + 1 < args.len() {
-                    out_path = Some(args[i + 1].clone());
-                    i += 1;
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let status = match cli.command.unwrap_or(Command::Repl) {
+        Command::Repl => match run_repl() {
+            Ok(status) => status,
+            Err(e) => {
+                eprintln!("REPL Error: {}", e);
+                1
+            }
+        },
+        Command::Run {
+            code,
+            file,
+            args,
+            diagnostics,
+            trace,
+        } => {
+            // (진단에 쓰는 이름, sys.argv[0], 소스). Python처럼 -c 뒤의 위치 인자는 모두 프로그램 인자
+            let (name, argv0, src, args) = match (code, file) {
+                (Some(code), file) => {
+                    let args = file.into_iter().chain(args).collect();
+                    ("<string>".to_string(), "-c".to_string(), code, args)
                 }
+                (None, Some(file)) => match read_source(&file) {
+                    Ok(src) if file == "-" => ("<stdin>".to_string(), file, src, args),
+                    Ok(src) => (file.clone(), file, src, args),
+                    Err(status) => return exit_code(status),
+                },
+                (None, None) => unreachable!("clap requires FILE without -c"),
+            };
+            let src = with_trailing_newline(src);
+            match check_source(&name, &src, diagnostics.max_errors) {
+                Some(program) => {
+                    let module = lib::compile_to_module(&program);
+                    let argv = std::iter::once(argv0).chain(args).collect();
+                    lib::exec_vm_module_with(module, argv, trace.tracer(Some(&src)))
+                }
+                None => lib::EXIT_COMPILE_ERROR,
+            }
+        }
+        Command::Compile {
+            file,
+            output,
+            diagnostics,
+        } => compile_file(&file, diagnostics.max_errors, |module| {
+            match lib::save_module(&module, &output) {
+                Ok(()) => {
+                    println!("wrote {}", output);
+                    0
+                }
+                Err(e) => {
+                    eprintln!("error: cannot write {}: {}", output, e);
+                    1
+                }
+            }
+        }),
+        Command::Dism { file, diagnostics } => compile_file(&file, diagnostics.max_errors, |module| {
+            print!("{}", lib::vm::disasm::disassemble_module_to_string(&module));
+            0
+        }),
+        Command::Disasm { file } => match load_bytecode(&file) {
+            Ok(module) => {
+                print!("{}", lib::vm::disasm::disassemble_module_to_string(&module));
+                0
             }
-            p => {
-                input_path = p.to_string();
+            Err(status) => status,
+        },
+        Command::Exec { file, args, trace } => match load_bytecode(&file) {
+            Ok(module) => {
+                let argv = std::iter::once(file).chain(args).collect();
+                lib::exec_vm_module_with(module, argv, trace.tracer(None))
             }
+            Err(status) => status,
+        },
+        Command::Judge {
+            solution,
+            tests,
+            time_limit,
+            memory_limit,
+        } => {
+            let limits = lib::vm::Limits {
+                time: Some(std::time::Duration::from_secs_f64(time_limit.max(0.0))),
+                memory: Some(memory_limit.saturating_mul(1024 * 1024)),
+            };
+            judge(&solution, &tests, limits)
+        }
+        Command::Test {
+            paths,
+            junit,
+            show_output,
+        } => run_tests(&paths, junit.as_deref(), show_output),
+        Command::Explain { code } => match lib::ErrorCode::from_code(&code) {
+            Some(code) => {
+                println!("{}: {}\n", code, code.title());
+                println!("{}", code.explanation());
+                0
+            }
+            None => {
+                eprintln!("Unknown error code: {}", code);
+                1
+            }
+        },
+    };
+    exit_code(status)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn judge(_solution: &str, _dir: &std::path::Path, _limits: lib::vm::Limits) -> i32 {
+    eprintln!("error: pyhc judge is not available in WASM builds");
+    1
+}
+
+/// `pyhc judge`: 테스트마다 채점하고 결과 표를 출력
+#[cfg(not(target_arch = "wasm32"))]
+fn judge(solution: &str, dir: &std::path::Path, limits: lib::vm::Limits) -> i32 {
+    use lib::judge::{self, CaseResult, JudgeReport, Verdict};
+
+    let src = match read_source(solution) {
+        Ok(src) => src,
+        Err(status) => return status,
+    };
+    let (cases, missing) = match judge::discover_cases(dir) {
+        Ok((cases, _)) if cases.is_empty() => {
+            eprintln!("error: no NAME.in/NAME.out pairs in {}", dir.display());
+            return lib::EXIT_NO_INPUT;
         }
-        i += 1;
+        Ok(found) => found,
+        Err(e) => {
+            eprintln!("error: cannot read {}: {}", dir.display(), e);
+            return lib::EXIT_NO_INPUT;
+        }
+    };
+    for input in missing {
+        eprintln!("warning: skipping {} (no matching .out file)", input.display());
     }
 
-    match subcmd.as_str() {
-        "repl" => {
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                if let Err(e) = run_repl() {
-                    eprintln!("REPL Error: {}", e);
-                }
-                return;
-            }
-            #[cfg(target_arch = "wasm32")]
-            {
-                eprintln!("REPL is not available in WASM builds");
-                return;
+    let mut report = JudgeReport::default();
+    let judge = match judge::Judge::new(&src, limits) {
+        Ok(judge) => judge.with_argv0(solution),
+        Err(diagnostics) => {
+            let src = with_trailing_newline(src);
+            report_diagnostics(solution, &src, diagnostics, lib::DEFAULT_MAX_DIAGNOSTICS);
+            for case in cases {
+                let result = CaseResult {
+                    verdict: Verdict::CompileError,
+                    time: std::time::Duration::ZERO,
+                    memory: None,
+                    detail: None,
+                };
+                report.results.push((case.name, result));
             }
+            print!("{}", report.table());
+            return lib::EXIT_COMPILE_ERROR;
         }
-        _ => {}
+    };
+    for case in cases {
+        let read = |path: &std::path::Path| {
+            std::fs::read_to_string(path).map_err(|e| {
+                eprintln!("error: cannot read {}: {}", path.display(), e);
+                lib::EXIT_NO_INPUT
+            })
+        };
+        let (input, expected) = match (read(&case.input), read(&case.expected)) {
+            (Ok(input), Ok(expected)) => (input, expected),
+            (Err(status), _) | (_, Err(status)) => return status,
+        };
+        report.results.push((case.name, judge.run(&input, &expected)));
     }
+    print!("{}", report.table());
+    if report.verdict() == Verdict::Accepted { 0 } else { 1 }
+}
 
-    // For file-based commands, read the source file
-    let path = input_path.as_str();
-    let src = std::fs::read_to_string(path).expect("Failed to read source file");
-    // add newline if not present at the end of file
-    let src = if src.ends_with('\n') {
-        src
-    } else {
-        format!("{}\n", src)
+#[cfg(target_arch = "wasm32")]
+fn run_tests(_paths: &[PathBuf], _junit: Option<&std::path::Path>, _show_output: bool) -> i32 {
+    eprintln!("error: pyhc test is not available in WASM builds");
+    1
+}
+
+/// `pyhc test`: 테스트 파일마다 결과를 한 줄씩 출력하고, 실패하면 위치와 메시지를 보여줌
+#[cfg(not(target_arch = "wasm32"))]
+fn run_tests(paths: &[PathBuf], junit: Option<&std::path::Path>, show_output: bool) -> i32 {
+    use lib::test_runner::{self, Outcome};
+
+    let files = match test_runner::discover(paths) {
+        Ok(files) if files.is_empty() => {
+            eprintln!("error: no test_*.pyh files found");
+            return lib::EXIT_NO_INPUT;
+        }
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("error: cannot read test paths: {}", e);
+            return lib::EXIT_NO_INPUT;
+        }
     };
 
-    match subcmd.as_str() {
-        "run" => {
-            let program = match lib::parse_source(&src) {
-                Ok(p) => p,
-                Err(diagnostics) => {
-                    for diag in diagnostics {
-                        eprint!("{}", diag.format(path, &src, "Parsing failed", 3));
-                    }
-                    return;
-                }
+    let start = std::time::Instant::now();
+    let mut report = test_runner::TestReport::default();
+    for file in &files {
+        for case in test_runner::run_file(file) {
+            let label = match case.outcome {
+                Outcome::Passed => "PASS ",
+                Outcome::Failed(_) => "FAIL ",
+                Outcome::Error(_) => "ERROR",
             };
-            if let Err(diag) = lib::analyze(&program) {
-                eprint!(
-                    "{}",
-                    diag.format(path, &src, "Semantic Analyzing Failed", 4)
-                );
-                return;
-            }
-            // VM only
-            let module = lib::compile_to_module(&program);
-            lib::exec_vm_module(module);
-        }
-        "compile" => {
-            let program = match lib::parse_source(&src) {
-                Ok(p) => p,
-                Err(diagnostics) => {
-                    for diag in diagnostics {
-                        eprint!("{}", diag.format(path, &src, "Parsing failed", 3));
-                    }
-                    return;
+            println!(
+                "{} {}::{} ({})",
+                label,
+                case.file,
+                case.name,
+                test_runner::format_duration(case.duration)
+            );
+            if let Some(failure) = case.outcome.failure() {
+                for line in test_runner::failure_text(&case.file, failure).lines() {
+                    println!("      {}", line);
                 }
-            };
-            if let Err(diag) = lib::analyze(&program) {
-                eprint!(
-                    "{}",
-                    diag.format(path, &src, "Semantic Analyzing Failed", 4)
-                );
-                return;
-            }
-            let module = lib::compile_to_module(&program);
-            let out = out_path.as_deref().unwrap_or("out.pyhb");
-            lib::save_module(&module, out).expect("failed to save module");
-            println!("wrote {}", out);
-        }
-        "dism" => {
-            let program = match lib::parse_source(&src) {
-                Ok(p) => p,
-                Err(diagnostics) => {
-                    for diag in diagnostics {
-                        eprint!("{}", diag.format(path, &src, "Parsing failed", 3));
+                if show_output && !case.output.is_empty() {
+                    println!("      ---- output ----");
+                    for line in case.output.lines() {
+                        println!("      {}", line);
                     }
-                    return;
                 }
-            };
-            if let Err(diag) = lib::analyze(&program) {
-                eprint!(
-                    "{}",
-                    diag.format(path, &src, "Semantic Analyzing Failed", 4)
-                );
-                return;
             }
-            let module = lib::compile_to_module(&program);
-            let output = lib::vm::disasm::disassemble_module_to_string(&module);
-            print!("{}", output);
-        }
-        "disasm" => {
-            let module = lib::load_module(path).expect("failed to load module");
-            let output = lib::vm::disasm::disassemble_module_to_string(&module);
-            print!("{}", output);
-        }
-        "exec" => {
-            let module = lib::load_module(path).expect("failed to load module");
-            lib::exec_vm_module(module);
+            report.cases.push(case);
         }
-        _ => {
-            eprintln!("Unknown subcommand: {}", subcmd);
+    }
+    report.duration = start.elapsed();
+    println!("\n{}", report.summary());
+
+    if let Some(path) = junit
+        && let Err(e) = std::fs::write(path, report.to_junit_xml())
+    {
+        eprintln!("error: cannot write {}: {}", path.display(), e);
+        return 1;
+    }
+    if report.is_success() { 0 } else { 1 }
+}
+
+/// 프로세스 종료 코드는 하위 8비트만 남음 (Python/POSIX처럼 `sys.exit(-1)`은 255, `sys.exit(256)`은 0)
+fn exit_code(status: i32) -> ExitCode {
+    ExitCode::from(status as u8)
+}
+
+/// 소스 파일(`-`이면 stdin)을 읽음. 실패하면 에러를 출력하고 종료 코드를 돌려줌
+fn read_source(path: &str) -> Result<String, i32> {
+    let result = if path == "-" {
+        let mut src = String::new();
+        std::io::stdin().read_to_string(&mut src).map(|_| src)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    result.map_err(|e| {
+        eprintln!("error: cannot read {}: {}", path, e);
+        lib::EXIT_NO_INPUT
+    })
+}
+
+fn load_bytecode(path: &str) -> Result<lib::vm::bytecode::Module, i32> {
+    lib::load_module(path).map_err(|e| {
+        eprintln!("error: cannot load bytecode {}: {}", path, e);
+        lib::EXIT_NO_INPUT
+    })
+}
+
+fn with_trailing_newline(src: String) -> String {
+    if src.ends_with('\n') {
+        src
+    } else {
+        format!("{}\n", src)
+    }
+}
+
+/// `--max-errors` 값: 0이면 아무것도 출력하지 않고 실패하게 되므로 1 이상만 받음
+fn parse_max_errors(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(0) => Err("must be at least 1".to_string()),
+        Ok(n) => Ok(n),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 소스 파일을 읽고 검사한 뒤 컴파일한 모듈을 `then`에 넘김
+fn compile_file(
+    path: &str,
+    max_errors: usize,
+    then: impl FnOnce(lib::vm::bytecode::Module) -> i32,
+) -> i32 {
+    let src = match read_source(path) {
+        Ok(src) => with_trailing_newline(src),
+        Err(status) => return status,
+    };
+    match check_source(path, &src, max_errors) {
+        Some(program) => then(lib::compile_to_module(&program)),
+        None => lib::EXIT_COMPILE_ERROR,
+    }
+}
+
+/// 파싱과 시맨틱 분석을 수행하고, 실패하면 모든 진단을 출력합니다.
+///
+/// 성공한 경우에도 경고는 출력합니다. 출력하는 진단 개수는 `max_errors`로 제한됩니다.
+fn check_source(
+    path: &str,
+    src: &str,
+    max_errors: usize,
+) -> Option<Vec<lib::parser::ast::StmtS>> {
+    match lib::parse_source(src) {
+        Ok(program) => match lib::analyze(&program) {
+            Ok(warnings) => {
+                report_diagnostics(path, src, warnings, max_errors);
+                Some(program)
+            }
+            Err(diagnostics) => {
+                report_diagnostics(path, src, diagnostics, max_errors);
+                None
+            }
+        },
+        Err(diagnostics) => {
+            report_diagnostics(path, src, diagnostics, max_errors);
+            None
         }
     }
 }
 
+fn report_diagnostics(path: &str, src: &str, mut diagnostics: Vec<lib::Diagnostic>, max: usize) {
+    let total = diagnostics.len();
+    let omitted = lib::truncate_diagnostics(&mut diagnostics, max);
+    for diag in &diagnostics {
+        eprint!("{}", diag.format(path, src));
+    }
+    if omitted > 0 {
+        eprintln!("... and {} more diagnostic(s) ({} total)", omitted, total);
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
-fn run_repl() -> Result<(), String> {
+fn run_repl() -> Result<i32, String> {
     println!("Pyhyeon REPL");
     println!("Type :help for help, :quit to exit\n");
 
@@ -171,6 +528,7 @@ fn run_repl() -> Result<(), String> {
     let mut repl_state = lib::repl::ReplState::new();
     let mut buffer = String::new();
     let mut in_block = false;
+    let mut status = 0;
 
     loop {
         // 프롬프트 설정
@@ -192,8 +550,8 @@ fn run_repl() -> Result<(), String> {
                     continue;
                 }
 
-                // 멀티라인 모드에서 빈 라인 입력 시 실행
-                if in_block && line.trim().is_empty() {
+                // 멀티라인 모드에서 빈 라인 입력 시 실행 (괄호나 세 따옴표 문자열이 열려 있으면 계속 입력)
+                if in_block && line.trim().is_empty() && !lib::repl::is_incomplete(&buffer) {
                     in_block = false;
 
                     // 히스토리에 전체 블록 추가
@@ -211,6 +569,10 @@ fn run_repl() -> Result<(), String> {
                             eprintln!("{}", e);
                         }
                     }
+                    if let Some(code) = repl_exit(&repl_state) {
+                        status = code;
+                        break;
+                    }
 
                     buffer.clear();
                     continue;
@@ -235,15 +597,16 @@ fn run_repl() -> Result<(), String> {
                 }
 
                 // 멀티라인 모드 진입/유지 체크
-                if lib::repl::needs_more_lines(&line) {
+                if lib::repl::needs_more_lines(&buffer) {
                     in_block = true;
                     continue;
                 }
 
-                // 블록 내부라면 계속
-                if in_block {
+                // 블록 내부라면 빈 줄이 올 때까지 계속
+                if in_block && lib::repl::is_in_block(&buffer) {
                     continue;
                 }
+                in_block = false;
 
                 // 단일 라인 실행
                 match repl_state.eval_line(&buffer) {
@@ -257,6 +620,10 @@ fn run_repl() -> Result<(), String> {
                         eprintln!("{}", e);
                     }
                 }
+                if let Some(code) = repl_exit(&repl_state) {
+                    status = code;
+                    break;
+                }
 
                 buffer.clear();
             }
@@ -281,10 +648,20 @@ fn run_repl() -> Result<(), String> {
     // 히스토리 저장
     let _ = rl.save_history(&history_path);
 
-    Ok(())
+    Ok(status)
+}
+
+/// 방금 실행한 입력이 `sys.exit()`를 불렀으면 REPL을 끝낼 종료 코드
+#[cfg(not(target_arch = "wasm32"))]
+fn repl_exit(repl_state: &lib::repl::ReplState) -> Option<i32> {
+    let code = repl_state.vm.exit_code()?;
+    if let Some(message) = repl_state.vm.exit_message() {
+        eprintln!("{}", message);
+    }
+    Some(code)
 }
 
 #[cfg(target_arch = "wasm32")]
-fn run_repl() -> Result<(), String> {
+fn run_repl() -> Result<i32, String> {
     Err("REPL is not available in WASM builds".to_string())
 }
@@ -17,6 +17,8 @@ pub enum UnaryOp {
     Not,
     Negate,
     Pos,
+    /// `~x` (비트 반전)
+    Invert,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -27,12 +29,29 @@ pub enum BinaryOp {
     Divide,
     FloorDivide,
     Modulo,
+    /// `a ** b` (오른쪽 결합)
+    Power,
+    BitAnd,
+    BitOr,
+    BitXor,
+    /// `a << b`
+    LeftShift,
+    /// `a >> b`
+    RightShift,
     Equal,
     NotEqual,
     Less,
     LessEqual,
     Greater,
     GreaterEqual,
+    /// `x in xs`
+    In,
+    /// `x not in xs`
+    NotIn,
+    /// `a is b` (객체 동일성)
+    Is,
+    /// `a is not b`
+    IsNot,
     And,
     Or,
 }
@@ -53,6 +72,8 @@ pub enum Expr {
     Call {
         func_name: Box<ExprS>,
         args: Vec<ExprS>,
+        /// 키워드 인자 `name=value` (위치 인자 뒤에만 올 수 있음)
+        kwargs: Vec<(String, ExprS)>,
     },
     Attribute {
         object: Box<ExprS>,
@@ -71,6 +92,38 @@ pub enum Expr {
         params: Vec<String>,
         body: Box<ExprS>,
     },
+    /// 연쇄 비교: `a < b <= c` (각 피연산자는 한 번만 평가)
+    Compare {
+        left: Box<ExprS>,
+        ops: Vec<(BinaryOp, ExprS)>,
+    },
+    /// f-string: `f"{name}: {score:.2f}"`
+    FString(Vec<FStringPart>),
+    /// 조건 표현식: `then_expr if condition else else_expr`
+    Conditional {
+        condition: Box<ExprS>,
+        then_expr: Box<ExprS>,
+        else_expr: Box<ExprS>,
+    },
+    /// 대입 표현식: `name := value` (값을 그대로 돌려줌)
+    NamedExpr {
+        name: String,
+        value: Box<ExprS>,
+    },
+    /// 언패킹 대상의 별표 요소: `first, *rest = xs`
+    Starred(Box<ExprS>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FStringPart {
+    Literal(String),
+    /// `{expr!conversion:spec}`
+    Expr {
+        expr: ExprS,
+        /// `!s` / `!r`
+        conversion: Option<char>,
+        spec: Option<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -86,7 +139,8 @@ pub enum Stmt {
         body: Vec<StmtS>,
     },
     For {
-        var: String,
+        /// 루프 대상: 이름 또는 언패킹 패턴 (`for k, v in ...`)
+        target: ExprS,
         iterable: ExprS,
         body: Vec<StmtS>,
     },
@@ -96,8 +150,15 @@ pub enum Stmt {
         body: Vec<StmtS>,
     },
     Return(ExprS),
+    /// 할당: 연쇄 할당 `a = b = value`는 대상을 왼쪽부터 차례로 가짐
     Assign {
+        targets: Vec<ExprS>,
+        value: ExprS,
+    },
+    /// 복합 할당: `target op= value` (대상은 한 번만 평가)
+    AugAssign {
         target: ExprS,
+        op: BinaryOp,
         value: ExprS,
     },
     Class {
@@ -105,6 +166,8 @@ pub enum Stmt {
         methods: Vec<MethodDef>,
         attributes: Vec<(String, ExprS)>,
     },
+    /// `import sys` (내장 모듈만 있음)
+    Import(String),
     Break,
     Continue,
     Pass,
@@ -1,4 +1,4 @@
-pub(crate) mod ast;
+pub mod ast;
 
 use crate::lexer::Token;
 use crate::types::Span;
@@ -11,11 +11,25 @@ pub use chumsky::span::SimpleSpan;
 
 type RichTokenError<'a> = Rich<'a, Token>;
 
+// Helper enum for call arguments: positional or keyword (`name=value`)
+#[derive(Debug, Clone)]
+enum CallArg {
+    Positional(ExprS),
+    Keyword(String, ExprS),
+}
+
+// Helper enum for class body items: method or class attribute (`count = 0`)
+#[derive(Debug, Clone)]
+enum ClassItem {
+    Method(MethodDef),
+    Attribute(String, ExprS),
+}
+
 // Helper enum for parsing postfix operations (internal to parser)
 #[derive(Debug, Clone)]
 enum PostfixOp {
     Attr(String, Span),
-    Call(Vec<ExprS>, Span),
+    Call(Vec<ExprS>, Vec<(String, ExprS)>, Span),
     Index(ExprS, Span),
 }
 
@@ -33,7 +47,29 @@ where
     I: ValueInput<'tokens, Token = Token, Span = SimpleSpan> + 'tokens,
 {
     // expr_parser를 사용하되, 최상위 레벨에서 쉼표로 구분된 리스트를 허용
-    expr_parser()
+    comma_list(expr_parser()).boxed()
+}
+
+/// 할당 문의 대상 목록: `tuple_or_expr_parser`에 별표 요소(`first, *rest`)를 더 허용합니다.
+/// 별표 요소의 위치와 개수는 의미 분석에서 검사합니다.
+pub fn target_list_parser<'tokens, I>()
+-> impl Parser<'tokens, I, ExprS, extra::Err<RichTokenError<'tokens>>>
+where
+    I: ValueInput<'tokens, Token = Token, Span = SimpleSpan> + 'tokens,
+{
+    let expr = expr_parser().boxed();
+    comma_list(starred_parser(expr.clone()).or(expr)).boxed()
+}
+
+/// 쉼표로 구분된 요소가 둘 이상이면 튜플, 하나면 그 표현식 자체
+fn comma_list<'tokens, I, P>(
+    elem: P,
+) -> impl Parser<'tokens, I, ExprS, extra::Err<RichTokenError<'tokens>>>
+where
+    I: ValueInput<'tokens, Token = Token, Span = SimpleSpan> + 'tokens,
+    P: Parser<'tokens, I, ExprS, extra::Err<RichTokenError<'tokens>>>,
+{
+    elem
         .separated_by(just(Token::Comma))
         .at_least(1)
         .allow_trailing()
@@ -50,7 +86,36 @@ where
                 (Expr::Tuple(exprs), span)
             }
         })
-        .boxed()
+}
+
+/// 별표 요소 `*expr` → `Expr::Starred`
+fn starred_parser<'tokens, I, P>(
+    expr: P,
+) -> impl Parser<'tokens, I, ExprS, extra::Err<RichTokenError<'tokens>>> + Clone
+where
+    I: ValueInput<'tokens, Token = Token, Span = SimpleSpan> + 'tokens,
+    P: Parser<'tokens, I, ExprS, extra::Err<RichTokenError<'tokens>>> + Clone,
+{
+    just(Token::Star)
+        .ignore_then(expr)
+        .map_with(|inner: ExprS, e| {
+            let s: I::Span = e.span();
+            (Expr::Starred(Box::new(inner)), s.into_range())
+        })
+        .labelled("starred expression")
+}
+
+/// 좌결합 이항 연산 fold: `left op right` → `Expr::Binary`
+fn fold_binary(left: ExprS, (op, right): (BinaryOp, ExprS)) -> ExprS {
+    let span = left.1.start..right.1.end;
+    (
+        Expr::Binary {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+        },
+        span,
+    )
 }
 
 pub fn expr_parser<'tokens, I>()
@@ -61,8 +126,11 @@ where
     recursive(|expr| {
         let ident = select! { Token::Identifier(s) => s }.labelled("identifier");
 
+        // 리스트/튜플 리터럴 요소: 언패킹 대상 `[a, *rest]`을 위해 별표 요소 허용
+        let elem = starred_parser(expr.clone()).or(expr.clone()).boxed();
+
         // List literal: [expr, expr, ...]
-        let list_literal = expr
+        let list_literal = elem
             .clone()
             .separated_by(just(Token::Comma))
             .allow_trailing()
@@ -119,10 +187,10 @@ where
         // We need to detect trailing comma to distinguish them
         let tuple_literal = just(Token::LParen)
             .ignore_then(
-                expr.clone()
+                elem.clone()
                     .then(
                         just(Token::Comma)
-                            .then(expr.clone().separated_by(just(Token::Comma)).allow_trailing().collect::<Vec<_>>())
+                            .then(elem.clone().separated_by(just(Token::Comma)).allow_trailing().collect::<Vec<_>>())
                             .map(|(_, rest)| rest)
                             .or_not()
                     )
@@ -147,16 +215,57 @@ where
             .labelled("tuple or grouped expression")
             .boxed();
 
+        // f-string: FStringStart (text | {expr!conv:spec})* FStringEnd
+        // 표현식 부분은 렉서가 일반 토큰으로 펼쳐 두었으므로 expr 파서를 그대로 사용
+        let fstring_part = choice((
+            select! { Token::FStringText(s) => FStringPart::Literal(s) },
+            just(Token::FStringExprStart)
+                .ignore_then(expr.clone())
+                .then(select! { Token::FStringConv(c) => c }.or_not())
+                .then(select! { Token::FStringSpec(s) => s }.or_not())
+                .then_ignore(just(Token::FStringExprEnd))
+                .map(|((expr, conversion), spec)| FStringPart::Expr {
+                    expr,
+                    conversion,
+                    spec,
+                }),
+        ));
+        let fstring_literal = fstring_part
+            .repeated()
+            .collect()
+            .delimited_by(just(Token::FStringStart), just(Token::FStringEnd))
+            .map(Expr::FString)
+            .labelled("f-string")
+            .boxed();
+
         // Primary: literals, variables, parenthesized expressions
+        // 렉서는 2^63을 Int(i64::MIN)으로 넘김: 앞에 `-`가 붙지 않은 경우만 여기로 옴
+        let int_literal = select! { Token::Int(i) => i }
+            .validate(|i, e, emitter| {
+                if i == i64::MIN {
+                    emitter.emit(Rich::custom(
+                        e.span(),
+                        format!(
+                            "Integer literal '{}' is out of range (valid range: {} to {})",
+                            1u64 << 63,
+                            i64::MIN,
+                            i64::MAX
+                        ),
+                    ));
+                }
+                Expr::Literal(Literal::Int(i))
+            })
+            .labelled("literal");
         let primary = choice((
+            int_literal,
             select! {
-                Token::Int(i) => Expr::Literal(Literal::Int(i)),
                 Token::Bool(b) => Expr::Literal(Literal::Bool(b)),
                 Token::String(s) => Expr::Literal(Literal::String(s)),
                 Token::Float(f) => Expr::Literal(Literal::Float(f)),
                 Token::None => Expr::Literal(Literal::None),
             }
             .labelled("literal"),
+            fstring_literal,
             list_literal,
             treeset_literal,
             set_or_dict_literal,
@@ -192,15 +301,43 @@ where
                     let s: I::Span = e.span();
                     PostfixOp::Attr(attr, s.into_range())
                 }),
-            // (args) (function/method call)
-            expr.clone()
+            // (args) (function/method call), 키워드 인자는 위치 인자 뒤에만 허용
+            ident
+                .then_ignore(just(Token::Equal))
+                .then(expr.clone())
+                .map(|(name, value)| CallArg::Keyword(name, value))
+                .or(expr.clone().map(CallArg::Positional))
                 .separated_by(just(Token::Comma))
                 .allow_trailing()
-                .collect()
+                .collect::<Vec<_>>()
                 .delimited_by(just(Token::LParen), just(Token::RParen))
-                .map_with(|args, e| {
+                .validate(|call_args, e, emitter| {
+                    let mut args = Vec::new();
+                    let mut kwargs: Vec<(String, ExprS)> = Vec::new();
+                    for arg in call_args {
+                        match arg {
+                            CallArg::Positional(value) => {
+                                if !kwargs.is_empty() {
+                                    emitter.emit(Rich::custom(
+                                        SimpleSpan::from(value.1.clone()),
+                                        "positional argument follows keyword argument",
+                                    ));
+                                }
+                                args.push(value);
+                            }
+                            CallArg::Keyword(name, value) => {
+                                if kwargs.iter().any(|(k, _)| *k == name) {
+                                    emitter.emit(Rich::custom(
+                                        SimpleSpan::from(value.1.clone()),
+                                        format!("keyword argument repeated: {}", name),
+                                    ));
+                                }
+                                kwargs.push((name, value));
+                            }
+                        }
+                    }
                     let s: I::Span = e.span();
-                    PostfixOp::Call(args, s.into_range())
+                    PostfixOp::Call(args, kwargs, s.into_range())
                 }),
             // [index] (indexing)
             expr.clone()
@@ -226,12 +363,13 @@ where
                     )
                 }
                 // {base}({args})
-                PostfixOp::Call(args, op_span) => {
+                PostfixOp::Call(args, kwargs, op_span) => {
                     let end = op_span.end;  // use actual span end from parser
                     (
                         Expr::Call {
                             func_name: Box::new(base),
                             args,
+                            kwargs,
                         },
                         start..end,
                     )
@@ -252,28 +390,57 @@ where
 
         // Capture unary operator with its span
         let op_unary = choice((
-            just(Token::Not).to(UnaryOp::Not),
             just(Token::Minus).to(UnaryOp::Negate),
             just(Token::Plus).to(UnaryOp::Pos),
+            just(Token::Tilde).to(UnaryOp::Invert),
         ))
         .map_with(|op, e| {
             let s: I::Span = e.span();
             (op, s.into_range())
         }).boxed();
 
-        let unary = op_unary.repeated().foldr(
-            atom,
-            |(op, op_span): (UnaryOp, Span), right: ExprS| {
-                let span = op_span.start..right.1.end;
-                (
-                    Expr::Unary {
-                        op,
-                        expr: Box::new(right),
-                    },
-                    span,
+        // 거듭제곱은 단항 연산자보다 강하게, 오른쪽으로 결합: -2 ** 2 == -4, 2 ** 3 ** 2 == 512
+        // 지수 자리는 단항식이라 2 ** -1 도 허용
+        let unary = recursive(|unary| {
+            let power = atom
+                .clone()
+                .then(just(Token::StarStar).ignore_then(unary).or_not())
+                .map(|(base, exp): (ExprS, Option<ExprS>)| match exp {
+                    Some(exp) => fold_binary(base, (BinaryOp::Power, exp)),
+                    None => base,
+                });
+            // `-9223372036854775808`: 부호를 리터럴에 접어야 i64::MIN을 쓸 수 있음
+            // (뒤에 `**`나 후위 연산이 오면 Python처럼 부호가 나중에 적용되므로 접지 않음)
+            let negated_min = just(Token::Minus)
+                .ignore_then(select! { Token::Int(i64::MIN) => () })
+                .then_ignore(
+                    choice((
+                        just(Token::StarStar),
+                        just(Token::Dot),
+                        just(Token::LParen),
+                        just(Token::LBracket),
+                    ))
+                    .not(),
                 )
-            }
-        ).boxed();
+                .map_with(|_, e| {
+                    let s: I::Span = e.span();
+                    (Expr::Literal(Literal::Int(i64::MIN)), s.into_range())
+                });
+            let prefixed = op_unary.clone().repeated().foldr(
+                power,
+                |(op, op_span): (UnaryOp, Span), right: ExprS| {
+                    let span = op_span.start..right.1.end;
+                    (
+                        Expr::Unary {
+                            op,
+                            expr: Box::new(right),
+                        },
+                        span,
+                    )
+                }
+            );
+            negated_min.or(prefixed)
+        }).boxed();
 
         let op = |t| just(t).ignored().boxed();
         let product = unary.clone().foldl(
@@ -316,7 +483,29 @@ where
                     )
                 },
             ).boxed();
-        let comparison = sum.clone().then(
+        // 비트 연산 (Python 우선순위: shift > & > ^ > |, 모두 비교보다 강함)
+        let shift = sum.clone().foldl(
+            choice((
+                op(Token::LessLess).to(BinaryOp::LeftShift),
+                op(Token::GreaterGreater).to(BinaryOp::RightShift),
+            ))
+            .then(sum)
+            .repeated(),
+            fold_binary,
+        ).boxed();
+        let bit_and = shift.clone().foldl(
+            op(Token::Amp).to(BinaryOp::BitAnd).then(shift).repeated(),
+            fold_binary,
+        ).boxed();
+        let bit_xor = bit_and.clone().foldl(
+            op(Token::Caret).to(BinaryOp::BitXor).then(bit_and).repeated(),
+            fold_binary,
+        ).boxed();
+        let bit_or = bit_xor.clone().foldl(
+            op(Token::Pipe).to(BinaryOp::BitOr).then(bit_xor).repeated(),
+            fold_binary,
+        ).boxed();
+        let comparison = bit_or.clone().then(
             choice((
                 op(Token::Less).to(BinaryOp::Less),
                 op(Token::LessEqual).to(BinaryOp::LessEqual),
@@ -324,39 +513,53 @@ where
                 op(Token::GreaterEqual).to(BinaryOp::GreaterEqual),
                 op(Token::EqualEqual).to(BinaryOp::Equal),
                 op(Token::NotEqual).to(BinaryOp::NotEqual),
+                op(Token::In).to(BinaryOp::In),
+                just(Token::Not).then(just(Token::In)).to(BinaryOp::NotIn),
+                just(Token::Is).then(just(Token::Not)).to(BinaryOp::IsNot),
+                op(Token::Is).to(BinaryOp::Is),
             ))
-            .then(sum)
+            .then(bit_or)
             .repeated()
             .collect::<Vec<_>>()
         )
-        .map(|(left, chain)| {
-            if chain.is_empty() {
-                return left;
-            }
-
-            let mut terms = vec![left];
-            terms.extend(chain.iter().map(|(_, term)| term.clone()));
-
-            let mut comparisons = vec![];
-            for i in 0..chain.len() {
-                let (op, _) = &chain[i];
-                let l = terms[i].clone();
-                let r = terms[i+1].clone();
-                let span = l.1.start..r.1.end;
-                comparisons.push((Expr::Binary { op: op.clone(), left: Box::new(l), right: Box::new(r) }, span));
+        .map(|(left, mut chain): (ExprS, Vec<(BinaryOp, ExprS)>)| {
+            match chain.len() {
+                0 => left,
+                // 단일 비교는 일반 이항 연산
+                1 => {
+                    let (op, right) = chain.pop().unwrap();
+                    let span = left.1.start..right.1.end;
+                    (Expr::Binary { op, left: Box::new(left), right: Box::new(right) }, span)
+                }
+                // 연쇄 비교: a < b < c → 중간 피연산자를 한 번만 평가
+                _ => {
+                    let span = left.1.start..chain.last().unwrap().1.1.end;
+                    (Expr::Compare { left: Box::new(left), ops: chain }, span)
+                }
             }
-
-            comparisons.into_iter().reduce(|acc, next| {
-                let span = acc.1.start..next.1.end;
-                (Expr::Binary {
-                    op: BinaryOp::And,
-                    left: Box::new(acc),
-                    right: Box::new(next),
-                }, span)
-            }).unwrap()
         }).boxed();
-        let and_expr = comparison.clone().foldl(
-            op(Token::And).to(BinaryOp::And).then(comparison).repeated(),
+        // `not`은 비교보다 약하게 결합: `not x in y` == `not (x in y)`
+        let not_expr = recursive(|not_expr| {
+            just(Token::Not)
+                .map_with(|_, e| {
+                    let s: I::Span = e.span();
+                    s.into_range()
+                })
+                .then(not_expr)
+                .map(|(op_span, right): (Span, ExprS)| {
+                    let span = op_span.start..right.1.end;
+                    (
+                        Expr::Unary {
+                            op: UnaryOp::Not,
+                            expr: Box::new(right),
+                        },
+                        span,
+                    )
+                })
+                .or(comparison)
+        }).boxed();
+        let and_expr = not_expr.clone().foldl(
+            op(Token::And).to(BinaryOp::And).then(not_expr).repeated(),
             |left: ExprS, (op, right): (BinaryOp, ExprS)| {
                 let span = left.1.start..right.1.end;
                 (
@@ -384,6 +587,48 @@ where
             },
         ).boxed();
 
+        // 조건 표현식: `a if cond else b` (else 쪽은 다시 조건 표현식이나 lambda 가능)
+        let conditional = or_expr
+            .clone()
+            .then(
+                just(Token::If)
+                    .ignore_then(or_expr.clone())
+                    .then_ignore(just(Token::Else))
+                    .then(expr.clone())
+                    .or_not(),
+            )
+            .map(|(then_expr, rest): (ExprS, Option<(ExprS, ExprS)>)| match rest {
+                Some((condition, else_expr)) => {
+                    let span = then_expr.1.start..else_expr.1.end;
+                    (
+                        Expr::Conditional {
+                            condition: Box::new(condition),
+                            then_expr: Box::new(then_expr),
+                            else_expr: Box::new(else_expr),
+                        },
+                        span,
+                    )
+                }
+                None => then_expr,
+            })
+            .boxed();
+
+        // 대입 표현식: `name := value`
+        let named_expr = ident
+            .then_ignore(just(Token::ColonEqual))
+            .then(expr.clone())
+            .map_with(|(name, value): (String, ExprS), e| {
+                let s: I::Span = e.span();
+                (
+                    Expr::NamedExpr {
+                        name,
+                        value: Box::new(value),
+                    },
+                    s.into_range(),
+                )
+            })
+            .labelled("assignment expression");
+
         let lambda_expr = just(Token::Lambda)
             .ignore_then(
                 ident
@@ -405,7 +650,7 @@ where
             })
             .labelled("lambda expression");
 
-        choice((lambda_expr, or_expr)).labelled("expression")
+        choice((lambda_expr, named_expr, conditional)).labelled("expression")
     }).boxed()
 }
 
@@ -417,6 +662,8 @@ where
     let expr = expr_parser().boxed();
     // 괄호 없는 튜플을 허용하는 표현식 파서 (RHS, return 값 등)
     let tuple_or_expr = tuple_or_expr_parser().boxed();
+    // 할당 대상 (별표 요소 허용)
+    let target_list = target_list_parser().boxed();
 
     recursive(|stmt| {
         let ident = select! { Token::Identifier(s) => s }.labelled("identifier");
@@ -432,22 +679,52 @@ where
             .boxed();
 
         // Assignment: LHS도 튜플 패턴을 지원해야 함
-        // LHS: expr (Variable, Attribute, Index, 또는 Tuple)
+        // LHS: expr (Variable, Attribute, Index, 또는 Tuple), 연쇄 할당 `a = b = 0` 허용
         // RHS: tuple_or_expr (괄호 없는 튜플 허용)
-        let assign_stmt = tuple_or_expr
+        let assign_stmt = target_list
             .clone()
             .then_ignore(just(Token::Equal))
+            .repeated()
+            .at_least(1)
+            .collect::<Vec<ExprS>>()
             .then(tuple_or_expr.clone())
-            .map_with(|(target, value), e| {
+            .map_with(|(targets, value): (Vec<ExprS>, ExprS), e| {
                 let s: I::Span = e.span();
+                let span = s.into_range();
                 Stmt::Assign {
-                    target: (target.0, s.into_range()),
+                    targets: targets
+                        .into_iter()
+                        .map(|target| (target.0, span.clone()))
+                        .collect(),
                     value,
                 }
             })
             .labelled("assignment")
             .boxed();
 
+        // 복합 할당: x += 1, obj.count -= 1, xs[i] <<= 2
+        let aug_op = choice((
+            just(Token::PlusEqual).to(BinaryOp::Add),
+            just(Token::MinusEqual).to(BinaryOp::Subtract),
+            just(Token::StarEqual).to(BinaryOp::Multiply),
+            just(Token::SlashEqual).to(BinaryOp::Divide),
+            just(Token::SlashSlashEqual).to(BinaryOp::FloorDivide),
+            just(Token::PercentEqual).to(BinaryOp::Modulo),
+            just(Token::StarStarEqual).to(BinaryOp::Power),
+            just(Token::AmpEqual).to(BinaryOp::BitAnd),
+            just(Token::PipeEqual).to(BinaryOp::BitOr),
+            just(Token::CaretEqual).to(BinaryOp::BitXor),
+            just(Token::LessLessEqual).to(BinaryOp::LeftShift),
+            just(Token::GreaterGreaterEqual).to(BinaryOp::RightShift),
+        ));
+        let aug_assign_stmt = expr
+            .clone()
+            .then(aug_op)
+            .then(tuple_or_expr.clone())
+            .map(|((target, op), value)| Stmt::AugAssign { target, op, value })
+            .labelled("augmented assignment")
+            .boxed();
+
         let expr_stmt = tuple_or_expr
             .clone()
             .map(Stmt::Expr)
@@ -466,14 +743,22 @@ where
 
         let pass_stmt = just(Token::Pass).to(Stmt::Pass).labelled("pass statement").boxed();
 
+        let import_stmt = just(Token::Import)
+            .ignore_then(ident)
+            .map(Stmt::Import)
+            .labelled("import statement")
+            .boxed();
+
         // A line of one or more simple statements separated by ';' with optional trailing ';'
         let simple_stmt = choice((
             return_stmt.clone(),
             assign_stmt.clone(),
+            aug_assign_stmt.clone(),
             expr_stmt.clone(),
             break_stmt.clone(),
             continue_stmt.clone(),
             pass_stmt.clone(),
+            import_stmt.clone(),
         ))
         .map_with(|node: Stmt, e| {
             let s: I::Span = e.span();
@@ -553,13 +838,62 @@ where
             .labelled("while statement")
             .boxed();
 
+        // for 대상: 이름, `*이름`, 괄호/대괄호로 묶은 중첩 패턴을 쉼표로 나열
+        // (`in`을 비교 연산자로 먹지 않도록 일반 표현식 대신 전용 파서 사용)
+        let for_target = recursive(|target| {
+            let group = |open, close| {
+                target
+                    .clone()
+                    .separated_by(just(Token::Comma))
+                    .allow_trailing()
+                    .collect::<Vec<ExprS>>()
+                    .delimited_by(just(open), just(close))
+            };
+            let single = choice((
+                ident.map(Expr::Variable),
+                just(Token::Star)
+                    .ignore_then(ident.map_with(|name, e| {
+                        let s: I::Span = e.span();
+                        (Expr::Variable(name), s.into_range())
+                    }))
+                    .map(|inner| Expr::Starred(Box::new(inner))),
+                // `(a)`는 괄호로 묶은 단일 대상
+                group(Token::LParen, Token::RParen).map(|mut elems| {
+                    if elems.len() == 1 {
+                        elems.pop().unwrap().0
+                    } else {
+                        Expr::Tuple(elems)
+                    }
+                }),
+                group(Token::LBracket, Token::RBracket).map(Expr::List),
+            ))
+            .map_with(|node, e| {
+                let s: I::Span = e.span();
+                (node, s.into_range())
+            });
+            single
+                .separated_by(just(Token::Comma))
+                .at_least(1)
+                .allow_trailing()
+                .collect::<Vec<ExprS>>()
+                .map_with(|mut targets, e| {
+                    if targets.len() == 1 {
+                        targets.pop().unwrap()
+                    } else {
+                        let s: I::Span = e.span();
+                        (Expr::Tuple(targets), s.into_range())
+                    }
+                })
+        })
+        .boxed();
+
         let for_stmt = just(Token::For)
-            .ignore_then(ident)
+            .ignore_then(for_target)
             .then_ignore(just(Token::In))
             .then(expr.clone())
             .then(block.clone())
-            .map(|((var, iterable), body)| Stmt::For {
-                var,
+            .map(|((target, iterable), body)| Stmt::For {
+                target,
                 iterable,
                 body,
             })
@@ -581,6 +915,15 @@ where
             .labelled("method definition")
             .boxed();
 
+        // 클래스 속성: 본문에서 `name = expr` 한 줄
+        let class_attr = ident
+            .then_ignore(just(Token::Equal))
+            .then(expr.clone())
+            .then_ignore(just(Token::Newline))
+            .then_ignore(just(Token::Newline).ignored().repeated())
+            .labelled("class attribute")
+            .boxed();
+
         let class_stmt = just(Token::Class)
             .ignore_then(ident)
             .then_ignore(just(Token::Colon))
@@ -589,23 +932,29 @@ where
                     .ignore_then(just(Token::Newline).ignored().repeated())
                     .ignore_then(just(Token::Indent))
                     .ignore_then(
-                        method_def
-                            .map_with(|m, e| {
-                                let s: I::Span = e.span();
-                                (m, s.into_range())
-                            })
-                            .repeated()
-                            .at_least(1)
-                            .collect::<Vec<(MethodDef, Span)>>(),
+                        choice((
+                            method_def.map(ClassItem::Method),
+                            class_attr.map(|(name, value)| ClassItem::Attribute(name, value)),
+                        ))
+                        .repeated()
+                        .at_least(1)
+                        .collect::<Vec<ClassItem>>(),
                     )
                     .then_ignore(just(Token::Dedent)),
             )
-            .map(|(name, methods)| {
-                let methods = methods.into_iter().map(|(m, _)| m).collect();
+            .map(|(name, items)| {
+                let mut methods = vec![];
+                let mut attributes = vec![];
+                for item in items {
+                    match item {
+                        ClassItem::Method(m) => methods.push(m),
+                        ClassItem::Attribute(attr, value) => attributes.push((attr, value)),
+                    }
+                }
                 Stmt::Class {
                     name,
                     methods,
-                    attributes: vec![], // v1에서는 클래스 속성 미지원
+                    attributes,
                 }
             })
             .labelled("class statement")
@@ -622,15 +971,31 @@ where
         // Allow empty lines between statements
         let blank_lines = just(Token::Newline).ignored().repeated().boxed();
 
+        // 에러 복구: 잘못된 줄(과 그 줄에 딸린 들여쓰기 블록)을 통째로 건너뛰고
+        // 다음 줄부터 다시 파싱한다. 건너뛴 줄은 문장을 만들지 않는다.
+        let skip_block = recursive(|skip_block| {
+            just(Token::Indent)
+                .ignore_then(
+                    skip_block
+                        .or(none_of([Token::Indent, Token::Dedent]).ignored())
+                        .repeated(),
+                )
+                .then_ignore(just(Token::Dedent))
+        });
+        let skip_line = choice((
+            none_of([Token::Newline, Token::Indent, Token::Dedent])
+                .repeated()
+                .at_least(1)
+                .then(just(Token::Newline).ignored().or(end()))
+                .ignored(),
+            just(Token::Newline).ignored(),
+        ))
+        .then(skip_block.or_not())
+        .to(Vec::new());
+
         choice((compound_stmt_line, simple_stmts_line))
             .padded_by(blank_lines)
-            .recover_with(skip_then_retry_until(
-                any().ignored(),
-                just(Token::Newline)
-                    .ignored()
-                    .or(just(Token::Dedent).ignored())
-                    .or(end().ignored()),
-            ))
+            .recover_with(via_parser(skip_line))
     }).boxed()
 }
 
@@ -771,19 +1136,99 @@ mod tests {
 
     #[test]
     fn test_parse_unary_negate() {
+        // 부호는 리터럴이 아니라 단항 연산자 (-2 ** 2 == -(2 ** 2))
         let result = parse_expr("-42");
         assert!(result.is_ok());
-        if let Expr::Literal(Literal::Int(-42)) = result.unwrap().0 {
-            assert!(true);
+        if let Expr::Unary { op, expr } = result.unwrap().0 {
+            assert!(matches!(op, UnaryOp::Negate));
+            assert!(matches!(expr.0, Expr::Literal(Literal::Int(42))));
         } else {
-            panic!("Expected literal int -42");
+            panic!("Expected unary negate");
         }
-        // if let Expr::Unary { op, expr } = result.unwrap().0 {
-        //     assert!(matches!(op, UnaryOp::Negate));
-        //     assert!(matches!(expr.0, Expr::Literal(Literal::Int(42))));
-        // } else {
-        //     panic!("Expected unary negate");
-        // }
+    }
+
+    #[test]
+    fn test_parse_negated_int_min() {
+        // 2^63은 `-`가 바로 앞에 있을 때만 i64::MIN 리터럴로 접힘
+        let expr = parse_expr("-9223372036854775808").unwrap();
+        assert!(matches!(expr.0, Expr::Literal(Literal::Int(i64::MIN))));
+        let expr = parse_expr("-0x8000000000000000").unwrap();
+        assert!(matches!(expr.0, Expr::Literal(Literal::Int(i64::MIN))));
+
+        for source in ["9223372036854775808", "1 - 9223372036854775808", "-9223372036854775808 ** 2"] {
+            let errors = parse_expr(source).unwrap_err();
+            assert!(
+                errors.iter().any(|e| e.to_string().contains("out of range")),
+                "{}: {:?}",
+                source,
+                errors
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_power_and_bitwise_precedence() {
+        // -2 ** 2 → Negate(Power(2, 2))
+        let expr = parse_expr("-2 ** 2").unwrap();
+        match expr.0 {
+            Expr::Unary { op: UnaryOp::Negate, expr } => {
+                assert!(matches!(expr.0, Expr::Binary { op: BinaryOp::Power, .. }));
+            }
+            other => panic!("Expected negate of power, got {:?}", other),
+        }
+
+        // 2 ** 3 ** 2 → Power(2, Power(3, 2)) (오른쪽 결합)
+        let expr = parse_expr("2 ** 3 ** 2").unwrap();
+        match expr.0 {
+            Expr::Binary { op: BinaryOp::Power, left, right } => {
+                assert!(matches!(left.0, Expr::Literal(Literal::Int(2))));
+                assert!(matches!(right.0, Expr::Binary { op: BinaryOp::Power, .. }));
+            }
+            other => panic!("Expected power, got {:?}", other),
+        }
+
+        // 1 | 2 ^ 3 & 4 << 1 → Or(1, Xor(2, And(3, Shl(4, 1))))
+        let expr = parse_expr("1 | 2 ^ 3 & 4 << 1").unwrap();
+        let Expr::Binary { op: BinaryOp::BitOr, right, .. } = expr.0 else {
+            panic!("Expected | at top level");
+        };
+        let Expr::Binary { op: BinaryOp::BitXor, right, .. } = right.0 else {
+            panic!("Expected ^ under |");
+        };
+        let Expr::Binary { op: BinaryOp::BitAnd, right, .. } = right.0 else {
+            panic!("Expected & under ^");
+        };
+        assert!(matches!(right.0, Expr::Binary { op: BinaryOp::LeftShift, .. }));
+
+        // 비트 연산은 비교보다 강하게 결합: x & 1 == 1 → (x & 1) == 1
+        let expr = parse_expr("x & 1 == 1").unwrap();
+        assert!(matches!(expr.0, Expr::Binary { op: BinaryOp::Equal, .. }));
+    }
+
+    #[test]
+    fn test_parse_class_attributes() {
+        let stmts = parse_program("class C:\n  count = 0\n\n  def get(self):\n    return self.count\n  name = 'c'\n").unwrap();
+        let Stmt::Class { methods, attributes, .. } = &stmts[0].0 else {
+            panic!("Expected class, got {:?}", stmts[0].0);
+        };
+        assert_eq!(methods.len(), 1);
+        let names: Vec<&str> = attributes.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["count", "name"]);
+        assert!(matches!(attributes[0].1.0, Expr::Literal(Literal::Int(0))));
+    }
+
+    #[test]
+    fn test_parse_aug_assign() {
+        let stmts = parse_program("xs[i] **= 2\n").unwrap();
+        match &stmts[0].0 {
+            Stmt::AugAssign { target, op, value } => {
+                assert!(matches!(target.0, Expr::Index { .. }));
+                assert_eq!(*op, BinaryOp::Power);
+                assert!(matches!(value.0, Expr::Literal(Literal::Int(2))));
+            }
+            other => panic!("Expected augmented assignment, got {:?}", other),
+        }
+        assert!(parse_program("a, b += 1\n").is_err());
     }
 
     #[test]
@@ -802,7 +1247,7 @@ mod tests {
     fn test_parse_call_no_args() {
         let result = parse_expr("foo()");
         assert!(result.is_ok());
-        if let Expr::Call { func_name, args } = result.unwrap().0 {
+        if let Expr::Call { func_name, args, .. } = result.unwrap().0 {
             assert!(matches!(func_name.0, Expr::Variable(_)));
             assert_eq!(args.len(), 0);
         } else {
@@ -814,7 +1259,7 @@ mod tests {
     fn test_parse_call_with_args() {
         let result = parse_expr("add(1, 2)");
         assert!(result.is_ok());
-        if let Expr::Call { func_name, args } = result.unwrap().0 {
+        if let Expr::Call { func_name, args, .. } = result.unwrap().0 {
             if let Expr::Variable(name) = &func_name.0 {
                 assert_eq!(name, "add");
             } else {
@@ -887,28 +1332,55 @@ mod tests {
 
     #[test]
     fn test_parse_comparison_chain() {
-        let result = parse_expr("1 < x < 10");
+        let result = parse_expr("1 < x <= 10");
         assert!(result.is_ok());
         let expr = result.unwrap();
-        // Should be parsed as (1 < x) and (x < 10)
-        if let Expr::Binary { op: BinaryOp::And, left, right } = expr.0 {
-            if let Expr::Binary { op: BinaryOp::Less, left: l1, right: r1 } = left.0 {
-                assert!(matches!(l1.0, Expr::Literal(Literal::Int(1))));
-                assert!(matches!(r1.0, Expr::Variable(_)));
-            } else {
-                panic!("Expected less expression on left");
-            }
-            if let Expr::Binary { op: BinaryOp::Less, left: l2, right: r2 } = right.0 {
-                assert!(matches!(l2.0, Expr::Variable(_)));
-                assert!(matches!(r2.0, Expr::Literal(Literal::Int(10))));
-            } else {
-                panic!("Expected less expression on right");
-            }
+        // 중간 피연산자를 복제하지 않는 Compare 노드
+        if let Expr::Compare { left, ops } = expr.0 {
+            assert!(matches!(left.0, Expr::Literal(Literal::Int(1))));
+            assert_eq!(ops.len(), 2);
+            assert!(matches!(ops[0], (BinaryOp::Less, (Expr::Variable(_), _))));
+            assert!(matches!(ops[1], (BinaryOp::LessEqual, (Expr::Literal(Literal::Int(10)), _))));
         } else {
-            panic!("Expected and expression at top level");
+            panic!("Expected chained comparison");
         }
     }
 
+    #[test]
+    fn test_parse_membership_and_identity() {
+        let cases = [
+            ("x in xs", BinaryOp::In),
+            ("x not in xs", BinaryOp::NotIn),
+            ("x is None", BinaryOp::Is),
+            ("x is not None", BinaryOp::IsNot),
+        ];
+        for (src, expected) in cases {
+            let expr = parse_expr(src).unwrap();
+            assert!(
+                matches!(&expr.0, Expr::Binary { op, .. } if *op == expected),
+                "{}: {:?}",
+                src,
+                expr.0
+            );
+        }
+        // 비교 연산자와 같은 우선순위로 연쇄 가능
+        assert!(matches!(parse_expr("a in b == c").unwrap().0, Expr::Compare { .. }));
+
+        // `not`은 비교보다 약하게: not x in y → not (x in y), not a == b and c → (not (a == b)) and c
+        for (src, inner) in [("not x in xs", BinaryOp::In), ("not x is None", BinaryOp::Is)] {
+            let Expr::Unary { op: UnaryOp::Not, expr } = parse_expr(src).unwrap().0 else {
+                panic!("{}: expected not at top level", src);
+            };
+            assert!(matches!(&expr.0, Expr::Binary { op, .. } if *op == inner), "{}", src);
+        }
+        let Expr::Binary { op: BinaryOp::And, left, .. } = parse_expr("not a == b and c").unwrap().0
+        else {
+            panic!("expected and at top level");
+        };
+        assert!(matches!(left.0, Expr::Unary { op: UnaryOp::Not, .. }));
+        assert!(parse_expr("1 + not x").is_err());
+    }
+
     // ========== 문장 파싱 테스트 ==========
 
     #[test]
@@ -918,7 +1390,8 @@ mod tests {
         assert!(result.is_ok(), "Failed to parse: {:?}", result.err());
         let stmts = result.unwrap();
         assert_eq!(stmts.len(), 1);
-        if let Stmt::Assign { target, value } = &stmts[0].0 {
+        if let Stmt::Assign { targets, value } = &stmts[0].0 {
+            let target = &targets[0];
             assert!(matches!(target.0, Expr::Variable(_)));
             if let Expr::Variable(name) = &target.0 {
                 assert_eq!(name, "x");
@@ -1118,7 +1591,8 @@ if x > 0:
         assert!(result.is_ok());
         let stmts = result.unwrap();
         assert_eq!(stmts.len(), 1);
-        if let Stmt::Assign { target, value } = &stmts[0].0 {
+        if let Stmt::Assign { targets, value } = &stmts[0].0 {
+            let target = &targets[0];
             assert!(matches!(target.0, Expr::Variable(_)));
             if let Expr::Tuple(elements) = &value.0 {
                 assert_eq!(elements.len(), 3);
@@ -1152,7 +1626,8 @@ if x > 0:
         let result = parse_program("a, b, c = 1, 2, 3\n");
         assert!(result.is_ok());
         let stmts = result.unwrap();
-        if let Stmt::Assign { target, value } = &stmts[0].0 {
+        if let Stmt::Assign { targets, value } = &stmts[0].0 {
+            let target = &targets[0];
             // LHS는 튜플 패턴
             if let Expr::Tuple(targets) = &target.0 {
                 assert_eq!(targets.len(), 3);
@@ -1173,7 +1648,8 @@ if x > 0:
         let result = parse_program("x, y, z = [1, 2, 3]\n");
         assert!(result.is_ok());
         let stmts = result.unwrap();
-        if let Stmt::Assign { target, value } = &stmts[0].0 {
+        if let Stmt::Assign { targets, value } = &stmts[0].0 {
+            let target = &targets[0];
             if let Expr::Tuple(targets) = &target.0 {
                 assert_eq!(targets.len(), 3);
             }
@@ -1186,12 +1662,13 @@ if x > 0:
     }
 
     #[test]
+    #[allow(clippy::collapsible_if)]
     fn test_parse_nested_unpack() {
         let result = parse_program("a, (b, c) = 1, (2, 3)\n");
         assert!(result.is_ok());
         let stmts = result.unwrap();
-        if let Stmt::Assign { target, value } = &stmts[0].0 {
-            if let Expr::Tuple(targets) = &target.0 {
+        if let Stmt::Assign { targets: lhs, .. } = &stmts[0].0 {
+            if let Expr::Tuple(targets) = &lhs[0].0 {
                 assert_eq!(targets.len(), 2);
                 // 두 번째 요소가 중첩 튜플인지 확인
                 if let Expr::Tuple(nested) = &targets[1].0 {
@@ -1204,6 +1681,67 @@ if x > 0:
     }
 
     #[test]
+    fn test_parse_chained_and_starred_assign() {
+        let stmts = parse_program("a = b[0] = first, *rest = xs\n").unwrap();
+        let Stmt::Assign { targets, value } = &stmts[0].0 else {
+            panic!("Expected assignment");
+        };
+        assert_eq!(targets.len(), 3);
+        assert!(matches!(targets[0].0, Expr::Variable(_)));
+        assert!(matches!(targets[1].0, Expr::Index { .. }));
+        let Expr::Tuple(elements) = &targets[2].0 else {
+            panic!("Expected tuple pattern");
+        };
+        assert!(matches!(elements[1].0, Expr::Starred(_)));
+        assert!(matches!(value.0, Expr::Variable(_)));
+    }
+
+    #[test]
+    fn test_parse_conditional_and_named_expr() {
+        let stmts = parse_program("x = 1 if a else 2 if b else 3\n").unwrap();
+        let Stmt::Assign { value, .. } = &stmts[0].0 else {
+            panic!("Expected assignment");
+        };
+        // else 쪽이 다시 조건 표현식 (오른쪽 결합)
+        let Expr::Conditional { else_expr, .. } = &value.0 else {
+            panic!("Expected conditional");
+        };
+        assert!(matches!(else_expr.0, Expr::Conditional { .. }));
+
+        let stmts = parse_program("while (n := n - 1) > 0:\n  pass\n").unwrap();
+        let Stmt::While { condition, .. } = &stmts[0].0 else {
+            panic!("Expected while");
+        };
+        let Expr::Binary { left, .. } = &condition.0 else {
+            panic!("Expected comparison");
+        };
+        assert!(matches!(&left.0, Expr::NamedExpr { name, .. } if name == "n"));
+    }
+
+    #[test]
+    fn test_parse_for_targets() {
+        let stmts = parse_program("for i, (k, *vs) in items:\n  pass\n").unwrap();
+        let Stmt::For { target, .. } = &stmts[0].0 else {
+            panic!("Expected for");
+        };
+        let Expr::Tuple(elements) = &target.0 else {
+            panic!("Expected tuple target");
+        };
+        assert!(matches!(elements[0].0, Expr::Variable(_)));
+        let Expr::Tuple(nested) = &elements[1].0 else {
+            panic!("Expected nested tuple");
+        };
+        assert!(matches!(nested[1].0, Expr::Starred(_)));
+
+        let stmts = parse_program("for (x) in xs:\n  pass\n").unwrap();
+        assert!(matches!(
+            &stmts[0].0,
+            Stmt::For { target: (Expr::Variable(name), _), .. } if name == "x"
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::collapsible_if)]
     fn test_parse_return_tuple() {
         let result = parse_program("def foo():\n  return 1, 2, 3\n");
         assert!(result.is_ok());
@@ -1218,4 +1756,62 @@ if x > 0:
             }
         }
     }
+
+    // ========== 에러 복구 테스트 ==========
+
+    #[test]
+    fn test_parse_recovers_and_reports_all_errors() {
+        // 블록 내부의 에러 이후에도 다음 줄부터 계속 파싱하여 모든 에러를 보고
+        let source = "x = 1 +\nif True:\n  y = )\n  z = 2\nprint(f(1 2))\nw = * 2\n";
+        let tokens = tokenize(source);
+        let eoi_span = SimpleSpan::new(source.len(), source.len());
+        let stream = chumsky::input::Stream::from_iter(tokens).map(eoi_span, |(t, s)| (t, s));
+        let (_, errors) = program_parser().parse(stream).into_output_errors();
+        assert_eq!(errors.len(), 4, "errors: {:?}", errors);
+    }
+
+    #[test]
+    fn test_parse_recovery_skips_bad_block_header() {
+        // 잘못된 def 헤더는 딸린 블록까지 건너뛰고, 이후 문장은 정상 파싱
+        let source = "def f(a b):\n  return 1\nx = 2\n";
+        let tokens = tokenize(source);
+        let eoi_span = SimpleSpan::new(source.len(), source.len());
+        let stream = chumsky::input::Stream::from_iter(tokens).map(eoi_span, |(t, s)| (t, s));
+        let (program, errors) = program_parser().parse(stream).into_output_errors();
+        assert_eq!(errors.len(), 1);
+        let program = program.expect("recovered output");
+        assert_eq!(program.len(), 1);
+        assert!(matches!(program[0].0, Stmt::Assign { .. }));
+    }
+
+    // ========== f-string / 키워드 인자 테스트 ==========
+
+    #[test]
+    fn test_parse_fstring() {
+        let expr = parse_expr("f\"{name}: {score * 2:.2f}!\"").unwrap();
+        let Expr::FString(parts) = expr.0 else {
+            panic!("Expected f-string, got {:?}", expr.0);
+        };
+        assert_eq!(parts.len(), 4);
+        assert!(matches!(&parts[0], FStringPart::Expr { expr, conversion: None, spec: None }
+            if matches!(&expr.0, Expr::Variable(n) if n == "name")));
+        assert_eq!(parts[1], FStringPart::Literal(": ".to_string()));
+        assert!(matches!(&parts[2], FStringPart::Expr { expr, spec: Some(spec), .. }
+            if spec == ".2f" && matches!(expr.0, Expr::Binary { .. })));
+        assert_eq!(parts[3], FStringPart::Literal("!".to_string()));
+    }
+
+    #[test]
+    fn test_parse_keyword_arguments() {
+        let expr = parse_expr("\"{} {x}\".format(1, x=2)").unwrap();
+        let Expr::Call { args, kwargs, .. } = expr.0 else {
+            panic!("Expected call");
+        };
+        assert_eq!(args.len(), 1);
+        assert_eq!(kwargs.len(), 1);
+        assert_eq!(kwargs[0].0, "x");
+
+        assert!(parse_expr("f(a=1, 2)").is_err());
+        assert!(parse_expr("f(a=1, a=2)").is_err());
+    }
 }
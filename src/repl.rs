@@ -2,11 +2,18 @@
 //!
 //! 대화형 실행 환경을 제공합니다.
 
+use crate::lexer::{Lexer, Token};
 use crate::vm::Vm;
+use crate::vm::native::NativeRegistry;
 use crate::vm::bytecode::{Instruction as I, Module, Value};
-use crate::vm::utils::display_value;
-use crate::{analyze_with_globals, parse_source};
+use crate::vm::type_def::TYPE_USER_START;
+use crate::vm::value::{Object, ObjectData};
+use crate::vm::utils::repr_value;
+use crate::parse_source;
+use crate::parser::ast::Stmt;
+use crate::semantic::analyze_with_natives;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 /// REPL 세션 상태
 ///
@@ -23,10 +30,15 @@ pub struct ReplState {
 impl ReplState {
     /// 새 REPL 세션 생성
     pub fn new() -> Self {
+        Self::with_natives(NativeRegistry::default())
+    }
+
+    /// 호스트가 등록한 네이티브 함수/클래스를 쓸 수 있는 세션
+    pub fn with_natives(natives: NativeRegistry) -> Self {
         Self {
             module: Module::new(),
             symbols: HashMap::new(),
-            vm: Vm::new(),
+            vm: Vm::with_natives(natives),
         }
     }
 
@@ -43,21 +55,35 @@ impl ReplState {
         }
 
         // 파싱
-        let program = match parse_source(input) {
+        let mut program = match parse_source(input) {
             Ok(p) => p,
             Err(diagnostics) => {
                 let mut error_msg = String::new();
                 for diag in diagnostics {
-                    error_msg.push_str(&diag.format("<repl>", input, "Parsing failed", 3));
+                    error_msg.push_str(&diag.format("<repl>", input));
                 }
                 return Err(error_msg);
             }
         };
 
         // 시맨틱 분석 (기존 전역 변수 포함)
-        let existing_globals: Vec<String> = self.symbols.keys().cloned().collect();
-        if let Err(diag) = analyze_with_globals(&program, &existing_globals) {
-            return Err(diag.format("<repl>", input, "Semantic Analyzing Failed", 4));
+        let existing_globals = self.global_names();
+        if let Err(diagnostics) =
+            analyze_with_natives(&program, &existing_globals, &self.vm.natives)
+        {
+            let mut error_msg = String::new();
+            for diag in diagnostics {
+                error_msg.push_str(&diag.format("<repl>", input));
+            }
+            return Err(error_msg);
+        }
+
+        // 마지막 표현식 문장은 결과를 돌려받아 출력하도록 main의 반환값으로 컴파일
+        // (의미 분석 뒤라 모듈 수준 return 검사를 피함, `Interpreter::eval`과 같은 방식)
+        if let Some(last) = program.last_mut()
+            && let Stmt::Expr(expr) = &last.0
+        {
+            last.0 = Stmt::Return(expr.clone());
         }
 
         // 컴파일 (REPL용: 기존 함수 정보 전달)
@@ -67,7 +93,7 @@ impl ReplState {
         self.merge_module(new_module)?;
 
         // VM 상태 초기화
-        self.vm = Vm::new();
+        self.reset_vm();
 
         // 함수 실행
         match self.vm.run(&mut self.module) {
@@ -76,8 +102,26 @@ impl ReplState {
         }
     }
 
+    /// 이전 실행의 스택과 프레임을 버림 (전역 상태는 모듈에 남음)
+    pub(crate) fn reset_vm(&mut self) {
+        self.vm = Vm::with_natives(std::mem::take(&mut self.vm.natives));
+    }
+
+    /// 값이 들어 있는 기존 전역 이름 (의미 분석에 넘김)
+    ///
+    /// 참조만 되고 정의되지 않은 심볼(예: 네이티브 호출 이름)은 빼서 네이티브를 가리지 않게 합니다.
+    pub(crate) fn global_names(&self) -> Vec<String> {
+        self.symbols
+            .iter()
+            .filter(|(_, idx)| {
+                matches!(self.module.globals.get(**idx as usize), Some(Some(_)))
+            })
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
     /// REPL용 컴파일: 기존 심볼과 함수 정보를 포함하여 컴파일
-    fn compile_with_context(
+    pub(crate) fn compile_with_context(
         &self,
         program: &[crate::parser::ast::StmtS],
     ) -> Result<Module, String> {
@@ -86,7 +130,8 @@ impl ReplState {
             self.symbols.clone(),
             self.module.symbols.clone(),
             self.module.functions.clone(),
-        );
+        )
+        .with_natives(&self.vm.natives);
 
         Ok(compiler.compile(program))
     }
@@ -94,7 +139,8 @@ impl ReplState {
     /// 새 모듈을 기존 상태에 병합
     ///
     /// 중요: 모든 인덱스(심볼, 함수, 문자열 등)를 재매핑해야 합니다.
-    fn merge_module(&mut self, new_module: Module) -> Result<(), String> {
+    #[allow(clippy::useless_format)]
+    pub(crate) fn merge_module(&mut self, new_module: Module) -> Result<(), String> {
         // 1. 심볼 병합 및 매핑 테이블 생성
         let mut symbol_map: HashMap<u16, u16> = HashMap::new();
         for (new_idx, new_symbol) in new_module.symbols.iter().enumerate() {
@@ -127,84 +173,64 @@ impl ReplState {
             }
         }
 
-        // 3. 함수 병합 (인덱스 재매핑)
-        let mut func_map: HashMap<u16, u16> = HashMap::new();
+        // 3. 함수 병합
+        // 컴파일러는 기존 함수들을 같은 인덱스에 복사해 두므로(with_context) 그 자리는 그대로 두고,
+        // __main__(0번)만 교체한 뒤 새로 정의된 함수를 끝에 추가합니다. 함수 인덱스는 바뀌지 않습니다.
+        let existing_functions = self.module.functions.len();
+        let const_base = self.module.consts.len() as u32;
+        let class_base = self.module.classes.len() as u16;
 
         for (new_idx, new_func) in new_module.functions.iter().enumerate() {
-            let new_idx = new_idx as u16;
-
-            // 함수 코드 내 인덱스 재매핑
+            if new_idx != 0 && new_idx < existing_functions {
+                continue;
+            }
             let mut remapped_func = new_func.clone();
             remapped_func.name_sym = *symbol_map
                 .get(&new_func.name_sym)
                 .ok_or_else(|| format!("Symbol mapping error for function name"))?;
+            remapped_func.code = new_func
+                .code
+                .iter()
+                .map(|ins| self.remap_instruction(ins, &symbol_map, &string_map, const_base))
+                .collect();
 
-            // __main__ 함수는 특별 처리 (항상 함수 0번에 위치)
-            let func_name = &self.module.symbols[remapped_func.name_sym as usize];
-            if func_name == "__main__" && new_idx == 0 {
-                // 새로운 __main__을 함수 0번에 교체
-                func_map.insert(0, 0);
-
-                // 바이트코드 명령어 재매핑 (아직 func_map이 완전하지 않으므로 나중에 처리)
-                let temp_code = new_func.code.clone();
-                remapped_func.code = temp_code;
-
-                // 함수 0이 없으면 추가, 있으면 교체
-                if self.module.functions.is_empty() {
-                    self.module.functions.push(remapped_func);
-                } else {
-                    self.module.functions[0] = remapped_func;
-                }
+            if new_idx < self.module.functions.len() {
+                self.module.functions[new_idx] = remapped_func;
             } else {
-                // 일반 함수는 끝에 추가
-                let old_idx = self.module.functions.len() as u16;
-                func_map.insert(new_idx, old_idx);
-
-                // 바이트코드 명령어는 나중에 일괄 재매핑
-                remapped_func.code = new_func.code.clone();
                 self.module.functions.push(remapped_func);
             }
         }
 
-        // 모든 함수의 바이트코드 명령어 재매핑
-        for (new_idx, _) in new_module.functions.iter().enumerate() {
-            let new_idx = new_idx as u16;
-            let old_idx = *func_map.get(&new_idx).unwrap();
-            let old_idx_usize = old_idx as usize;
-
-            if old_idx_usize < self.module.functions.len() {
-                let original_code = new_module.functions[new_idx as usize].code.clone();
-                self.module.functions[old_idx_usize].code = original_code
-                    .iter()
-                    .map(|ins| self.remap_instruction(ins, &symbol_map, &string_map, &func_map))
-                    .collect();
-            }
+        // 4. 상수 병합: 클래스 객체는 병합된 클래스 테이블의 ID를 가리키도록 바꿈
+        for value in &new_module.consts {
+            let value = match value {
+                Value::Object(obj) => match &obj.data {
+                    ObjectData::UserClass {
+                        class_id,
+                        name,
+                        methods,
+                    } => {
+                        let class_id = class_base + class_id;
+                        Value::Object(Rc::new(Object::new(
+                            TYPE_USER_START + class_id,
+                            ObjectData::UserClass {
+                                class_id,
+                                name: name.clone(),
+                                methods: methods.clone(),
+                            },
+                        )))
+                    }
+                    _ => value.clone(),
+                },
+                _ => value.clone(),
+            };
+            self.module.consts.push(value);
         }
 
-        // 4. 클래스 병합
-        let mut class_map: HashMap<u16, u16> = HashMap::new();
-        let base_class_idx = self.module.classes.len() as u16;
-
-        for (new_idx, new_class) in new_module.classes.iter().enumerate() {
-            let new_idx = new_idx as u16;
-            let old_idx = base_class_idx + new_idx;
-            class_map.insert(new_idx, old_idx);
-
-            // 클래스의 메서드 테이블 재매핑
-            let mut remapped_class = new_class.clone();
-            remapped_class.methods = new_class
-                .methods
-                .iter()
-                .map(|(name, &func_id)| {
-                    let remapped_func_id = *func_map.get(&func_id).unwrap_or(&func_id);
-                    (name.clone(), remapped_func_id)
-                })
-                .collect();
+        // 5. 클래스 병합 (메서드 함수 인덱스는 그대로 유효)
+        self.module.classes.extend(new_module.classes.iter().cloned());
 
-            self.module.classes.push(remapped_class);
-        }
-
-        // 5. 타입 테이블 병합 (사용자 정의 타입만)
+        // 6. 타입 테이블 병합 (사용자 정의 타입만)
         // builtin 타입은 이미 초기화되어 있으므로 건너뜀
         for new_type in new_module.types.iter().skip(100) {
             self.module.types.push(new_type.clone());
@@ -219,28 +245,47 @@ impl ReplState {
         ins: &I,
         symbol_map: &HashMap<u16, u16>,
         string_map: &HashMap<u32, u32>,
-        func_map: &HashMap<u16, u16>,
+        const_base: u32,
     ) -> I {
         match ins {
             I::ConstStr(idx) => I::ConstStr(*string_map.get(idx).unwrap_or(idx)),
+            I::LoadConst(idx) => I::LoadConst(const_base + idx),
             I::LoadLocal(idx) => I::LoadLocal(*idx),
             I::StoreLocal(idx) => I::StoreLocal(*idx),
             I::LoadGlobal(idx) => I::LoadGlobal(*symbol_map.get(idx).unwrap_or(idx)),
             I::StoreGlobal(idx) => I::StoreGlobal(*symbol_map.get(idx).unwrap_or(idx)),
-            I::Call(func_id, argc) => I::Call(*func_map.get(func_id).unwrap_or(func_id), *argc),
             I::CallMethod(method_sym, argc) => {
                 I::CallMethod(*symbol_map.get(method_sym).unwrap_or(method_sym), *argc)
             }
+            I::CallMethodKw(method_sym, argc) => {
+                I::CallMethodKw(*symbol_map.get(method_sym).unwrap_or(method_sym), *argc)
+            }
+            I::CallNative(name_sym, argc) => {
+                I::CallNative(*symbol_map.get(name_sym).unwrap_or(name_sym), *argc)
+            }
             I::LoadAttr(attr_sym) => I::LoadAttr(*symbol_map.get(attr_sym).unwrap_or(attr_sym)),
             I::StoreAttr(attr_sym) => I::StoreAttr(*symbol_map.get(attr_sym).unwrap_or(attr_sym)),
+            I::ImportModule(name_sym) => {
+                I::ImportModule(*symbol_map.get(name_sym).unwrap_or(name_sym))
+            }
             // 나머지 명령어는 그대로 복사
             _ => ins.clone(),
         }
     }
 
-    /// 결과 출력
+    /// 결과 출력 (Python REPL처럼 repr로 표시)
     pub fn print_result(&self, value: &Value) {
-        println!("{}", display_value(value));
+        if let Some(echo) = self.format_result(value) {
+            println!("{}", echo);
+        }
+    }
+
+    /// REPL이 보여줄 결과 문자열 (Python REPL처럼 None, 예를 들어 `print(...)`의 결과는 출력하지 않음)
+    pub fn format_result(&self, value: &Value) -> Option<String> {
+        match value {
+            Value::None => None,
+            _ => Some(repr_value(value)),
+        }
     }
 
     /// 정의된 심볼 목록 출력
@@ -283,6 +328,7 @@ impl Default for ReplState {
 /// 특수 명령어 처리
 ///
 /// 반환값: true이면 REPL 종료
+#[allow(clippy::trim_split_whitespace)]
 pub fn handle_command(cmd: &str, state: &mut ReplState) -> Result<bool, String> {
     let parts: Vec<&str> = cmd.trim().split_whitespace().collect();
     if parts.is_empty() {
@@ -356,34 +402,66 @@ fn print_help() {
   :type <expr>, :t   Show type of expression
 
 Tips:
-  - Lines ending with ':' continue on the next line
+  - Lines ending with ':' continue on the next line; end the block with an empty line
+  - Open brackets, triple-quoted strings and a trailing '\' continue automatically
   - Use Ctrl+C to interrupt input
   - Use arrow keys to navigate history
 "#
     );
 }
 
-/// 입력이 계속되어야 하는지 확인
-pub fn needs_more_lines(line: &str) -> bool {
-    line.trim_end().ends_with(':')
+/// 버퍼를 토큰화한 결과 중 연속 입력 판단에 필요한 것
+struct InputScan {
+    /// 괄호, 세 따옴표 문자열, 줄 끝 백슬래시가 열린 채로 끝남
+    open: bool,
+    /// 마지막 토큰이 `:` (블록 헤더)
+    ends_with_colon: bool,
+    /// 들여쓴 블록이 있음
+    has_block: bool,
 }
 
-/// 현재 버퍼가 멀티라인 모드인지 확인 (블록 내부)
-pub fn is_in_block(buffer: &str) -> bool {
-    if buffer.is_empty() {
-        return false;
-    }
-
-    // 마지막 비어있지 않은 라인 찾기
-    let lines: Vec<&str> = buffer.lines().collect();
-    for line in lines.iter().rev() {
-        let trimmed = line.trim();
-        if !trimmed.is_empty() {
-            // 들여쓰기가 있으면 블록 내부
-            return line.starts_with("  ") || line.starts_with("\t");
+fn scan_input(buffer: &str) -> InputScan {
+    let mut lexer = Lexer::new(buffer);
+    let mut last = None;
+    let mut open = false;
+    let mut has_block = false;
+    loop {
+        match lexer.next_token() {
+            Token::Eof => break,
+            Token::Newline | Token::Dedent => {}
+            Token::Indent => has_block = true,
+            Token::Error(msg, _)
+                if msg.starts_with("Unterminated triple-quoted") || msg.ends_with("was never closed") =>
+            {
+                open = true;
+            }
+            token => last = Some(token),
         }
     }
-    false
+    InputScan {
+        open: open || lexer.ends_with_continuation(),
+        ends_with_colon: last == Some(Token::Colon),
+        has_block,
+    }
+}
+
+/// 입력이 아직 끝나지 않았는지 확인 (열린 괄호, 세 따옴표 문자열, 줄 끝 백슬래시)
+pub fn is_incomplete(buffer: &str) -> bool {
+    scan_input(buffer).open
+}
+
+/// 입력이 계속되어야 하는지 확인 (블록 헤더이거나 아직 끝나지 않은 입력)
+pub fn needs_more_lines(buffer: &str) -> bool {
+    let scan = scan_input(buffer);
+    scan.open || scan.ends_with_colon
+}
+
+/// 현재 버퍼가 멀티라인 모드인지 확인 (들여쓴 블록이 있거나 입력이 끝나지 않음)
+///
+/// 블록은 빈 줄로 끝내야 실행되지만, 괄호로 이어진 단순 문장은 괄호가 닫히면 바로 실행됩니다.
+pub fn is_in_block(buffer: &str) -> bool {
+    let scan = scan_input(buffer);
+    scan.open || scan.has_block
 }
 
 /// 다음 라인의 자동 들여쓰기 레벨 계산
@@ -470,6 +548,51 @@ mod tests {
         assert!(needs_more_lines("while True:"));
         assert!(!needs_more_lines("x = 10"));
         assert!(!needs_more_lines("print('hello')"));
+        assert!(needs_more_lines("if x:  # comment\n"));
+        assert!(needs_more_lines("xs = [1,\n"));
+        assert!(needs_more_lines("d = {\n  'a': (1,\n"));
+        assert!(needs_more_lines("x = 1 + \\\n"));
+        assert!(needs_more_lines("s = \"\"\"doc\n"));
+        assert!(!needs_more_lines("xs = [1,\n  2]\n"));
+        assert!(!needs_more_lines("s = '''a\nb'''\n"));
+        assert!(!needs_more_lines("print('(:')\n"));
+    }
+
+    #[test]
+    fn test_block_detection() {
+        assert!(is_in_block("def f():\n  return 1\n"));
+        assert!(is_in_block("xs = [\n"));
+        // 괄호로 이어진 단순 문장은 괄호가 닫히면 블록이 아님
+        assert!(!is_in_block("xs = [\n  1,\n  2]\n"));
+        assert!(!is_in_block("x = 1\n"));
+        assert!(is_incomplete("f(1,\n"));
+        assert!(!is_incomplete("def f():\n"));
+    }
+
+    #[test]
+    fn test_multiline_literal_eval() {
+        let mut state = ReplState::new();
+        assert!(state.eval_line("xs = [\n  1,\n  2,\n]\n").is_ok());
+        assert!(state.eval_line("n = len(xs) + \\\n  1\n").is_ok());
+        let slot = state.symbols["n"] as usize;
+        assert!(matches!(state.module.globals[slot], Some(Value::Int(3))));
+    }
+
+    #[test]
+    fn test_bare_expressions_are_echoed() {
+        let mut state = ReplState::new();
+        state.eval_line("x = [1, 'a']\ndef square(n):\n  return n * n\n").unwrap();
+        let echo = |state: &mut ReplState, input: &str| {
+            let value = state.eval_line(input).unwrap();
+            value.and_then(|v| state.format_result(&v))
+        };
+        assert_eq!(echo(&mut state, "x\n").as_deref(), Some("[1, 'a']"));
+        assert_eq!(echo(&mut state, "\"a\"\n").as_deref(), Some("'a'"));
+        assert_eq!(echo(&mut state, "1 + 2\n").as_deref(), Some("3"));
+        assert_eq!(echo(&mut state, "square\n").as_deref(), Some("<function square>"));
+        // 문장과 None은 출력하지 않음
+        assert_eq!(echo(&mut state, "y = 1\n"), None);
+        assert_eq!(echo(&mut state, "print(y)\n"), None);
     }
 
     #[test]
@@ -485,6 +608,19 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_definitions_across_lines() {
+        let mut state = ReplState::new();
+        state.eval_line("def f(x):\n  return x * 2\n").unwrap();
+        state.eval_line("class P:\n  def get(self):\n    return 'p'\n").unwrap();
+        state.eval_line("def g(x):\n  return f(x) + 1\n").unwrap();
+        state.eval_line("n = g(3) if P().get() == 'p' else 0\n").unwrap();
+        assert_eq!(
+            state.module.globals[state.symbols["n"] as usize],
+            Some(Value::Int(7))
+        );
+    }
+
     #[test]
     fn test_module_merge() {
         let mut state = ReplState::new();
@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::io::{self, BufRead, BufWriter, Read, StdinLock, Stdout, Write};
 
 /// Result type for read operations
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -17,27 +18,69 @@ pub trait RuntimeIo {
     fn write(&mut self, s: &str);
     fn read_line(&mut self) -> ReadResult;
     fn read_line_with_prompt(&mut self, prompt: Option<&str>) -> ReadResult;
+
+    /// 모아 둔 출력을 내보냄. VM이 실행을 멈출 때마다(끝, 에러, 입력 대기) 부르므로
+    /// 구현은 마음껏 버퍼링해도 됩니다.
+    fn flush(&mut self) {}
+
+    /// 줄바꿈을 포함한 한 줄 읽기 (`sys.stdin.readline()`). `""`이면 입력 끝
+    fn read_raw_line(&mut self) -> ReadResult {
+        ReadResult::Error("reading stdin is not supported".to_string())
+    }
+
+    /// 입력 끝까지 모두 읽기 (`sys.stdin.read()`)
+    fn read_to_end(&mut self) -> ReadResult {
+        ReadResult::Error("reading stdin is not supported".to_string())
+    }
 }
 
 /// Default I/O that talks to process stdout/stdin (CLI use).
-pub struct StdIo;
+///
+/// 출력은 `BufWriter`에 모았다가 VM이 멈출 때, drop될 때, `sys.stdout.flush()`에서,
+/// 그리고 stdin을 읽기 전마다 내보냅니다. 파이썬처럼 `input()` 프롬프트와 앞선 출력이
+/// 입력을 기다리기 전에 보이고, 상대 프로세스가 출력을 기다리는 파이프에서도 멈추지 않습니다.
+/// stdin은 처음 읽을 때 잠근 뒤 계속 잠가 둡니다.
+pub struct StdIo {
+    out: BufWriter<Stdout>,
+    input: Option<StdinLock<'static>>,
+}
+
+impl StdIo {
+    pub fn new() -> Self {
+        Self {
+            out: BufWriter::with_capacity(64 * 1024, io::stdout()),
+            input: None,
+        }
+    }
+
+    /// 잠근 stdin (먼저 모아 둔 출력을 내보냄)
+    fn stdin(&mut self) -> &mut StdinLock<'static> {
+        let _ = self.out.flush();
+        self.input.get_or_insert_with(|| io::stdin().lock())
+    }
+}
+
+impl Default for StdIo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl RuntimeIo for StdIo {
     fn write_line(&mut self, s: &str) {
-        println!("{}", s);
+        let _ = self.out.write_all(s.as_bytes());
+        let _ = self.out.write_all(b"\n");
     }
     fn write(&mut self, s: &str) {
-        use std::io::Write;
-        print!("{}", s);
-        let _ = std::io::stdout().flush();
+        let _ = self.out.write_all(s.as_bytes());
     }
     fn read_line(&mut self) -> ReadResult {
-        use std::io::{self, BufRead};
-        let stdin = io::stdin();
-        let mut line = String::new();
-        match stdin.lock().read_line(&mut line) {
-            Ok(_) => ReadResult::Ok(line.trim_end().to_string()),
-            Err(e) => ReadResult::Error(e.to_string()),
+        match self.read_raw_line() {
+            ReadResult::Ok(mut line) => {
+                line.truncate(line.trim_end().len());
+                ReadResult::Ok(line)
+            }
+            other => other,
         }
     }
     fn read_line_with_prompt(&mut self, prompt: Option<&str>) -> ReadResult {
@@ -46,27 +89,60 @@ impl RuntimeIo for StdIo {
         }
         self.read_line()
     }
+    fn flush(&mut self) {
+        let _ = self.out.flush();
+    }
+    fn read_raw_line(&mut self) -> ReadResult {
+        let mut line = String::new();
+        match self.stdin().read_line(&mut line) {
+            Ok(_) => ReadResult::Ok(line),
+            Err(e) => ReadResult::Error(e.to_string()),
+        }
+    }
+    fn read_to_end(&mut self) -> ReadResult {
+        let mut text = String::new();
+        match self.stdin().read_to_string(&mut text) {
+            Ok(_) => ReadResult::Ok(text),
+            Err(e) => ReadResult::Error(e.to_string()),
+        }
+    }
 }
 
 /// Buffer-based I/O for browsers/tests: caller pushes input, we accumulate output.
+///
+/// [`BufferIo::with_buffered_output`]을 쓰면 [`StdIo`]처럼 출력이 flush(읽기 전,
+/// VM이 멈출 때) 뒤에야 `get_output`/`drain_output`에 보입니다. 한꺼번에 읽기
+/// (`read_raw_line`, `read_to_end`)는 기다리지 않고, 입력 큐가 비어 있으면 입력 끝으로 봅니다.
 pub struct BufferIo {
     output: String,
+    /// 아직 내보내지 않은 출력 (버퍼링 모드에서만 `Some`)
+    pending: Option<String>,
     input: VecDeque<String>,
-    last_prompt: Option<String>,
 }
 
 impl BufferIo {
     pub fn new() -> Self {
         Self {
             output: String::new(),
+            pending: None,
             input: VecDeque::new(),
-            last_prompt: None,
         }
     }
+    /// CLI의 stdout처럼 다음 flush까지 출력을 모아 둠
+    pub fn with_buffered_output(mut self) -> Self {
+        self.pending = Some(String::new());
+        self
+    }
     pub fn push_input_line<S: Into<String>>(&mut self, line: S) {
         self.input.push_back(line.into());
     }
-    pub fn take_output(self) -> String {
+    /// 입력 전체(테스트 케이스 파일 등)를 줄 단위로 큐에 넣음
+    pub fn push_input(&mut self, text: &str) {
+        self.input.extend(text.lines().map(str::to_string));
+    }
+    /// 아직 내보내지 않은 것까지 포함한 전체 출력
+    pub fn take_output(mut self) -> String {
+        self.flush();
         self.output
     }
     pub fn get_output(&self) -> &str {
@@ -88,13 +164,15 @@ impl Default for BufferIo {
 
 impl RuntimeIo for BufferIo {
     fn write_line(&mut self, s: &str) {
-        self.output.push_str(s);
-        self.output.push('\n');
+        let out = self.pending.as_mut().unwrap_or(&mut self.output);
+        out.push_str(s);
+        out.push('\n');
     }
     fn write(&mut self, s: &str) {
-        self.output.push_str(s);
+        self.pending.as_mut().unwrap_or(&mut self.output).push_str(s);
     }
     fn read_line(&mut self) -> ReadResult {
+        self.flush();
         if let Some(line) = self.input.pop_front() {
             ReadResult::Ok(line)
         } else {
@@ -102,21 +180,63 @@ impl RuntimeIo for BufferIo {
         }
     }
     fn read_line_with_prompt(&mut self, prompt: Option<&str>) -> ReadResult {
-        // Show prompt only if it's different from last time (prevents duplicate on retry)
-        if let Some(p) = prompt
-            && self.last_prompt.as_deref() != Some(p)
-        {
+        // 입력이 없으면 VM이 멈췄다가 호스트의 응답으로 이어가므로 프롬프트는 한 번만 쓰임
+        if let Some(p) = prompt {
             self.write(p);
-            self.last_prompt = Some(p.to_string());
         }
-
-        match self.read_line() {
-            ReadResult::Ok(line) => {
-                // Clear last_prompt after successful read
-                self.last_prompt = None;
-                ReadResult::Ok(line)
-            }
-            other => other,
+        self.read_line()
+    }
+    fn flush(&mut self) {
+        if let Some(pending) = &mut self.pending {
+            self.output.push_str(pending);
+            pending.clear();
+        }
+    }
+    fn read_raw_line(&mut self) -> ReadResult {
+        self.flush();
+        match self.input.pop_front() {
+            Some(line) => ReadResult::Ok(line + "\n"),
+            None => ReadResult::Ok(String::new()),
+        }
+    }
+    fn read_to_end(&mut self) -> ReadResult {
+        self.flush();
+        let mut text = String::new();
+        for line in self.input.drain(..) {
+            text.push_str(&line);
+            text.push('\n');
         }
+        ReadResult::Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffered_output_appears_on_flush_and_before_reads() {
+        let mut io = BufferIo::new().with_buffered_output();
+        io.push_input_line("x");
+        io.write("prompt> ");
+        assert_eq!(io.get_output(), "");
+        assert_eq!(io.read_line(), ReadResult::Ok("x".to_string()));
+        assert_eq!(io.get_output(), "prompt> ");
+        io.write_line("done");
+        io.flush();
+        assert_eq!(io.drain_output(), "prompt> done\n");
+        io.write_line("tail");
+        assert_eq!(io.take_output(), "tail\n");
+    }
+
+    #[test]
+    fn test_bulk_reads_treat_empty_queue_as_end_of_input() {
+        let mut io = BufferIo::new();
+        io.push_input("1\n\n2 3\n4");
+        assert_eq!(io.read_raw_line(), ReadResult::Ok("1\n".to_string()));
+        assert_eq!(io.read_raw_line(), ReadResult::Ok("\n".to_string()));
+        assert_eq!(io.read_to_end(), ReadResult::Ok("2 3\n4\n".to_string()));
+        assert_eq!(io.read_raw_line(), ReadResult::Ok(String::new()));
+        assert_eq!(io.read_line(), ReadResult::WaitingForInput);
     }
 }
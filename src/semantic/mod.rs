@@ -1,23 +1,57 @@
 pub mod scope;
 pub mod typecheck;
 
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 
-use crate::parser::ast::{Expr, ExprS, Stmt, StmtS};
+use crate::diagnostic::{Diagnostic, ErrorCode, did_you_mean};
+use crate::parser::ast::{Expr, ExprS, FStringPart, Stmt, StmtS};
 use crate::types::Span;
+use crate::vm::native::NativeRegistry;
 
-#[derive(Debug)]
-pub struct SemanticError {
-    pub message: String,
-    pub span: Span,
-}
+/// 의미 분석 진단 (에러 또는 경고)
+pub type SemanticError = Diagnostic;
 
 pub type SemanticResult<T> = Result<T, SemanticError>;
 
+/// 분석 결과: 발견된 모든 진단을 소스 위치 순으로 반환
+pub type SemanticErrors = Vec<SemanticError>;
+
 #[derive(Default)]
 pub struct ProgramContext {
     builtins: HashSet<String>,
     functions: HashMap<String, usize>, // name -> arity
+    classes: HashMap<String, ClassInfo>,
+    /// 함수/클래스 이름 -> 정의 헤더(`def name`) 위치, 진단의 보조 라벨용
+    definitions: HashMap<String, Span>,
+    /// 문장 단위로 복구하면서 모은 에러들
+    errors: RefCell<Vec<SemanticError>>,
+    /// 호스트가 등록한 네이티브 함수/클래스 (이름은 `builtins`에도 들어감)
+    natives: NativeRegistry,
+    /// REPL/Interpreter의 앞선 실행에서 정의된 전역 이름 (같은 이름의 네이티브를 가림)
+    existing_globals: HashSet<String>,
+    /// 클래스 밖에서 `obj.attr = ...`로 붙인 (클래스 이름, 속성): 경고한 뒤로는 읽어도 됨
+    outside_attributes: RefCell<HashSet<(String, String)>>,
+    /// 타입을 모르는 객체에 `obj.attr = ...`로 할당한 속성 이름: 어느 클래스의 속성일 수도 있음
+    untyped_attributes: RefCell<HashSet<String>>,
+    /// 보류한 "속성 없음" 에러: 프로그램 전체를 본 뒤 `untyped_attributes`에 없는 것만 보고
+    unknown_attributes: RefCell<Vec<(String, SemanticError)>>,
+}
+
+/// 클래스 타입 정보 (typecheck에서 속성/메서드 검사에 사용)
+#[derive(Debug, Default, Clone)]
+struct ClassInfo {
+    /// 인스턴스 속성: 메서드 안의 `self.x = ...` 와 클래스 본문 할당
+    attributes: HashSet<String>,
+    /// 메서드 이름 -> self를 제외한 인자 개수
+    methods: HashMap<String, usize>,
+}
+
+impl ClassInfo {
+    /// 생성자 호출 시 필요한 인자 개수 (`__init__`이 없으면 0)
+    fn init_arity(&self) -> usize {
+        self.methods.get("__init__").copied().unwrap_or(0)
+    }
 }
 
 impl ProgramContext {
@@ -32,16 +66,52 @@ impl ProgramContext {
     fn is_builtin(&self, name: &str) -> bool {
         self.builtins.contains(name)
     }
+
+    /// 클래스 안에서 정의했거나 클래스 밖에서 이미 할당한 인스턴스 속성인지
+    fn has_attribute(&self, class_name: &str, class: &ClassInfo, attr: &str) -> bool {
+        class.attributes.contains(attr)
+            || self
+                .outside_attributes
+                .borrow()
+                .contains(&(class_name.to_string(), attr.to_string()))
+    }
+
+    /// 문장 단위 에러 복구: 에러를 기록하고 호출자는 다음 문장으로 계속 진행합니다.
+    fn recover(&self, result: SemanticResult<()>) {
+        if let Err(e) = result {
+            self.errors.borrow_mut().push(e);
+        }
+    }
 }
 
-pub fn analyze(program: &[StmtS]) -> SemanticResult<()> {
+/// 성공하면 경고 목록을, 에러가 하나라도 있으면 경고를 포함한 모든 진단을 반환합니다.
+pub fn analyze(program: &[StmtS]) -> Result<SemanticErrors, SemanticErrors> {
     analyze_with_globals(program, &[])
 }
 
 /// REPL용: 기존 전역 변수를 포함하여 분석
-pub fn analyze_with_globals(program: &[StmtS], existing_globals: &[String]) -> SemanticResult<()> {
+///
+/// 첫 에러에서 멈추지 않고 문장 단위로 복구하면서 이름 해석과 타입 검사의
+/// 에러를 모두 모아 반환합니다.
+pub fn analyze_with_globals(
+    program: &[StmtS],
+    existing_globals: &[String],
+) -> Result<SemanticErrors, SemanticErrors> {
+    analyze_with_natives(program, existing_globals, &NativeRegistry::default())
+}
+
+/// 임베딩용: 호스트가 등록한 네이티브 함수/클래스를 builtin처럼 보고 분석
+///
+/// 이름 해석에 더해 호출의 인자 개수와 네이티브 인스턴스의 메서드도 검사합니다.
+pub fn analyze_with_natives(
+    program: &[StmtS],
+    existing_globals: &[String],
+    natives: &NativeRegistry,
+) -> Result<SemanticErrors, SemanticErrors> {
     // 1) 이름 해석(스코프) + 간단 규칙 확인
     let mut ctx = ProgramContext::new_with_builtins();
+    ctx.builtins.extend(natives.names().map(str::to_string));
+    ctx.natives = natives.clone();
     let mut scopes = scope::ScopeStack::new();
     // preload builtins into global scope for resolution
     for b in ctx.builtins.clone() {
@@ -52,15 +122,127 @@ pub fn analyze_with_globals(program: &[StmtS], existing_globals: &[String]) -> S
     for g in existing_globals {
         scopes.define(g.clone());
     }
+    ctx.existing_globals = existing_globals.iter().cloned().collect();
 
     // 모듈 레벨 분석
     for stmt in program {
-        analyze_stmt_module(stmt, &mut scopes, &mut ctx)?;
+        let result = analyze_stmt_module(stmt, &mut scopes, &mut ctx);
+        ctx.recover(result);
     }
 
-    // 2) 타입 검사
-    typecheck::typecheck_program(program, &ctx)?;
-    Ok(())
+    // 2) 타입 검사 (이름 에러가 있어도 계속 진행, Unknown은 낙관적으로 처리됨)
+    typecheck::typecheck_program(program, &ctx);
+
+    // 3) 경고: return/break/continue 뒤의 도달 불가능한 코드
+    check_unreachable(program, &ctx);
+
+    let mut diagnostics = ctx.errors.take();
+    diagnostics.sort_by_key(|e| (e.span.start, e.span.end));
+    diagnostics.dedup_by(|a, b| a.span == b.span && a.message == b.message);
+    if diagnostics.iter().any(|d| d.is_error()) {
+        Err(diagnostics)
+    } else {
+        Ok(diagnostics)
+    }
+}
+
+/// 블록 안에서 return/break/continue 뒤에 오는 문장들을 경고로 보고합니다.
+/// 블록마다 첫 번째 점프만 보고하고, 중첩 블록과 함수/메서드 본문도 검사합니다.
+fn check_unreachable(stmts: &[StmtS], ctx: &ProgramContext) {
+    let jump = stmts.iter().position(|s| {
+        matches!(s.0, Stmt::Return(_) | Stmt::Break | Stmt::Continue)
+    });
+    if let Some(idx) = jump
+        && let (Some(first), Some(last)) = (stmts.get(idx + 1), stmts.last())
+    {
+        let keyword = match stmts[idx].0 {
+            Stmt::Return(_) => "return",
+            Stmt::Break => "break",
+            _ => "continue",
+        };
+        ctx.errors.borrow_mut().push(
+            SemanticError::new(
+                ErrorCode::UnreachableCode,
+                "this code will never be executed",
+                first.1.start..last.1.end,
+            )
+            .with_label(
+                stmts[idx].1.clone(),
+                format!("any code after this '{}' is unreachable", keyword),
+            ),
+        );
+    }
+
+    for s in stmts {
+        match &s.0 {
+            Stmt::If {
+                then_block,
+                elif_blocks,
+                else_block,
+                ..
+            } => {
+                check_unreachable(then_block, ctx);
+                for (_, block) in elif_blocks {
+                    check_unreachable(block, ctx);
+                }
+                if let Some(block) = else_block {
+                    check_unreachable(block, ctx);
+                }
+            }
+            Stmt::While { body, .. } | Stmt::For { body, .. } | Stmt::Def { body, .. } => {
+                check_unreachable(body, ctx);
+            }
+            Stmt::Class { methods, .. } => {
+                for method in methods {
+                    check_unreachable(&method.body, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 정의되지 않은 이름 에러. 스코프와 빌트인에서 비슷한 이름을 찾아 제안합니다.
+fn undefined_name(
+    message: String,
+    name: &str,
+    span: Span,
+    scopes: &scope::ScopeStack,
+    ctx: &ProgramContext,
+) -> SemanticError {
+    let error = SemanticError::new(ErrorCode::UndefinedName, message, span);
+    let candidates = scopes
+        .names()
+        .chain(ctx.builtins.iter().map(String::as_str));
+    match did_you_mean(name, candidates) {
+        Some(help) => error.with_help(help),
+        None => error,
+    }
+}
+
+/// `import name`: 내장 모듈만 가져올 수 있음
+fn check_import(name: &str, span: &Span) -> SemanticResult<()> {
+    if crate::builtins::lookup_module(name).is_some() {
+        return Ok(());
+    }
+    let error = SemanticError::new(
+        ErrorCode::UnknownModule,
+        format!("ModuleNotFoundError: No module named '{}'", name),
+        span.clone(),
+    );
+    let candidates = crate::builtins::all_modules().iter().map(|m| m.name);
+    Err(match did_you_mean(name, candidates) {
+        Some(help) => error.with_help(help),
+        None => error,
+    })
+}
+
+/// 함수 안에서 할당되기 전에 읽힌 로컬 변수 에러
+fn unbound_local(message: String, name: &str, span: Span) -> SemanticError {
+    SemanticError::new(ErrorCode::UnboundLocal, message, span).with_note(format!(
+        "'{}' is assigned somewhere in this function, which makes it local to the whole function",
+        name
+    ))
 }
 
 fn analyze_stmt_module(
@@ -74,83 +256,120 @@ fn analyze_stmt_module(
             // Pass is always allowed as a no-op
             Ok(())
         }
+        Stmt::Import(name) => {
+            // 모듈이 없어도 이름은 정의해 두어 이후 문장에서 연쇄 에러가 나지 않게 함
+            scopes.define(name.clone());
+            check_import(name, &stmt.1)
+        }
         Stmt::Def { name, params, body } => {
             // 정의는 현재 스코프(모듈)에 바인딩
             scopes.define(name.clone());
             ctx.functions.insert(name.clone(), params.len());
+            ctx.definitions
+                .insert(name.clone(), header_span(&stmt.1, "def ", name));
             analyze_function(name, params, body, scopes, ctx, stmt.1.clone())
         }
-        Stmt::Assign { target, value } => {
-            analyze_expr_module(value, scopes, ctx)?;
+        Stmt::Assign { targets, value } => {
+            // 값에 에러가 있어도 대상은 정의해 두어 이후 문장에서 연쇄 에러가 나지 않게 함
+            ctx.recover(analyze_expr_module(value, scopes, ctx));
             // 튜플 언패킹 지원: target이 Tuple인 경우 각 요소 검증
-            validate_and_analyze_assign_target(target, scopes, ctx, false)?;
+            for target in targets {
+                validate_and_analyze_assign_target(target, scopes, ctx)?;
+            }
             Ok(())
         }
+        Stmt::AugAssign { target, value, .. } => {
+            ctx.recover(analyze_expr_module(value, scopes, ctx));
+            // 복합 할당은 대상을 먼저 읽으므로 이미 정의되어 있어야 함
+            check_aug_assign_target(target)?;
+            analyze_expr_module(target, scopes, ctx)
+        }
         Stmt::If {
             condition,
             then_block,
             elif_blocks,
             else_block,
         } => {
-            analyze_expr_module(condition, scopes, ctx)?;
+            ctx.recover(analyze_expr_module(condition, scopes, ctx));
             // 블록 스코프는 만들지 않는다
             for s in then_block {
-                analyze_stmt_module(s, scopes, ctx)?;
+                let result = analyze_stmt_module(s, scopes, ctx);
+                ctx.recover(result);
             }
             for (cond, block) in elif_blocks {
-                analyze_expr_module(cond, scopes, ctx)?;
+                ctx.recover(analyze_expr_module(cond, scopes, ctx));
                 for s in block {
-                    analyze_stmt_module(s, scopes, ctx)?;
+                    let result = analyze_stmt_module(s, scopes, ctx);
+                    ctx.recover(result);
                 }
             }
             if let Some(block) = else_block {
                 for s in block {
-                    analyze_stmt_module(s, scopes, ctx)?;
+                    let result = analyze_stmt_module(s, scopes, ctx);
+                    ctx.recover(result);
                 }
             }
             Ok(())
         }
         Stmt::While { condition, body } => {
-            analyze_expr_module(condition, scopes, ctx)?;
+            ctx.recover(analyze_expr_module(condition, scopes, ctx));
             for s in body {
-                analyze_stmt_module(s, scopes, ctx)?;
+                let result = analyze_stmt_module(s, scopes, ctx);
+                ctx.recover(result);
             }
             Ok(())
         }
         Stmt::For {
-            var,
+            target,
             iterable,
             body,
         } => {
             // iterable 표현식 분석
-            analyze_expr_module(iterable, scopes, ctx)?;
+            ctx.recover(analyze_expr_module(iterable, scopes, ctx));
 
-            // 루프 변수를 현재 스코프에 정의
-            scopes.define(var.clone());
+            // 루프 변수를 현재 스코프에 정의 (언패킹 패턴 포함)
+            ctx.recover(validate_and_analyze_assign_target(target, scopes, ctx));
 
             // body 분석
             for s in body {
-                analyze_stmt_module(s, scopes, ctx)?;
+                let result = analyze_stmt_module(s, scopes, ctx);
+                ctx.recover(result);
             }
             Ok(())
         }
         Stmt::Return(expr) => analyze_expr_module(expr, scopes, ctx),
         Stmt::Expr(expr) => analyze_expr_module(expr, scopes, ctx),
-        Stmt::Class { name, methods, .. } => {
+        Stmt::Class {
+            name,
+            methods,
+            attributes,
+        } => {
             // 클래스를 현재 스코프에 정의
             scopes.define(name.clone());
+            ctx.definitions
+                .insert(name.clone(), header_span(&stmt.1, "class ", name));
+
+            // 클래스 속성 값은 클래스 정의 시점에 모듈 스코프에서 평가
+            for (_, value) in attributes {
+                ctx.recover(analyze_expr_module(value, scopes, ctx));
+            }
 
             // 각 메서드 검증
             for method in methods {
                 // 첫 번째 파라미터가 self인지 확인 (__init__ 포함)
                 if method.params.is_empty() || method.params[0] != "self" {
-                    return Err(SemanticError {
-                        message: format!(
+                    ctx.recover(Err(SemanticError::new(
+                        ErrorCode::MissingSelf,
+                        format!(
                             "Method '{}' in class '{}' must have 'self' as first parameter",
                             method.name, name
                         ),
-                        span: stmt.1.clone(),
-                    });
+                        stmt.1.clone(),
+                    )
+                    .with_help(format!(
+                        "add 'self' as the first parameter: def {}(self, ...)",
+                        method.name
+                    ))));
                 }
 
                 // 메서드 본문 분석
@@ -163,24 +382,98 @@ fn analyze_stmt_module(
                 let mut assigned: HashSet<String> = method.params.iter().cloned().collect();
 
                 for s in &method.body {
-                    analyze_stmt_function(s, scopes, ctx, &locals, &mut assigned)?;
+                    ctx.recover(analyze_stmt_function(s, scopes, ctx, &locals, &mut assigned));
                 }
 
                 scopes.pop();
             }
 
+            // 타입 검사를 위한 클래스 정보 등록
+            let mut info = ClassInfo::default();
+            for (attr, _) in attributes {
+                info.attributes.insert(attr.clone());
+            }
+            for method in methods {
+                info.methods
+                    .insert(method.name.clone(), method.params.len().saturating_sub(1));
+                collect_self_attributes(&method.body, &mut info.attributes);
+            }
+            ctx.classes.insert(name.clone(), info);
+
             Ok(())
         }
     }
 }
 
+/// f-string에 포함된 표현식들
+pub(crate) fn fstring_exprs(parts: &[FStringPart]) -> impl Iterator<Item = &ExprS> {
+    parts.iter().filter_map(|part| match part {
+        FStringPart::Expr { expr, .. } => Some(expr),
+        FStringPart::Literal(_) => None,
+    })
+}
+
+/// 정의 문장에서 `def name` / `class name` 부분의 위치
+fn header_span(stmt_span: &Span, keyword: &str, name: &str) -> Span {
+    let end = (stmt_span.start + keyword.len() + name.len()).min(stmt_span.end);
+    stmt_span.start..end
+}
+
+/// 메서드 본문에서 `self.attr = ...` 형태로 할당되는 속성 이름을 수집합니다.
+/// 중첩된 if/while/for 블록과 튜플 언패킹도 따라갑니다.
+fn collect_self_attributes(stmts: &[StmtS], out: &mut HashSet<String>) {
+    fn from_target(target: &Expr, out: &mut HashSet<String>) {
+        match target {
+            Expr::Attribute { object, attr } => {
+                if matches!(&object.0, Expr::Variable(v) if v == "self") {
+                    out.insert(attr.clone());
+                }
+            }
+            Expr::Tuple(elements) | Expr::List(elements) => {
+                for elem in elements {
+                    from_target(&elem.0, out);
+                }
+            }
+            Expr::Starred(inner) => from_target(&inner.0, out),
+            _ => {}
+        }
+    }
+
+    for s in stmts {
+        match &s.0 {
+            Stmt::Assign { targets, .. } => {
+                for target in targets {
+                    from_target(&target.0, out);
+                }
+            }
+            Stmt::If {
+                then_block,
+                elif_blocks,
+                else_block,
+                ..
+            } => {
+                collect_self_attributes(then_block, out);
+                for (_, block) in elif_blocks {
+                    collect_self_attributes(block, out);
+                }
+                if let Some(block) = else_block {
+                    collect_self_attributes(block, out);
+                }
+            }
+            Stmt::While { body, .. } | Stmt::For { body, .. } => {
+                collect_self_attributes(body, out);
+            }
+            _ => {}
+        }
+    }
+}
+
 /// 할당 대상(target)을 검증하고 분석합니다.
 /// 튜플 언패킹을 재귀적으로 지원합니다.
 fn validate_and_analyze_assign_target(
     target: &ExprS,
     scopes: &mut scope::ScopeStack,
     ctx: &ProgramContext,
-    in_function: bool,
 ) -> SemanticResult<()> {
     match &target.0 {
         Expr::Variable(name) => {
@@ -192,22 +485,172 @@ fn validate_and_analyze_assign_target(
         Expr::Attribute { .. } | Expr::Index { .. } => {
             analyze_expr_module(target, scopes, ctx)
         }
-        Expr::Tuple(elements) => {
-            // 튜플 언패킹: 각 요소가 유효한 할당 대상인지 재귀적으로 검증
+        Expr::Tuple(elements) | Expr::List(elements) => {
+            // 튜플/리스트 언패킹: 각 요소가 유효한 할당 대상인지 재귀적으로 검증
+            check_starred_count(elements, &target.1)?;
             for elem in elements {
-                validate_and_analyze_assign_target(elem, scopes, ctx, in_function)?;
+                let elem = match &elem.0 {
+                    Expr::Starred(inner) => inner.as_ref(),
+                    _ => elem,
+                };
+                validate_and_analyze_assign_target(elem, scopes, ctx)?;
             }
             Ok(())
         }
-        Expr::Literal(_) | Expr::Call { .. } | Expr::Binary { .. } | Expr::Unary { .. } | Expr::Lambda { .. } | Expr::List(_) | Expr::Dict(_) | Expr::Set(_) | Expr::TreeSet(_) => {
-            Err(SemanticError {
-                message: "Invalid assignment target: cannot assign to literal, call, or expression".to_string(),
-                span: target.1.clone(),
-            })
+        Expr::Starred(_) => Err(starred_outside_unpacking(&target.1)),
+        Expr::Literal(_) | Expr::Call { .. } | Expr::Binary { .. } | Expr::Compare { .. } | Expr::Unary { .. } | Expr::Lambda { .. } | Expr::Dict(_) | Expr::Set(_) | Expr::TreeSet(_) | Expr::FString(_) | Expr::Conditional { .. } | Expr::NamedExpr { .. } => {
+            Err(SemanticError::new(
+                ErrorCode::InvalidAssignTarget,
+                "Invalid assignment target: cannot assign to literal, call, or expression".to_string(),
+                target.1.clone(),
+            ))
         }
     }
 }
 
+/// 언패킹 패턴에는 별표 요소가 하나만 올 수 있음: `a, *b, *c = xs`는 에러
+fn check_starred_count(elements: &[ExprS], span: &Span) -> SemanticResult<()> {
+    let starred = elements
+        .iter()
+        .filter(|e| matches!(e.0, Expr::Starred(_)))
+        .count();
+    if starred > 1 {
+        return Err(SemanticError::new(
+            ErrorCode::InvalidAssignTarget,
+            "multiple starred expressions in assignment".to_string(),
+            span.clone(),
+        ));
+    }
+    Ok(())
+}
+
+/// 별표 요소는 튜플/리스트 언패킹 대상 안에서만 쓸 수 있음
+fn starred_outside_unpacking(span: &Span) -> SemanticError {
+    SemanticError::new(
+        ErrorCode::InvalidAssignTarget,
+        "starred expression can only be used inside a tuple or list assignment target"
+            .to_string(),
+        span.clone(),
+    )
+    .with_help("unpack into a tuple of names, e.g. `first, *rest = xs`")
+}
+
+/// 대입 표현식은 lambda 본문에서 쓸 수 없음 (lambda는 자기 로컬 슬롯이 없음)
+fn check_no_named_expr_in_lambda(body: &ExprS, span: &Span) -> SemanticResult<()> {
+    let mut names = HashSet::new();
+    collect_named_expr_targets(body, &mut names);
+    if !names.is_empty() {
+        return Err(SemanticError::new(
+            ErrorCode::InvalidAssignTarget,
+            "assignment expression cannot be used in a lambda".to_string(),
+            span.clone(),
+        ));
+    }
+    Ok(())
+}
+
+/// 표현식 안의 대입 표현식(`name := value`)이 바인딩하는 이름을 수집합니다.
+pub(crate) fn collect_named_expr_targets(expr: &ExprS, out: &mut HashSet<String>) {
+    match &expr.0 {
+        Expr::Literal(_) | Expr::Variable(_) => {}
+        Expr::NamedExpr { name, value } => {
+            out.insert(name.clone());
+            collect_named_expr_targets(value, out);
+        }
+        Expr::Unary { expr, .. } | Expr::Starred(expr) => collect_named_expr_targets(expr, out),
+        Expr::Attribute { object, .. } => collect_named_expr_targets(object, out),
+        Expr::Binary { left, right, .. } => {
+            collect_named_expr_targets(left, out);
+            collect_named_expr_targets(right, out);
+        }
+        Expr::Index { object, index } => {
+            collect_named_expr_targets(object, out);
+            collect_named_expr_targets(index, out);
+        }
+        Expr::Compare { left, ops } => {
+            collect_named_expr_targets(left, out);
+            for (_, operand) in ops {
+                collect_named_expr_targets(operand, out);
+            }
+        }
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            collect_named_expr_targets(condition, out);
+            collect_named_expr_targets(then_expr, out);
+            collect_named_expr_targets(else_expr, out);
+        }
+        Expr::Call {
+            func_name,
+            args,
+            kwargs,
+        } => {
+            collect_named_expr_targets(func_name, out);
+            for arg in args.iter().chain(kwargs.iter().map(|(_, v)| v)) {
+                collect_named_expr_targets(arg, out);
+            }
+        }
+        Expr::List(elements)
+        | Expr::Tuple(elements)
+        | Expr::Set(elements)
+        | Expr::TreeSet(elements) => {
+            for elem in elements {
+                collect_named_expr_targets(elem, out);
+            }
+        }
+        Expr::Dict(pairs) => {
+            for (key, value) in pairs {
+                collect_named_expr_targets(key, out);
+                collect_named_expr_targets(value, out);
+            }
+        }
+        Expr::FString(parts) => {
+            for expr in fstring_exprs(parts) {
+                collect_named_expr_targets(expr, out);
+            }
+        }
+        Expr::Lambda { body, .. } => collect_named_expr_targets(body, out),
+    }
+}
+
+/// 문장이 직접 평가하는 표현식들 (중첩 블록의 문장은 제외)
+pub(crate) fn stmt_exprs(stmt: &Stmt) -> Vec<&ExprS> {
+    match stmt {
+        Stmt::If {
+            condition,
+            elif_blocks,
+            ..
+        } => std::iter::once(condition)
+            .chain(elif_blocks.iter().map(|(cond, _)| cond))
+            .collect(),
+        Stmt::While { condition, .. } => vec![condition],
+        Stmt::For { iterable, .. } => vec![iterable],
+        Stmt::Assign { value, .. } | Stmt::AugAssign { value, .. } => vec![value],
+        Stmt::Return(expr) | Stmt::Expr(expr) => vec![expr],
+        Stmt::Def { .. }
+        | Stmt::Class { .. }
+        | Stmt::Import(_)
+        | Stmt::Break
+        | Stmt::Continue
+        | Stmt::Pass => vec![],
+    }
+}
+
+/// 복합 할당(`x += 1`)의 대상은 변수, 속성, 인덱스만 가능 (튜플 언패킹 불가)
+fn check_aug_assign_target(target: &ExprS) -> SemanticResult<()> {
+    match &target.0 {
+        Expr::Variable(_) | Expr::Attribute { .. } | Expr::Index { .. } => Ok(()),
+        _ => Err(SemanticError::new(
+            ErrorCode::InvalidAssignTarget,
+            "Invalid augmented assignment target: expected a name, attribute or subscript"
+                .to_string(),
+            target.1.clone(),
+        )),
+    }
+}
+
 /// 함수 내부에서 할당 대상을 검증하고 분석합니다.
 /// 튜플 언패킹을 재귀적으로 지원합니다.
 fn validate_and_analyze_assign_target_function(
@@ -228,18 +671,25 @@ fn validate_and_analyze_assign_target_function(
         Expr::Attribute { .. } | Expr::Index { .. } => {
             analyze_expr_function(target, scopes, ctx, locals, assigned)
         }
-        Expr::Tuple(elements) => {
-            // 튜플 언패킹: 각 요소가 유효한 할당 대상인지 재귀적으로 검증
+        Expr::Tuple(elements) | Expr::List(elements) => {
+            // 튜플/리스트 언패킹: 각 요소가 유효한 할당 대상인지 재귀적으로 검증
+            check_starred_count(elements, &target.1)?;
             for elem in elements {
+                let elem = match &elem.0 {
+                    Expr::Starred(inner) => inner.as_ref(),
+                    _ => elem,
+                };
                 validate_and_analyze_assign_target_function(elem, scopes, ctx, locals, assigned)?;
             }
             Ok(())
         }
-        Expr::Literal(_) | Expr::Call { .. } | Expr::Binary { .. } | Expr::Unary { .. } | Expr::Lambda { .. } | Expr::List(_) | Expr::Dict(_) | Expr::Set(_) | Expr::TreeSet(_) => {
-            Err(SemanticError {
-                message: "Invalid assignment target: cannot assign to literal, call, or expression".to_string(),
-                span: target.1.clone(),
-            })
+        Expr::Starred(_) => Err(starred_outside_unpacking(&target.1)),
+        Expr::Literal(_) | Expr::Call { .. } | Expr::Binary { .. } | Expr::Compare { .. } | Expr::Unary { .. } | Expr::Lambda { .. } | Expr::Dict(_) | Expr::Set(_) | Expr::TreeSet(_) | Expr::FString(_) | Expr::Conditional { .. } | Expr::NamedExpr { .. } => {
+            Err(SemanticError::new(
+                ErrorCode::InvalidAssignTarget,
+                "Invalid assignment target: cannot assign to literal, call, or expression".to_string(),
+                target.1.clone(),
+            ))
         }
     }
 }
@@ -253,10 +703,13 @@ fn analyze_expr_module(
         Expr::Literal(_) => Ok(()),
         Expr::Variable(name) => {
             if !scopes.is_defined(name) && !ctx.is_builtin(name) {
-                return Err(SemanticError {
-                    message: format!("Undefined variable: {}", name),
-                    span: expr.1.clone(),
-                });
+                return Err(undefined_name(
+                    format!("Undefined variable: {}", name),
+                    name,
+                    expr.1.clone(),
+                    scopes,
+                    ctx,
+                ));
             }
             Ok(())
         }
@@ -265,24 +718,40 @@ fn analyze_expr_module(
             analyze_expr_module(left, scopes, ctx)?;
             analyze_expr_module(right, scopes, ctx)
         }
-        Expr::Call { func_name, args } => {
+        Expr::Compare { left, ops } => {
+            analyze_expr_module(left, scopes, ctx)?;
+            for (_, operand) in ops {
+                analyze_expr_module(operand, scopes, ctx)?;
+            }
+            Ok(())
+        }
+        Expr::Call { func_name, args, kwargs } => {
             // func_name이 Variable인 경우만 체크
             if let Expr::Variable(name) = &func_name.0 {
                 if !scopes.is_defined(name) && !ctx.is_builtin(name) {
-                    return Err(SemanticError {
-                        message: format!("Undefined function: {}", name),
-                        span: expr.1.clone(),
-                    });
+                    return Err(undefined_name(
+                        format!("Undefined function: {}", name),
+                        name,
+                        expr.1.clone(),
+                        scopes,
+                        ctx,
+                    ));
                 }
             } else {
                 // Attribute 등 다른 경우는 func_name 자체를 분석
                 analyze_expr_module(func_name, scopes, ctx)?;
             }
-            for a in args {
+            for a in args.iter().chain(kwargs.iter().map(|(_, v)| v)) {
                 analyze_expr_module(a, scopes, ctx)?;
             }
             Ok(())
         }
+        Expr::FString(parts) => {
+            for expr in fstring_exprs(parts) {
+                analyze_expr_module(expr, scopes, ctx)?;
+            }
+            Ok(())
+        }
         Expr::Attribute { object, .. } => {
             analyze_expr_module(object, scopes, ctx)?;
             Ok(())
@@ -323,17 +792,38 @@ fn analyze_expr_module(
             analyze_expr_module(index, scopes, ctx)?;
             Ok(())
         }
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            analyze_expr_module(condition, scopes, ctx)?;
+            analyze_expr_module(then_expr, scopes, ctx)?;
+            analyze_expr_module(else_expr, scopes, ctx)
+        }
+        Expr::NamedExpr { name, value } => {
+            analyze_expr_module(value, scopes, ctx)?;
+            if !scopes.is_defined(name) {
+                scopes.define(name.clone());
+            }
+            Ok(())
+        }
+        Expr::Starred(_) => Err(starred_outside_unpacking(&expr.1)),
         Expr::Lambda { params, body } => {
+            check_no_named_expr_in_lambda(body, &expr.1)?;
             // Check for unbound captured variables
             let mut free_vars = HashSet::new();
             collect_free_vars(body, params, &mut free_vars);
 
             for var in &free_vars {
                 if !scopes.is_defined(var) && !ctx.is_builtin(var) {
-                    return Err(SemanticError {
-                        message: format!("Undefined variable '{}' captured by lambda", var),
-                        span: expr.1.clone(),
-                    });
+                    return Err(undefined_name(
+                        format!("Undefined variable '{}' captured by lambda", var),
+                        var,
+                        expr.1.clone(),
+                        scopes,
+                        ctx,
+                    ));
                 }
             }
 
@@ -371,7 +861,7 @@ fn analyze_function(
     let mut assigned: HashSet<String> = params.iter().cloned().collect();
 
     for s in body {
-        analyze_stmt_function(s, scopes, ctx, &locals, &mut assigned)?;
+        ctx.recover(analyze_stmt_function(s, scopes, ctx, &locals, &mut assigned));
     }
 
     scopes.pop();
@@ -386,12 +876,13 @@ fn collect_locals_from_target(target: &Expr, locals: &mut HashSet<String>) {
         Expr::Variable(name) => {
             locals.insert(name.clone());
         }
-        Expr::Tuple(elements) => {
+        Expr::Tuple(elements) | Expr::List(elements) => {
             // 튜플 언패킹: 각 요소에서 재귀적으로 수집
             for elem in elements {
                 collect_locals_from_target(&elem.0, locals);
             }
         }
+        Expr::Starred(inner) => collect_locals_from_target(&inner.0, locals),
         Expr::Attribute { .. } | Expr::Index { .. } => {
             // 속성/인덱스 할당은 로컬 변수를 생성하지 않음
         }
@@ -403,15 +894,24 @@ fn collect_locals_from_target(target: &Expr, locals: &mut HashSet<String>) {
 
 fn collect_locals(body: &Vec<StmtS>, locals: &mut HashSet<String>) {
     for s in body {
+        // 대입 표현식(`n := ...`)의 이름도 함수 로컬
+        for expr in stmt_exprs(&s.0) {
+            collect_named_expr_targets(expr, locals);
+        }
         match &s.0 {
-            Stmt::Assign { target, .. } => {
+            Stmt::Assign { targets, .. } => {
                 // 튜플 언패킹 지원: 재귀적으로 변수 수집
+                for target in targets {
+                    collect_locals_from_target(&target.0, locals);
+                }
+            }
+            Stmt::AugAssign { target, .. } => {
                 collect_locals_from_target(&target.0, locals);
             }
             Stmt::Def { name, .. } => {
                 locals.insert(name.clone());
             }
-            Stmt::Class { name, .. } => {
+            Stmt::Class { name, .. } | Stmt::Import(name) => {
                 locals.insert(name.clone());
             }
             Stmt::If {
@@ -431,9 +931,9 @@ fn collect_locals(body: &Vec<StmtS>, locals: &mut HashSet<String>) {
             Stmt::While { body, .. } => {
                 collect_locals(body, locals);
             }
-            Stmt::For { var, body, .. } => {
+            Stmt::For { target, body, .. } => {
                 // 루프 변수를 local로 수집
-                locals.insert(var.clone());
+                collect_locals_from_target(&target.0, locals);
                 collect_locals(body, locals);
             }
             Stmt::Break | Stmt::Continue | Stmt::Pass => {}
@@ -455,12 +955,23 @@ fn collect_free_vars(expr: &ExprS, params: &Vec<String>, free_vars: &mut HashSet
             collect_free_vars(left, params, free_vars);
             collect_free_vars(right, params, free_vars);
         }
-        Expr::Call { func_name, args } => {
+        Expr::Compare { left, ops } => {
+            collect_free_vars(left, params, free_vars);
+            for (_, operand) in ops {
+                collect_free_vars(operand, params, free_vars);
+            }
+        }
+        Expr::Call { func_name, args, kwargs } => {
             collect_free_vars(func_name, params, free_vars);
-            for arg in args {
+            for arg in args.iter().chain(kwargs.iter().map(|(_, v)| v)) {
                 collect_free_vars(arg, params, free_vars);
             }
         }
+        Expr::FString(parts) => {
+            for expr in fstring_exprs(parts) {
+                collect_free_vars(expr, params, free_vars);
+            }
+        }
         Expr::Attribute { object, .. } => {
             collect_free_vars(object, params, free_vars);
         }
@@ -494,6 +1005,18 @@ fn collect_free_vars(expr: &ExprS, params: &Vec<String>, free_vars: &mut HashSet
             collect_free_vars(object, params, free_vars);
             collect_free_vars(index, params, free_vars);
         }
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            collect_free_vars(condition, params, free_vars);
+            collect_free_vars(then_expr, params, free_vars);
+            collect_free_vars(else_expr, params, free_vars);
+        }
+        Expr::NamedExpr { value, .. } | Expr::Starred(value) => {
+            collect_free_vars(value, params, free_vars);
+        }
         Expr::Lambda {
             params: inner_params,
             body,
@@ -522,12 +1045,25 @@ fn analyze_stmt_function(
             // Pass is always allowed as a no-op
             Ok(())
         }
-        Stmt::Assign { target, value } => {
-            analyze_expr_function(value, scopes, ctx, locals, assigned)?;
+        Stmt::Import(name) => {
+            // import도 로컬 변수에 바인딩
+            scopes.define(name.clone());
+            assigned.insert(name.clone());
+            check_import(name, &stmt.1)
+        }
+        Stmt::Assign { targets, value } => {
+            ctx.recover(analyze_expr_function(value, scopes, ctx, locals, assigned));
             // 튜플 언패킹 지원: target이 Tuple인 경우 각 요소 검증
-            validate_and_analyze_assign_target_function(target, scopes, ctx, locals, assigned)?;
+            for target in targets {
+                validate_and_analyze_assign_target_function(target, scopes, ctx, locals, assigned)?;
+            }
             Ok(())
         }
+        Stmt::AugAssign { target, value, .. } => {
+            ctx.recover(analyze_expr_function(value, scopes, ctx, locals, assigned));
+            check_aug_assign_target(target)?;
+            analyze_expr_function(target, scopes, ctx, locals, assigned)
+        }
         Stmt::Def { name, params, body } => {
             // 함수 정의도 로컬에 바인딩
             if !scopes.is_defined(name) {
@@ -537,9 +1073,20 @@ fn analyze_stmt_function(
             let mut inner_ctx = ProgramContext {
                 builtins: ctx.builtins.clone(),
                 functions: ctx.functions.clone(),
+                classes: ctx.classes.clone(),
+                definitions: ctx.definitions.clone(),
+                errors: RefCell::default(),
+                natives: ctx.natives.clone(),
+                existing_globals: ctx.existing_globals.clone(),
+                outside_attributes: RefCell::default(),
+                untyped_attributes: RefCell::default(),
+                unknown_attributes: RefCell::default(),
             };
             inner_ctx.functions.insert(name.clone(), params.len());
-            analyze_function(name, params, body, scopes, &mut inner_ctx, stmt.1.clone())
+            let result =
+                analyze_function(name, params, body, scopes, &mut inner_ctx, stmt.1.clone());
+            ctx.errors.borrow_mut().extend(inner_ctx.errors.take());
+            result
         }
         Stmt::If {
             condition,
@@ -547,51 +1094,46 @@ fn analyze_stmt_function(
             elif_blocks,
             else_block,
         } => {
-            analyze_expr_function(condition, scopes, ctx, locals, assigned)?;
+            ctx.recover(analyze_expr_function(condition, scopes, ctx, locals, assigned));
             for s in then_block {
-                analyze_stmt_function(s, scopes, ctx, locals, assigned)?;
+                ctx.recover(analyze_stmt_function(s, scopes, ctx, locals, assigned));
             }
             for (cond, block) in elif_blocks {
-                analyze_expr_function(cond, scopes, ctx, locals, assigned)?;
+                ctx.recover(analyze_expr_function(cond, scopes, ctx, locals, assigned));
                 for s in block {
-                    analyze_stmt_function(s, scopes, ctx, locals, assigned)?;
+                    ctx.recover(analyze_stmt_function(s, scopes, ctx, locals, assigned));
                 }
             }
             if let Some(block) = else_block {
                 for s in block {
-                    analyze_stmt_function(s, scopes, ctx, locals, assigned)?;
+                    ctx.recover(analyze_stmt_function(s, scopes, ctx, locals, assigned));
                 }
             }
             Ok(())
         }
         Stmt::While { condition, body } => {
-            analyze_expr_function(condition, scopes, ctx, locals, assigned)?;
+            ctx.recover(analyze_expr_function(condition, scopes, ctx, locals, assigned));
             for s in body {
-                analyze_stmt_function(s, scopes, ctx, locals, assigned)?;
+                ctx.recover(analyze_stmt_function(s, scopes, ctx, locals, assigned));
             }
             Ok(())
         }
         Stmt::For {
-            var,
+            target,
             iterable,
             body,
         } => {
             // iterable 표현식 분석
-            analyze_expr_function(iterable, scopes, ctx, locals, assigned)?;
-
-            // 루프 변수를 스코프에 정의
-            if !scopes.is_defined(var) {
-                scopes.define(var.clone());
-            }
+            ctx.recover(analyze_expr_function(iterable, scopes, ctx, locals, assigned));
 
-            // 루프 변수가 할당됨을 표시
-            if locals.contains(var) {
-                assigned.insert(var.clone());
-            }
+            // 루프 변수를 스코프에 정의하고 할당됨을 표시 (언패킹 패턴 포함)
+            ctx.recover(validate_and_analyze_assign_target_function(
+                target, scopes, ctx, locals, assigned,
+            ));
 
             // body 분석
             for s in body {
-                analyze_stmt_function(s, scopes, ctx, locals, assigned)?;
+                ctx.recover(analyze_stmt_function(s, scopes, ctx, locals, assigned));
             }
             Ok(())
         }
@@ -610,25 +1152,29 @@ fn analyze_expr_function(
     scopes: &mut scope::ScopeStack,
     ctx: &ProgramContext,
     locals: &HashSet<String>,
-    assigned: &HashSet<String>,
+    assigned: &mut HashSet<String>,
 ) -> SemanticResult<()> {
     match &expr.0 {
         Expr::Literal(_) => Ok(()),
         Expr::Variable(name) => {
             if locals.contains(name) {
                 if !assigned.contains(name) {
-                    return Err(SemanticError {
-                        message: format!("Unbound local variable: {}", name),
-                        span: expr.1.clone(),
-                    });
+                    return Err(unbound_local(
+                        format!("Unbound local variable: {}", name),
+                        name,
+                        expr.1.clone(),
+                    ));
                 }
                 return Ok(());
             }
             if !scopes.is_defined(name) && !ctx.is_builtin(name) {
-                return Err(SemanticError {
-                    message: format!("Undefined variable: {}", name),
-                    span: expr.1.clone(),
-                });
+                return Err(undefined_name(
+                    format!("Undefined variable: {}", name),
+                    name,
+                    expr.1.clone(),
+                    scopes,
+                    ctx,
+                ));
             }
             Ok(())
         }
@@ -639,31 +1185,48 @@ fn analyze_expr_function(
             analyze_expr_function(left, scopes, ctx, locals, assigned)?;
             analyze_expr_function(right, scopes, ctx, locals, assigned)
         }
-        Expr::Call { func_name, args } => {
+        Expr::Compare { left, ops } => {
+            analyze_expr_function(left, scopes, ctx, locals, assigned)?;
+            for (_, operand) in ops {
+                analyze_expr_function(operand, scopes, ctx, locals, assigned)?;
+            }
+            Ok(())
+        }
+        Expr::Call { func_name, args, kwargs } => {
             // func_name이 Variable인 경우만 체크
             if let Expr::Variable(name) = &func_name.0 {
                 if locals.contains(name) {
                     if !assigned.contains(name) {
-                        return Err(SemanticError {
-                            message: format!("Unbound local function: {}", name),
-                            span: expr.1.clone(),
-                        });
+                        return Err(unbound_local(
+                            format!("Unbound local function: {}", name),
+                            name,
+                            expr.1.clone(),
+                        ));
                     }
                 } else if !scopes.is_defined(name) && !ctx.is_builtin(name) {
-                    return Err(SemanticError {
-                        message: format!("Undefined function: {}", name),
-                        span: expr.1.clone(),
-                    });
+                    return Err(undefined_name(
+                        format!("Undefined function: {}", name),
+                        name,
+                        expr.1.clone(),
+                        scopes,
+                        ctx,
+                    ));
                 }
             } else {
                 // Attribute 등 다른 경우는 func_name 자체를 분석
                 analyze_expr_function(func_name, scopes, ctx, locals, assigned)?;
             }
-            for a in args {
+            for a in args.iter().chain(kwargs.iter().map(|(_, v)| v)) {
                 analyze_expr_function(a, scopes, ctx, locals, assigned)?;
             }
             Ok(())
         }
+        Expr::FString(parts) => {
+            for expr in fstring_exprs(parts) {
+                analyze_expr_function(expr, scopes, ctx, locals, assigned)?;
+            }
+            Ok(())
+        }
         Expr::Attribute { object, .. } => {
             analyze_expr_function(object, scopes, ctx, locals, assigned)?;
             Ok(())
@@ -704,17 +1267,37 @@ fn analyze_expr_function(
             analyze_expr_function(index, scopes, ctx, locals, assigned)?;
             Ok(())
         }
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            analyze_expr_function(condition, scopes, ctx, locals, assigned)?;
+            analyze_expr_function(then_expr, scopes, ctx, locals, assigned)?;
+            analyze_expr_function(else_expr, scopes, ctx, locals, assigned)
+        }
+        Expr::NamedExpr { name, value } => {
+            analyze_expr_function(value, scopes, ctx, locals, assigned)?;
+            assigned.insert(name.clone());
+            if !scopes.is_defined(name) {
+                scopes.define(name.clone());
+            }
+            Ok(())
+        }
+        Expr::Starred(_) => Err(starred_outside_unpacking(&expr.1)),
         Expr::Lambda { params, body } => {
+            check_no_named_expr_in_lambda(body, &expr.1)?;
             // Check for unbound captured variables
             let mut free_vars = HashSet::new();
             collect_free_vars(body, params, &mut free_vars);
 
             for var in &free_vars {
                 if locals.contains(var) && !assigned.contains(var) {
-                    return Err(SemanticError {
-                        message: format!("Unbound local variable '{}' captured by lambda", var),
-                        span: expr.1.clone(),
-                    });
+                    return Err(unbound_local(
+                        format!("Unbound local variable '{}' captured by lambda", var),
+                        var,
+                        expr.1.clone(),
+                    ));
                 }
             }
 
@@ -727,7 +1310,8 @@ fn analyze_expr_function(
             // The body of a lambda is an expression, so it can't contain assignments.
             // Its locals are just its parameters.
             let lambda_locals: HashSet<String> = params.iter().cloned().collect();
-            analyze_expr_function(body, scopes, ctx, &lambda_locals, &lambda_locals)?;
+            let mut lambda_assigned = lambda_locals.clone();
+            analyze_expr_function(body, scopes, ctx, &lambda_locals, &mut lambda_assigned)?;
 
             scopes.pop();
             Ok(())
@@ -754,7 +1338,7 @@ mod tests {
     fn test_analyze_global_variable() {
         let program = vec![
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("x".to_string())),
+                targets: vec![make_expr(Expr::Variable("x".to_string()))],
                 value: make_expr(Expr::Literal(Literal::Int(42))),
             }),
             make_stmt(Stmt::Expr(make_expr(Expr::Variable("x".to_string())))),
@@ -772,8 +1356,8 @@ mod tests {
 
         let result = analyze(&program);
         assert!(result.is_err());
-        if let Err(err) = result {
-            assert!(err.message.contains("Undefined variable"));
+        if let Err(errors) = result {
+            assert!(errors[0].message.contains("Undefined variable"));
         }
     }
 
@@ -790,6 +1374,7 @@ mod tests {
             make_stmt(Stmt::Expr(make_expr(Expr::Call {
                 func_name: Box::new(make_expr(Expr::Variable("foo".to_string()))),
                 args: vec![],
+                kwargs: vec![],
             }))),
         ];
 
@@ -802,12 +1387,13 @@ mod tests {
         let program = vec![make_stmt(Stmt::Expr(make_expr(Expr::Call {
             func_name: Box::new(make_expr(Expr::Variable("undefined".to_string()))),
             args: vec![],
+            kwargs: vec![],
         })))];
 
         let result = analyze(&program);
         assert!(result.is_err());
-        if let Err(err) = result {
-            assert!(err.message.contains("Undefined function"));
+        if let Err(errors) = result {
+            assert!(errors[0].message.contains("Undefined function"));
         }
     }
 
@@ -836,7 +1422,7 @@ mod tests {
             body: vec![
                 make_stmt(Stmt::Expr(make_expr(Expr::Variable("x".to_string())))),
                 make_stmt(Stmt::Assign {
-                    target: make_expr(Expr::Variable("x".to_string())),
+                    targets: vec![make_expr(Expr::Variable("x".to_string()))],
                     value: make_expr(Expr::Literal(Literal::Int(42))),
                 }),
             ],
@@ -844,8 +1430,8 @@ mod tests {
 
         let result = analyze(&program);
         assert!(result.is_err());
-        if let Err(err) = result {
-            assert!(err.message.contains("Unbound local variable"));
+        if let Err(errors) = result {
+            assert!(errors[0].message.contains("Unbound local variable"));
         }
     }
 
@@ -854,6 +1440,7 @@ mod tests {
         let program = vec![make_stmt(Stmt::Expr(make_expr(Expr::Call {
             func_name: Box::new(make_expr(Expr::Variable("print".to_string()))),
             args: vec![make_expr(Expr::Literal(Literal::Int(42)))],
+            kwargs: vec![],
         })))];
 
         let result = analyze(&program);
@@ -864,7 +1451,7 @@ mod tests {
     fn test_analyze_nested_scopes() {
         let program = vec![
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("x".to_string())),
+                targets: vec![make_expr(Expr::Variable("x".to_string()))],
                 value: make_expr(Expr::Literal(Literal::Int(1))),
             }),
             make_stmt(Stmt::Def {
@@ -969,7 +1556,7 @@ mod tests {
 
     #[test]
     fn test_analyze_multiple_errors() {
-        // 여러 개의 에러가 있는 경우, 첫 번째 에러만 보고됨
+        // 여러 개의 에러가 있는 경우, 모든 에러가 보고됨
         let program = vec![
             make_stmt(Stmt::Expr(make_expr(Expr::Variable(
                 "undefined1".to_string(),
@@ -979,14 +1566,16 @@ mod tests {
             )))),
         ];
 
-        let result = analyze(&program);
-        assert!(result.is_err());
+        let errors = analyze(&program).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message.contains("undefined1"));
+        assert!(errors[1].message.contains("undefined2"));
     }
 
     #[test]
     fn test_analyze_complex_expression() {
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Variable("result".to_string())),
+            targets: vec![make_expr(Expr::Variable("result".to_string()))],
             value: make_expr(Expr::Binary {
                 op: BinaryOp::Add,
                 left: Box::new(make_expr(Expr::Binary {
@@ -1008,7 +1597,7 @@ mod tests {
     fn test_analyze_tuple_expression() {
         // 튜플 표현식 분석
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Variable("t".to_string())),
+            targets: vec![make_expr(Expr::Variable("t".to_string()))],
             value: make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Literal(Literal::Int(1))),
                 make_expr(Expr::Literal(Literal::Int(2))),
@@ -1024,10 +1613,11 @@ mod tests {
     fn test_analyze_for_with_range() {
         // range()를 사용한 for 루프 - 루프 변수는 Int로 추론
         let program = vec![make_stmt(Stmt::For {
-            var: "i".to_string(),
+            target: make_expr(Expr::Variable("i".to_string())),
             iterable: make_expr(Expr::Call {
                 func_name: Box::new(make_expr(Expr::Variable("range".to_string()))),
                 args: vec![make_expr(Expr::Literal(Literal::Int(10)))],
+                kwargs: vec![],
             }),
             body: vec![make_stmt(Stmt::Expr(make_expr(Expr::Binary {
                 op: BinaryOp::Add,
@@ -1044,10 +1634,11 @@ mod tests {
     fn test_analyze_for_loop_type_mismatch() {
         // range() 루프에서 Int 변수를 String과 연산하면 에러
         let program = vec![make_stmt(Stmt::For {
-            var: "i".to_string(),
+            target: make_expr(Expr::Variable("i".to_string())),
             iterable: make_expr(Expr::Call {
                 func_name: Box::new(make_expr(Expr::Variable("range".to_string()))),
                 args: vec![make_expr(Expr::Literal(Literal::Int(10)))],
+                kwargs: vec![],
             }),
             body: vec![make_stmt(Stmt::Expr(make_expr(Expr::Binary {
                 op: BinaryOp::Add,
@@ -1064,7 +1655,7 @@ mod tests {
     fn test_analyze_for_with_list() {
         // 리스트를 사용한 for 루프
         let program = vec![make_stmt(Stmt::For {
-            var: "x".to_string(),
+            target: make_expr(Expr::Variable("x".to_string())),
             iterable: make_expr(Expr::List(vec![
                 make_expr(Expr::Literal(Literal::Int(1))),
                 make_expr(Expr::Literal(Literal::Int(2))),
@@ -1086,11 +1677,11 @@ mod tests {
         // Lambda가 외부 변수를 캡처
         let program = vec![
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("x".to_string())),
+                targets: vec![make_expr(Expr::Variable("x".to_string()))],
                 value: make_expr(Expr::Literal(Literal::Int(10))),
             }),
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("f".to_string())),
+                targets: vec![make_expr(Expr::Variable("f".to_string()))],
                 value: make_expr(Expr::Lambda {
                     params: vec!["y".to_string()],
                     body: Box::new(make_expr(Expr::Binary {
@@ -1110,7 +1701,7 @@ mod tests {
     fn test_analyze_lambda_undefined_capture() {
         // Lambda가 정의되지 않은 변수를 캡처하면 에러
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Variable("f".to_string())),
+            targets: vec![make_expr(Expr::Variable("f".to_string()))],
             value: make_expr(Expr::Lambda {
                 params: vec!["y".to_string()],
                 body: Box::new(make_expr(Expr::Binary {
@@ -1130,7 +1721,7 @@ mod tests {
         // map() 빌트인 함수 호출
         let program = vec![
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("f".to_string())),
+                targets: vec![make_expr(Expr::Variable("f".to_string()))],
                 value: make_expr(Expr::Lambda {
                     params: vec!["x".to_string()],
                     body: Box::new(make_expr(Expr::Binary {
@@ -1141,7 +1732,7 @@ mod tests {
                 }),
             }),
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("result".to_string())),
+                targets: vec![make_expr(Expr::Variable("result".to_string()))],
                 value: make_expr(Expr::Call {
                     func_name: Box::new(make_expr(Expr::Variable("map".to_string()))),
                     args: vec![
@@ -1149,8 +1740,10 @@ mod tests {
                         make_expr(Expr::Call {
                             func_name: Box::new(make_expr(Expr::Variable("range".to_string()))),
                             args: vec![make_expr(Expr::Literal(Literal::Int(5)))],
+                            kwargs: vec![],
                         }),
                     ],
+                    kwargs: vec![],
                 }),
             }),
         ];
@@ -1164,7 +1757,7 @@ mod tests {
         // filter() 빌트인 함수 호출
         let program = vec![
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("is_even".to_string())),
+                targets: vec![make_expr(Expr::Variable("is_even".to_string()))],
                 value: make_expr(Expr::Lambda {
                     params: vec!["x".to_string()],
                     body: Box::new(make_expr(Expr::Binary {
@@ -1179,7 +1772,7 @@ mod tests {
                 }),
             }),
             make_stmt(Stmt::Assign {
-                target: make_expr(Expr::Variable("evens".to_string())),
+                targets: vec![make_expr(Expr::Variable("evens".to_string()))],
                 value: make_expr(Expr::Call {
                     func_name: Box::new(make_expr(Expr::Variable("filter".to_string()))),
                     args: vec![
@@ -1187,8 +1780,10 @@ mod tests {
                         make_expr(Expr::Call {
                             func_name: Box::new(make_expr(Expr::Variable("range".to_string()))),
                             args: vec![make_expr(Expr::Literal(Literal::Int(10)))],
+                            kwargs: vec![],
                         }),
                     ],
+                    kwargs: vec![],
                 }),
             }),
         ];
@@ -1203,11 +1798,11 @@ mod tests {
     fn test_analyze_unpack_assignment() {
         // 기본 언패킹
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Tuple(vec![
+            targets: vec![make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Variable("a".to_string())),
                 make_expr(Expr::Variable("b".to_string())),
                 make_expr(Expr::Variable("c".to_string())),
-            ])),
+            ]))],
             value: make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Literal(Literal::Int(1))),
                 make_expr(Expr::Literal(Literal::Int(2))),
@@ -1223,11 +1818,11 @@ mod tests {
     fn test_analyze_unpack_from_list() {
         // 리스트에서 언패킹
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Tuple(vec![
+            targets: vec![make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Variable("x".to_string())),
                 make_expr(Expr::Variable("y".to_string())),
                 make_expr(Expr::Variable("z".to_string())),
-            ])),
+            ]))],
             value: make_expr(Expr::List(vec![
                 make_expr(Expr::Literal(Literal::Int(1))),
                 make_expr(Expr::Literal(Literal::Int(2))),
@@ -1243,13 +1838,13 @@ mod tests {
     fn test_analyze_nested_unpack() {
         // 중첩 언패킹
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Tuple(vec![
+            targets: vec![make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Variable("a".to_string())),
                 make_expr(Expr::Tuple(vec![
                     make_expr(Expr::Variable("b".to_string())),
                     make_expr(Expr::Variable("c".to_string())),
                 ])),
-            ])),
+            ]))],
             value: make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Literal(Literal::Int(1))),
                 make_expr(Expr::Tuple(vec![
@@ -1267,10 +1862,10 @@ mod tests {
     fn test_analyze_invalid_unpack_target() {
         // 리터럴에 할당 불가
         let program = vec![make_stmt(Stmt::Assign {
-            target: make_expr(Expr::Tuple(vec![
+            targets: vec![make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Literal(Literal::Int(1))),
                 make_expr(Expr::Literal(Literal::Int(2))),
-            ])),
+            ]))],
             value: make_expr(Expr::Tuple(vec![
                 make_expr(Expr::Literal(Literal::Int(10))),
                 make_expr(Expr::Literal(Literal::Int(20))),
@@ -1280,4 +1875,259 @@ mod tests {
         let result = analyze(&program);
         assert!(result.is_err(), "Should fail: cannot assign to literal");
     }
+
+    // ========== 클래스 타입 검사 테스트 ==========
+
+    fn analyze_src(src: &str) -> Result<SemanticErrors, SemanticErrors> {
+        let program = crate::parse_source(src).expect("parse failed");
+        analyze(&program)
+    }
+
+    #[test]
+    fn test_analyze_class_known_attributes() {
+        // __init__ 뿐 아니라 다른 메서드/블록 안에서 할당된 속성도 허용
+        let src = "class Counter:\n  def __init__(self, start):\n    self.count = start\n\n  def reset(self):\n    if self.count > 0:\n      self.last = self.count\n    self.count = 0\n\nc = Counter(3)\nc.reset()\nprint(c.count)\nprint(c.last)\n";
+        assert!(analyze_src(src).is_ok());
+    }
+
+    #[test]
+    fn test_analyze_class_unknown_attribute() {
+        let src = "class Person:\n  def __init__(self, name):\n    self.name = name\n\n  def greet(self):\n    return self.nmae\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert!(err.message.contains("'Person' object has no attribute 'nmae'"));
+    }
+
+    #[test]
+    fn test_analyze_attribute_assigned_outside_class_is_warning() {
+        // Python처럼 허용: 경고만 하고 이후 읽기도 통과
+        let src = "class P:\n  def __init__(self):\n    self.x = 1\n\np = P()\np.y = 2\nprint(p.y)\np.y = 3\n";
+        let warnings = analyze_src(src).expect("warnings must not fail analysis");
+        assert_eq!(warnings.len(), 1, "warnings: {:?}", warnings);
+        assert_eq!(warnings[0].code, ErrorCode::AttributeOutsideClass);
+        assert!(!warnings[0].is_error());
+        assert_eq!(&src[warnings[0].span.clone()], "p.y = 2");
+        assert_eq!(warnings[0].message, "attribute 'y' is not defined in class 'P'");
+
+        // 오타면 기존 속성을 제안
+        let src = "class P:\n  def __init__(self):\n    self.count = 1\n\np = P()\np.cuont = 2\n";
+        let warnings = analyze_src(src).unwrap();
+        assert_eq!(warnings[0].help.as_deref(), Some("did you mean `count`?"));
+
+        // 할당 전에 읽는 것은 여전히 에러
+        let src = "class P:\n  def __init__(self):\n    self.x = 1\n\np = P()\nprint(p.y)\np.y = 2\n";
+        let errors = analyze_src(src).unwrap_err();
+        assert!(errors[0].message.contains("'P' object has no attribute 'y'"));
+    }
+
+    #[test]
+    fn test_analyze_attributes_assigned_through_untyped_receivers() {
+        // 타입을 모르는 매개변수로 할당한 속성은 어느 클래스에서든 읽을 수 있음 (정의 순서 무관)
+        let src = "class Node:\n  def __init__(self, val):\n    self.val = val\n\ndef first(n):\n  return n.next.val\n\ndef link(a, b):\n  a.next = b\n\nn = Node(1)\nlink(n, Node(2))\nprint(n.next.val, first(n))\n";
+        let warnings = analyze_src(src).expect("attributes set through untyped receivers");
+        assert!(warnings.is_empty(), "warnings: {:?}", warnings);
+
+        // 메서드의 다른 매개변수로 할당한 속성도 마찬가지
+        let src = "class Node:\n  def __init__(self):\n    self.parent = None\n\n  def attach(self, other):\n    other.child = self\n\nm = Node()\nNode().attach(m)\nprint(m.child)\n";
+        let warnings = analyze_src(src).expect("attributes set through untyped receivers");
+        assert!(warnings.is_empty(), "warnings: {:?}", warnings);
+
+        // 어디에서도 할당하지 않은 속성은 여전히 에러
+        let src = "class Node:\n  def __init__(self):\n    self.val = 1\n\ndef link(a, b):\n  a.next = b\n\nprint(Node().nxt)\n";
+        let errors = analyze_src(src).unwrap_err();
+        assert!(errors[0].message.contains("'Node' object has no attribute 'nxt'"));
+    }
+
+    #[test]
+    fn test_analyze_type_messages_use_python_names() {
+        let src = "class Cat:\n  def __init__(self):\n    self.n = 1\n\nc = Cat()\nx = [1]\nx = c\nprint(1 + \"a\")\nprint(map(len, [\"a\"]) + 1)\n";
+        let errors = analyze_src(src).unwrap_err();
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "TypeError: cannot assign value of type Cat to variable of type list[int]",
+                "TypeError: unsupported operand types for +: int and str",
+                "TypeError: unsupported operand types for +: map and int",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_class_unknown_method_through_variable() {
+        // 인스턴스 타입이 변수를 통해 전달됨
+        let src = "class Dog:\n  def bark(self):\n    return \"woof\"\n\nd = Dog()\ne = d\ne.meow()\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert!(err.message.contains("AttributeError"));
+        assert!(err.message.contains("'meow'"));
+    }
+
+    #[test]
+    fn test_analyze_class_method_arity() {
+        let src = "class Point:\n  def __init__(self, x, y):\n    self.x = x\n    self.y = y\n\n  def move(self, dx, dy):\n    self.x = self.x + dx\n\np = Point(1, 2)\np.move(1)\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert!(err.message.contains("ArityError"));
+        assert!(err.message.contains("Point.move"));
+    }
+
+    #[test]
+    fn test_analyze_class_constructor_arity() {
+        let src = "class Point:\n  def __init__(self, x, y):\n    self.x = x\n    self.y = y\n\np = Point(1)\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert!(err.message.contains("ArityError: Point() takes 2"));
+
+        // __init__이 없으면 인자 없이 생성
+        let src = "class Empty:\n  def hello(self):\n    return 1\n\ne = Empty(1)\n";
+        assert!(analyze_src(src).is_err());
+    }
+
+    #[test]
+    fn test_analyze_collects_errors_across_statements() {
+        // 함수 본문 안의 에러, 타입 에러, 이름 에러가 모두 위치 순으로 보고됨
+        let src = "def f(a):\n  b = a + missing\n  return b\nf(1, 2)\nx = 1 + True\nbreak\n";
+        let errors = analyze_src(src).unwrap_err();
+        assert_eq!(errors.len(), 4, "errors: {:?}", errors);
+        assert!(errors[0].message.contains("missing"));
+        assert!(errors[1].message.contains("ArityError"));
+        assert!(errors[2].message.contains("TypeError"));
+        assert!(errors[3].message.contains("'break' outside loop"));
+    }
+
+    #[test]
+    fn test_analyze_no_cascade_after_bad_assignment() {
+        // 값에 에러가 있어도 대상 변수는 정의되므로 이후 사용은 에러가 아님
+        let src = "x = undefined_name\nprint(x)\n";
+        let errors = analyze_src(src).unwrap_err();
+        assert_eq!(errors.len(), 1);
+    }
+
+    // ========== 진단: 코드, 제안, 경고 ==========
+
+    #[test]
+    fn test_analyze_suggests_similar_name() {
+        let src = "length = 3\nprint(lenght)\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert_eq!(err.code, ErrorCode::UndefinedName);
+        assert_eq!(err.help.as_deref(), Some("did you mean `length`?"));
+
+        // 빌트인도 후보에 포함
+        let err = &analyze_src("pritn(1)\n").unwrap_err()[0];
+        assert_eq!(err.help.as_deref(), Some("did you mean `print`?"));
+    }
+
+    #[test]
+    fn test_analyze_suggests_attribute_and_builtin_method() {
+        let src = "class Person:\n  def __init__(self, name):\n    self.name = name\n\np = Person(\"Kim\")\nprint(p.nmae)\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert_eq!(err.code, ErrorCode::UnknownAttribute);
+        assert_eq!(err.help.as_deref(), Some("did you mean `name`?"));
+
+        // 빌트인 타입 메서드는 TypeDef 테이블에서 찾음
+        let err = &analyze_src("s = \"abc\"\nt = s.uper()\n").unwrap_err()[0];
+        assert!(err.message.contains("'str' object has no attribute 'uper'"));
+        assert_eq!(err.help.as_deref(), Some("did you mean `upper`?"));
+
+        let err = &analyze_src("xs = [1]\nxs.append(1, 2)\n").unwrap_err()[0];
+        assert_eq!(err.code, ErrorCode::ArityMismatch);
+        assert!(err.message.contains("list.append"));
+    }
+
+    #[test]
+    fn test_analyze_arity_error_points_at_definition() {
+        let src = "def add(a, b):\n  return a + b\n\nadd(1)\n";
+        let err = &analyze_src(src).unwrap_err()[0];
+        assert_eq!(err.labels.len(), 1);
+        assert_eq!(&src[err.labels[0].0.clone()], "def add");
+    }
+
+    #[test]
+    fn test_analyze_unreachable_code_is_warning() {
+        let src = "def f():\n  return 1\n  print(2)\n  print(3)\n\nfor i in range(3):\n  break\n  print(i)\n";
+        let warnings = analyze_src(src).expect("warnings must not fail analysis");
+        assert_eq!(warnings.len(), 2, "warnings: {:?}", warnings);
+        assert!(warnings.iter().all(|w| w.code == ErrorCode::UnreachableCode));
+        assert!(!warnings[0].is_error());
+        assert_eq!(&src[warnings[0].span.clone()], "print(2)\n  print(3)");
+
+        // 에러와 함께 있으면 경고도 함께 반환
+        let diagnostics = analyze_src("def f():\n  return 1\n  x = 1 + True\n").unwrap_err();
+        assert_eq!(diagnostics.len(), 2);
+    }
+
+    #[test]
+    fn test_analyze_fstring_fields() {
+        // f-string 필드의 표현식도 일반 표현식처럼 분석
+        let errors = analyze_src("name = 1\nprint(f\"{nmae}\")\n").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::UndefinedName);
+        assert!(analyze_src("def f(x):\n  return f\"{x:>4}|{x!r}\"\n").is_ok());
+
+        let errors = analyze_src("x = 1\nprint(f\"{x:.2q}\")\nprint(format(x, \"z\"))\n").unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| e.code == ErrorCode::InvalidFormatSpec));
+    }
+
+    #[test]
+    fn test_analyze_keyword_arguments() {
+        assert!(analyze_src("print(\"{a}\".format(a=1))\n").is_ok());
+        let errors =
+            analyze_src("def add(a, b):\n  return a + b\n\nadd(1, b=2)\nprint(\"x\".upper(n=1))\n")
+                .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|e| e.code == ErrorCode::UnexpectedKeyword));
+
+        // sorted와 list.sort는 key=, reverse=만 받음
+        assert!(analyze_src("xs = [2, 1]\nxs.sort(reverse=True)\nprint(sorted(xs, key=lambda x: -x))\n").is_ok());
+        let errors = analyze_src("xs = [2, 1]\nxs.sort(cmp=1)\nprint(sorted(xs, keys=1))\nprint(len(xs, key=1))\n")
+            .unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|e| e.code == ErrorCode::UnexpectedKeyword));
+    }
+
+    #[test]
+    fn test_analyze_aug_assign() {
+        assert!(analyze_src("n = 1\nn += 2\nn <<= 1\nxs = [1]\nxs[0] **= 2\n").is_ok());
+
+        // 복합 할당은 대상을 먼저 읽음
+        let errors = analyze_src("total += 1\n").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::UndefinedName);
+        let errors = analyze_src("count = 0\ndef bump():\n  count += 1\n").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::UnboundLocal);
+
+        let errors = analyze_src("s = \"a\"\ns &= 1\n").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::OperandType);
+        let errors = analyze_src("print(~1.5)\n").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::OperandType);
+    }
+
+    #[test]
+    fn test_analyze_membership_and_identity() {
+        assert!(analyze_src("x = None
+print(x is None, 1 in [1], \"a\" not in \"abc\")
+").is_ok());
+        assert!(analyze_src("print(1 < 2 <= 3 != 4)
+").is_ok());
+
+        let errors = analyze_src("print(1 in 5)
+").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::NotIterable);
+
+        let errors = analyze_src("print(1 in \"abc\")
+").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::OperandType);
+    }
+
+    #[test]
+    fn test_analyze_import_builtin_modules() {
+        let src = "import sys\nprint(sys.argv)\ndef f():\n  import sys\n  sys.exit(0)\n";
+        assert!(analyze_src(src).is_ok());
+
+        let errors = analyze_src("import sy\nprint(sy)\n").unwrap_err();
+        assert_eq!(errors.len(), 1, "the name is still defined after a failed import");
+        assert_eq!(errors[0].code, ErrorCode::UnknownModule);
+        assert_eq!(errors[0].help.as_deref(), Some("did you mean `sys`?"));
+
+        let errors = analyze_src("import sys\nprint(sys.args)\n").unwrap_err();
+        assert!(errors[0].message.contains("module 'sys' has no attribute 'args'"));
+        let errors = analyze_src("import sys\nsys.exit(1, 2)\n").unwrap_err();
+        assert_eq!(errors[0].code, ErrorCode::ArityMismatch);
+    }
 }
@@ -34,4 +34,9 @@ impl ScopeStack {
         }
         false
     }
+
+    /// 모든 스코프에 정의된 이름들 (이름 제안용)
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.stack.iter().flatten().map(String::as_str)
+    }
 }
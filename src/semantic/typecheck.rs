@@ -1,6 +1,14 @@
 use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
 
-use crate::parser::ast::{BinaryOp, Expr, ExprS, Stmt, StmtS, UnaryOp};
+use crate::builtins::{
+    BuiltinModule, TYPE_BOOL, TYPE_DICT, TYPE_FLOAT, TYPE_INT, TYPE_LIST, TYPE_MODULE, TYPE_RANGE,
+    TYPE_STR, TYPE_TUPLE, lookup_module,
+};
+use crate::diagnostic::{ErrorCode, did_you_mean};
+use crate::parser::ast::{BinaryOp, Expr, ExprS, FStringPart, Stmt, StmtS, UnaryOp};
+use crate::vm::type_def::{Arity, MethodImpl, TypeDef, init_builtin_types};
 
 use super::{SemanticError, SemanticResult};
 
@@ -19,6 +27,42 @@ enum Ty {
     Function,
     MapIter(Box<Ty>),
     FilterIter(Box<Ty>),
+    /// 사용자 정의 클래스 객체 자체 (호출하면 인스턴스 생성)
+    Class(String),
+    /// 사용자 정의 클래스의 인스턴스
+    Instance(String),
+    /// `import`한 내장 모듈
+    Module(String),
+}
+
+/// 진단 메시지용 Python식 타입 이름 (`list[int]`, `dict[str, int]`, 클래스 인스턴스는 클래스 이름)
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ty::Int => write!(f, "int"),
+            Ty::Bool => write!(f, "bool"),
+            Ty::String => write!(f, "str"),
+            Ty::Float => write!(f, "float"),
+            Ty::NoneType => write!(f, "NoneType"),
+            Ty::Unknown => write!(f, "object"),
+            Ty::List(elem) if **elem == Ty::Unknown => write!(f, "list"),
+            Ty::List(elem) => write!(f, "list[{}]", elem),
+            Ty::Dict(k, v) if **k == Ty::Unknown && **v == Ty::Unknown => write!(f, "dict"),
+            Ty::Dict(k, v) => write!(f, "dict[{}, {}]", k, v),
+            Ty::Tuple(elems) if elems.is_empty() => write!(f, "tuple"),
+            Ty::Tuple(elems) => {
+                let names: Vec<String> = elems.iter().map(Ty::to_string).collect();
+                write!(f, "tuple[{}]", names.join(", "))
+            }
+            Ty::Range => write!(f, "range"),
+            Ty::Function => write!(f, "function"),
+            Ty::MapIter(_) => write!(f, "map"),
+            Ty::FilterIter(_) => write!(f, "filter"),
+            Ty::Class(name) => write!(f, "type[{}]", name),
+            Ty::Instance(name) => write!(f, "{}", name),
+            Ty::Module(_) => write!(f, "module"),
+        }
+    }
 }
 
 #[derive(Default, Clone)]
@@ -54,15 +98,36 @@ impl TypeEnv {
     }
 }
 
-pub fn typecheck_program(program: &[StmtS], ctx: &super::ProgramContext) -> SemanticResult<()> {
+/// 타입 검사: 에러는 문장 단위로 복구하면서 `ctx`에 모두 기록됩니다.
+pub fn typecheck_program(program: &[StmtS], ctx: &super::ProgramContext) {
     let mut tenv = TypeEnv::new();
     // Optionally register globals that have known types beforehand
 
     // Walk module-level statements
     for stmt in program {
-        tc_stmt(stmt, &mut tenv, ctx, &mut Some(Ty::Unknown), false)?;
+        ctx.recover(tc_stmt(stmt, &mut tenv, ctx, &mut Some(Ty::Unknown), false));
+    }
+
+    // `def link(a, b): a.next = b`처럼 어디서든 타입 모를 객체에 할당한 속성은 읽어도 됨
+    let untyped = ctx.untyped_attributes.borrow();
+    for (attr, error) in ctx.unknown_attributes.take() {
+        if !untyped.contains(&attr) {
+            ctx.errors.borrow_mut().push(error);
+        }
     }
-    Ok(())
+}
+
+/// 클래스에 없는 속성 읽기: 다른 곳에서 타입 모를 객체로 할당할 수도 있어 검사를 끝까지 미룸
+fn defer_unknown_attribute(
+    class_name: &str,
+    attr: &str,
+    class: &super::ClassInfo,
+    span: crate::types::Span,
+    ctx: &super::ProgramContext,
+) -> Ty {
+    let error = unknown_attribute(class_name, attr, class, span);
+    ctx.unknown_attributes.borrow_mut().push((attr.to_string(), error));
+    Ty::Unknown
 }
 
 fn tc_stmt(
@@ -75,19 +140,21 @@ fn tc_stmt(
     match &stmt.0 {
         Stmt::Break => {
             if !in_loop {
-                return Err(SemanticError {
-                    message: "SyntaxError: 'break' outside loop".to_string(),
-                    span: stmt.1.clone(),
-                });
+                return Err(SemanticError::new(
+                    ErrorCode::OutsideLoop,
+                    "SyntaxError: 'break' outside loop".to_string(),
+                    stmt.1.clone(),
+                ));
             }
             Ok(())
         }
         Stmt::Continue => {
             if !in_loop {
-                return Err(SemanticError {
-                    message: "SyntaxError: 'continue' outside loop".to_string(),
-                    span: stmt.1.clone(),
-                });
+                return Err(SemanticError::new(
+                    ErrorCode::OutsideLoop,
+                    "SyntaxError: 'continue' outside loop".to_string(),
+                    stmt.1.clone(),
+                ));
             }
             Ok(())
         }
@@ -95,16 +162,53 @@ fn tc_stmt(
             // Pass는 항상 허용 (no-op)
             Ok(())
         }
-        Stmt::Class { .. } => {
-            // 클래스 정의는 타입 체크 스킵 (v1에서는 간단히 처리)
+        Stmt::Import(name) => {
+            tenv.set(name.clone(), Ty::Module(name.clone()));
             Ok(())
         }
-        Stmt::Assign { target, value } => {
+        Stmt::Class {
+            name,
+            methods,
+            attributes,
+        } => {
+            tenv.set(name.clone(), Ty::Class(name.clone()));
+            for (_, value) in attributes {
+                let _ = tc_expr(value, tenv, ctx)?;
+            }
+            // 메서드 본문은 self를 인스턴스 타입으로 두고 검사
+            for method in methods {
+                tenv.push();
+                for (i, p) in method.params.iter().enumerate() {
+                    let ty = if i == 0 {
+                        Ty::Instance(name.clone())
+                    } else {
+                        Ty::Unknown
+                    };
+                    tenv.set(p.clone(), ty);
+                }
+                let mut fn_return_ty = Some(Ty::Unknown);
+                for s in &method.body {
+                    ctx.recover(tc_stmt(s, tenv, ctx, &mut fn_return_ty, false));
+                }
+                tenv.pop();
+            }
+            Ok(())
+        }
+        Stmt::Assign { targets, value } => {
             let rhs = tc_expr(value, tenv, ctx)?;
-            // 튜플 언패킹 지원
-            tc_assign_target(target, rhs, tenv, ctx)?;
+            // 튜플 언패킹 지원, 연쇄 할당은 모든 대상에 같은 값
+            for target in targets {
+                tc_assign_target(target, rhs.clone(), tenv, ctx)?;
+            }
             Ok(())
         }
+        Stmt::AugAssign { target, op, value } => {
+            // x op= v 는 x = x op v 와 같은 타입 규칙 (대상은 한 번만 평가되지만 타입은 동일)
+            let tl = tc_expr(target, tenv, ctx)?;
+            let tr = tc_expr(value, tenv, ctx)?;
+            let result = tc_binary(op, tl, tr, stmt.1.clone())?;
+            tc_assign_target(target, result, tenv, ctx)
+        }
         Stmt::Expr(expr) => {
             let _ = tc_expr(expr, tenv, ctx)?;
             Ok(())
@@ -113,13 +217,14 @@ fn tc_stmt(
             let t = tc_expr(expr, tenv, ctx)?;
             if let Some(ptr) = current_fn_return {
                 let old = ptr.clone();
-                let new = unify_return(old.clone(), t.clone()).ok_or_else(|| SemanticError {
-                    message: format!(
-                        "TypeError: inconsistent return types in function: {:?} vs {:?}",
+                let new = unify_return(old.clone(), t.clone()).ok_or_else(|| SemanticError::new(
+                    ErrorCode::IncompatibleTypes,
+                    format!(
+                        "TypeError: inconsistent return types in function: {} vs {}",
                         old, t
                     ),
-                    span: expr.1.clone(),
-                })?;
+                    expr.1.clone(),
+                ))?;
                 *ptr = new;
             }
             Ok(())
@@ -130,7 +235,7 @@ fn tc_stmt(
             elif_blocks,
             else_block,
         } => {
-            ensure_bool(condition, tenv, ctx)?;
+            ctx.recover(ensure_bool(condition, tenv, ctx));
             // Base snapshot (environment before entering branches)
             let base = snapshot_env(tenv);
 
@@ -139,7 +244,7 @@ fn tc_stmt(
             let mut then_assigned: HashSet<String> = HashSet::new();
             with_env(&mut then_env, |e| {
                 for s in then_block {
-                    let _ = tc_stmt(s, e, ctx, current_fn_return, in_loop);
+                    ctx.recover(tc_stmt(s, e, ctx, current_fn_return, in_loop));
                 }
             });
             collect_assigned(&base, &then_env, &mut then_assigned);
@@ -148,11 +253,11 @@ fn tc_stmt(
             let mut branches: Vec<(TypeEnv, HashSet<String>)> = Vec::new();
             branches.push((then_env, then_assigned));
             for (cond, block) in elif_blocks {
-                ensure_bool(cond, tenv, ctx)?;
+                ctx.recover(ensure_bool(cond, tenv, ctx));
                 let mut env_i = snapshot_env(&base);
                 with_env(&mut env_i, |e| {
                     for s in block {
-                        let _ = tc_stmt(s, e, ctx, current_fn_return, in_loop);
+                        ctx.recover(tc_stmt(s, e, ctx, current_fn_return, in_loop));
                     }
                 });
                 let mut assigned_i = HashSet::new();
@@ -166,7 +271,7 @@ fn tc_stmt(
                 let mut else_assigned: HashSet<String> = HashSet::new();
                 with_env(&mut else_env, |e| {
                     for s in block {
-                        let _ = tc_stmt(s, e, ctx, current_fn_return, in_loop);
+                        ctx.recover(tc_stmt(s, e, ctx, current_fn_return, in_loop));
                     }
                 });
                 collect_assigned(&base, &else_env, &mut else_assigned);
@@ -189,13 +294,14 @@ fn tc_stmt(
                         if t_b != Ty::Unknown {
                             if let Some(ref m) = merged {
                                 if *m != t_b {
-                                    return Err(SemanticError {
-                                        message: format!(
+                                    return Err(SemanticError::new(
+                                        ErrorCode::IncompatibleTypes,
+                                        format!(
                                             "TypeError: variable '{}' has incompatible types across branches",
                                             var
                                         ),
-                                        span: condition.1.clone(),
-                                    });
+                                        condition.1.clone(),
+                                    ));
                                 }
                             } else {
                                 merged = Some(t_b);
@@ -211,18 +317,18 @@ fn tc_stmt(
             Ok(())
         }
         Stmt::While { condition, body } => {
-            ensure_bool(condition, tenv, ctx)?;
+            ctx.recover(ensure_bool(condition, tenv, ctx));
             // Conservative: do not commit new types from loop body to outer env
             let mut loop_env = snapshot_env(tenv);
             with_env(&mut loop_env, |e| {
                 for s in body {
-                    let _ = tc_stmt(s, e, ctx, current_fn_return, true);
+                    ctx.recover(tc_stmt(s, e, ctx, current_fn_return, true));
                 }
             });
             Ok(())
         }
         Stmt::For {
-            var,
+            target,
             iterable,
             body,
         } => {
@@ -246,17 +352,24 @@ fn tc_stmt(
                 Ty::FilterIter(elem_ty) => *elem_ty.clone(),
                 Ty::Unknown => Ty::Unknown,
                 _ => {
-                    return Err(SemanticError {
-                        message: format!("TypeError: type '{:?}' is not iterable", iterable_ty),
-                        span: iterable.1.clone(),
-                    });
+                    // 본문은 루프 변수를 Unknown으로 두고 계속 검사
+                    ctx.recover(Err(SemanticError::new(
+                        ErrorCode::NotIterable,
+                        format!("TypeError: type '{}' is not iterable", iterable_ty),
+                        iterable.1.clone(),
+                    )));
+                    Ty::Unknown
                 }
             };
 
             let mut loop_env = snapshot_env(tenv);
-            loop_env.set(var.clone(), loop_var_ty);
+            match &target.0 {
+                // 단일 루프 변수는 이전 타입과 상관없이 루프 원소 타입으로 덮어씀
+                Expr::Variable(name) => loop_env.set(name.clone(), loop_var_ty),
+                _ => ctx.recover(tc_assign_target(target, loop_var_ty, &mut loop_env, ctx)),
+            }
             for s in body {
-                tc_stmt(s, &mut loop_env, ctx, current_fn_return, true)?;
+                ctx.recover(tc_stmt(s, &mut loop_env, ctx, current_fn_return, true));
             }
             Ok(())
         }
@@ -272,7 +385,7 @@ fn tc_stmt(
             }
             let mut fn_return_ty = Some(Ty::Unknown);
             for s in body {
-                tc_stmt(s, tenv, ctx, &mut fn_return_ty, false)?;
+                ctx.recover(tc_stmt(s, tenv, ctx, &mut fn_return_ty, false));
             }
             tenv.pop();
             let _ = fn_return_ty; // currently unused for call-site checks
@@ -297,21 +410,41 @@ fn tc_assign_target(
                 (Some(Ty::Unknown), t) => t,
                 (Some(t), Ty::Unknown) => t,
                 (Some(a), b) if a == b => a,
+                (Some(a), b) if let Some(joined) = join_instance(&a, &b) => joined,
                 (Some(a), b) => {
-                    return Err(SemanticError {
-                        message: format!(
-                            "TypeError: cannot assign value of type {:?} to variable of type {:?}",
+                    return Err(SemanticError::new(
+                        ErrorCode::IncompatibleTypes,
+                        format!(
+                            "TypeError: cannot assign value of type {} to variable of type {}",
                             b, a
                         ),
-                        span: target.1.clone(),
-                    });
+                        target.1.clone(),
+                    ));
                 }
             };
             tenv.set(name.clone(), new_ty);
             Ok(())
         }
-        Expr::Attribute { .. } => {
+        Expr::Attribute { object, attr } => {
             // Attribute 할당은 타입 추적하지 않음, 검증만
+            let obj_ty = tc_expr(object, tenv, ctx)?;
+            if let Ty::Instance(class_name) = &obj_ty
+                && let Some(class) = ctx.classes.get(class_name)
+                && !class.methods.contains_key(attr)
+                && !ctx.has_attribute(class_name, class, attr)
+            {
+                // Python처럼 허용하되 오타일 수 있으므로 경고하고, 이후 읽기는 허용
+                ctx.errors
+                    .borrow_mut()
+                    .push(attribute_outside_class(class_name, attr, class, target.1.clone()));
+                ctx.outside_attributes
+                    .borrow_mut()
+                    .insert((class_name.clone(), attr.clone()));
+                return Ok(());
+            }
+            if !matches!(&obj_ty, Ty::Instance(class_name) if ctx.classes.contains_key(class_name)) {
+                ctx.untyped_attributes.borrow_mut().insert(attr.clone());
+            }
             let _ = tc_expr(target, tenv, ctx)?;
             Ok(())
         }
@@ -320,19 +453,25 @@ fn tc_assign_target(
             let _ = tc_expr(target, tenv, ctx)?;
             Ok(())
         }
-        Expr::Tuple(elements) => {
+        Expr::Tuple(elements) | Expr::List(elements)
+            if elements.iter().any(|e| matches!(e.0, Expr::Starred(_))) =>
+        {
+            tc_starred_unpack(elements, rhs_ty, target, tenv, ctx)
+        }
+        Expr::Tuple(elements) | Expr::List(elements) => {
             // 튜플 언패킹: RHS가 튜플 또는 리스트여야 함
             match rhs_ty {
                 Ty::Tuple(elem_tys) => {
                     if elements.len() != elem_tys.len() {
-                        return Err(SemanticError {
-                            message: format!(
+                        return Err(SemanticError::new(
+                            ErrorCode::NotIterable,
+                            format!(
                                 "TypeError: cannot unpack tuple of length {} into {} variables",
                                 elem_tys.len(),
                                 elements.len()
                             ),
-                            span: target.1.clone(),
-                        });
+                            target.1.clone(),
+                        ));
                     }
                     // 각 요소를 재귀적으로 할당
                     for (target_elem, elem_ty) in elements.iter().zip(elem_tys.iter()) {
@@ -340,8 +479,9 @@ fn tc_assign_target(
                     }
                     Ok(())
                 }
-                Ty::List(elem_ty) => {
-                    // 리스트 언패킹: 모든 요소가 같은 타입이라고 가정
+                Ty::List(elem_ty) | Ty::MapIter(elem_ty) | Ty::FilterIter(elem_ty) => {
+                    // 리스트/iterator 언패킹 (`a, b = map(int, input().split())`):
+                    // 모든 요소가 같은 타입이라고 가정
                     for target_elem in elements {
                         tc_assign_target(target_elem, *elem_ty.clone(), tenv, ctx)?;
                     }
@@ -354,22 +494,91 @@ fn tc_assign_target(
                     }
                     Ok(())
                 }
-                _ => Err(SemanticError {
-                    message: format!(
-                        "TypeError: cannot unpack non-iterable type {:?}",
+                _ => Err(SemanticError::new(
+                    ErrorCode::NotIterable,
+                    format!(
+                        "TypeError: cannot unpack non-iterable type {}",
                         rhs_ty
                     ),
-                    span: target.1.clone(),
-                }),
+                    target.1.clone(),
+                )),
             }
         }
-        _ => Err(SemanticError {
-            message: "Invalid assignment target".to_string(),
-            span: target.1.clone(),
-        }),
+        _ => Err(SemanticError::new(
+            ErrorCode::InvalidAssignTarget,
+            "Invalid assignment target".to_string(),
+            target.1.clone(),
+        )),
     }
 }
 
+/// 별표 요소가 있는 언패킹 `first, *rest = xs`: 별표 대상은 항상 리스트
+fn tc_starred_unpack(
+    elements: &[ExprS],
+    rhs_ty: Ty,
+    target: &ExprS,
+    tenv: &mut TypeEnv,
+    ctx: &super::ProgramContext,
+) -> SemanticResult<()> {
+    let star = elements
+        .iter()
+        .position(|e| matches!(e.0, Expr::Starred(_)))
+        .unwrap_or(0);
+    let after = elements.len() - star - 1;
+    // 각 대상 위치의 타입 (별표 자리는 리스트 원소 타입)
+    let (before_tys, star_elem_ty, after_tys): (Vec<Ty>, Ty, Vec<Ty>) = match rhs_ty {
+        Ty::Tuple(elem_tys) => {
+            if elem_tys.len() < elements.len() - 1 {
+                return Err(SemanticError::new(
+                    ErrorCode::NotIterable,
+                    format!(
+                        "TypeError: cannot unpack tuple of length {} into at least {} variables",
+                        elem_tys.len(),
+                        elements.len() - 1
+                    ),
+                    target.1.clone(),
+                ));
+            }
+            let middle = &elem_tys[star..elem_tys.len() - after];
+            let star_ty = match middle.first() {
+                Some(first) if middle.iter().all(|t| t == first) => first.clone(),
+                _ => Ty::Unknown,
+            };
+            (
+                elem_tys[..star].to_vec(),
+                star_ty,
+                elem_tys[elem_tys.len() - after..].to_vec(),
+            )
+        }
+        Ty::List(elem_ty) | Ty::MapIter(elem_ty) | Ty::FilterIter(elem_ty) => (
+            vec![*elem_ty.clone(); star],
+            *elem_ty.clone(),
+            vec![*elem_ty; after],
+        ),
+        Ty::String => (vec![Ty::String; star], Ty::String, vec![Ty::String; after]),
+        Ty::Range => (vec![Ty::Int; star], Ty::Int, vec![Ty::Int; after]),
+        Ty::Unknown => (vec![Ty::Unknown; star], Ty::Unknown, vec![Ty::Unknown; after]),
+        _ => {
+            return Err(SemanticError::new(
+                ErrorCode::NotIterable,
+                format!("TypeError: cannot unpack non-iterable type {}", rhs_ty),
+                target.1.clone(),
+            ));
+        }
+    };
+    let tys = before_tys
+        .into_iter()
+        .chain(std::iter::once(Ty::List(Box::new(star_elem_ty))))
+        .chain(after_tys);
+    for (elem, ty) in elements.iter().zip(tys) {
+        match &elem.0 {
+            Expr::Starred(inner) => tc_assign_target(inner, ty, tenv, ctx)?,
+            _ => tc_assign_target(elem, ty, tenv, ctx)?,
+        }
+    }
+    Ok(())
+}
+
 fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> SemanticResult<Ty> {
     match &expr.0 {
         Expr::Literal(lit) => Ok(match lit {
@@ -385,97 +594,43 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
             match op {
                 UnaryOp::Not => expect_bool(t, expr.1.clone()),
                 UnaryOp::Negate | UnaryOp::Pos => expect_int_or_float(t, expr.1.clone()),
+                UnaryOp::Invert => match t {
+                    Ty::Int | Ty::Bool | Ty::Unknown => Ok(Ty::Int),
+                    _ => Err(SemanticError::new(
+                        ErrorCode::OperandType,
+                        format!("TypeError: bad operand type for unary ~: {}", t),
+                        expr.1.clone(),
+                    )),
+                },
             }
         }
         Expr::Binary { op, left, right } => {
             let tl = tc_expr(left, tenv, ctx)?;
             let tr = tc_expr(right, tenv, ctx)?;
-            match op {
-                BinaryOp::Add => {
-                    // Type rules for addition:
-                    // - Int + Int -> Int
-                    // - Float + Float -> Float
-                    // - Int + Float -> Float (promotion)
-                    // - String + String -> String
-                    // - Operations with Unknown are optimistic.
-                    match (tl, tr) {
-                        (Ty::Int, Ty::Int) => Ok(Ty::Int),
-                        (Ty::Float, Ty::Float) => Ok(Ty::Float),
-                        (Ty::Int, Ty::Float) | (Ty::Float, Ty::Int) => Ok(Ty::Float),
-                        (Ty::String, Ty::String) => Ok(Ty::String),
-                        (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Int),
-                        (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Float),
-                        (Ty::Unknown, Ty::String) | (Ty::String, Ty::Unknown) => Ok(Ty::String),
-                        (Ty::Unknown, Ty::Unknown) => Ok(Ty::Unknown),
-                        (tl, tr) => Err(SemanticError {
-                            message: format!(
-                                "TypeError: unsupported operand types for +: {:?} and {:?}",
-                                tl, tr
-                            ),
-                            span: expr.1.clone(),
-                        }),
-                    }
-                }
-                BinaryOp::Multiply => {
-                    // Type rules for multiplication:
-                    // - Int * Int -> Int
-                    // - Float * Float -> Float
-                    // - Int * Float -> Float (promotion)
-                    // - String * Int -> String
-                    // - Operations with Unknown are optimistic.
-                    match (tl, tr) {
-                        (Ty::Int, Ty::Int) => Ok(Ty::Int),
-                        (Ty::Float, Ty::Float) => Ok(Ty::Float),
-                        (Ty::Int, Ty::Float) | (Ty::Float, Ty::Int) => Ok(Ty::Float),
-                        (Ty::String, Ty::Int) | (Ty::Int, Ty::String) => Ok(Ty::String),
-                        (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Int),
-                        (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Float),
-                        (Ty::Unknown, Ty::String) | (Ty::String, Ty::Unknown) => Ok(Ty::String),
-                        (Ty::Unknown, Ty::Unknown) => Ok(Ty::Unknown),
-                        (tl, tr) => Err(SemanticError {
-                            message: format!(
-                                "TypeError: unsupported operand types for *: {:?} and {:?}",
-                                tl, tr
-                            ),
-                            span: expr.1.clone(),
-                        }),
-                    }
-                }
-                BinaryOp::Subtract | BinaryOp::Modulo => {
-                    expect_numeric_pair(tl, tr, expr.1.clone())
-                }
-                BinaryOp::Divide => {
-                    // / 연산은 항상 Float 반환 (Python 3 스타일)
-                    expect_numeric_pair(tl, tr, expr.1.clone()).map(|_| Ty::Float)
-                }
-                BinaryOp::FloorDivide => {
-                    // // 연산은 피연산자 타입 유지
-                    expect_numeric_pair(tl, tr, expr.1.clone())
-                }
-                BinaryOp::Less
-                | BinaryOp::LessEqual
-                | BinaryOp::Greater
-                | BinaryOp::GreaterEqual => match (tl, tr) {
-                    (Ty::Int, Ty::Int) => Ok(Ty::Bool),
-                    (Ty::Float, Ty::Float) => Ok(Ty::Bool),
-                    (Ty::Int, Ty::Float) | (Ty::Float, Ty::Int) => Ok(Ty::Bool),
-                    (Ty::String, Ty::String) => Ok(Ty::Bool),
-                    (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Bool),
-                    (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Bool),
-                    (Ty::Unknown, Ty::String) | (Ty::String, Ty::Unknown) => Ok(Ty::Bool),
-                    (Ty::Unknown, Ty::Unknown) => Ok(Ty::Bool),
-                    (tl, tr) => Err(SemanticError {
-                        message: format!("TypeError: cannot compare {:?} and {:?}", tl, tr),
-                        span: expr.1.clone(),
-                    }),
-                },
-                BinaryOp::Equal | BinaryOp::NotEqual => {
-                    expect_same_or_unknown(tl, tr, expr.1.clone()).map(|_| Ty::Bool)
-                }
-                BinaryOp::And | BinaryOp::Or => Ok(Ty::Bool),
+            tc_binary(op, tl, tr, expr.1.clone())
+        }
+        Expr::Compare { left, ops } => {
+            // a < b < c: 인접한 쌍마다 검사
+            let mut prev = tc_expr(left, tenv, ctx)?;
+            for (op, operand) in ops {
+                let next = tc_expr(operand, tenv, ctx)?;
+                let span = left.1.start..operand.1.end;
+                tc_compare(op, prev, next.clone(), span)?;
+                prev = next;
             }
+            Ok(Ty::Bool)
         }
-        Expr::Call { func_name, args } => {
+        Expr::Call {
+            func_name,
+            args,
+            kwargs,
+        } => {
+            // 키워드 인자는 빌트인 타입의 일부 메서드만 받음 (예: str.format)
+            for (_, value) in kwargs {
+                let _ = tc_expr(value, tenv, ctx)?;
+            }
+            let first_kwarg = kwargs.first().map(|(name, value)| (name.as_str(), value.1.clone()));
+
             // func_name이 Variable인 경우에만 builtin 체크
             let func_name_str = if let Expr::Variable(name) = &func_name.0 {
                 Some(name.as_str())
@@ -484,7 +639,14 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
             };
 
             if let Some(name) = func_name_str {
-                if let Some(bi) = crate::builtins::lookup(name) {
+                let builtin = crate::builtins::lookup(name);
+                if let Some((keyword, value)) = kwargs
+                    .iter()
+                    .find(|(k, _)| !builtin.is_some_and(|bi| bi.accepts_keyword(k)))
+                {
+                    return Err(unexpected_keyword(name, keyword, value.1.clone()));
+                }
+                if let Some(bi) = builtin {
                     // Unified arity checking using Arity enum
                     if !bi.check_arity(args.len()) {
                         let msg = format!(
@@ -493,10 +655,11 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             bi.arity.description(),
                             args.len()
                         );
-                        return Err(SemanticError {
-                            message: msg,
-                            span: expr.1.clone(),
-                        });
+                        return Err(SemanticError::new(
+                            ErrorCode::ArityMismatch,
+                            msg,
+                            expr.1.clone(),
+                        ));
                     }
 
                     // Type-specific validation (type checking, not arity)
@@ -513,13 +676,14 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             if args.len() == 1 {
                                 let arg_ty = tc_expr(&args[0], tenv, ctx)?;
                                 if arg_ty != Ty::String && arg_ty != Ty::Unknown {
-                                    return Err(SemanticError {
-                                        message: format!(
-                                            "TypeError: input() prompt must be a string, got {:?}",
+                                    return Err(SemanticError::new(
+                                        ErrorCode::ArgumentType,
+                                        format!(
+                                            "TypeError: input() prompt must be a string, got {}",
                                             arg_ty
                                         ),
-                                        span: expr.1.clone(),
-                                    });
+                                        expr.1.clone(),
+                                    ));
                                 }
                             }
                             return Ok(Ty::String);
@@ -540,6 +704,29 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             let _ = tc_expr(&args[0], tenv, ctx)?;
                             return Ok(Ty::String);
                         }
+                        "format" => {
+                            // format(value[, spec]): 리터럴 spec은 미리 검증
+                            let _ = tc_expr(&args[0], tenv, ctx)?;
+                            if let Some(spec) = args.get(1) {
+                                let spec_ty = tc_expr(spec, tenv, ctx)?;
+                                if spec_ty != Ty::String && spec_ty != Ty::Unknown {
+                                    return Err(SemanticError::new(
+                                        ErrorCode::ArgumentType,
+                                        format!(
+                                            "TypeError: format() spec must be a string, got {}",
+                                            spec_ty
+                                        ),
+                                        spec.1.clone(),
+                                    ));
+                                }
+                                if let Expr::Literal(crate::parser::ast::Literal::String(s)) =
+                                    &spec.0
+                                {
+                                    check_format_spec(s, spec.1.clone())?;
+                                }
+                            }
+                            return Ok(Ty::String);
+                        }
                         "len" => {
                             let arg_ty = tc_expr(&args[0], tenv, ctx)?;
                             return match arg_ty {
@@ -547,13 +734,14 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                                     Ok(Ty::Int)
                                 }
                                 Ty::Unknown => Ok(Ty::Int),
-                                _ => Err(SemanticError {
-                                    message: format!(
-                                        "TypeError: object of type {:?} has no len()",
+                                _ => Err(SemanticError::new(
+                                    ErrorCode::NotIterable,
+                                    format!(
+                                        "TypeError: object of type {} has no len()",
                                         arg_ty
                                     ),
-                                    span: expr.1.clone(),
-                                }),
+                                    expr.1.clone(),
+                                )),
                             };
                         }
                         "range" => {
@@ -562,20 +750,23 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             for arg in args {
                                 let arg_ty = tc_expr(arg, tenv, ctx)?;
                                 if arg_ty != Ty::Int && arg_ty != Ty::Unknown {
-                                    return Err(SemanticError {
-                                        message: format!(
-                                            "TypeError: range() arguments must be integers, got {:?}",
+                                    return Err(SemanticError::new(
+                                        ErrorCode::ArgumentType,
+                                        format!(
+                                            "TypeError: range() arguments must be integers, got {}",
                                             arg_ty
                                         ),
-                                        span: expr.1.clone(),
-                                    });
+                                        expr.1.clone(),
+                                    ));
                                 }
                             }
                             // range는 iterator 객체를 반환 (타입 시스템에서는 Range로 처리)
                             return Ok(Ty::Range);
                         }
                         "assert" => {
-                            let _ = tc_expr(&args[0], tenv, ctx)?;
+                            for arg in args {
+                                let _ = tc_expr(arg, tenv, ctx)?;
+                            }
                             return Ok(Ty::NoneType);
                         }
                         "map" => {
@@ -583,13 +774,14 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             // First arg should be a function/lambda
                             let func_ty = tc_expr(&args[0], tenv, ctx)?;
                             if func_ty != Ty::Function && func_ty != Ty::Unknown {
-                                return Err(SemanticError {
-                                    message: format!(
-                                        "TypeError: map() argument 1 must be callable, got {:?}",
+                                return Err(SemanticError::new(
+                                    ErrorCode::ArgumentType,
+                                    format!(
+                                        "TypeError: map() argument 1 must be callable, got {}",
                                         func_ty
                                     ),
-                                    span: args[0].1.clone(),
-                                });
+                                    args[0].1.clone(),
+                                ));
                             }
                             // Second arg should be iterable
                             let iter_ty = tc_expr(&args[1], tenv, ctx)?;
@@ -601,13 +793,14 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                                 Ty::FilterIter(t) => *t,
                                 Ty::Unknown => Ty::Unknown,
                                 _ => {
-                                    return Err(SemanticError {
-                                        message: format!(
-                                            "TypeError: map() argument 2 must be iterable, got {:?}",
+                                    return Err(SemanticError::new(
+                                        ErrorCode::ArgumentType,
+                                        format!(
+                                            "TypeError: map() argument 2 must be iterable, got {}",
                                             iter_ty
                                         ),
-                                        span: args[1].1.clone(),
-                                    });
+                                        args[1].1.clone(),
+                                    ));
                                 }
                             };
                             // map output type is Unknown since we can't infer lambda return type easily
@@ -619,13 +812,14 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             // First arg should be a function/lambda
                             let func_ty = tc_expr(&args[0], tenv, ctx)?;
                             if func_ty != Ty::Function && func_ty != Ty::Unknown {
-                                return Err(SemanticError {
-                                    message: format!(
-                                        "TypeError: filter() argument 1 must be callable, got {:?}",
+                                return Err(SemanticError::new(
+                                    ErrorCode::ArgumentType,
+                                    format!(
+                                        "TypeError: filter() argument 1 must be callable, got {}",
                                         func_ty
                                     ),
-                                    span: args[0].1.clone(),
-                                });
+                                    args[0].1.clone(),
+                                ));
                             }
                             // Second arg should be iterable
                             let iter_ty = tc_expr(&args[1], tenv, ctx)?;
@@ -637,18 +831,47 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                                 Ty::FilterIter(t) => *t,
                                 Ty::Unknown => Ty::Unknown,
                                 _ => {
-                                    return Err(SemanticError {
-                                        message: format!(
-                                            "TypeError: filter() argument 2 must be iterable, got {:?}",
+                                    return Err(SemanticError::new(
+                                        ErrorCode::ArgumentType,
+                                        format!(
+                                            "TypeError: filter() argument 2 must be iterable, got {}",
                                             iter_ty
                                         ),
-                                        span: args[1].1.clone(),
-                                    });
+                                        args[1].1.clone(),
+                                    ));
                                 }
                             };
                             // filter preserves element type
                             return Ok(Ty::FilterIter(Box::new(elem_ty)));
                         }
+                        "abs" => {
+                            let arg_ty = tc_expr(&args[0], tenv, ctx)?;
+                            return match arg_ty {
+                                Ty::Bool => Ok(Ty::Int),
+                                t => expect_int_or_float(t, args[0].1.clone()),
+                            };
+                        }
+                        "round" => {
+                            for arg in args {
+                                let _ = tc_expr(arg, tenv, ctx)?;
+                            }
+                            // round(x)는 항상 int, round(x, n)은 x의 타입
+                            return Ok(if args.len() == 1 { Ty::Int } else { Ty::Unknown });
+                        }
+                        "chr" | "hex" | "bin" | "repr" => {
+                            let _ = tc_expr(&args[0], tenv, ctx)?;
+                            return Ok(Ty::String);
+                        }
+                        "ord" | "hash" | "id" => {
+                            let _ = tc_expr(&args[0], tenv, ctx)?;
+                            return Ok(Ty::Int);
+                        }
+                        "any" | "all" | "isinstance" => {
+                            for arg in args {
+                                let _ = tc_expr(arg, tenv, ctx)?;
+                            }
+                            return Ok(Ty::Bool);
+                        }
                         _ => {
                             // Generic fallback: type-check all arguments
                             for arg in args {
@@ -657,23 +880,62 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                             return Ok(Ty::Unknown);
                         }
                     }
+                } else if let Some(native) = ctx.natives.function(name)
+                    && !shadows_native(name, tenv, ctx)
+                {
+                    // 호스트가 등록한 네이티브 함수 (반환 타입은 알 수 없음)
+                    check_native_arity(name, native.arity, args.len(), expr.1.clone())?;
+                    for a in args {
+                        let _ = tc_expr(a, tenv, ctx)?;
+                    }
+                    return Ok(Ty::Unknown);
+                } else if let Some(class) = ctx.natives.class(name)
+                    && !shadows_native(name, tenv, ctx)
+                {
+                    check_native_arity(name, class.init_arity, args.len(), expr.1.clone())?;
+                    for a in args {
+                        let _ = tc_expr(a, tenv, ctx)?;
+                    }
+                    return Ok(Ty::Instance(name.to_string()));
                 } else if let Some(&arity) = ctx.functions.get(name) {
                     // user-defined function
                     if args.len() != arity {
-                        return Err(SemanticError {
-                            message: format!(
+                        let error = SemanticError::new(
+                            ErrorCode::ArityMismatch,
+                            format!(
                                 "ArityError: function '{}' takes {} positional arguments but {} were given",
                                 name,
                                 arity,
                                 args.len()
                             ),
-                            span: expr.1.clone(),
-                        });
+                            expr.1.clone(),
+                        );
+                        return Err(with_definition_label(error, name, ctx));
                     }
                     for a in args {
                         let _ = tc_expr(a, tenv, ctx)?;
                     }
                     return Ok(Ty::Unknown);
+                } else if let Some(class) = ctx.classes.get(name) {
+                    // 생성자 호출: __init__의 인자 개수 확인
+                    let arity = class.init_arity();
+                    if args.len() != arity {
+                        let error = SemanticError::new(
+                            ErrorCode::ArityMismatch,
+                            format!(
+                                "ArityError: {}() takes {} positional arguments but {} were given",
+                                name,
+                                arity,
+                                args.len()
+                            ),
+                            expr.1.clone(),
+                        );
+                        return Err(with_definition_label(error, name, ctx));
+                    }
+                    for a in args {
+                        let _ = tc_expr(a, tenv, ctx)?;
+                    }
+                    return Ok(Ty::Instance(name.to_string()));
                 } else {
                     // Undefined function - semantic analysis should have caught this
                     // But we allow it in typecheck for flexibility
@@ -684,16 +946,159 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                 }
             }
 
-            // Attribute 등 다른 경우: 간단히 Unknown 반환
+            // 인스턴스 메서드 호출: 메서드 존재 여부와 인자 개수 확인
+            if let Expr::Attribute { object, attr } = &func_name.0 {
+                let obj_ty = tc_expr(object, tenv, ctx)?;
+                if let Ty::Instance(class_name) = &obj_ty
+                    && let Some(class) = ctx.classes.get(class_name)
+                {
+                    if let Some((keyword, span)) = first_kwarg {
+                        return Err(unexpected_keyword(
+                            &format!("{}.{}", class_name, attr),
+                            keyword,
+                            span,
+                        ));
+                    }
+                    if let Some(&arity) = class.methods.get(attr) {
+                        if args.len() != arity {
+                            return Err(SemanticError::new(
+                                ErrorCode::ArityMismatch,
+                                format!(
+                                    "ArityError: method '{}.{}' takes {} positional arguments but {} were given",
+                                    class_name,
+                                    attr,
+                                    arity,
+                                    args.len()
+                                ),
+                                expr.1.clone(),
+                            ));
+                        }
+                    } else if !ctx.has_attribute(class_name, class, attr) {
+                        defer_unknown_attribute(class_name, attr, class, func_name.1.clone(), ctx);
+                    }
+                } else if let Ty::Instance(class_name) = &obj_ty
+                    && let Some(class) = ctx.natives.class(class_name)
+                {
+                    if let Some((keyword, span)) = first_kwarg {
+                        return Err(unexpected_keyword(
+                            &format!("{}.{}", class_name, attr),
+                            keyword,
+                            span,
+                        ));
+                    }
+                    match class.method_arity(attr) {
+                        Some(arity) => check_native_arity(
+                            &format!("{}.{}", class_name, attr),
+                            arity,
+                            args.len(),
+                            expr.1.clone(),
+                        )?,
+                        None => {
+                            return Err(unknown_native_method(class, attr, func_name.1.clone()));
+                        }
+                    }
+                } else if let Ty::Module(module_name) = &obj_ty
+                    && let Some(module) = lookup_module(module_name)
+                    && !module.functions.contains(&attr.as_str())
+                {
+                    // 값 속성(`sys.argv`)은 호출할 수 있는지 알 수 없으므로 그대로 둠
+                    if !module.attributes.contains(&attr.as_str()) {
+                        return Err(unknown_module_attribute(module, attr, func_name.1.clone()));
+                    }
+                } else if let Some(type_def) = builtin_type_def(&obj_ty) {
+                    // 빌트인 타입 메서드: VM과 같은 TypeDef 테이블로 존재 여부와 인자 개수 확인
+                    match type_def.methods.get(attr) {
+                        Some(MethodImpl::Native { arity, .. }) if !arity.check(args.len()) => {
+                            return Err(SemanticError::new(
+                                ErrorCode::ArityMismatch,
+                                format!(
+                                    "ArityError: method '{}.{}' takes {} argument(s) but {} given",
+                                    type_def.name,
+                                    attr,
+                                    arity.description(),
+                                    args.len()
+                                ),
+                                expr.1.clone(),
+                            ));
+                        }
+                        Some(MethodImpl::Native { func, .. }) => {
+                            if let Some((keyword, value)) =
+                                kwargs.iter().find(|(k, _)| !func.accepts_keyword(k))
+                            {
+                                let (keyword, span) = (keyword.as_str(), value.1.clone());
+                                return Err(unexpected_keyword(
+                                    &format!("{}.{}", type_def.name, attr),
+                                    keyword,
+                                    span,
+                                ));
+                            }
+                        }
+                        Some(MethodImpl::UserDefined { .. }) => {}
+                        None => {
+                            return Err(unknown_method(type_def, attr, func_name.1.clone()));
+                        }
+                    }
+                }
+                for a in args {
+                    let _ = tc_expr(a, tenv, ctx)?;
+                }
+                return Ok(Ty::Unknown);
+            }
+
+            // 그 외의 경우 (람다 호출 등): 간단히 Unknown 반환
+            if let Some((keyword, span)) = first_kwarg {
+                return Err(unexpected_keyword("<lambda>", keyword, span));
+            }
             let _ = tc_expr(func_name, tenv, ctx)?;
             for a in args {
                 let _ = tc_expr(a, tenv, ctx)?;
             }
             Ok(Ty::Unknown)
         }
-        Expr::Attribute { object, .. } => {
-            let _ = tc_expr(object, tenv, ctx)?;
-            Ok(Ty::Unknown) // Attribute는 Unknown 타입으로 처리
+        Expr::Attribute { object, attr } => {
+            let obj_ty = tc_expr(object, tenv, ctx)?;
+            if let Ty::Instance(class_name) = &obj_ty
+                && let Some(class) = ctx.classes.get(class_name)
+            {
+                if class.methods.contains_key(attr) {
+                    return Ok(Ty::Function);
+                }
+                if !ctx.has_attribute(class_name, class, attr) {
+                    return Ok(defer_unknown_attribute(class_name, attr, class, expr.1.clone(), ctx));
+                }
+            } else if let Ty::Instance(class_name) = &obj_ty
+                && let Some(class) = ctx.natives.class(class_name)
+            {
+                // 네이티브 인스턴스에는 속성이 없고, 메서드는 호출로만 쓸 수 있음
+                return Err(unknown_native_method(class, attr, expr.1.clone()));
+            } else if let Ty::Module(module_name) = &obj_ty
+                && let Some(module) = lookup_module(module_name)
+            {
+                if module.functions.contains(&attr.as_str()) {
+                    return Ok(Ty::Function);
+                }
+                if !module.attributes.contains(&attr.as_str()) {
+                    return Err(unknown_module_attribute(module, attr, expr.1.clone()));
+                }
+            } else if let Some(type_def) = builtin_type_def(&obj_ty) {
+                // 빌트인 타입에는 메서드만 있음 (바운드 메서드로 꺼내기)
+                if type_def.methods.contains_key(attr) {
+                    return Ok(Ty::Function);
+                }
+                return Err(unknown_method(type_def, attr, expr.1.clone()));
+            }
+            Ok(Ty::Unknown) // 속성 값의 타입은 추적하지 않음
+        }
+        Expr::FString(parts) => {
+            for part in parts {
+                if let FStringPart::Expr { expr: inner, spec, .. } = part {
+                    let _ = tc_expr(inner, tenv, ctx)?;
+                    if let Some(spec) = spec {
+                        check_format_spec(spec, inner.1.clone())?;
+                    }
+                }
+            }
+            Ok(Ty::String)
         }
         Expr::List(elements) => {
             let mut elem_ty = Ty::Unknown;
@@ -758,37 +1163,40 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
             match obj_ty {
                 Ty::List(elem_ty) => {
                     if idx_ty != Ty::Int && idx_ty != Ty::Unknown {
-                        return Err(SemanticError {
-                            message: format!(
-                                "TypeError: list indices must be integers, not {:?}",
+                        return Err(SemanticError::new(
+                            ErrorCode::IndexType,
+                            format!(
+                                "TypeError: list indices must be integers, not {}",
                                 idx_ty
                             ),
-                            span: index.1.clone(),
-                        });
+                            index.1.clone(),
+                        ));
                     }
                     Ok(*elem_ty)
                 }
                 Ty::Dict(key_ty, val_ty) => {
                     if idx_ty != *key_ty && idx_ty != Ty::Unknown && *key_ty != Ty::Unknown {
-                        return Err(SemanticError {
-                            message: format!(
-                                "TypeError: dictionary key type mismatch: expected {:?}, got {:?}",
+                        return Err(SemanticError::new(
+                            ErrorCode::IndexType,
+                            format!(
+                                "TypeError: dictionary key type mismatch: expected {}, got {}",
                                 *key_ty, idx_ty
                             ),
-                            span: index.1.clone(),
-                        });
+                            index.1.clone(),
+                        ));
                     }
                     Ok(*val_ty)
                 }
                 Ty::Tuple(elem_tys) => {
                     if idx_ty != Ty::Int && idx_ty != Ty::Unknown {
-                        return Err(SemanticError {
-                            message: format!(
-                                "TypeError: tuple indices must be integers, not {:?}",
+                        return Err(SemanticError::new(
+                            ErrorCode::IndexType,
+                            format!(
+                                "TypeError: tuple indices must be integers, not {}",
                                 idx_ty
                             ),
-                            span: index.1.clone(),
-                        });
+                            index.1.clone(),
+                        ));
                     }
                     // For tuple indexing, we can't know the exact type at compile time
                     // unless the index is a literal. Return Unknown for now.
@@ -803,23 +1211,46 @@ fn tc_expr(expr: &ExprS, tenv: &mut TypeEnv, ctx: &super::ProgramContext) -> Sem
                 }
                 Ty::String => {
                     if idx_ty != Ty::Int && idx_ty != Ty::Unknown {
-                        return Err(SemanticError {
-                            message: format!(
-                                "TypeError: string indices must be integers, not {:?}",
+                        return Err(SemanticError::new(
+                            ErrorCode::IndexType,
+                            format!(
+                                "TypeError: string indices must be integers, not {}",
                                 idx_ty
                             ),
-                            span: index.1.clone(),
-                        });
+                            index.1.clone(),
+                        ));
                     }
                     Ok(Ty::String)
                 }
                 Ty::Unknown => Ok(Ty::Unknown),
-                _ => Err(SemanticError {
-                    message: format!("TypeError: type '{:?}' is not subscriptable", obj_ty),
-                    span: object.1.clone(),
-                }),
+                _ => Err(SemanticError::new(
+                    ErrorCode::NotIterable,
+                    format!("TypeError: type '{}' is not subscriptable", obj_ty),
+                    object.1.clone(),
+                )),
             }
         }
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            ensure_bool(condition, tenv, ctx)?;
+            let then_ty = tc_expr(then_expr, tenv, ctx)?;
+            let else_ty = tc_expr(else_expr, tenv, ctx)?;
+            Ok(if then_ty == else_ty { then_ty } else { Ty::Unknown })
+        }
+        Expr::NamedExpr { name, value } => {
+            let ty = tc_expr(value, tenv, ctx)?;
+            let target = (Expr::Variable(name.clone()), expr.1.clone());
+            tc_assign_target(&target, ty.clone(), tenv, ctx)?;
+            Ok(ty)
+        }
+        // 별표 요소는 의미 분석에서 위치를 검사하므로 여기서는 값만 확인
+        Expr::Starred(inner) => {
+            let _ = tc_expr(inner, tenv, ctx)?;
+            Ok(Ty::Unknown)
+        }
         Expr::Lambda { .. } => {
             // A full implementation would check the body and infer a more specific
             // function type, but for now, just marking it as a function is enough.
@@ -834,24 +1265,443 @@ fn ensure_bool(
     ctx: &super::ProgramContext,
 ) -> SemanticResult<()> {
     let t = tc_expr(cond, tenv, ctx)?;
-    if t != Ty::Bool {
-        return Err(SemanticError {
-            message: "TypeError: condition must be Bool".to_string(),
-            span: cond.1.clone(),
-        });
+    // Unknown은 낙관적으로 허용 (예: 사용자 함수의 반환값), 인스턴스는 `if node:`처럼 참/거짓으로 씀
+    if t != Ty::Bool && t != Ty::Unknown && !matches!(t, Ty::Instance(_)) {
+        return Err(SemanticError::new(
+            ErrorCode::ConditionType,
+            "TypeError: condition must be bool".to_string(),
+            cond.1.clone(),
+        )
+        .with_help("write an explicit comparison, e.g. `x != 0` or `len(xs) > 0`"));
     }
     Ok(())
 }
 
+fn unknown_attribute(
+    class_name: &str,
+    attr: &str,
+    class: &super::ClassInfo,
+    span: crate::types::Span,
+) -> SemanticError {
+    let error = SemanticError::new(
+        ErrorCode::UnknownAttribute,
+        format!(
+            "AttributeError: '{}' object has no attribute '{}'",
+            class_name, attr
+        ),
+        span,
+    );
+    let candidates = class
+        .attributes
+        .iter()
+        .chain(class.methods.keys())
+        .map(String::as_str);
+    match did_you_mean(attr, candidates) {
+        Some(help) => error.with_help(help),
+        None => error,
+    }
+}
+
+/// 클래스 밖에서 새 인스턴스 속성을 할당 (경고)
+fn attribute_outside_class(
+    class_name: &str,
+    attr: &str,
+    class: &super::ClassInfo,
+    span: crate::types::Span,
+) -> SemanticError {
+    let error = SemanticError::new(
+        ErrorCode::AttributeOutsideClass,
+        format!("attribute '{}' is not defined in class '{}'", attr, class_name),
+        span,
+    );
+    let candidates = class.attributes.iter().map(String::as_str);
+    match did_you_mean(attr, candidates) {
+        Some(help) => error.with_help(help),
+        None => error.with_help(format!(
+            "assign it in __init__ (`self.{} = ...`) so every '{}' object has it",
+            attr, class_name
+        )),
+    }
+}
+
+/// 빌트인 타입에 없는 메서드 (매직 메서드는 제안하지 않음)
+fn unknown_method(type_def: &TypeDef, attr: &str, span: crate::types::Span) -> SemanticError {
+    let error = SemanticError::new(
+        ErrorCode::UnknownAttribute,
+        format!(
+            "AttributeError: '{}' object has no attribute '{}'",
+            type_def.name, attr
+        ),
+        span,
+    );
+    let candidates = type_def
+        .methods
+        .keys()
+        .map(String::as_str)
+        .filter(|m| !m.starts_with("__"));
+    match did_you_mean(attr, candidates) {
+        Some(help) => error.with_help(help),
+        None => error,
+    }
+}
+
+fn unknown_module_attribute(
+    module: &BuiltinModule,
+    attr: &str,
+    span: crate::types::Span,
+) -> SemanticError {
+    let error = SemanticError::new(
+        ErrorCode::UnknownAttribute,
+        format!(
+            "AttributeError: module '{}' has no attribute '{}'",
+            module.name, attr
+        ),
+        span,
+    );
+    let candidates = module.attributes.iter().chain(module.functions).copied();
+    match did_you_mean(attr, candidates) {
+        Some(help) => error.with_help(help),
+        None => error,
+    }
+}
+
+/// 사용자가 같은 이름을 함수, 클래스, 변수로 정의해 네이티브를 가리는지
+fn shadows_native(name: &str, tenv: &TypeEnv, ctx: &super::ProgramContext) -> bool {
+    ctx.functions.contains_key(name)
+        || ctx.classes.contains_key(name)
+        || ctx.existing_globals.contains(name)
+        || tenv.get(name).is_some()
+}
+
+/// 네이티브 함수/생성자/메서드 호출의 인자 개수 검사 (builtin과 같은 메시지)
+fn check_native_arity(
+    name: &str,
+    arity: Arity,
+    got: usize,
+    span: crate::types::Span,
+) -> SemanticResult<()> {
+    if arity.check(got) {
+        return Ok(());
+    }
+    Err(SemanticError::new(
+        ErrorCode::ArityMismatch,
+        format!(
+            "ArityError: {}() takes {} argument(s) but {} given",
+            name,
+            arity.description(),
+            got
+        ),
+        span,
+    ))
+}
+
+fn unknown_native_method(
+    class: &crate::vm::native::NativeClass,
+    attr: &str,
+    span: crate::types::Span,
+) -> SemanticError {
+    let error = SemanticError::new(
+        ErrorCode::UnknownAttribute,
+        format!(
+            "AttributeError: '{}' object has no attribute '{}'",
+            class.name, attr
+        ),
+        span,
+    );
+    match did_you_mean(attr, class.method_names()) {
+        Some(help) => error.with_help(help),
+        None => error,
+    }
+}
+
+/// 비교/멤버십/동일성 연산의 타입 검사 (결과는 항상 Bool)
+fn tc_compare(op: &BinaryOp, tl: Ty, tr: Ty, span: crate::types::Span) -> SemanticResult<Ty> {
+    // 사용자 클래스는 `__eq__`/`__lt__` 등으로 비교하고, `node != None`도 흔히 씀
+    if matches!(tl, Ty::Instance(_)) || matches!(tr, Ty::Instance(_)) {
+        return Ok(Ty::Bool);
+    }
+    match op {
+        BinaryOp::Less | BinaryOp::LessEqual | BinaryOp::Greater | BinaryOp::GreaterEqual => {
+            match (tl, tr) {
+                (Ty::Int, Ty::Int) => Ok(Ty::Bool),
+                (Ty::Float, Ty::Float) => Ok(Ty::Bool),
+                (Ty::Int, Ty::Float) | (Ty::Float, Ty::Int) => Ok(Ty::Bool),
+                (Ty::String, Ty::String) => Ok(Ty::Bool),
+                (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Bool),
+                (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Bool),
+                (Ty::Unknown, Ty::String) | (Ty::String, Ty::Unknown) => Ok(Ty::Bool),
+                (Ty::Unknown, Ty::Unknown) => Ok(Ty::Bool),
+                // list, tuple은 사전식 비교 (원소 비교는 런타임에 검사)
+                (Ty::List(_), Ty::List(_)) | (Ty::Tuple(_), Ty::Tuple(_)) => Ok(Ty::Bool),
+                (Ty::Unknown, Ty::List(_) | Ty::Tuple(_))
+                | (Ty::List(_) | Ty::Tuple(_), Ty::Unknown) => Ok(Ty::Bool),
+                (tl, tr) => Err(SemanticError::new(
+                    ErrorCode::OperandType,
+                    format!("TypeError: cannot compare {} and {}", tl, tr),
+                    span,
+                )),
+            }
+        }
+        BinaryOp::Equal | BinaryOp::NotEqual => {
+            expect_same_or_unknown(tl, tr, span).map(|_| Ty::Bool)
+        }
+        BinaryOp::In | BinaryOp::NotIn => match (&tl, &tr) {
+            // 부분 문자열 검사는 왼쪽도 문자열이어야 함
+            (Ty::String | Ty::Unknown, Ty::String) => Ok(Ty::Bool),
+            (_, Ty::String) => Err(SemanticError::new(
+                ErrorCode::OperandType,
+                format!(
+                    "TypeError: 'in <string>' requires string as left operand, not {}",
+                    tl
+                ),
+                span,
+            )),
+            (_, Ty::Int | Ty::Float | Ty::Bool | Ty::NoneType | Ty::Function) => {
+                Err(SemanticError::new(
+                    ErrorCode::NotIterable,
+                    format!("TypeError: argument of type {} is not iterable", tr),
+                    span,
+                ))
+            }
+            _ => Ok(Ty::Bool),
+        },
+        BinaryOp::Is | BinaryOp::IsNot => Ok(Ty::Bool),
+        _ => unreachable!("not a comparison operator: {:?}", op),
+    }
+}
+
+/// 받지 않는 키워드 인자
+fn unexpected_keyword(callee: &str, keyword: &str, span: crate::types::Span) -> SemanticError {
+    SemanticError::new(
+        ErrorCode::UnexpectedKeyword,
+        format!(
+            "TypeError: {}() got an unexpected keyword argument '{}'",
+            callee, keyword
+        ),
+        span,
+    )
+}
+
+/// 리터럴 format spec을 컴파일 시점에 검증합니다 (런타임과 같은 파서 사용).
+fn check_format_spec(spec: &str, span: crate::types::Span) -> SemanticResult<()> {
+    crate::vm::format::FormatSpec::parse(spec)
+        .map(|_| ())
+        .map_err(|message| {
+            SemanticError::new(ErrorCode::InvalidFormatSpec, message, span)
+                .with_note("format spec: [[fill]align][sign][#][0][width][,][.precision][type]")
+        })
+}
+
+/// 정적으로 타입을 아는 값의 빌트인 TypeDef (VM이 메서드 디스패치에 쓰는 테이블과 동일)
+fn builtin_type_def(ty: &Ty) -> Option<&'static TypeDef> {
+    static TYPES: OnceLock<Vec<TypeDef>> = OnceLock::new();
+    let type_id = match ty {
+        Ty::Int => TYPE_INT,
+        Ty::Bool => TYPE_BOOL,
+        Ty::String => TYPE_STR,
+        Ty::Float => TYPE_FLOAT,
+        Ty::List(_) => TYPE_LIST,
+        Ty::Dict(_, _) => TYPE_DICT,
+        Ty::Tuple(_) => TYPE_TUPLE,
+        Ty::Range => TYPE_RANGE,
+        Ty::Module(_) => TYPE_MODULE,
+        _ => return None,
+    };
+    TYPES.get_or_init(init_builtin_types).get(type_id as usize)
+}
+
+/// 함수/클래스 호출 에러에 정의 위치를 보조 라벨로 붙입니다.
+fn with_definition_label(
+    error: SemanticError,
+    name: &str,
+    ctx: &super::ProgramContext,
+) -> SemanticError {
+    match ctx.definitions.get(name) {
+        Some(span) => error.with_label(span.clone(), format!("'{}' is defined here", name)),
+        None => error,
+    }
+}
+
+/// 이항 연산의 결과 타입 (`a op b`와 복합 할당 `a op= b`에서 공유)
+fn tc_binary(op: &BinaryOp, tl: Ty, tr: Ty, span: crate::types::Span) -> SemanticResult<Ty> {
+    // 사용자 클래스의 산술은 `__add__` 등 매직 메서드가 런타임에 처리 (결과 타입은 알 수 없음)
+    if (matches!(tl, Ty::Instance(_)) || matches!(tr, Ty::Instance(_)))
+        && !matches!(
+            op,
+            BinaryOp::Less
+                | BinaryOp::LessEqual
+                | BinaryOp::Greater
+                | BinaryOp::GreaterEqual
+                | BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::In
+                | BinaryOp::NotIn
+                | BinaryOp::Is
+                | BinaryOp::IsNot
+        )
+    {
+        return Ok(Ty::Unknown);
+    }
+    match op {
+        BinaryOp::Add => {
+            // Type rules for addition:
+            // - Int + Int -> Int
+            // - Float + Float -> Float
+            // - Int + Float -> Float (promotion)
+            // - String + String -> String
+            // - List + List -> List, Tuple + Tuple -> Tuple (concatenation)
+            // - Operations with Unknown are optimistic.
+            match (tl, tr) {
+                (Ty::Int, Ty::Int) => Ok(Ty::Int),
+                (Ty::Float, Ty::Float) => Ok(Ty::Float),
+                (Ty::Int, Ty::Float) | (Ty::Float, Ty::Int) => Ok(Ty::Float),
+                (Ty::String, Ty::String) => Ok(Ty::String),
+                (Ty::List(a), Ty::List(b)) if a == b => Ok(Ty::List(a)),
+                (Ty::List(_), Ty::List(_)) => Ok(Ty::List(Box::new(Ty::Unknown))),
+                (Ty::Tuple(mut a), Ty::Tuple(b)) => {
+                    a.extend(b);
+                    Ok(Ty::Tuple(a))
+                }
+                (Ty::Unknown, Ty::List(t)) | (Ty::List(t), Ty::Unknown) => Ok(Ty::List(t)),
+                (Ty::Unknown, Ty::Tuple(_)) | (Ty::Tuple(_), Ty::Unknown) => Ok(Ty::Unknown),
+                (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Int),
+                (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Float),
+                (Ty::Unknown, Ty::String) | (Ty::String, Ty::Unknown) => Ok(Ty::String),
+                (Ty::Unknown, Ty::Unknown) => Ok(Ty::Unknown),
+                (tl, tr) => Err(SemanticError::new(
+                    ErrorCode::OperandType,
+                    format!(
+                        "TypeError: unsupported operand types for +: {} and {}",
+                        tl, tr
+                    ),
+                    span.clone(),
+                )),
+            }
+        }
+        BinaryOp::Multiply => {
+            // Type rules for multiplication:
+            // - Int * Int -> Int
+            // - Float * Float -> Float
+            // - Int * Float -> Float (promotion)
+            // - String * Int -> String
+            // - List * Int -> List, Tuple * Int -> Tuple (repetition)
+            // - Operations with Unknown are optimistic.
+            match (tl, tr) {
+                (Ty::Int, Ty::Int) => Ok(Ty::Int),
+                (Ty::Float, Ty::Float) => Ok(Ty::Float),
+                (Ty::Int, Ty::Float) | (Ty::Float, Ty::Int) => Ok(Ty::Float),
+                (Ty::String, Ty::Int) | (Ty::Int, Ty::String) => Ok(Ty::String),
+                (Ty::List(t), Ty::Int | Ty::Bool | Ty::Unknown)
+                | (Ty::Int | Ty::Bool | Ty::Unknown, Ty::List(t)) => Ok(Ty::List(t)),
+                // 반복 후의 길이는 정적으로 알 수 없음
+                (Ty::Tuple(_), Ty::Int | Ty::Bool | Ty::Unknown)
+                | (Ty::Int | Ty::Bool | Ty::Unknown, Ty::Tuple(_)) => Ok(Ty::Unknown),
+                (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Int),
+                (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Float),
+                (Ty::Unknown, Ty::String) | (Ty::String, Ty::Unknown) => Ok(Ty::String),
+                (Ty::Unknown, Ty::Unknown) => Ok(Ty::Unknown),
+                (tl, tr) => Err(SemanticError::new(
+                    ErrorCode::OperandType,
+                    format!(
+                        "TypeError: unsupported operand types for *: {} and {}",
+                        tl, tr
+                    ),
+                    span.clone(),
+                )),
+            }
+        }
+        BinaryOp::Modulo => match (tl, tr) {
+            // printf 스타일 문자열 포매팅: "%d" % x, "%s %s" % (a, b)
+            (Ty::String, _) => Ok(Ty::String),
+            (tl, tr) => expect_numeric_pair(tl, tr, span.clone()),
+        },
+        BinaryOp::Subtract => expect_numeric_pair(tl, tr, span.clone()),
+        BinaryOp::Divide => {
+            // / 연산은 항상 Float 반환 (Python 3 스타일)
+            expect_numeric_pair(tl, tr, span.clone()).map(|_| Ty::Float)
+        }
+        BinaryOp::FloorDivide => {
+            // // 연산은 피연산자 타입 유지
+            expect_numeric_pair(tl, tr, span.clone())
+        }
+        BinaryOp::Less
+        | BinaryOp::LessEqual
+        | BinaryOp::Greater
+        | BinaryOp::GreaterEqual
+        | BinaryOp::Equal
+        | BinaryOp::NotEqual
+        | BinaryOp::In
+        | BinaryOp::NotIn
+        | BinaryOp::Is
+        | BinaryOp::IsNot => tc_compare(op, tl, tr, span.clone()),
+        BinaryOp::Power => expect_numeric_pair(tl, tr, span),
+        BinaryOp::BitAnd
+        | BinaryOp::BitOr
+        | BinaryOp::BitXor
+        | BinaryOp::LeftShift
+        | BinaryOp::RightShift => match (tl, tr) {
+            (Ty::Bool, Ty::Bool) if !matches!(op, BinaryOp::LeftShift | BinaryOp::RightShift) => {
+                Ok(Ty::Bool)
+            }
+            // set, treeset은 아직 Unknown으로 추론되므로 결과도 알 수 없음 (`a | b`)
+            (Ty::Unknown, Ty::Unknown) => Ok(Ty::Unknown),
+            (Ty::Int | Ty::Bool | Ty::Unknown, Ty::Int | Ty::Bool | Ty::Unknown) => Ok(Ty::Int),
+            (tl, tr) => Err(SemanticError::new(
+                ErrorCode::OperandType,
+                format!(
+                    "TypeError: unsupported operand types for {}: {} and {}",
+                    binary_op_symbol(op),
+                    tl,
+                    tr
+                ),
+                span,
+            )),
+        },
+        BinaryOp::And | BinaryOp::Or => Ok(Ty::Bool),
+    }
+}
+
+/// 에러 메시지용 연산자 기호
+fn binary_op_symbol(op: &BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Add => "+",
+        BinaryOp::Subtract => "-",
+        BinaryOp::Multiply => "*",
+        BinaryOp::Divide => "/",
+        BinaryOp::FloorDivide => "//",
+        BinaryOp::Modulo => "%",
+        BinaryOp::Power => "**",
+        BinaryOp::BitAnd => "&",
+        BinaryOp::BitOr => "|",
+        BinaryOp::BitXor => "^",
+        BinaryOp::LeftShift => "<<",
+        BinaryOp::RightShift => ">>",
+        BinaryOp::Equal => "==",
+        BinaryOp::NotEqual => "!=",
+        BinaryOp::Less => "<",
+        BinaryOp::LessEqual => "<=",
+        BinaryOp::Greater => ">",
+        BinaryOp::GreaterEqual => ">=",
+        BinaryOp::In => "in",
+        BinaryOp::NotIn => "not in",
+        BinaryOp::Is => "is",
+        BinaryOp::IsNot => "is not",
+        BinaryOp::And => "and",
+        BinaryOp::Or => "or",
+    }
+}
+
 fn expect_int_or_float(t: Ty, span: crate::types::Span) -> SemanticResult<Ty> {
     match t {
         Ty::Int => Ok(Ty::Int),
         Ty::Float => Ok(Ty::Float),
         Ty::Unknown => Ok(Ty::Unknown), // optimistic
-        _ => Err(SemanticError {
-            message: format!("TypeError: expected Int or Float, got {:?}", t),
+        Ty::Instance(_) => Ok(Ty::Unknown), // `__neg__`, `__abs__` 등
+        _ => Err(SemanticError::new(
+            ErrorCode::OperandType,
+            format!("TypeError: expected int or float, got {}", t),
             span,
-        }),
+        )),
     }
 }
 
@@ -859,10 +1709,12 @@ fn expect_bool(t: Ty, span: crate::types::Span) -> SemanticResult<Ty> {
     match t {
         Ty::Bool => Ok(Ty::Bool),
         Ty::Unknown => Ok(Ty::Bool), // optimistic
-        _ => Err(SemanticError {
-            message: format!("TypeError: expected Bool, got {:?}", t),
+        Ty::Instance(_) => Ok(Ty::Bool), // 인스턴스의 참/거짓 (`not node`)
+        _ => Err(SemanticError::new(
+            ErrorCode::OperandType,
+            format!("TypeError: expected bool, got {}", t),
             span,
-        }),
+        )),
     }
 }
 
@@ -880,13 +1732,14 @@ fn expect_numeric_pair(t1: Ty, t2: Ty, span: crate::types::Span) -> SemanticResu
         (Ty::Unknown, Ty::Int) | (Ty::Int, Ty::Unknown) => Ok(Ty::Int),
         (Ty::Unknown, Ty::Float) | (Ty::Float, Ty::Unknown) => Ok(Ty::Float),
         (Ty::Unknown, Ty::Unknown) => Ok(Ty::Unknown),
-        (t1, t2) => Err(SemanticError {
-            message: format!(
-                "TypeError: expected numeric types, got {:?} and {:?}",
+        (t1, t2) => Err(SemanticError::new(
+            ErrorCode::OperandType,
+            format!(
+                "TypeError: expected numeric types, got {} and {}",
                 t1, t2
             ),
             span,
-        }),
+        )),
     }
 }
 
@@ -911,13 +1764,14 @@ fn expect_same_or_unknown(t1: Ty, t2: Ty, span: crate::types::Span) -> SemanticR
         // Int-Float 비교 허용
         Ok(())
     } else {
-        Err(SemanticError {
-            message: format!(
-                "TypeError: equality operands must have same type, got {:?} and {:?}",
+        Err(SemanticError::new(
+            ErrorCode::OperandType,
+            format!(
+                "TypeError: equality operands must have same type, got {} and {}",
                 t1, t2
             ),
             span,
-        })
+        ))
     }
 }
 
@@ -925,6 +1779,19 @@ fn unify_return(a: Ty, b: Ty) -> Option<Ty> {
     match (a, b) {
         (Ty::Unknown, x) | (x, Ty::Unknown) => Some(x),
         (x, y) if x == y => Some(x),
+        (x, y) => join_instance(&x, &y),
+    }
+}
+
+/// 사용자 클래스 인스턴스가 섞인 두 타입을 합침 (`cur = None` 뒤의 `cur = node`, 연결 리스트의 끝)
+///
+/// None과 섞이면 인스턴스 타입을 유지하고, 다른 클래스의 인스턴스와 섞이면 Unknown입니다.
+/// 그 밖의 조합은 `None` (호출한 쪽에서 타입 에러).
+fn join_instance(a: &Ty, b: &Ty) -> Option<Ty> {
+    match (a, b) {
+        (Ty::Instance(_), Ty::NoneType) => Some(a.clone()),
+        (Ty::NoneType, Ty::Instance(_)) => Some(b.clone()),
+        (Ty::Instance(_), Ty::Instance(_)) => Some(Ty::Unknown),
         _ => None,
     }
 }
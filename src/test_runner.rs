@@ -0,0 +1,679 @@
+//! 내장 테스트 러너 (`pyhc test`)
+//!
+//! `test_*.pyh` 파일의 모듈 수준 `def test_*()` 함수를 찾아 각각 새 VM에서 실행합니다.
+//! 모듈 코드를 실행한 뒤 테스트 함수를 부르므로 테스트끼리 전역 상태를 나누지 않습니다.
+//!
+//! 픽스처 파일 (테스트 파일 `test_x.pyh` 옆):
+//! - `test_x.test_name.in` / `test_x.in` - 그 테스트(없으면 파일의 모든 테스트)의 `input()` 내용
+//! - `test_x.test_name.out` - 그 테스트가 출력해야 하는 내용 (모듈 코드의 출력 포함)
+//! - `test_x.out` - 파일을 프로그램으로 실행한 출력. 있으면 `<module>` 테스트가 추가됨
+//!
+//! 테스트 함수가 없는 파일은 프로그램으로 실행해서 에러 없이 끝나면 통과입니다.
+//!
+//! ```
+//! use pyhyeon::test_runner::run_source;
+//!
+//! let source = "def test_ok():\n  assert(1 + 1 == 2)\n\ndef test_bad():\n  assert(1 > 2, 'math')\n";
+//! let cases = run_source("test_math.pyh", source, |_, _| None);
+//! assert!(cases[0].outcome.is_passed());
+//! let failure = cases[1].outcome.failure().unwrap();
+//! assert_eq!(failure.message, "AssertionError: math");
+//! assert_eq!(failure.location.as_ref().unwrap().line, 5);
+//! ```
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::parser::ast::{Expr, Stmt, StmtS};
+use crate::runtime_io::BufferIo;
+use crate::vm::{Vm, VmErrorKind};
+
+/// 테스트 파일 하나를 프로그램으로 실행하는 경우의 테스트 이름
+pub const MODULE_TEST: &str = "<module>";
+
+/// 테스트 하나의 결과
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    /// 테스트 파일 경로 (표시용)
+    pub file: String,
+    /// `test_*` 함수 이름, 또는 [`MODULE_TEST`]
+    pub name: String,
+    pub outcome: Outcome,
+    pub duration: Duration,
+    /// 테스트가 출력한 내용
+    pub output: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Passed,
+    /// `assert`가 실패했거나 출력이 `.out` 파일과 다름
+    Failed(Failure),
+    /// 그 밖의 런타임 에러, 컴파일 에러, 입력 부족
+    Error(Failure),
+}
+
+impl Outcome {
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Outcome::Passed)
+    }
+
+    pub fn failure(&self) -> Option<&Failure> {
+        match self {
+            Outcome::Passed => None,
+            Outcome::Failed(failure) | Outcome::Error(failure) => Some(failure),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    /// 한 줄 요약: `AssertionError: ...`
+    pub message: String,
+    /// 실패한 문장의 위치
+    pub location: Option<Location>,
+    /// 추가 설명 (출력 비교의 다른 줄 등)
+    pub detail: Option<String>,
+}
+
+impl Failure {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            detail: None,
+        }
+    }
+}
+
+/// 소스 위치 (줄과 열은 1부터)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// 그 줄의 소스 코드
+    pub source_line: String,
+}
+
+impl Location {
+    /// 바이트 오프셋의 위치
+    pub fn of(source: &str, pos: usize) -> Self {
+        let pos = pos.min(source.len());
+        let line_start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[pos..].find('\n').map_or(source.len(), |i| pos + i);
+        Self {
+            line: source[..pos].matches('\n').count() + 1,
+            column: source[line_start..pos].chars().count() + 1,
+            source_line: source[line_start..line_end].to_string(),
+        }
+    }
+}
+
+/// 여러 파일의 테스트 결과
+#[derive(Debug, Clone, Default)]
+pub struct TestReport {
+    pub cases: Vec<TestCase>,
+    pub duration: Duration,
+}
+
+impl TestReport {
+    pub fn passed(&self) -> usize {
+        self.count(|o| matches!(o, Outcome::Passed))
+    }
+
+    pub fn failed(&self) -> usize {
+        self.count(|o| matches!(o, Outcome::Failed(_)))
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(|o| matches!(o, Outcome::Error(_)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.passed() == self.cases.len()
+    }
+
+    fn count(&self, pred: impl Fn(&Outcome) -> bool) -> usize {
+        self.cases.iter().filter(|c| pred(&c.outcome)).count()
+    }
+
+    /// 마지막 요약 줄: `3 passed, 1 failed, 0 errors in 12.30ms`
+    pub fn summary(&self) -> String {
+        let errors = self.errors();
+        format!(
+            "{} passed, {} failed, {} {} in {}",
+            self.passed(),
+            self.failed(),
+            errors,
+            if errors == 1 { "error" } else { "errors" },
+            format_duration(self.duration)
+        )
+    }
+
+    /// JUnit XML (파일마다 `<testsuite>` 하나)
+    pub fn to_junit_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"pyhc test\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.6}\">",
+            self.cases.len(),
+            self.failed(),
+            self.errors(),
+            self.duration.as_secs_f64()
+        );
+        let mut start = 0;
+        while start < self.cases.len() {
+            let file = &self.cases[start].file;
+            let end = start
+                + self.cases[start..]
+                    .iter()
+                    .take_while(|c| &c.file == file)
+                    .count();
+            let suite = TestReport {
+                cases: self.cases[start..end].to_vec(),
+                duration: self.cases[start..end].iter().map(|c| c.duration).sum(),
+            };
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.6}\">",
+                xml_escape(file),
+                suite.cases.len(),
+                suite.failed(),
+                suite.errors(),
+                suite.duration.as_secs_f64()
+            );
+            for case in &suite.cases {
+                let _ = write!(
+                    xml,
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.6}\"",
+                    xml_escape(&case.file),
+                    xml_escape(&case.name),
+                    case.duration.as_secs_f64()
+                );
+                let (tag, failure) = match &case.outcome {
+                    Outcome::Passed => {
+                        xml.push_str("/>\n");
+                        continue;
+                    }
+                    Outcome::Failed(failure) => ("failure", failure),
+                    Outcome::Error(failure) => ("error", failure),
+                };
+                let _ = writeln!(
+                    xml,
+                    ">\n      <{} message=\"{}\">{}</{}>",
+                    tag,
+                    xml_escape(&failure.message),
+                    xml_escape(&failure_text(&case.file, failure)),
+                    tag
+                );
+                if !case.output.is_empty() {
+                    let _ = writeln!(
+                        xml,
+                        "      <system-out>{}</system-out>",
+                        xml_escape(&case.output)
+                    );
+                }
+                xml.push_str("    </testcase>\n");
+            }
+            xml.push_str("  </testsuite>\n");
+            start = end;
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+/// 실패 설명: `file:line:col: message`, 소스 줄, 추가 설명
+pub fn failure_text(file: &str, failure: &Failure) -> String {
+    let mut text = match &failure.location {
+        Some(loc) => format!(
+            "{}:{}:{}: {}\n{:>5} | {}\n",
+            file, loc.line, loc.column, failure.message, loc.line, loc.source_line
+        ),
+        None => format!("{}: {}\n", file, failure.message),
+    };
+    if let Some(detail) = &failure.detail {
+        text.push_str(detail);
+        if !detail.ends_with('\n') {
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// `12.30ms`, `1.52s`
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs < 1.0 {
+        format!("{:.2}ms", secs * 1000.0)
+    } else {
+        format!("{:.2}s", secs)
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0에서 쓸 수 없는 제어 문자 (ANSI 색상 코드 등)
+            c if (c as u32) < 0x20 && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// `paths`에서 테스트 파일을 찾음
+///
+/// 디렉터리는 하위까지 뒤져 `test_*.pyh`를 모으고 (`.`으로 시작하는 이름은 건너뜀),
+/// 직접 지정한 파일은 이름과 상관없이 포함합니다.
+pub fn discover(paths: &[PathBuf]) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            collect_dir(path, &mut files)?;
+        } else {
+            std::fs::metadata(path)?;
+            files.push(path.clone());
+        }
+    }
+    Ok(files)
+}
+
+fn collect_dir(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+    for path in entries {
+        let hidden = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if hidden {
+            continue;
+        } else if path.is_dir() {
+            collect_dir(&path, files)?;
+        } else if is_test_file(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_test_file(path: &Path) -> bool {
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    name.starts_with("test_") && name.ends_with(".pyh")
+}
+
+/// 테스트 파일 하나를 읽어 실행. 픽스처는 같은 디렉터리에서 찾습니다.
+pub fn run_file(path: &Path) -> Vec<TestCase> {
+    let file = path.display().to_string();
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) => {
+            let failure = Failure::new(format!("cannot read file: {}", e));
+            return vec![error_case(&file, MODULE_TEST, failure)];
+        }
+    };
+    run_source(&file, &source, |test, ext| {
+        let stem = path.file_stem()?.to_str()?;
+        let name = match test {
+            Some(test) => format!("{}.{}.{}", stem, test, ext),
+            None => format!("{}.{}", stem, ext),
+        };
+        std::fs::read_to_string(path.with_file_name(name)).ok()
+    })
+}
+
+/// 소스 코드의 테스트를 실행
+///
+/// `fixture(test, ext)`는 테스트(`None`이면 파일 전체)의 `"in"`/`"out"` 픽스처 내용입니다.
+pub fn run_source(
+    file: &str,
+    source: &str,
+    fixture: impl Fn(Option<&str>, &str) -> Option<String>,
+) -> Vec<TestCase> {
+    let start = Instant::now();
+    let source = if source.ends_with('\n') {
+        source.to_string()
+    } else {
+        format!("{}\n", source)
+    };
+    let program = match crate::parse_source(&source)
+        .and_then(|program| crate::analyze(&program).map(|_| program))
+    {
+        Ok(program) => program,
+        Err(diagnostics) => {
+            let mut errors = diagnostics.iter().filter(|d| d.is_error());
+            let first = errors.next().expect("failed analysis reports an error");
+            let failure = Failure {
+                message: format!("[{}] {}", first.code, first.message),
+                location: Some(Location::of(&source, first.span.start)),
+                detail: errors
+                    .map(|d| {
+                        let loc = Location::of(&source, d.span.start);
+                        format!("{}:{}:{}: [{}] {}", file, loc.line, loc.column, d.code, d.message)
+                    })
+                    .reduce(|a, b| a + "\n" + &b),
+            };
+            let mut case = error_case(file, MODULE_TEST, failure);
+            case.duration = start.elapsed();
+            return vec![case];
+        }
+    };
+
+    let tests = test_functions(&program);
+    let file_input = fixture(None, "in");
+    let mut cases = Vec::new();
+    if tests.is_empty() || fixture(None, "out").is_some() {
+        let run = TestRun {
+            file,
+            source: &source,
+            input: file_input.as_deref(),
+            expected: fixture(None, "out"),
+        };
+        cases.push(run.run(MODULE_TEST, program.clone()));
+    }
+    for (name, params, def_stmt) in tests {
+        if params > 0 {
+            let mut failure = Failure::new(format!(
+                "test function '{}' must not take arguments ({} given)",
+                name, params
+            ));
+            failure.location = Some(Location::of(&source, def_stmt.1.start));
+            cases.push(error_case(file, name, failure));
+            continue;
+        }
+        // 모듈 코드 뒤에 `test_x()` 호출을 붙여 한 프로그램으로 실행
+        let call = Expr::Call {
+            func_name: Box::new((Expr::Variable(name.to_string()), def_stmt.1.clone())),
+            args: vec![],
+            kwargs: vec![],
+        };
+        let mut test_program = program.clone();
+        test_program.push((Stmt::Expr((call, def_stmt.1.clone())), def_stmt.1.clone()));
+        let input = fixture(Some(name), "in");
+        let run = TestRun {
+            file,
+            source: &source,
+            input: input.as_deref().or(file_input.as_deref()),
+            expected: fixture(Some(name), "out"),
+        };
+        cases.push(run.run(name, test_program));
+    }
+    cases
+}
+
+/// 모듈 수준의 `def test_*` (이름, 매개변수 개수, 정의 문장). 같은 이름은 처음 것만
+fn test_functions(program: &[StmtS]) -> Vec<(&str, usize, &StmtS)> {
+    let mut tests: Vec<(&str, usize, &StmtS)> = Vec::new();
+    for stmt in program {
+        if let Stmt::Def { name, params, .. } = &stmt.0
+            && name.starts_with("test_")
+            && !tests.iter().any(|(seen, _, _)| seen == name)
+        {
+            tests.push((name, params.len(), stmt));
+        }
+    }
+    tests
+}
+
+fn error_case(file: &str, name: &str, failure: Failure) -> TestCase {
+    TestCase {
+        file: file.to_string(),
+        name: name.to_string(),
+        outcome: Outcome::Error(failure),
+        duration: Duration::ZERO,
+        output: String::new(),
+    }
+}
+
+/// 테스트 하나의 실행 환경
+struct TestRun<'a> {
+    file: &'a str,
+    source: &'a str,
+    input: Option<&'a str>,
+    expected: Option<String>,
+}
+
+impl TestRun<'_> {
+    fn run(&self, name: &str, program: Vec<StmtS>) -> TestCase {
+        let start = Instant::now();
+        let mut module = crate::compile_to_module(&program);
+        let mut io = BufferIo::new();
+        io.push_input(self.input.unwrap_or(""));
+        let mut vm = Vm::new();
+        vm.set_argv(vec![self.file.to_string()]);
+        let result = vm.run_with_io(&mut module, &mut io);
+        let duration = start.elapsed();
+
+        let outcome = match result {
+            Err(error) => {
+                let failure = Failure {
+                    message: error.message.clone(),
+                    location: vm
+                        .last_position(&module)
+                        .map(|pos| Location::of(self.source, pos)),
+                    detail: None,
+                };
+                if matches!(error.kind, VmErrorKind::AssertionError) {
+                    Outcome::Failed(failure)
+                } else {
+                    Outcome::Error(failure)
+                }
+            }
+            Ok(_) if vm.is_waiting_for_input() => {
+                let mut failure = Failure::new(match self.input {
+                    Some(_) => "EOFError: input() read past the end of the .in fixture",
+                    None => "EOFError: input() called without a .in fixture",
+                });
+                failure.location = vm
+                    .last_position(&module)
+                    .map(|pos| Location::of(self.source, pos));
+                Outcome::Error(failure)
+            }
+            Ok(_) if vm.exit_code().is_some_and(|code| code != 0) => {
+                let mut failure = Failure::new(format!(
+                    "SystemExit: {}",
+                    vm.exit_code().unwrap_or_default()
+                ));
+                failure.detail = vm.exit_message().map(str::to_string);
+                Outcome::Error(failure)
+            }
+            Ok(_) => match &self.expected {
+                Some(expected) => compare_output(expected, io.get_output()),
+                None => Outcome::Passed,
+            },
+        };
+        TestCase {
+            file: self.file.to_string(),
+            name: name.to_string(),
+            outcome,
+            duration,
+            output: io.take_output(),
+        }
+    }
+}
+
+/// `.out` 파일과 출력 비교 (줄바꿈은 `\n`으로 맞추고, 파일 끝의 줄바꿈은 없어도 됨)
+fn compare_output(expected: &str, actual: &str) -> Outcome {
+    let expected = expected.replace("\r\n", "\n");
+    let expected = expected.strip_suffix('\n').unwrap_or(&expected);
+    let actual = actual.strip_suffix('\n').unwrap_or(actual);
+    if expected == actual {
+        return Outcome::Passed;
+    }
+    let expected_lines: Vec<&str> = expected.split('\n').collect();
+    let actual_lines: Vec<&str> = actual.split('\n').collect();
+    let line = (0..)
+        .find(|&i| expected_lines.get(i) != actual_lines.get(i))
+        .expect("different outputs differ at some line");
+    let show = |l: Option<&&str>| l.map_or("<end of output>".to_string(), |l| format!("{:?}", l));
+    Outcome::Failed(Failure {
+        message: format!("output differs from .out at line {}", line + 1),
+        location: None,
+        detail: Some(format!(
+            "  expected: {}\n  actual:   {}",
+            show(expected_lines.get(line)),
+            show(actual_lines.get(line))
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(source: &str) -> Vec<TestCase> {
+        run_source("test_x.pyh", source, |_, _| None)
+    }
+
+    #[test]
+    fn test_each_test_runs_in_a_fresh_vm() {
+        let source = "\
+counter = [0]
+
+def bump():
+  counter[0] += 1
+  return counter[0]
+
+def test_first():
+  assert(bump() == 1)
+
+def test_second():
+  assert(bump() == 1)
+
+def helper_not_collected():
+  assert(False)
+";
+        let cases = run(source);
+        let names: Vec<&str> = cases.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["test_first", "test_second"]);
+        assert!(cases.iter().all(|c| c.outcome.is_passed()));
+    }
+
+    #[test]
+    fn test_failures_point_at_the_failing_statement() {
+        let source = "\
+def check(x):
+  assert(x > 0, f'x was {x}')
+
+def test_nested():
+  check(1)
+  check(-2)
+
+def test_error():
+  print('before')
+  xs = []
+  return xs[3]
+";
+        let cases = run(source);
+        let Outcome::Failed(failure) = &cases[0].outcome else {
+            panic!("{:?}", cases[0].outcome);
+        };
+        assert_eq!(failure.message, "AssertionError: x was -2");
+        let loc = failure.location.as_ref().unwrap();
+        assert_eq!((loc.line, loc.column), (2, 3));
+        assert_eq!(loc.source_line, "  assert(x > 0, f'x was {x}')");
+
+        let Outcome::Error(failure) = &cases[1].outcome else {
+            panic!("{:?}", cases[1].outcome);
+        };
+        assert_eq!(failure.location.as_ref().unwrap().line, 11);
+        assert_eq!(cases[1].output, "before\n");
+        assert!(failure_text("t.pyh", failure).starts_with("t.pyh:11:3: "));
+    }
+
+    #[test]
+    fn test_fixtures_feed_input_and_check_output() {
+        let source = "\
+def test_echo():
+  name = input()
+  print('hi ' + name)
+
+def test_no_input():
+  input()
+
+print('module')
+";
+        let fixture = |test: Option<&str>, ext: &str| match (test, ext) {
+            (None, "in") => Some("bob\n".to_string()),
+            (None, "out") => Some("module".to_string()),
+            (Some("test_echo"), "out") => Some("module\nhi bob\n".to_string()),
+            _ => None,
+        };
+        let cases = run_source("test_io.pyh", source, fixture);
+        let names: Vec<&str> = cases.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, [MODULE_TEST, "test_echo", "test_no_input"]);
+        assert!(cases[0].outcome.is_passed(), "{:?}", cases[0].outcome);
+        assert!(cases[1].outcome.is_passed(), "{:?}", cases[1].outcome);
+        // 파일의 .in은 한 줄뿐이라 두 번째 input()에서 끝남
+        let source2 = "def test_two():\n  input()\n  input()\n";
+        let cases = run_source("t.pyh", source2, |_, ext| (ext == "in").then(|| "bob\n".into()));
+        let failure = cases[0].outcome.failure().unwrap();
+        assert!(failure.message.starts_with("EOFError"), "{}", failure.message);
+        assert_eq!(failure.location.as_ref().unwrap().line, 3);
+
+        let wrong = |test: Option<&str>, ext: &str| match (test, ext) {
+            (Some(_), "out") => Some("module\nhi alice\n".to_string()),
+            (_, "in") => Some("bob".to_string()),
+            _ => None,
+        };
+        let cases = run_source("test_io.pyh", source, wrong);
+        let Outcome::Failed(failure) = &cases[0].outcome else {
+            panic!("{:?}", cases[0].outcome);
+        };
+        assert_eq!(failure.message, "output differs from .out at line 2");
+        assert_eq!(
+            failure.detail.as_deref(),
+            Some("  expected: \"hi alice\"\n  actual:   \"hi bob\"")
+        );
+    }
+
+    #[test]
+    fn test_compile_errors_and_bad_tests_are_errors() {
+        let cases = run("def test_a():\n  print(missing)\n");
+        assert_eq!(cases.len(), 1);
+        assert_eq!(cases[0].name, MODULE_TEST);
+        let failure = cases[0].outcome.failure().unwrap();
+        assert!(failure.message.starts_with("[E0201]"), "{}", failure.message);
+        assert_eq!(failure.location.as_ref().unwrap().line, 2);
+
+        let cases = run("import sys\ndef test_args(x):\n  pass\n\ndef test_exit():\n  sys.exit(3)\n");
+        assert!(matches!(&cases[0].outcome, Outcome::Error(f) if f.message.contains("must not take")));
+        assert!(matches!(&cases[1].outcome, Outcome::Error(f) if f.message == "SystemExit: 3"));
+
+        // 테스트 함수가 없으면 프로그램으로 실행
+        let cases = run("assert(1 < 2)\nprint('ok')\n");
+        assert_eq!((cases[0].name.as_str(), cases[0].output.as_str()), (MODULE_TEST, "ok\n"));
+        assert!(cases[0].outcome.is_passed());
+    }
+
+    #[test]
+    fn test_report_summary_and_junit_xml() {
+        let mut cases = run("def test_ok():\n  pass\n\ndef test_bad():\n  assert(1 > 2, '<&>')\n");
+        cases.extend(run_source("b.pyh", "x = 1 // 0\n", |_, _| None));
+        let report = TestReport {
+            cases,
+            duration: Duration::from_millis(12),
+        };
+        assert_eq!(report.summary(), "1 passed, 1 failed, 1 error in 12.00ms");
+        assert!(!report.is_success());
+
+        let xml = report.to_junit_xml();
+        assert!(xml.contains("<testsuites name=\"pyhc test\" tests=\"3\" failures=\"1\" errors=\"1\""));
+        assert!(xml.contains("<testsuite name=\"test_x.pyh\" tests=\"2\" failures=\"1\" errors=\"0\""));
+        assert!(xml.contains("<testcase classname=\"test_x.pyh\" name=\"test_ok\""));
+        assert!(xml.contains("<failure message=\"AssertionError: &lt;&amp;&gt;\">test_x.pyh:5:3: "));
+        assert!(xml.contains("<testsuite name=\"b.pyh\" tests=\"1\" failures=\"0\" errors=\"1\""));
+        assert!(xml.contains("<testcase classname=\"b.pyh\" name=\"&lt;module&gt;\""));
+        assert!(xml.ends_with("</testsuites>\n"));
+    }
+}
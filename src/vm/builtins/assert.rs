@@ -1,23 +1,23 @@
-use crate::vm::{Value, VmErrorKind, VmResult, builtins::bool, err};
+use crate::vm::{Value, VmErrorKind, VmResult, builtins::bool, err, utils::display_value};
 
-/// assert builtin 함수
+/// assert builtin 함수: `assert(condition[, message])`
 pub fn call(args: Vec<Value>) -> VmResult<Value> {
-    if args.len() != 1 {
+    if args.is_empty() || args.len() > 2 {
         return Err(err(
             VmErrorKind::ArityError {
                 expected: 1,
                 got: args.len(),
             },
-            format!("assert() takes exactly 1 argument ({} given)", args.len()),
+            format!("assert() takes 1 or 2 arguments ({} given)", args.len()),
         ));
     }
-    // let v = &args[0];
     let b = bool::to_bool(&args[0]);
     if !b {
-        return Err(err(
-            VmErrorKind::AssertionError,
-            "AssertionError: assert failed".to_string(),
-        ));
+        let message = match args.get(1) {
+            Some(message) => format!("AssertionError: {}", display_value(message)),
+            None => "AssertionError: assert failed".to_string(),
+        };
+        return Err(err(VmErrorKind::AssertionError, message));
     }
     Ok(Value::None)
 }
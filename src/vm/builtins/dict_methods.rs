@@ -132,6 +132,12 @@ pub fn dict_clear(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
 
 /// dict.__iter__() - keys iterator
 pub fn dict_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    // iterator의 __iter__는 자기 자신 (`iter(iter(xs))`)
+    if let Value::Object(obj) = receiver
+        && matches!(obj.data, ObjectData::BuiltinInstance { .. })
+    {
+        return Ok(receiver.clone());
+    }
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::Dict { map } = &obj.data {
@@ -360,8 +366,29 @@ pub fn dict_items(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
     }
 }
 
+/// dict.__contains__(key): `key in d`
+pub fn dict_contains(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    match receiver {
+        Value::Object(obj) => {
+            if let ObjectData::Dict { map } = &obj.data {
+                let key = value_to_dict_key(&args[0])?;
+                Ok(Value::Bool(map.borrow().contains_key(&key)))
+            } else {
+                Err(err(
+                    VmErrorKind::TypeError("dict.__contains__"),
+                    "__contains__() requires a dict".to_string(),
+                ))
+            }
+        }
+        _ => Err(err(
+            VmErrorKind::TypeError("dict.__contains__"),
+            "__contains__() requires a dict".to_string(),
+        )),
+    }
+}
+
 // Helper functions
-fn value_to_dict_key(value: &Value) -> VmResult<DictKey> {
+pub fn value_to_dict_key(value: &Value) -> VmResult<DictKey> {
     match value {
         Value::Int(i) => Ok(DictKey::Int(*i)),
         Value::Bool(b) => Ok(DictKey::Bool(*b)),
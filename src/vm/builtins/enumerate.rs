@@ -0,0 +1,103 @@
+//! enumerate() builtin function and enumerate iterator implementation
+
+use super::super::bytecode::{Module, Value};
+use super::super::machine::Vm;
+use super::super::utils::make_tuple;
+use super::super::value::{BuiltinInstanceData, Object, ObjectData};
+use super::super::{VmError, VmErrorKind, VmResult, err};
+use super::type_name;
+use crate::builtins::{BuiltinClassType, TYPE_ENUMERATE};
+use crate::runtime_io::RuntimeIo;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// enumerate(iterable, start=0) 생성자
+///
+/// `(index, value)` tuple을 하나씩 만들어내는 lazy iterator를 반환합니다.
+///
+/// ```python
+/// for pair in enumerate(["a", "b"], 1):
+///     print(pair)  # (1, 'a'), (2, 'b')
+/// ```
+pub fn create_enumerate<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let start = match args.get(1) {
+        None => 0,
+        Some(Value::Int(i)) => *i,
+        Some(Value::Bool(b)) => *b as i64,
+        Some(other) => {
+            return Err(err(
+                VmErrorKind::TypeError("enumerate"),
+                format!(
+                    "'{}' object cannot be interpreted as an integer",
+                    type_name(other)
+                ),
+            ));
+        }
+    };
+    let source_iter = vm.get_iter(&args[0], module, io)?;
+
+    Ok(Value::Object(Rc::new(Object::new(
+        TYPE_ENUMERATE,
+        ObjectData::BuiltinInstance {
+            class_type: BuiltinClassType::Enumerate,
+            data: BuiltinInstanceData::EnumerateIterator {
+                source_iter: Box::new(source_iter),
+                index: RefCell::new(start),
+            },
+        },
+    ))))
+}
+
+// ========== Iterator Protocol 메서드들 ==========
+
+/// enumerate.__iter__(): 자기 자신을 반환
+pub fn enumerate_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    Ok(receiver.clone())
+}
+
+/// enumerate.__has_next__(): source iterator에 위임
+pub fn enumerate_has_next<IO: RuntimeIo>(
+    receiver: &Value,
+    _args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let (source_iter, _) = expect_enumerate(receiver)?;
+    vm.call_method(source_iter, "__has_next__", vec![], module, io)
+}
+
+/// enumerate.__next__(): `(index, source.__next__())`
+pub fn enumerate_next<IO: RuntimeIo>(
+    receiver: &Value,
+    _args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let (source_iter, index) = expect_enumerate(receiver)?;
+    let value = vm.call_method(source_iter, "__next__", vec![], module, io)?;
+    let i = *index.borrow();
+    *index.borrow_mut() = i.wrapping_add(1);
+    Ok(make_tuple(vec![Value::Int(i), value]))
+}
+
+fn expect_enumerate(receiver: &Value) -> VmResult<(&Value, &RefCell<i64>)> {
+    if let Value::Object(obj) = receiver
+        && let ObjectData::BuiltinInstance {
+            data: BuiltinInstanceData::EnumerateIterator { source_iter, index },
+            ..
+        } = &obj.data
+    {
+        return Ok((source_iter, index));
+    }
+    Err(err(
+        VmErrorKind::TypeError("enumerate"),
+        "expected enumerate object".into(),
+    ))
+}
@@ -86,7 +86,7 @@ pub fn float_mod(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     if b == 0.0 {
         return Err(err(VmErrorKind::ZeroDivision, "modulo by zero".into()));
     }
-    Ok(Value::Float(a % b))
+    Ok(Value::Float(floor_mod(a, b)))
 }
 
 pub fn float_neg(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
@@ -126,3 +126,45 @@ pub fn float_ne(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     let (a, b) = (expect_float(receiver)?, expect_float(&args[0])?);
     Ok(Value::Bool(a != b))
 }
+
+pub fn float_pow(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let (a, b) = (expect_float(receiver)?, expect_float(&args[0])?);
+    pow_floats(a, b).map(Value::Float)
+}
+
+/// Python 규칙의 실수 `%`: 결과의 부호는 `b`를 따름 (`-7.5 % 2 == 0.5`)
+pub fn floor_mod(a: f64, b: f64) -> f64 {
+    let r = a % b;
+    if r == 0.0 {
+        0.0f64.copysign(b)
+    } else if (r < 0.0) != (b < 0.0) {
+        r + b
+    } else {
+        r
+    }
+}
+
+/// Python 규칙의 실수 거듭제곱 (VM fast path와 공유)
+pub fn pow_floats(a: f64, b: f64) -> VmResult<f64> {
+    if a == 0.0 && b < 0.0 {
+        return Err(err(
+            VmErrorKind::ZeroDivision,
+            "0.0 cannot be raised to a negative power".into(),
+        ));
+    }
+    if a < 0.0 && b.fract() != 0.0 {
+        // Python은 복소수를 돌려주지만 pyhyeon에는 복소수가 없음
+        return Err(err(
+            VmErrorKind::TypeError("pow"),
+            "ValueError: negative number cannot be raised to a fractional power".into(),
+        ));
+    }
+    let result = a.powf(b);
+    if result.is_infinite() && a.is_finite() && b.is_finite() {
+        return Err(err(
+            VmErrorKind::TypeError("pow"),
+            "OverflowError: float power result too large".into(),
+        ));
+    }
+    Ok(result)
+}
@@ -0,0 +1,23 @@
+use super::super::bytecode::Value;
+use super::super::utils::{expect_string, make_string};
+use super::super::{VmError, VmErrorKind, VmResult, err};
+
+/// format() builtin 함수: format(value[, spec])
+pub fn call(args: Vec<Value>) -> VmResult<Value> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(err(
+            VmErrorKind::ArityError {
+                expected: 1,
+                got: args.len(),
+            },
+            format!("format() takes 1 or 2 arguments ({} given)", args.len()),
+        ));
+    }
+
+    let spec = match args.get(1) {
+        Some(spec) => expect_string(spec)?,
+        None => "",
+    };
+    let formatted = crate::vm::format::format_with_spec(&args[0], spec)?;
+    Ok(make_string(formatted))
+}
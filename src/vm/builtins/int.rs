@@ -84,7 +84,7 @@ pub fn int_floordiv(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
             "integer division or modulo by zero".into(),
         ));
     }
-    Ok(Value::Int(a / b))
+    Ok(Value::Int(floor_div(a, b)))
 }
 
 /// __truediv__: Int / Int
@@ -110,7 +110,7 @@ pub fn int_mod(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
             "integer division or modulo by zero".into(),
         ));
     }
-    Ok(Value::Int(a % b))
+    Ok(Value::Int(floor_mod(a, b)))
 }
 
 /// __neg__: -Int
@@ -166,3 +166,128 @@ pub fn int_ne(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     let b = expect_int(&args[0])?;
     Ok(Value::Bool(a != b))
 }
+
+/// __pow__: Int ** Int
+pub fn int_pow(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    let b = expect_int(&args[0])?;
+    pow_ints(a, b)
+}
+
+/// __and__: Int & Int
+pub fn int_and(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    let b = expect_int(&args[0])?;
+    Ok(Value::Int(a & b))
+}
+
+/// __or__: Int | Int
+pub fn int_or(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    let b = expect_int(&args[0])?;
+    Ok(Value::Int(a | b))
+}
+
+/// __xor__: Int ^ Int
+pub fn int_xor(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    let b = expect_int(&args[0])?;
+    Ok(Value::Int(a ^ b))
+}
+
+/// __lshift__: Int << Int
+pub fn int_lshift(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    let b = expect_int(&args[0])?;
+    shift_left(a, b).map(Value::Int)
+}
+
+/// __rshift__: Int >> Int
+pub fn int_rshift(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    let b = expect_int(&args[0])?;
+    shift_right(a, b).map(Value::Int)
+}
+
+/// __invert__: ~Int
+pub fn int_invert(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let a = expect_int(receiver)?;
+    Ok(Value::Int(!a))
+}
+
+// ========== 연산 헬퍼 (VM fast path와 공유) ==========
+
+/// Python 규칙의 정수 거듭제곱: 음수 지수는 float, 결과가 i64를 넘으면 OverflowError
+pub fn pow_ints(a: i64, b: i64) -> VmResult<Value> {
+    if b < 0 {
+        if a == 0 {
+            return Err(err(
+                VmErrorKind::ZeroDivision,
+                "0 cannot be raised to a negative power".into(),
+            ));
+        }
+        return Ok(Value::Float((a as f64).powf(b as f64)));
+    }
+    let result = match a {
+        0 | 1 => Some(if b == 0 { 1 } else { a }),
+        -1 => Some(if b % 2 == 0 { 1 } else { -1 }),
+        _ => u32::try_from(b).ok().and_then(|exp| a.checked_pow(exp)),
+    };
+    result.map(Value::Int).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("pow"),
+            format!("OverflowError: {} ** {} is too large for a 64-bit integer", a, b),
+        )
+    })
+}
+
+/// Python 규칙의 정수 `//`: 음의 무한대 쪽으로 내림 (`-7 // 2 == -4`)
+pub fn floor_div(a: i64, b: i64) -> i64 {
+    let q = a.wrapping_div(b);
+    if a.wrapping_rem(b) != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+/// Python 규칙의 정수 `%`: 결과의 부호는 `b`를 따름 (`-7 % 2 == 1`)
+pub fn floor_mod(a: i64, b: i64) -> i64 {
+    let r = a.wrapping_rem(b);
+    if r != 0 && (r < 0) != (b < 0) { r + b } else { r }
+}
+
+/// `a << b`: 음수 시프트는 ValueError, 넘치면 OverflowError
+pub fn shift_left(a: i64, b: i64) -> VmResult<i64> {
+    if b < 0 {
+        return Err(negative_shift_count());
+    }
+    if a == 0 {
+        return Ok(0);
+    }
+    if b < 64 {
+        let shifted = a << b;
+        if shifted >> b == a {
+            return Ok(shifted);
+        }
+    }
+    Err(err(
+        VmErrorKind::TypeError("lshift"),
+        format!("OverflowError: {} << {} is too large for a 64-bit integer", a, b),
+    ))
+}
+
+/// `a >> b`: 산술 시프트 (음수는 -1 쪽으로 내림)
+pub fn shift_right(a: i64, b: i64) -> VmResult<i64> {
+    if b < 0 {
+        return Err(negative_shift_count());
+    }
+    Ok(if b >= 64 { if a < 0 { -1 } else { 0 } } else { a >> b })
+}
+
+fn negative_shift_count() -> VmError {
+    err(
+        VmErrorKind::TypeError("shift"),
+        "ValueError: negative shift count".into(),
+    )
+}
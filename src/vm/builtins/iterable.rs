@@ -0,0 +1,404 @@
+//! iterable을 받는 builtin 함수: min, max, sum, sorted, any, all, list, tuple, dict,
+//! iter, next, reversed
+//!
+//! 모두 `__iter__`/`__has_next__`/`__next__` 프로토콜로 순회하므로 사용자 정의
+//! iterator도 그대로 받을 수 있습니다. 사용자 정의 비교/덧셈도 VM을 통해 호출합니다.
+
+use super::super::bytecode::{Instruction, Module, Value};
+use super::super::machine::Vm;
+use super::super::utils::{make_dict, make_list, make_list_iterator, make_range, make_tuple};
+use super::super::value::{BuiltinInstanceData, ObjectData};
+use super::super::{VmError, VmErrorKind, VmResult, err};
+use super::bool::to_bool;
+use super::dict_methods::value_to_dict_key;
+use crate::runtime_io::RuntimeIo;
+use std::collections::HashMap;
+
+/// min(iterable) / min(a, b, ...)
+pub fn min<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    extreme("min", args, false, module, vm, io)
+}
+
+/// max(iterable) / max(a, b, ...)
+pub fn max<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    extreme("max", args, true, module, vm, io)
+}
+
+/// min/max 공통 구현: 같은 값이 여럿이면 먼저 나온 값을 돌려줌
+fn extreme<IO: RuntimeIo>(
+    name: &str,
+    args: Vec<Value>,
+    want_max: bool,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let source = match args.len() {
+        0 => {
+            return Err(err(
+                VmErrorKind::TypeError("min/max"),
+                format!("{} expected at least 1 argument, got 0", name),
+            ));
+        }
+        1 => args.into_iter().next().unwrap(),
+        _ => make_list(args),
+    };
+
+    let iter = vm.get_iter(&source, module, io)?;
+    let Some(mut best) = vm.next_item(&iter, module, io)? else {
+        return Err(err(
+            VmErrorKind::TypeError("min/max"),
+            format!("ValueError: {}() arg is an empty sequence", name),
+        ));
+    };
+    while let Some(item) = vm.next_item(&iter, module, io)? {
+        let replace = if want_max {
+            vm.less_than(&best, &item, module, io)?
+        } else {
+            vm.less_than(&item, &best, module, io)?
+        };
+        if replace {
+            best = item;
+        }
+    }
+    Ok(best)
+}
+
+/// sum(iterable, start=0): `+` 연산자와 같은 규칙으로 더함
+pub fn sum<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let mut total = args.get(1).cloned().unwrap_or(Value::Int(0));
+    if let Value::Object(obj) = &total
+        && matches!(obj.data, ObjectData::String(_))
+    {
+        return Err(err(
+            VmErrorKind::TypeError("sum"),
+            "sum() can't sum strings [use ''.join(seq) instead]".into(),
+        ));
+    }
+
+    let iter = vm.get_iter(&args[0], module, io)?;
+    while let Some(item) = vm.next_item(&iter, module, io)? {
+        total = vm.binary_op(&Instruction::Add, total, item, module, io)?;
+    }
+    Ok(total)
+}
+
+/// sorted(iterable, key=None, reverse=False): 새 list로 정렬 (stable)
+pub fn sorted<IO: RuntimeIo>(
+    args: Vec<Value>,
+    kwargs: &HashMap<String, Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let (key, reverse) = sort_options(kwargs);
+    let items = vm.collect_iterable(&args[0], module, io)?;
+    Ok(make_list(sort_values(items, key, reverse, module, vm, io)?))
+}
+
+/// `key=`, `reverse=` 키워드 인자 해석 (`key=None`은 key 없음과 같음)
+pub fn sort_options(kwargs: &HashMap<String, Value>) -> (Option<&Value>, bool) {
+    let key = kwargs.get("key").filter(|k| !matches!(k, Value::None));
+    let reverse = kwargs.get("reverse").is_some_and(to_bool);
+    (key, reverse)
+}
+
+/// `<` 연산자로 비교하는 stable merge sort
+///
+/// `key`가 있으면 원소마다 한 번씩만 호출해 얻은 값으로 비교합니다.
+/// `reverse`여도 같은 값끼리는 원래 순서를 유지합니다 (Python과 동일).
+/// 비교가 사용자 정의 `__lt__`를 호출하거나 실패할 수 있으므로
+/// 표준 라이브러리 정렬 대신 직접 구현합니다.
+pub fn sort_values<IO: RuntimeIo>(
+    items: Vec<Value>,
+    key: Option<&Value>,
+    reverse: bool,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Vec<Value>> {
+    let mut decorated = Vec::with_capacity(items.len());
+    for item in items {
+        let k = match key {
+            Some(func) => vm.call_function(func, vec![item.clone()], module, io)?,
+            None => item.clone(),
+        };
+        decorated.push((k, item));
+    }
+    let sorted = merge_sort(decorated, reverse, module, vm, io)?;
+    Ok(sorted.into_iter().map(|(_, item)| item).collect())
+}
+
+fn merge_sort<IO: RuntimeIo>(
+    mut items: Vec<(Value, Value)>,
+    reverse: bool,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Vec<(Value, Value)>> {
+    if items.len() < 2 {
+        return Ok(items);
+    }
+    let right = items.split_off(items.len() / 2);
+    let left = merge_sort(items, reverse, module, vm, io)?;
+    let right = merge_sort(right, reverse, module, vm, io)?;
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        // 오른쪽이 엄격하게 앞설 때만 먼저 꺼내야 stable
+        let right_first = if reverse {
+            vm.less_than(&left[i].0, &right[j].0, module, io)?
+        } else {
+            vm.less_than(&right[j].0, &left[i].0, module, io)?
+        };
+        if right_first {
+            merged.push(right[j].clone());
+            j += 1;
+        } else {
+            merged.push(left[i].clone());
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&left[i..]);
+    merged.extend_from_slice(&right[j..]);
+    Ok(merged)
+}
+
+/// any(iterable): 참인 원소를 만나면 바로 멈춤
+pub fn any<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let iter = vm.get_iter(&args[0], module, io)?;
+    while let Some(item) = vm.next_item(&iter, module, io)? {
+        if to_bool(&item) {
+            return Ok(Value::Bool(true));
+        }
+    }
+    Ok(Value::Bool(false))
+}
+
+/// all(iterable): 거짓인 원소를 만나면 바로 멈춤
+pub fn all<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let iter = vm.get_iter(&args[0], module, io)?;
+    while let Some(item) = vm.next_item(&iter, module, io)? {
+        if !to_bool(&item) {
+            return Ok(Value::Bool(false));
+        }
+    }
+    Ok(Value::Bool(true))
+}
+
+/// list() / list(iterable)
+pub fn list<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    match args.first() {
+        None => Ok(make_list(vec![])),
+        Some(iterable) => Ok(make_list(vm.collect_iterable(iterable, module, io)?)),
+    }
+}
+
+/// tuple() / tuple(iterable)
+pub fn tuple<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    match args.first() {
+        None => Ok(make_tuple(vec![])),
+        Some(iterable) => Ok(make_tuple(vm.collect_iterable(iterable, module, io)?)),
+    }
+}
+
+/// dict() / dict(mapping) / dict(iterable of (key, value) pairs)
+pub fn dict<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let Some(source) = args.first() else {
+        return Ok(make_dict(HashMap::new()));
+    };
+    if let Value::Object(obj) = source
+        && let ObjectData::Dict { map } = &obj.data
+    {
+        return Ok(make_dict(map.borrow().clone()));
+    }
+
+    let mut map = HashMap::new();
+    for (index, pair) in vm.collect_iterable(source, module, io)?.into_iter().enumerate() {
+        let items = vm.collect_iterable(&pair, module, io)?;
+        let [key, value]: [Value; 2] = items.try_into().map_err(|items: Vec<Value>| {
+            err(
+                VmErrorKind::TypeError("dict"),
+                format!(
+                    "ValueError: dictionary update sequence element #{} has length {}; 2 is required",
+                    index,
+                    items.len()
+                ),
+            )
+        })?;
+        map.insert(value_to_dict_key(&key)?, value);
+    }
+    Ok(make_dict(map))
+}
+
+/// iter(iterable)
+pub fn iter<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    vm.get_iter(&args[0], module, io)
+}
+
+/// next(iterator) / next(iterator, default)
+///
+/// 끝에 도달하면 default를 돌려주고, default가 없으면 StopIteration입니다.
+pub fn next<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let iterator = &args[0];
+    let is_iterator = match iterator {
+        Value::Object(obj) => matches!(
+            obj.data,
+            ObjectData::BuiltinInstance { .. } | ObjectData::UserInstance { .. }
+        ),
+        _ => false,
+    };
+    if !is_iterator {
+        return Err(err(
+            VmErrorKind::TypeError("next"),
+            format!(
+                "'{}' object is not an iterator",
+                vm.type_name_of(iterator, module)
+            ),
+        ));
+    }
+
+    match vm.next_item(iterator, module, io)? {
+        Some(item) => Ok(item),
+        None => args.get(1).cloned().ok_or_else(|| {
+            err(
+                VmErrorKind::TypeError("StopIteration"),
+                "StopIteration".into(),
+            )
+        }),
+    }
+}
+
+/// reversed(sequence)
+///
+/// range는 뒤집은 range를 새로 만들어 lazy하게 순회하고, list/tuple/str은
+/// 호출 시점의 원소를 거꾸로 순회합니다. 사용자 클래스는 `__reversed__`를 사용합니다.
+pub fn reversed<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let seq = &args[0];
+    if let Value::Object(obj) = seq {
+        match &obj.data {
+            ObjectData::List { items } => {
+                return Ok(make_list_iterator(items.borrow().iter().rev().cloned().collect()));
+            }
+            ObjectData::Tuple { items } => {
+                return Ok(make_list_iterator(items.iter().rev().cloned().collect()));
+            }
+            ObjectData::String(s) => {
+                return Ok(make_list_iterator(
+                    s.chars()
+                        .rev()
+                        .map(|c| super::make_string(c.to_string()))
+                        .collect(),
+                ));
+            }
+            ObjectData::BuiltinInstance {
+                data: BuiltinInstanceData::Range { current, stop, step },
+                ..
+            } => {
+                return Ok(reversed_range(*current.borrow(), *stop, *step));
+            }
+            ObjectData::UserInstance { .. } => {
+                return vm.call_method(seq, "__reversed__", vec![], module, io);
+            }
+            _ => {}
+        }
+    }
+    Err(err(
+        VmErrorKind::TypeError("reversed"),
+        format!("'{}' object is not reversible", vm.type_name_of(seq, module)),
+    ))
+}
+
+/// range(start, stop, step)의 남은 원소를 거꾸로 도는 range
+fn reversed_range(start: i64, stop: i64, step: i64) -> Value {
+    let (start, stop, step) = (start as i128, stop as i128, step as i128);
+    let len = if step > 0 && start < stop {
+        (stop - start - 1) / step + 1
+    } else if step < 0 && start > stop {
+        (start - stop - 1) / -step + 1
+    } else {
+        0
+    };
+    if len == 0 {
+        return make_range(0, 0, 1);
+    }
+    let last = start + (len - 1) * step;
+    let end = (start - step).clamp(i64::MIN as i128, i64::MAX as i128);
+    make_range(last as i64, end as i64, -step as i64)
+}
+
+/// pow(base, exp[, mod]): 2인자는 `**` 연산자와 같음
+pub fn pow<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    match args.as_slice() {
+        [base, exp, modulus] => super::numeric::pow_mod(base, exp, modulus),
+        [base, exp] => vm.binary_op(&Instruction::Pow, base.clone(), exp.clone(), module, io),
+        _ => Err(err(
+            VmErrorKind::ArityError {
+                expected: 2,
+                got: args.len(),
+            },
+            format!("pow() takes 2 or 3 arguments ({} given)", args.len()),
+        )),
+    }
+}
@@ -19,6 +19,7 @@ pub fn call(args: Vec<Value>) -> VmResult<Value> {
         Value::Object(obj) => {
             use super::super::value::ObjectData;
             match &obj.data {
+                ObjectData::String(s) if obj.is_ascii_str() => Ok(Value::Int(s.len() as i64)),
                 ObjectData::String(s) => Ok(Value::Int(s.chars().count() as i64)),
                 ObjectData::List { items } => Ok(Value::Int(items.borrow().len() as i64)),
                 ObjectData::Tuple { items } => Ok(Value::Int(items.len() as i64)),
@@ -1,10 +1,15 @@
 //! List methods implementation
 
-use super::super::bytecode::Value;
+use super::super::bytecode::{Module, Value};
+use super::super::machine::Vm;
 use super::super::value::{BuiltinInstanceData, Object, ObjectData};
-use super::super::{VmError, VmErrorKind, VmResult, err};
-use crate::builtins::{BuiltinClassType, TYPE_LIST};
+use super::super::{VmError, VmErrorKind, VmResult, err, limits};
+use super::iterable;
+use super::super::utils::{make_list, type_name};
+use crate::builtins::{BuiltinClassType, TYPE_LIST_ITERATOR};
+use crate::runtime_io::RuntimeIo;
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 /// list.append(item)
@@ -176,7 +181,7 @@ pub fn list_insert(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
                         let len = items.borrow().len() as i64;
 
                         if i < 0 {
-                            0.max((len + i) as usize)
+                            (len + i).max(0) as usize
                         } else {
                             let items_len = items.borrow().len();
                             (i as usize).min(items_len)
@@ -225,7 +230,7 @@ pub fn list_remove(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
 
                 // 첫 번째로 일치하는 항목 찾기
                 for (i, item) in items_mut.iter().enumerate() {
-                    if super::super::utils::eq_vals(item, &args[0]) {
+                    if super::super::utils::eq_vals(item, &args[0])? {
                         items_mut.remove(i);
                         return Ok(Value::None);
                     }
@@ -271,25 +276,23 @@ pub fn list_reverse(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
     }
 }
 
-/// list.sort()
-pub fn list_sort(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+/// list.sort(key=None, reverse=False): 제자리 정렬 (stable)
+///
+/// 비교 중 사용자 코드가 실행될 수 있으므로 원소를 복사해 정렬한 뒤 되돌려 씁니다.
+pub fn list_sort<IO: RuntimeIo>(
+    receiver: &Value,
+    kwargs: &HashMap<String, Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::List { items } = &obj.data {
-                let mut items_mut = items.borrow_mut();
-
-                // 정수 리스트만 정렬 지원
-                let all_ints = items_mut.iter().all(|v| matches!(v, Value::Int(_)));
-
-                if !all_ints {
-                    return Err(err(
-                        VmErrorKind::TypeError("list.sort"),
-                        "sort() currently only supports lists of integers".to_string(),
-                    ));
-                }
-
-                items_mut.sort_by_key(|v| if let Value::Int(i) = v { *i } else { 0 });
-
+                let (key, reverse) = iterable::sort_options(kwargs);
+                let snapshot = items.borrow().clone();
+                let sorted = iterable::sort_values(snapshot, key, reverse, module, vm, io)?;
+                *items.borrow_mut() = sorted;
                 Ok(Value::None)
             } else {
                 Err(err(
@@ -344,7 +347,7 @@ pub fn list_index(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
                 let items_ref = items.borrow();
 
                 for (i, item) in items_ref.iter().enumerate() {
-                    if super::super::utils::eq_vals(item, &args[0]) {
+                    if super::super::utils::eq_vals(item, &args[0])? {
                         return Ok(Value::Int(i as i64));
                     }
                 }
@@ -383,10 +386,12 @@ pub fn list_count(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
         Value::Object(obj) => {
             if let ObjectData::List { items } = &obj.data {
                 let items_ref = items.borrow();
-                let count = items_ref
-                    .iter()
-                    .filter(|item| super::super::utils::eq_vals(item, &args[0]))
-                    .count();
+                let mut count = 0;
+                for item in items_ref.iter() {
+                    if super::super::utils::eq_vals(item, &args[0])? {
+                        count += 1;
+                    }
+                }
 
                 Ok(Value::Int(count as i64))
             } else {
@@ -405,14 +410,20 @@ pub fn list_count(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
 
 /// list.__iter__()
 pub fn list_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    // iterator의 __iter__는 자기 자신 (`iter(iter(xs))`)
+    if let Value::Object(obj) = receiver
+        && matches!(obj.data, ObjectData::BuiltinInstance { .. })
+    {
+        return Ok(receiver.clone());
+    }
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::List { items } = &obj.data {
                 // ListIterator 생성
                 let iterator = Value::Object(Rc::new(Object::new(
-                    TYPE_LIST,
+                    TYPE_LIST_ITERATOR,
                     ObjectData::BuiltinInstance {
-                        class_type: BuiltinClassType::List,
+                        class_type: BuiltinClassType::ListIterator,
                         data: BuiltinInstanceData::ListIterator {
                             items: Rc::new(RefCell::clone(items)),
                             current: RefCell::new(0),
@@ -439,7 +450,7 @@ pub fn list_has_next(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::BuiltinInstance {
-                class_type: BuiltinClassType::List,
+                class_type: BuiltinClassType::ListIterator,
                 data: BuiltinInstanceData::ListIterator { items, current },
             } = &obj.data
             {
@@ -465,7 +476,7 @@ pub fn list_next(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::BuiltinInstance {
-                class_type: BuiltinClassType::List,
+                class_type: BuiltinClassType::ListIterator,
                 data: BuiltinInstanceData::ListIterator { items, current },
             } = &obj.data
             {
@@ -495,3 +506,89 @@ pub fn list_next(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
         )),
     }
 }
+
+/// list.__contains__(item): `item in xs`
+pub fn list_contains(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    match receiver {
+        Value::Object(obj) => {
+            if let ObjectData::List { items } = &obj.data {
+                for item in items.borrow().iter() {
+                    if super::super::utils::eq_vals(item, &args[0])? {
+                        return Ok(Value::Bool(true));
+                    }
+                }
+                Ok(Value::Bool(false))
+            } else {
+                Err(err(
+                    VmErrorKind::TypeError("list.__contains__"),
+                    "__contains__() requires a list".to_string(),
+                ))
+            }
+        }
+        _ => Err(err(
+            VmErrorKind::TypeError("list.__contains__"),
+            "__contains__() requires a list".to_string(),
+        )),
+    }
+}
+
+/// list.__add__(other): `[1] + [2]` → 새 list
+pub fn list_add(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_list_items(receiver, "__add__")?;
+    if let Value::Object(other) = &args[0]
+        && let ObjectData::List { items: other_items } = &other.data
+    {
+        let mut result = items.borrow().clone();
+        result.extend(other_items.borrow().iter().cloned());
+        return Ok(make_list(result));
+    }
+    Err(err(
+        VmErrorKind::TypeError("list.__add__"),
+        format!(
+            "can only concatenate list (not \"{}\") to list",
+            type_name(&args[0])
+        ),
+    ))
+}
+
+/// list.__mul__(n): `[0] * 3` → 새 list (음수는 빈 list)
+pub fn list_mul(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_list_items(receiver, "__mul__")?;
+    let times = repeat_count(&args[0])?;
+    let items = items.borrow();
+    limits::reserve_array::<Value>(limits::repeat_len(items.len(), times, "list")?)?;
+    Ok(make_list(
+        std::iter::repeat_n(items.iter(), times).flatten().cloned().collect(),
+    ))
+}
+
+/// Helper: list의 원소 RefCell
+fn expect_list_items<'a>(
+    receiver: &'a Value,
+    method: &'static str,
+) -> VmResult<&'a RefCell<Vec<Value>>> {
+    if let Value::Object(obj) = receiver
+        && let ObjectData::List { items } = &obj.data
+    {
+        return Ok(items);
+    }
+    Err(err(
+        VmErrorKind::TypeError("list"),
+        format!("{}() requires a list", method),
+    ))
+}
+
+/// Helper: 시퀀스 반복 횟수 (`seq * n`의 n)
+pub(super) fn repeat_count(n: &Value) -> VmResult<usize> {
+    match n {
+        Value::Int(i) => Ok((*i).max(0) as usize),
+        Value::Bool(b) => Ok(*b as usize),
+        other => Err(err(
+            VmErrorKind::TypeError("sequence repeat"),
+            format!(
+                "can't multiply sequence by non-int of type '{}'",
+                type_name(other)
+            ),
+        )),
+    }
+}
@@ -41,13 +41,13 @@ pub fn create_map(args: Vec<Value>) -> VmResult<Value> {
             ObjectData::List { items } => {
                 // ListIterator 생성
                 use super::super::value::BuiltinInstanceData;
-                use crate::builtins::TYPE_LIST;
+                use crate::builtins::TYPE_LIST_ITERATOR;
                 use std::cell::RefCell;
 
                 Value::Object(Rc::new(super::super::value::Object::new(
-                    TYPE_LIST,
+                    TYPE_LIST_ITERATOR,
                     ObjectData::BuiltinInstance {
-                        class_type: BuiltinClassType::List,
+                        class_type: BuiltinClassType::ListIterator,
                         data: BuiltinInstanceData::ListIterator {
                             items: Rc::new(RefCell::clone(items)),
                             current: RefCell::new(0),
@@ -101,7 +101,10 @@ fn is_callable(value: &Value) -> bool {
     match value {
         Value::Object(obj) => matches!(
             obj.data,
-            ObjectData::UserFunction { .. } | ObjectData::BuiltinClass { .. }
+            ObjectData::UserFunction { .. }
+                | ObjectData::BuiltinClass { .. }
+                | ObjectData::BuiltinFunction { .. }
+                | ObjectData::NativeFunction { .. }
         ),
         _ => false,
     }
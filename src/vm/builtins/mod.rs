@@ -2,36 +2,52 @@ pub mod assert;
 pub mod bool;
 pub mod dict;
 pub mod dict_methods;
+pub mod enumerate;
 pub mod filter;
 pub mod float;
+pub mod format;
 pub mod input;
 pub mod int;
+pub mod iterable;
 pub mod len;
 pub mod list;
 pub mod list_methods;
 pub mod map;
 pub mod none;
+pub mod numeric;
+pub mod object;
 pub mod print;
 pub mod range;
 pub mod set;
 pub mod set_methods;
 pub mod str;
 pub mod str_methods;
+pub mod sys;
 pub mod treeset;
 pub mod treeset_methods;
+pub mod tuple_methods;
+pub mod zip;
 
 #[cfg(test)]
 mod tests;
 
-use super::bytecode::Value;
+use super::bytecode::{Module, Value};
+use super::machine::Vm;
 use super::type_def::TypeDef;
 use super::{VmError, VmErrorKind, VmResult, err};
 use crate::builtins::{
-    BUILTIN_ASSERT_ID, BUILTIN_BOOL_ID, BUILTIN_DICT_ID, BUILTIN_FILTER_ID, BUILTIN_FLOAT_ID,
-    BUILTIN_INPUT_ID, BUILTIN_INT_ID, BUILTIN_LEN_ID, BUILTIN_LIST_ID, BUILTIN_MAP_ID,
-    BUILTIN_PRINT_ID, BUILTIN_RANGE_ID, BUILTIN_SET_ID, BUILTIN_STR_ID, BUILTIN_TREESET_ID,
+    BUILTIN_ABS_ID, BUILTIN_ALL_ID, BUILTIN_ANY_ID, BUILTIN_ASSERT_ID, BUILTIN_BIN_ID,
+    BUILTIN_BOOL_ID, BUILTIN_CHR_ID, BUILTIN_DICT_ID, BUILTIN_DIVMOD_ID, BUILTIN_ENUMERATE_ID,
+    BUILTIN_FILTER_ID, BUILTIN_FLOAT_ID, BUILTIN_FORMAT_ID, BUILTIN_HASH_ID, BUILTIN_HEX_ID,
+    BUILTIN_ID_ID, BUILTIN_INPUT_ID, BUILTIN_INT_ID, BUILTIN_ISINSTANCE_ID, BUILTIN_ITER_ID,
+    BUILTIN_LEN_ID, BUILTIN_LIST_ID, BUILTIN_MAP_ID, BUILTIN_MAX_ID, BUILTIN_MIN_ID,
+    BUILTIN_NEXT_ID, BUILTIN_ORD_ID, BUILTIN_POW_ID, BUILTIN_PRINT_ID, BUILTIN_RANGE_ID,
+    BUILTIN_REPR_ID, BUILTIN_REVERSED_ID, BUILTIN_ROUND_ID, BUILTIN_SET_ID, BUILTIN_SORTED_ID,
+    BUILTIN_STR_ID, BUILTIN_SUM_ID, BUILTIN_TREESET_ID, BUILTIN_TUPLE_ID, BUILTIN_TYPE_ID,
+    BUILTIN_ZIP_ID,
 };
 use crate::runtime_io::RuntimeIo;
+use std::collections::HashMap;
 
 // ========== Builtin 호출 디스패처 ==========
 /// Builtin 함수 호출
@@ -49,10 +65,18 @@ pub fn call_builtin<IO: RuntimeIo>(id: u8, args: Vec<Value>, io: &mut IO) -> VmR
         BUILTIN_FILTER_ID => filter::create_filter(args),
         BUILTIN_SET_ID => set::call(args),
         BUILTIN_TREESET_ID => treeset::call(args),
-        // TODO: list()와 dict() 생성자는 나중에 구현
-        // BUILTIN_LIST_ID => list::call(args),
-        // BUILTIN_DICT_ID => dict::call(args),
         BUILTIN_ASSERT_ID => assert::call(args),
+        BUILTIN_FORMAT_ID => format::call(args),
+        BUILTIN_ABS_ID => numeric::abs(args),
+        BUILTIN_ROUND_ID => numeric::round(args),
+        BUILTIN_DIVMOD_ID => numeric::divmod(args),
+        BUILTIN_HEX_ID => numeric::hex(args),
+        BUILTIN_BIN_ID => numeric::bin(args),
+        BUILTIN_CHR_ID => object::chr(args),
+        BUILTIN_ORD_ID => object::ord(args),
+        BUILTIN_REPR_ID => object::repr(args),
+        BUILTIN_HASH_ID => object::hash(args),
+        BUILTIN_ID_ID => object::id(args),
         _ => Err(err(
             VmErrorKind::TypeError("builtin"),
             format!("unknown builtin id {}", id),
@@ -60,6 +84,70 @@ pub fn call_builtin<IO: RuntimeIo>(id: u8, args: Vec<Value>, io: &mut IO) -> VmR
     }
 }
 
+/// VM이 필요한 builtin 호출 (iterable 순회, 사용자 정의 메서드/연산자 호출)
+///
+/// 나머지 builtin은 `call_builtin`으로 넘깁니다.
+pub fn call_builtin_with_vm<IO: RuntimeIo>(
+    id: u8,
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    match id {
+        BUILTIN_LIST_ID => iterable::list(args, module, vm, io),
+        BUILTIN_DICT_ID => iterable::dict(args, module, vm, io),
+        BUILTIN_TUPLE_ID => iterable::tuple(args, module, vm, io),
+        BUILTIN_MIN_ID => iterable::min(args, module, vm, io),
+        BUILTIN_MAX_ID => iterable::max(args, module, vm, io),
+        BUILTIN_SUM_ID => iterable::sum(args, module, vm, io),
+        BUILTIN_SORTED_ID => iterable::sorted(args, &HashMap::new(), module, vm, io),
+        BUILTIN_REVERSED_ID => iterable::reversed(args, module, vm, io),
+        BUILTIN_ANY_ID => iterable::any(args, module, vm, io),
+        BUILTIN_ALL_ID => iterable::all(args, module, vm, io),
+        BUILTIN_ITER_ID => iterable::iter(args, module, vm, io),
+        BUILTIN_NEXT_ID => iterable::next(args, module, vm, io),
+        BUILTIN_POW_ID => iterable::pow(args, module, vm, io),
+        BUILTIN_ENUMERATE_ID => enumerate::create_enumerate(args, module, vm, io),
+        BUILTIN_ZIP_ID => zip::create_zip(args, module, vm, io),
+        BUILTIN_TYPE_ID => object::type_of(args, module),
+        BUILTIN_ISINSTANCE_ID => object::isinstance(args, module),
+        _ => call_builtin(id, args, io),
+    }
+}
+
+/// 키워드 인자가 있는 builtin 호출 (`sorted(xs, key=f, reverse=True)`)
+///
+/// 받을 수 있는 키워드는 `BuiltinFunction::accepts_keyword`가 정합니다.
+pub fn call_builtin_kw<IO: RuntimeIo>(
+    id: u8,
+    args: Vec<Value>,
+    kwargs: HashMap<String, Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let builtin = crate::builtins::lookup_by_id(id);
+    if let Some(name) = kwargs
+        .keys()
+        .find(|name| !builtin.is_some_and(|bi| bi.accepts_keyword(name)))
+    {
+        return Err(err(
+            VmErrorKind::TypeError("builtin"),
+            format!(
+                "{}() got an unexpected keyword argument '{}'",
+                builtin.map_or("<builtin>", |bi| bi.name),
+                name
+            ),
+        ));
+    }
+
+    match id {
+        BUILTIN_SORTED_ID => iterable::sorted(args, &kwargs, module, vm, io),
+        _ => call_builtin_with_vm(id, args, module, vm, io),
+    }
+}
+
 // ========== 헬퍼 함수들 ==========
 
 // 유틸리티 함수들은 vm::utils에서 재export
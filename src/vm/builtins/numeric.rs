@@ -0,0 +1,280 @@
+//! 숫자 builtin 함수: abs, round, pow(3인자), divmod, hex, bin
+
+use super::super::bytecode::Value;
+use super::super::utils::{float_repr, make_string, make_tuple};
+use super::super::{VmError, VmErrorKind, VmResult, err};
+use super::{float, int, type_name};
+
+/// abs(x)
+pub fn abs(args: Vec<Value>) -> VmResult<Value> {
+    match &args[0] {
+        Value::Int(i) => i.checked_abs().map(Value::Int).ok_or_else(|| {
+            err(
+                VmErrorKind::TypeError("abs"),
+                format!("OverflowError: abs({}) is too large for a 64-bit integer", i),
+            )
+        }),
+        Value::Bool(b) => Ok(Value::Int(*b as i64)),
+        Value::Float(f) => Ok(Value::Float(f.abs())),
+        other => Err(err(
+            VmErrorKind::TypeError("abs"),
+            format!("bad operand type for abs(): '{}'", type_name(other)),
+        )),
+    }
+}
+
+/// round(x) / round(x, ndigits)
+///
+/// Python처럼 정확히 중간값이면 짝수 쪽으로 반올림합니다 (`round(2.5) == 2`).
+/// `ndigits`가 없으면 int, 있으면 x와 같은 타입을 반환합니다.
+pub fn round(args: Vec<Value>) -> VmResult<Value> {
+    let ndigits = match args.get(1) {
+        None | Some(Value::None) => None,
+        Some(Value::Int(n)) => Some(*n),
+        Some(other) => {
+            return Err(err(
+                VmErrorKind::TypeError("round"),
+                format!(
+                    "'{}' object cannot be interpreted as an integer",
+                    type_name(other)
+                ),
+            ));
+        }
+    };
+
+    match (&args[0], ndigits) {
+        (Value::Int(i), None) => Ok(Value::Int(*i)),
+        (Value::Bool(b), None) => Ok(Value::Int(*b as i64)),
+        (Value::Int(i), Some(n)) => round_int(*i, n).map(Value::Int),
+        (Value::Bool(b), Some(n)) => round_int(*b as i64, n).map(Value::Int),
+        (Value::Float(f), None) => {
+            let rounded = f.round_ties_even();
+            if !rounded.is_finite() {
+                return Err(err(
+                    VmErrorKind::TypeError("round"),
+                    format!("OverflowError: cannot convert float {} to integer", f),
+                ));
+            }
+            // -2^63 <= rounded < 2^63 이어야 i64로 정확히 바뀜 (`as`는 조용히 포화됨)
+            if !(-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&rounded) {
+                return Err(err(
+                    VmErrorKind::TypeError("round"),
+                    format!("OverflowError: round({}) is too large for a 64-bit integer", float_repr(*f)),
+                ));
+            }
+            Ok(Value::Int(rounded as i64))
+        }
+        (Value::Float(f), Some(n)) => round_float(*f, n).map(Value::Float),
+        (other, _) => Err(err(
+            VmErrorKind::TypeError("round"),
+            format!(
+                "type {} doesn't define __round__ method",
+                type_name(other)
+            ),
+        )),
+    }
+}
+
+/// 정수를 10^-ndigits 단위로 반올림 (`round(1250, -2) == 1200`)
+fn round_int(i: i64, ndigits: i64) -> VmResult<i64> {
+    if ndigits >= 0 {
+        return Ok(i);
+    }
+    // 10^19는 i64를 넘으므로 모든 i64가 0으로 반올림됨 (|i| < 10^19 / 2)
+    let Some(unit) = u32::try_from(ndigits.unsigned_abs()).ok().and_then(|e| 10i64.checked_pow(e)) else {
+        return Ok(0);
+    };
+    let (q, r) = (i.div_euclid(unit), i.rem_euclid(unit));
+    let q = match (r * 2).cmp(&unit) {
+        std::cmp::Ordering::Less => q,
+        std::cmp::Ordering::Greater => q + 1,
+        std::cmp::Ordering::Equal => q + q.rem_euclid(2),
+    };
+    q.checked_mul(unit).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("round"),
+            format!("OverflowError: round({}, {}) is too large for a 64-bit integer", i, ndigits),
+        )
+    })
+}
+
+/// 실수를 소수점 아래 `ndigits`자리로 반올림
+///
+/// `f * 10^n`은 곱셈에서 다시 반올림되므로 (`2.675`는 실제로 2.67499...인데 `* 100`은 `267.5`),
+/// f의 정확한 십진 값에서 반올림(짝수 쪽)해 다시 파싱합니다. 양수 자릿수는 `{:.n}`,
+/// 음수 자릿수는 정수 부분의 자릿수 문자열을 씁니다.
+fn round_float(f: f64, ndigits: i64) -> VmResult<f64> {
+    // 2^52 이상은 이미 정수이고, 가장 작은 subnormal도 소수점 아래 330자리 안에 들어감
+    if !f.is_finite() || (ndigits >= 0 && f.abs() >= 4_503_599_627_370_496.0) || ndigits > 330 {
+        return Ok(f);
+    }
+    if ndigits >= 0 {
+        return Ok(format!("{:.*}", ndigits as usize, f).parse().unwrap_or(f));
+    }
+    // 가장 큰 float도 309자리이므로 10^309 이상 단위로는 0 (부호는 유지)
+    if ndigits < -309 {
+        return Ok(0.0f64.copysign(f));
+    }
+    let a = f.abs();
+    let digits = format!("{:.0}", a.trunc()); // 정수 부분은 정확히 출력됨
+    let k = ndigits.unsigned_abs() as usize;
+    if digits.len() < k {
+        return Ok(0.0f64.copysign(f));
+    }
+    let (head, tail) = digits.split_at(digits.len() - k);
+    let half = format!("5{}", "0".repeat(k - 1));
+    // 버린 부분이 정확히 절반이면 소수 부분이 남았는지로 판단
+    let cmp = tail
+        .cmp(half.as_str())
+        .then(if a.fract() > 0.0 { std::cmp::Ordering::Greater } else { std::cmp::Ordering::Equal });
+    let head_odd = head.bytes().last().is_some_and(|d| (d - b'0') % 2 == 1);
+    let head = match cmp {
+        std::cmp::Ordering::Greater => increment_digits(head),
+        std::cmp::Ordering::Equal if head_odd => increment_digits(head),
+        _ => head.to_string(),
+    };
+    let rounded: f64 = format!("{}{}", head, "0".repeat(k)).parse().unwrap_or(a);
+    if rounded.is_infinite() {
+        return Err(err(
+            VmErrorKind::TypeError("round"),
+            "OverflowError: rounded value too large to represent".into(),
+        ));
+    }
+    Ok(rounded.copysign(f))
+}
+
+/// 십진 자릿수 문자열에 1을 더함 (`"199"` → `"200"`, `""` → `"1"`)
+fn increment_digits(digits: &str) -> String {
+    let mut bytes = digits.as_bytes().to_vec();
+    for b in bytes.iter_mut().rev() {
+        if *b == b'9' {
+            *b = b'0';
+        } else {
+            *b += 1;
+            return String::from_utf8(bytes).unwrap_or_default();
+        }
+    }
+    format!("1{}", String::from_utf8(bytes).unwrap_or_default())
+}
+
+/// pow(base, exp, mod): 모듈러 거듭제곱 (정수 전용)
+///
+/// 결과의 부호는 Python처럼 `mod`를 따릅니다.
+pub fn pow_mod(base: &Value, exp: &Value, modulus: &Value) -> VmResult<Value> {
+    let (Some(base), Some(exp), Some(modulus)) = (as_int(base), as_int(exp), as_int(modulus)) else {
+        return Err(err(
+            VmErrorKind::TypeError("pow"),
+            "pow() 3rd argument not allowed unless all arguments are integers".into(),
+        ));
+    };
+    if modulus == 0 {
+        return Err(err(
+            VmErrorKind::TypeError("pow"),
+            "ValueError: pow() 3rd argument cannot be 0".into(),
+        ));
+    }
+    if exp < 0 {
+        return Err(err(
+            VmErrorKind::TypeError("pow"),
+            "ValueError: pow() negative exponent is not supported with a modulus".into(),
+        ));
+    }
+
+    let m = (modulus as i128).abs();
+    let mut result: i128 = 1 % m;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    if modulus < 0 && result != 0 {
+        result -= m;
+    }
+    Ok(Value::Int(result as i64))
+}
+
+/// divmod(a, b): `(a // b, a % b)`
+///
+/// `//`, `%` 연산자와 같은 규칙을 사용하므로 항상 `q * b + r == a`입니다.
+pub fn divmod(args: Vec<Value>) -> VmResult<Value> {
+    let (a, b) = (&args[0], &args[1]);
+    if let (Some(x), Some(y)) = (as_int(a), as_int(b)) {
+        if y == 0 {
+            return Err(err(
+                VmErrorKind::ZeroDivision,
+                "integer division or modulo by zero".into(),
+            ));
+        }
+        return Ok(make_tuple(vec![
+            Value::Int(int::floor_div(x, y)),
+            Value::Int(int::floor_mod(x, y)),
+        ]));
+    }
+    if let (Some(x), Some(y)) = (as_float(a), as_float(b)) {
+        if y == 0.0 {
+            return Err(err(
+                VmErrorKind::ZeroDivision,
+                "float divmod()".into(),
+            ));
+        }
+        return Ok(make_tuple(vec![
+            Value::Float((x / y).floor()),
+            Value::Float(float::floor_mod(x, y)),
+        ]));
+    }
+    Err(err(
+        VmErrorKind::TypeError("divmod"),
+        format!(
+            "unsupported operand type(s) for divmod(): '{}' and '{}'",
+            type_name(a),
+            type_name(b)
+        ),
+    ))
+}
+
+/// hex(x): `'0xff'`, 음수는 `'-0xff'`
+pub fn hex(args: Vec<Value>) -> VmResult<Value> {
+    let i = expect_index(&args[0])?;
+    let sign = if i < 0 { "-" } else { "" };
+    Ok(make_string(format!("{}0x{:x}", sign, i.unsigned_abs())))
+}
+
+/// bin(x): `'0b101'`, 음수는 `'-0b101'`
+pub fn bin(args: Vec<Value>) -> VmResult<Value> {
+    let i = expect_index(&args[0])?;
+    let sign = if i < 0 { "-" } else { "" };
+    Ok(make_string(format!("{}0b{:b}", sign, i.unsigned_abs())))
+}
+
+/// int와 bool은 정수로 취급
+fn as_int(v: &Value) -> Option<i64> {
+    match v {
+        Value::Int(i) => Some(*i),
+        Value::Bool(b) => Some(*b as i64),
+        _ => None,
+    }
+}
+
+fn as_float(v: &Value) -> Option<f64> {
+    match v {
+        Value::Float(f) => Some(*f),
+        _ => as_int(v).map(|i| i as f64),
+    }
+}
+
+/// hex/bin 인자: 정수만 허용 (float는 TypeError)
+fn expect_index(v: &Value) -> VmResult<i64> {
+    as_int(v).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("index"),
+            format!(
+                "'{}' object cannot be interpreted as an integer",
+                type_name(v)
+            ),
+        )
+    })
+}
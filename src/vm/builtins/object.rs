@@ -0,0 +1,216 @@
+//! 객체 builtin 함수: chr, ord, repr, hash, id, type, isinstance
+
+use super::super::bytecode::{Module, Value};
+use super::super::utils::{make_builtin_class, make_string, make_user_class, repr_value};
+use super::super::value::ObjectData;
+use super::super::{VmError, VmErrorKind, VmResult, err};
+use super::type_name;
+use crate::builtins::BuiltinClassType;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+/// chr(i): 유니코드 코드 포인트 → 한 글자 문자열
+pub fn chr(args: Vec<Value>) -> VmResult<Value> {
+    let code = match &args[0] {
+        Value::Int(i) => *i,
+        Value::Bool(b) => *b as i64,
+        other => {
+            return Err(err(
+                VmErrorKind::TypeError("chr"),
+                format!(
+                    "'{}' object cannot be interpreted as an integer",
+                    type_name(other)
+                ),
+            ));
+        }
+    };
+    u32::try_from(code)
+        .ok()
+        .and_then(char::from_u32)
+        .map(|c| make_string(c.to_string()))
+        .ok_or_else(|| {
+            err(
+                VmErrorKind::TypeError("chr"),
+                "ValueError: chr() arg not in range(0x110000)".into(),
+            )
+        })
+}
+
+/// ord(c): 한 글자 문자열 → 유니코드 코드 포인트
+pub fn ord(args: Vec<Value>) -> VmResult<Value> {
+    if let Value::Object(obj) = &args[0]
+        && let ObjectData::String(s) = &obj.data
+    {
+        let mut chars = s.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(Value::Int(c as i64)),
+            _ => Err(err(
+                VmErrorKind::TypeError("ord"),
+                format!(
+                    "ord() expected a character, but string of length {} found",
+                    s.chars().count()
+                ),
+            )),
+        };
+    }
+    Err(err(
+        VmErrorKind::TypeError("ord"),
+        format!(
+            "ord() expected string of length 1, but {} found",
+            type_name(&args[0])
+        ),
+    ))
+}
+
+/// repr(x)
+pub fn repr(args: Vec<Value>) -> VmResult<Value> {
+    Ok(make_string(repr_value(&args[0])))
+}
+
+/// hash(x)
+///
+/// 같은 값(`==`)은 같은 해시를 가집니다: `hash(1) == hash(1.0) == hash(True)`.
+/// list, dict, set처럼 변경 가능한 컨테이너는 해시할 수 없습니다.
+pub fn hash(args: Vec<Value>) -> VmResult<Value> {
+    hash_value(&args[0]).map(Value::Int)
+}
+
+fn hash_value(v: &Value) -> VmResult<i64> {
+    match v {
+        Value::Int(i) => Ok(*i),
+        Value::Bool(b) => Ok(*b as i64),
+        Value::Float(f) if f.fract() == 0.0 && f.abs() < i64::MAX as f64 => Ok(*f as i64),
+        Value::Float(f) => Ok(hash_of(&f.to_bits())),
+        Value::None => Ok(hash_of(&"None")),
+        Value::Object(obj) => match &obj.data {
+            ObjectData::String(s) => Ok(hash_of(s)),
+            ObjectData::Tuple { items } => {
+                let hashes = items.iter().map(hash_value).collect::<VmResult<Vec<_>>>()?;
+                Ok(hash_of(&hashes))
+            }
+            ObjectData::List { .. }
+            | ObjectData::Dict { .. }
+            | ObjectData::Set { .. }
+            | ObjectData::TreeSet { .. } => Err(err(
+                VmErrorKind::TypeError("hash"),
+                format!("unhashable type: '{}'", type_name(v)),
+            )),
+            // 그 외 객체는 identity 기반
+            _ => Ok(object_id(v)),
+        },
+    }
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> i64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish() as i64
+}
+
+/// id(x)
+///
+/// 객체는 주소를 돌려줍니다. int, float 같은 값 타입은 `is`가 값으로 비교되므로
+/// id도 값에서 만들어 `a is b`이면 `id(a) == id(b)`가 성립하게 합니다.
+pub fn id(args: Vec<Value>) -> VmResult<Value> {
+    match &args[0] {
+        v @ Value::Object(_) => Ok(Value::Int(object_id(v))),
+        Value::Float(f) => Ok(Value::Int(hash_of(&("float", f.to_bits())))),
+        v => Ok(Value::Int(hash_of(&(type_name(v), hash_value(v)?)))),
+    }
+}
+
+fn object_id(v: &Value) -> i64 {
+    match v {
+        Value::Object(obj) => Rc::as_ptr(obj) as usize as i64,
+        _ => 0,
+    }
+}
+
+/// type(x): 값의 타입 객체
+pub fn type_of(args: Vec<Value>, module: &Module) -> VmResult<Value> {
+    let v = &args[0];
+    let class_type = match v {
+        Value::Int(_) => BuiltinClassType::Int,
+        Value::Float(_) => BuiltinClassType::Float,
+        Value::Bool(_) => BuiltinClassType::Bool,
+        Value::None => BuiltinClassType::NoneType,
+        Value::Object(obj) => match &obj.data {
+            ObjectData::String(_) => BuiltinClassType::Str,
+            ObjectData::List { .. } => BuiltinClassType::List,
+            ObjectData::Tuple { .. } => BuiltinClassType::Tuple,
+            ObjectData::Dict { .. } => BuiltinClassType::Dict,
+            ObjectData::Set { .. } => BuiltinClassType::Set,
+            ObjectData::TreeSet { .. } => BuiltinClassType::TreeSet,
+            ObjectData::BuiltinInstance { class_type, .. } => *class_type,
+            ObjectData::UserFunction { .. } => BuiltinClassType::Function,
+            ObjectData::Module { .. } => BuiltinClassType::Module,
+            ObjectData::Stream { .. } => BuiltinClassType::TextIO,
+            ObjectData::BuiltinFunction { .. }
+            | ObjectData::NativeFunction { is_class: false, .. } => BuiltinClassType::BuiltinFunction,
+            ObjectData::UserClass { .. }
+            | ObjectData::BuiltinClass { .. }
+            | ObjectData::NativeFunction { is_class: true, .. } => {
+                BuiltinClassType::Type
+            }
+            ObjectData::UserInstance { class_id, class_name } => {
+                let class_def = &module.classes[*class_id as usize];
+                return Ok(make_user_class(
+                    *class_id,
+                    class_name.clone(),
+                    class_def.methods.clone(),
+                ));
+            }
+            ObjectData::NativeInstance { class, .. } => {
+                return Err(err(
+                    VmErrorKind::TypeError("type"),
+                    format!("type() is not supported for native '{}' objects", class.name),
+                ));
+            }
+        },
+    };
+    Ok(make_builtin_class(class_type))
+}
+
+/// isinstance(obj, classinfo): classinfo는 타입 객체 또는 타입 객체의 tuple
+///
+/// Python처럼 bool은 int의 하위 타입으로 취급합니다.
+pub fn isinstance(args: Vec<Value>, module: &Module) -> VmResult<Value> {
+    let actual = type_of(vec![args[0].clone()], module)?;
+    let candidates = match &args[1] {
+        Value::Object(obj) => match &obj.data {
+            ObjectData::Tuple { items } => items.clone(),
+            _ => vec![args[1].clone()],
+        },
+        _ => vec![args[1].clone()],
+    };
+
+    for candidate in &candidates {
+        if !is_type_object(candidate) {
+            return Err(err(
+                VmErrorKind::TypeError("isinstance"),
+                "isinstance() arg 2 must be a type or tuple of types".into(),
+            ));
+        }
+        if super::super::utils::eq_vals(&actual, candidate)? {
+            return Ok(Value::Bool(true));
+        }
+        if matches!(args[0], Value::Bool(_))
+            && let Value::Object(obj) = candidate
+            && let ObjectData::BuiltinClass {
+                class_type: BuiltinClassType::Int,
+            } = &obj.data
+        {
+            return Ok(Value::Bool(true));
+        }
+    }
+    Ok(Value::Bool(false))
+}
+
+fn is_type_object(v: &Value) -> bool {
+    matches!(
+        v,
+        Value::Object(obj)
+            if matches!(obj.data, ObjectData::BuiltinClass { .. } | ObjectData::UserClass { .. })
+    )
+}
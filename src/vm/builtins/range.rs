@@ -132,6 +132,60 @@ pub fn range_has_next(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     }
 }
 
+/// `current`부터 남은 원소 수
+pub fn remaining(current: i64, stop: i64, step: i64) -> usize {
+    let (current, stop, step) = (current as i128, stop as i128, step as i128);
+    let len = if step > 0 && current < stop {
+        (stop - current - 1) / step + 1
+    } else if step < 0 && current > stop {
+        (current - stop - 1) / -step + 1
+    } else {
+        0
+    };
+    usize::try_from(len).unwrap_or(usize::MAX)
+}
+
+/// range.__contains__(x): 순회하지 않고 산술로 판정
+pub fn range_contains(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let x = match &args[0] {
+        Value::Int(n) => *n,
+        Value::Bool(b) => *b as i64,
+        Value::Float(f) if f.fract() == 0.0 => *f as i64,
+        _ => return Ok(Value::Bool(false)),
+    };
+    match receiver {
+        Value::Object(obj) => match &obj.data {
+            ObjectData::BuiltinInstance {
+                data:
+                    BuiltinInstanceData::Range {
+                        current,
+                        stop,
+                        step,
+                    },
+                ..
+            } => {
+                let start = *current.borrow();
+                let in_bounds = if *step > 0 {
+                    start <= x && x < *stop
+                } else if *step < 0 {
+                    *stop < x && x <= start
+                } else {
+                    false
+                };
+                Ok(Value::Bool(in_bounds && (x - start) % step == 0))
+            }
+            _ => Err(err(
+                VmErrorKind::TypeError("range"),
+                "expected Range object".into(),
+            )),
+        },
+        _ => Err(err(
+            VmErrorKind::TypeError("range"),
+            "expected Range".into(),
+        )),
+    }
+}
+
 pub fn range_next(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     if !args.is_empty() {
         return Err(err(
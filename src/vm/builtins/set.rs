@@ -12,6 +12,7 @@ use std::rc::Rc;
 ///
 /// set() - 빈 set 생성
 /// set(iterable) - iterable의 원소들로 set 생성
+#[allow(clippy::useless_format)]
 pub fn call(args: Vec<Value>) -> VmResult<Value> {
     if args.is_empty() {
         // 빈 set 생성
@@ -75,6 +75,7 @@ pub fn set_add(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
 }
 
 /// set.remove(item)
+#[allow(clippy::useless_format)]
 pub fn set_remove(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     if args.len() != 1 {
         return Err(err(
@@ -158,24 +159,7 @@ pub fn set_union(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::Set { items } = &obj.data {
-                let other_set = match &args[0] {
-                    Value::Object(other_obj) => {
-                        if let ObjectData::Set { items: other_items } = &other_obj.data {
-                            other_items.borrow().clone()
-                        } else {
-                            return Err(err(
-                                VmErrorKind::TypeError("set.union"),
-                                "union() requires a set argument".to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(err(
-                            VmErrorKind::TypeError("set.union"),
-                            "union() requires a set argument".to_string(),
-                        ));
-                    }
-                };
+                let other_set = other_set_keys(&args[0], "union", false)?;
 
                 let mut result = items.borrow().clone();
                 result.extend(other_set);
@@ -215,24 +199,7 @@ pub fn set_intersection(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::Set { items } = &obj.data {
-                let other_set = match &args[0] {
-                    Value::Object(other_obj) => {
-                        if let ObjectData::Set { items: other_items } = &other_obj.data {
-                            other_items.borrow().clone()
-                        } else {
-                            return Err(err(
-                                VmErrorKind::TypeError("set.intersection"),
-                                "intersection() requires a set argument".to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(err(
-                            VmErrorKind::TypeError("set.intersection"),
-                            "intersection() requires a set argument".to_string(),
-                        ));
-                    }
-                };
+                let other_set = other_set_keys(&args[0], "intersection", false)?;
 
                 let result: HashSet<SetKey> = items
                     .borrow()
@@ -275,24 +242,7 @@ pub fn set_difference(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::Set { items } = &obj.data {
-                let other_set = match &args[0] {
-                    Value::Object(other_obj) => {
-                        if let ObjectData::Set { items: other_items } = &other_obj.data {
-                            other_items.borrow().clone()
-                        } else {
-                            return Err(err(
-                                VmErrorKind::TypeError("set.difference"),
-                                "difference() requires a set argument".to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(err(
-                            VmErrorKind::TypeError("set.difference"),
-                            "difference() requires a set argument".to_string(),
-                        ));
-                    }
-                };
+                let other_set = other_set_keys(&args[0], "difference", false)?;
 
                 let result: HashSet<SetKey> = items
                     .borrow()
@@ -369,6 +319,12 @@ pub fn set_copy(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
 
 /// set.__iter__()
 pub fn set_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    // iterator의 __iter__는 자기 자신 (`iter(iter(xs))`)
+    if let Value::Object(obj) = receiver
+        && matches!(obj.data, ObjectData::BuiltinInstance { .. })
+    {
+        return Ok(receiver.clone());
+    }
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::Set { items } = &obj.data {
@@ -453,3 +409,132 @@ pub fn set_next(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
     }
 }
 
+// ========== 집합 연산자와 추가 메서드 ==========
+
+/// set.symmetric_difference(other) / `a ^ b`
+pub fn set_symmetric_difference(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "symmetric_difference")?;
+    let other = other_set_keys(&args[0], "symmetric_difference", false)?;
+    let result = items.borrow().symmetric_difference(&other).cloned().collect();
+    Ok(make_set(result))
+}
+
+/// set.issubset(other) / `a <= b`
+pub fn set_issubset(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "issubset")?;
+    let other = other_set_keys(&args[0], "issubset", false)?;
+    Ok(Value::Bool(items.borrow().is_subset(&other)))
+}
+
+/// set.issuperset(other) / `a >= b`
+pub fn set_issuperset(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "issuperset")?;
+    let other = other_set_keys(&args[0], "issuperset", false)?;
+    Ok(Value::Bool(items.borrow().is_superset(&other)))
+}
+
+/// set.__lt__(other): 진부분집합 `a < b`
+pub fn set_lt(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "__lt__")?;
+    let other = other_set_keys(&args[0], "__lt__", false)?;
+    let items = items.borrow();
+    Ok(Value::Bool(items.len() < other.len() && items.is_subset(&other)))
+}
+
+/// set.__gt__(other): 진상위집합 `a > b`
+pub fn set_gt(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "__gt__")?;
+    let other = other_set_keys(&args[0], "__gt__", false)?;
+    let items = items.borrow();
+    Ok(Value::Bool(items.len() > other.len() && items.is_superset(&other)))
+}
+
+/// set.discard(item): remove()와 달리 원소가 없어도 에러가 아님
+pub fn set_discard(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "discard")?;
+    let key = value_to_set_key(&args[0])?;
+    items.borrow_mut().remove(&key);
+    Ok(Value::None)
+}
+
+/// set.pop(): 임의의 원소 하나를 꺼냄
+pub fn set_pop(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let mut items = expect_set(receiver, "pop")?.borrow_mut();
+    let key = items.iter().next().cloned();
+    if let Some(key) = &key {
+        items.remove(key);
+    }
+    key.map(|k| set_key_to_value(&k)).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("set.pop"),
+            "KeyError: 'pop from an empty set'".to_string(),
+        )
+    })
+}
+
+/// set.update(*iterables): set, treeset, list, tuple들의 원소를 모두 추가
+pub fn set_update(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_set(receiver, "update")?;
+    for arg in &args {
+        // 자기 자신으로 update해도 되도록 먼저 모아 둠
+        let other = other_set_keys(arg, "update", true)?;
+        items.borrow_mut().extend(other);
+    }
+    Ok(Value::None)
+}
+
+/// Helper: receiver가 set인지 확인
+fn expect_set<'a>(
+    receiver: &'a Value,
+    method: &'static str,
+) -> VmResult<&'a RefCell<HashSet<SetKey>>> {
+    if let Value::Object(obj) = receiver
+        && let ObjectData::Set { items } = &obj.data
+    {
+        return Ok(items);
+    }
+    Err(err(
+        VmErrorKind::TypeError("set"),
+        format!("{}() requires a set", method),
+    ))
+}
+
+/// Helper: 다른 집합(set 또는 treeset)의 원소
+///
+/// `allow_sequences`이면 list, tuple도 받습니다 (`update`).
+fn other_set_keys(
+    value: &Value,
+    method: &'static str,
+    allow_sequences: bool,
+) -> VmResult<HashSet<SetKey>> {
+    if let Value::Object(obj) = value {
+        match &obj.data {
+            ObjectData::Set { items } => return Ok(items.borrow().iter().cloned().collect()),
+            ObjectData::TreeSet { items } => return Ok(items.borrow().iter().cloned().collect()),
+            ObjectData::List { items } if allow_sequences => {
+                return items.borrow().iter().map(value_to_set_key).collect();
+            }
+            ObjectData::Tuple { items } if allow_sequences => {
+                return items.iter().map(value_to_set_key).collect();
+            }
+            _ => {}
+        }
+    }
+    Err(err(
+        VmErrorKind::TypeError("set"),
+        format!(
+            "{}() argument must be a set, not '{}'",
+            method,
+            super::type_name(value)
+        ),
+    ))
+}
+
+fn make_set(items: HashSet<SetKey>) -> Value {
+    Value::Object(Rc::new(Object::new(
+        TYPE_SET,
+        ObjectData::Set {
+            items: RefCell::new(items),
+        },
+    )))
+}
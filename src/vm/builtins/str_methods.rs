@@ -1,9 +1,11 @@
 //! str methods implementation
 
 use super::super::bytecode::Value;
-use super::super::utils::{expect_list, expect_string, make_list, make_string};
+use super::super::utils::{
+    expect_list, expect_string, make_list, make_list_iterator, make_string, make_tuple,
+};
 use super::super::value::ObjectData;
-use super::super::{VmError, VmErrorKind, VmResult, err};
+use super::super::{VmError, VmErrorKind, VmResult, err, limits};
 use super::display_value;
 use crate::builtins::TYPE_STR;
 
@@ -54,52 +56,160 @@ pub fn str_lower(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     Ok(make_string(s.to_lowercase()))
 }
 
+/// str.strip(chars=None): 양쪽 끝의 공백(또는 chars에 속한 문자)을 제거
 pub fn str_strip(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
-    if !args.is_empty() {
-        return Err(err(
-            VmErrorKind::ArityError {
-                expected: 0,
-                got: args.len(),
-            },
-            format!("str.strip() takes 0 arguments but {} given", args.len()),
-        ));
-    }
+    strip_with(receiver, &args, "strip", (true, true))
+}
+
+/// str.lstrip(chars=None)
+pub fn str_lstrip(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    strip_with(receiver, &args, "lstrip", (true, false))
+}
+
+/// str.rstrip(chars=None)
+pub fn str_rstrip(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    strip_with(receiver, &args, "rstrip", (false, true))
+}
+
+/// `(left, right)`: 어느 쪽 끝을 제거할지
+fn strip_with(
+    receiver: &Value,
+    args: &[Value],
+    method: &'static str,
+    (left, right): (bool, bool),
+) -> VmResult<Value> {
     let s = expect_string(receiver)?;
-    Ok(make_string(s.trim().to_string()))
+    let chars = optional_str_arg(args.first(), method)?;
+    let strip = |c: char| chars.map_or(c.is_whitespace(), |chars| chars.contains(c));
+    let mut stripped = s;
+    if left {
+        stripped = stripped.trim_start_matches(strip);
+    }
+    if right {
+        stripped = stripped.trim_end_matches(strip);
+    }
+    Ok(make_string(stripped.to_string()))
 }
 
+/// str.split(sep=None, maxsplit=-1)
+///
+/// `sep`이 없으면 연속된 공백을 하나의 구분자로 보고 양 끝 공백은 무시합니다.
 pub fn str_split(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
-    if args.len() > 1 {
+    let s = expect_string(receiver)?;
+    let (sep, maxsplit) = split_args(&args, "split")?;
+    let parts: Vec<&str> = match (sep, maxsplit) {
+        (None, _) => split_whitespace(s, maxsplit),
+        (Some(sep), Some(n)) => s.splitn(n + 1, sep).collect(),
+        (Some(sep), None) => s.split(sep).collect(),
+    };
+    Ok(make_str_list(parts))
+}
+
+/// str.rsplit(sep=None, maxsplit=-1): 오른쪽부터 최대 maxsplit번 나눔
+pub fn str_rsplit(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let (sep, maxsplit) = split_args(&args, "rsplit")?;
+    let mut parts: Vec<&str> = match (sep, maxsplit) {
+        (None, _) => rsplit_whitespace(s, maxsplit),
+        (Some(sep), Some(n)) => s.rsplitn(n + 1, sep).collect(),
+        (Some(sep), None) => s.rsplit(sep).collect(),
+    };
+    parts.reverse();
+    Ok(make_str_list(parts))
+}
+
+/// str.splitlines(): `\n`, `\r\n`, `\r`에서 나누고 줄바꿈 문자는 버림
+pub fn str_splitlines(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' || c == '\r' {
+            lines.push(&s[start..i]);
+            start = i + 1;
+            if c == '\r' && chars.next_if(|&(_, next)| next == '\n').is_some() {
+                start += 1;
+            }
+        }
+    }
+    if start < s.len() {
+        lines.push(&s[start..]);
+    }
+    Ok(make_str_list(lines))
+}
+
+/// split/rsplit 인자: `(sep, maxsplit)`. 음수 maxsplit은 제한 없음
+fn split_args<'a>(
+    args: &'a [Value],
+    method: &'static str,
+) -> VmResult<(Option<&'a str>, Option<usize>)> {
+    let sep = optional_str_arg(args.first(), method)?;
+    if sep == Some("") {
         return Err(err(
-            VmErrorKind::ArityError {
-                expected: 1,
-                got: args.len(),
-            },
-            format!(
-                "str.split() takes 0 or 1 argument, but {} given",
-                args.len()
-            ),
+            VmErrorKind::TypeError("str.split"),
+            "ValueError: empty separator".into(),
         ));
     }
-    let sep = match args.get(0) {
-        None => " ",
-        Some(value) if expect_string(value).is_ok() => expect_string(value)?,
-        _ => {
+    let maxsplit = match args.get(1) {
+        None => None,
+        Some(Value::Int(n)) => usize::try_from(*n).ok(),
+        Some(other) => {
             return Err(err(
                 VmErrorKind::TypeError("str.split"),
                 format!(
-                    "str.split() takes 0 or 1 argument, but {} given",
-                    args.len()
+                    "'{}' object cannot be interpreted as an integer",
+                    super::type_name(other)
                 ),
             ));
         }
     };
-    let s = expect_string(receiver)?;
-    let result = s
-        .split(sep)
-        .map(|s| make_string(s.to_string()))
-        .collect::<Vec<Value>>();
-    Ok(make_list(result))
+    Ok((sep, maxsplit))
+}
+
+fn split_whitespace(s: &str, maxsplit: Option<usize>) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        if maxsplit.is_some_and(|n| parts.len() >= n) {
+            parts.push(rest);
+            break;
+        }
+        match rest.find(char::is_whitespace) {
+            Some(end) => {
+                parts.push(&rest[..end]);
+                rest = rest[end..].trim_start();
+            }
+            None => {
+                parts.push(rest);
+                break;
+            }
+        }
+    }
+    parts
+}
+
+/// 오른쪽부터 나눈 조각들 (역순)
+fn rsplit_whitespace(s: &str, maxsplit: Option<usize>) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = s.trim_end();
+    while !rest.is_empty() {
+        if maxsplit.is_some_and(|n| parts.len() >= n) {
+            parts.push(rest);
+            break;
+        }
+        match rest.char_indices().rev().find(|(_, c)| c.is_whitespace()) {
+            Some((start, c)) => {
+                parts.push(&rest[start + c.len_utf8()..]);
+                rest = rest[..start].trim_end();
+            }
+            None => {
+                parts.push(rest);
+                break;
+            }
+        }
+    }
+    parts
 }
 
 pub fn str_join(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
@@ -168,22 +278,48 @@ pub fn str_ends_with(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     Ok(Value::Bool(s.ends_with(suffix)))
 }
 
+/// str.find(sub): 처음 나타나는 문자 위치, 없으면 -1
 pub fn str_find(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
-    if args.len() != 1 {
-        return Err(err(
-            VmErrorKind::ArityError {
-                expected: 1,
-                got: args.len(),
-            },
-            format!("str.find() takes 1 argument but {} given", args.len()),
-        ));
-    }
     let s = expect_string(receiver)?;
-    let substr = expect_string(&args[0])?;
-    match s.find(substr) {
-        Some(pos) => Ok(Value::Int(pos as i64)),
-        None => Ok(Value::Int(-1)),
-    }
+    let sub = expect_string(&args[0])?;
+    Ok(Value::Int(s.find(sub).map_or(-1, |pos| char_pos(s, pos))))
+}
+
+/// str.rfind(sub): 마지막으로 나타나는 문자 위치, 없으면 -1
+pub fn str_rfind(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let sub = expect_string(&args[0])?;
+    Ok(Value::Int(s.rfind(sub).map_or(-1, |pos| char_pos(s, pos))))
+}
+
+/// str.index(sub): find()와 같지만 없으면 ValueError
+pub fn str_index(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let sub = expect_string(&args[0])?;
+    s.find(sub)
+        .map(|pos| Value::Int(char_pos(s, pos)))
+        .ok_or_else(substring_not_found)
+}
+
+/// str.rindex(sub): rfind()와 같지만 없으면 ValueError
+pub fn str_rindex(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let sub = expect_string(&args[0])?;
+    s.rfind(sub)
+        .map(|pos| Value::Int(char_pos(s, pos)))
+        .ok_or_else(substring_not_found)
+}
+
+/// 바이트 위치 → 문자 위치 (`s[i]`와 같은 기준)
+fn char_pos(s: &str, byte_pos: usize) -> i64 {
+    s[..byte_pos].chars().count() as i64
+}
+
+fn substring_not_found() -> VmError {
+    err(
+        VmErrorKind::TypeError("str.index"),
+        "ValueError: substring not found".into(),
+    )
 }
 
 pub fn str_count(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
@@ -202,8 +338,251 @@ pub fn str_count(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     Ok(Value::Int(count as i64))
 }
 
+/// str.partition(sep): `(앞, sep, 뒤)`, sep이 없으면 `(s, "", "")`
+pub fn str_partition(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let sep = expect_separator(&args[0])?;
+    Ok(match s.split_once(sep) {
+        Some((head, tail)) => make_str_tuple([head, sep, tail]),
+        None => make_str_tuple([s, "", ""]),
+    })
+}
+
+/// str.rpartition(sep): 마지막 sep 기준, sep이 없으면 `("", "", s)`
+pub fn str_rpartition(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let sep = expect_separator(&args[0])?;
+    Ok(match s.rsplit_once(sep) {
+        Some((head, tail)) => make_str_tuple([head, sep, tail]),
+        None => make_str_tuple(["", "", s]),
+    })
+}
+
+// ---------- 문자 분류 (빈 문자열은 모두 False) ----------
+
+pub fn str_isdigit(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    all_chars(receiver, char::is_numeric)
+}
+
+pub fn str_isalpha(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    all_chars(receiver, char::is_alphabetic)
+}
+
+pub fn str_isalnum(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    all_chars(receiver, char::is_alphanumeric)
+}
+
+pub fn str_isspace(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    all_chars(receiver, char::is_whitespace)
+}
+
+/// str.isupper(): 대소문자가 있는 문자가 하나 이상이고 모두 대문자
+pub fn str_isupper(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    Ok(Value::Bool(
+        s.chars().any(char::is_uppercase) && !s.chars().any(char::is_lowercase),
+    ))
+}
+
+/// str.islower(): 대소문자가 있는 문자가 하나 이상이고 모두 소문자
+pub fn str_islower(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    Ok(Value::Bool(
+        s.chars().any(char::is_lowercase) && !s.chars().any(char::is_uppercase),
+    ))
+}
+
+fn all_chars(receiver: &Value, pred: fn(char) -> bool) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    Ok(Value::Bool(!s.is_empty() && s.chars().all(pred)))
+}
+
+// ---------- 대소문자 변환 ----------
+
+/// str.title(): 단어(연속된 글자)마다 첫 글자만 대문자
+pub fn str_title(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let mut result = String::with_capacity(s.len());
+    let mut in_word = false;
+    for c in s.chars() {
+        if in_word {
+            result.extend(c.to_lowercase());
+        } else {
+            result.extend(c.to_uppercase());
+        }
+        in_word = c.is_alphabetic();
+    }
+    Ok(make_string(result))
+}
+
+/// str.capitalize(): 첫 글자만 대문자, 나머지는 소문자
+pub fn str_capitalize(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let mut chars = s.chars();
+    let result = match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.as_str().to_lowercase().chars()).collect(),
+        None => String::new(),
+    };
+    Ok(make_string(result))
+}
+
+// ---------- 정렬과 채우기 (width는 문자 수 기준) ----------
+
+/// str.center(width, fillchar=" ")
+pub fn str_center(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    pad(receiver, &args, "center", |margin, width| {
+        // CPython과 같은 규칙: 홀수 여백은 width가 홀수일 때 왼쪽에 더 줌
+        let left = margin / 2 + (margin & width & 1);
+        (left, margin - left)
+    })
+}
+
+/// str.ljust(width, fillchar=" ")
+pub fn str_ljust(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    pad(receiver, &args, "ljust", |margin, _| (0, margin))
+}
+
+/// str.rjust(width, fillchar=" ")
+pub fn str_rjust(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    pad(receiver, &args, "rjust", |margin, _| (margin, 0))
+}
+
+/// str.zfill(width): 부호 뒤를 0으로 채움 (`"-42".zfill(5) == "-0042"`)
+pub fn str_zfill(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let width = expect_width(&args[0])?;
+    let margin = width.saturating_sub(s.chars().count());
+    // 너비가 크면 할당 전에 MemoryError (할당이 실패하면 프로세스가 abort됨)
+    limits::reserve(margin.saturating_add(s.len()))?;
+    let (sign, digits) = match s.strip_prefix(['+', '-']) {
+        Some(rest) => s.split_at(s.len() - rest.len()),
+        None => ("", s),
+    };
+    Ok(make_string(format!("{}{}{}", sign, "0".repeat(margin), digits)))
+}
+
+fn pad(
+    receiver: &Value,
+    args: &[Value],
+    method: &'static str,
+    split: fn(usize, usize) -> (usize, usize),
+) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    let width = expect_width(&args[0])?;
+    let fill = match args.get(1) {
+        None => ' ',
+        Some(value) => {
+            let f = expect_string(value)?;
+            let mut chars = f.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(err(
+                        VmErrorKind::TypeError("str.pad"),
+                        format!(
+                            "{}(): the fill character must be exactly one character long",
+                            method
+                        ),
+                    ));
+                }
+            }
+        }
+    };
+    let margin = width.saturating_sub(s.chars().count());
+    limits::reserve(margin.saturating_mul(fill.len_utf8()).saturating_add(s.len()))?;
+    let (left, right) = split(margin, width);
+    let mut result = String::with_capacity(s.len() + margin);
+    result.extend(std::iter::repeat_n(fill, left));
+    result.push_str(s);
+    result.extend(std::iter::repeat_n(fill, right));
+    Ok(make_string(result))
+}
+
+fn expect_width(v: &Value) -> VmResult<usize> {
+    match v {
+        Value::Int(n) => Ok(usize::try_from(*n).unwrap_or(0)),
+        other => Err(err(
+            VmErrorKind::TypeError("str.pad"),
+            format!(
+                "'{}' object cannot be interpreted as an integer",
+                super::type_name(other)
+            ),
+        )),
+    }
+}
+
+// ---------- 인자/결과 헬퍼 ----------
+
+/// 생략 가능한 문자열 인자 (None도 생략으로 취급)
+fn optional_str_arg<'a>(arg: Option<&'a Value>, method: &'static str) -> VmResult<Option<&'a str>> {
+    match arg {
+        None | Some(Value::None) => Ok(None),
+        Some(value) => expect_string(value).map(Some).map_err(|_| {
+            err(
+                VmErrorKind::TypeError("str"),
+                format!(
+                    "{}() argument must be str or None, not {}",
+                    method,
+                    super::type_name(value)
+                ),
+            )
+        }),
+    }
+}
+
+fn expect_separator(v: &Value) -> VmResult<&str> {
+    let sep = expect_string(v)?;
+    if sep.is_empty() {
+        return Err(err(
+            VmErrorKind::TypeError("str.partition"),
+            "ValueError: empty separator".into(),
+        ));
+    }
+    Ok(sep)
+}
+
+fn make_str_list(parts: Vec<&str>) -> Value {
+    make_list(parts.into_iter().map(|p| make_string(p.to_string())).collect())
+}
+
+fn make_str_tuple(parts: [&str; 3]) -> Value {
+    make_tuple(parts.iter().map(|p| make_string(p.to_string())).collect())
+}
+
+/// str.format(*args): 키워드 인자는 CallMethodKw 경로에서 처리
+pub fn str_format(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let template = expect_string(receiver)?;
+    let formatted = super::super::format::str_format(template, &args, &Default::default())?;
+    Ok(make_string(formatted))
+}
+
 // ========== 매직 메서드 구현 ==========
 
+/// __contains__: `sub in s` (부분 문자열 검사)
+pub fn str_contains(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    match &args[0] {
+        Value::Object(obj) if matches!(obj.data, ObjectData::String(_)) => {
+            Ok(Value::Bool(s.contains(expect_string(&args[0])?)))
+        }
+        other => Err(err(
+            VmErrorKind::TypeError("str.__contains__"),
+            format!(
+                "'in <string>' requires string as left operand, not {}",
+                super::type_name(other)
+            ),
+        )),
+    }
+}
+
+/// __iter__: 문자 단위로 순회
+pub fn str_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let s = expect_string(receiver)?;
+    Ok(make_list_iterator(
+        s.chars().map(|c| make_string(c.to_string())).collect(),
+    ))
+}
+
 /// __add__: String + String (concatenation)
 pub fn str_add(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     let s1 = expect_string(receiver)?;
@@ -227,6 +606,7 @@ pub fn str_mul(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     if n < 0 {
         Ok(make_string(String::new()))
     } else {
+        limits::reserve(limits::repeat_len(s.len(), n as usize, "string")?)?;
         Ok(make_string(s.repeat(n as usize)))
     }
 }
@@ -272,3 +652,10 @@ pub fn str_ne(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     let s2 = expect_string(&args[0])?;
     Ok(Value::Bool(s1 != s2))
 }
+
+/// __mod__: String % value (printf 스타일 포매팅)
+pub fn str_mod(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let template = expect_string(receiver)?;
+    let formatted = super::super::format::percent_format(template, &args[0])?;
+    Ok(make_string(formatted))
+}
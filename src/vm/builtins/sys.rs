@@ -0,0 +1,147 @@
+use std::rc::Rc;
+
+use super::super::bytecode::Value;
+use super::super::value::{Object, ObjectData, StreamKind};
+use super::super::{VmErrorKind, VmResult, err};
+use super::super::utils::make_list;
+use super::make_string;
+use crate::builtins::{TYPE_MODULE, TYPE_TEXT_IO};
+use crate::runtime_io::{ReadResult, RuntimeIo};
+
+/// `import sys`가 만드는 모듈 객체 (`sys.argv`는 속성, `sys.exit`는 TYPE_MODULE 메서드)
+pub fn make_module(argv: &[String]) -> Value {
+    let mut module = Object::new_with_attrs(
+        TYPE_MODULE,
+        ObjectData::Module {
+            name: "sys".to_string(),
+        },
+    );
+    let argv = argv.iter().cloned().map(make_string).collect();
+    module.set_attr("argv".to_string(), make_list(argv));
+    module.set_attr("stdin".to_string(), make_stream(StreamKind::Stdin));
+    module.set_attr("stdout".to_string(), make_stream(StreamKind::Stdout));
+    Value::Object(Rc::new(module))
+}
+
+fn make_stream(kind: StreamKind) -> Value {
+    Value::Object(Rc::new(Object::new(TYPE_TEXT_IO, ObjectData::Stream { kind })))
+}
+
+/// sys.exit([status]): 프로그램을 끝냄
+///
+/// `VmErrorKind::Exit`로 실행 중인 모든 프레임을 빠져나가고, VM이 받아서 정상 종료로 바꿉니다.
+/// None이나 인자가 없으면 0, 문자열이면 메시지와 함께 1입니다.
+pub fn sys_exit(args: Vec<Value>) -> VmResult<Value> {
+    let (code, message) = match args.first() {
+        None | Some(Value::None) => (0, String::new()),
+        Some(Value::Bool(b)) => (*b as i32, String::new()),
+        Some(Value::Int(n)) => (
+            i32::try_from(*n).map_err(|_| {
+                err(
+                    VmErrorKind::TypeError("sys.exit"),
+                    format!("sys.exit() status out of range: {}", n),
+                )
+            })?,
+            String::new(),
+        ),
+        Some(Value::Object(obj)) if matches!(obj.data, ObjectData::String(_)) => {
+            (1, super::display_value(&Value::Object(obj.clone())))
+        }
+        Some(other) => {
+            return Err(err(
+                VmErrorKind::TypeError("sys.exit"),
+                format!(
+                    "sys.exit() argument must be int, str or None, not '{}'",
+                    super::type_name(other)
+                ),
+            ));
+        }
+    };
+    Err(err(VmErrorKind::Exit(code), message))
+}
+
+/// 받는 쪽이 스트림이 아니면 TypeError (메서드를 꺼내 다른 객체에 붙여 부른 경우 등)
+fn stream_kind(receiver: &Value) -> VmResult<StreamKind> {
+    match receiver {
+        Value::Object(obj) if let ObjectData::Stream { kind } = &obj.data => Ok(*kind),
+        other => Err(err(
+            VmErrorKind::TypeError("io"),
+            format!(
+                "descriptor requires a 'TextIOWrapper' object but received a '{}'",
+                super::type_name(other)
+            ),
+        )),
+    }
+}
+
+/// stdin 메서드를 stdout에 부른 경우 등
+fn expect_stream(receiver: &Value, want: StreamKind, what: &str) -> VmResult<()> {
+    if stream_kind(receiver)? == want {
+        Ok(())
+    } else {
+        Err(err(
+            VmErrorKind::TypeError("io"),
+            format!("UnsupportedOperation: not {}", what),
+        ))
+    }
+}
+
+fn read_text(result: ReadResult) -> VmResult<String> {
+    match result {
+        ReadResult::Ok(text) => Ok(text),
+        ReadResult::WaitingForInput => {
+            Err(err(VmErrorKind::TypeError("io"), "Waiting for input".into()))
+        }
+        ReadResult::Error(e) => Err(err(VmErrorKind::TypeError("io"), e)),
+    }
+}
+
+/// sys.stdin.read(): 입력 끝까지 한 문자열로
+pub fn stream_read<IO: RuntimeIo>(receiver: &Value, io: &mut IO) -> VmResult<Value> {
+    expect_stream(receiver, StreamKind::Stdin, "readable")?;
+    Ok(make_string(read_text(io.read_to_end())?))
+}
+
+/// sys.stdin.readline(): 줄바꿈을 포함한 한 줄 (입력이 끝나면 "")
+pub fn stream_readline<IO: RuntimeIo>(receiver: &Value, io: &mut IO) -> VmResult<Value> {
+    expect_stream(receiver, StreamKind::Stdin, "readable")?;
+    Ok(make_string(read_text(io.read_raw_line())?))
+}
+
+/// sys.stdin.readlines(): 남은 줄 전부 (각 줄은 줄바꿈 포함)
+pub fn stream_readlines<IO: RuntimeIo>(receiver: &Value, io: &mut IO) -> VmResult<Value> {
+    expect_stream(receiver, StreamKind::Stdin, "readable")?;
+    let text = read_text(io.read_to_end())?;
+    let lines = text.split_inclusive('\n').map(|line| make_string(line.to_string()));
+    Ok(make_list(lines.collect()))
+}
+
+/// sys.stdout.write(s): 줄바꿈 없이 출력하고 쓴 글자 수를 반환
+pub fn stream_write<IO: RuntimeIo>(
+    receiver: &Value,
+    args: Vec<Value>,
+    io: &mut IO,
+) -> VmResult<Value> {
+    expect_stream(receiver, StreamKind::Stdout, "writable")?;
+    match &args[0] {
+        Value::Object(obj) if let ObjectData::String(s) = &obj.data => {
+            io.write(s);
+            Ok(Value::Int(s.chars().count() as i64))
+        }
+        other => Err(err(
+            VmErrorKind::TypeError("io"),
+            format!(
+                "write() argument must be str, not {}",
+                super::type_name(other)
+            ),
+        )),
+    }
+}
+
+/// sys.stdout.flush(): 모아 둔 출력을 바로 내보냄
+pub fn stream_flush<IO: RuntimeIo>(receiver: &Value, io: &mut IO) -> VmResult<Value> {
+    if stream_kind(receiver)? == StreamKind::Stdout {
+        io.flush();
+    }
+    Ok(Value::None)
+}
@@ -399,6 +399,18 @@ impl RuntimeIo for MockIo {
     }
 }
 
+#[test]
+fn test_stream_methods_reject_non_stream_receiver() {
+    let mut io = MockIo::new();
+    let err = sys::stream_read(&Value::Int(1), &mut io).unwrap_err();
+    assert!(matches!(err.kind, VmErrorKind::TypeError(_)));
+    assert_eq!(
+        err.message,
+        "descriptor requires a 'TextIOWrapper' object but received a 'int'"
+    );
+    assert!(sys::stream_flush(&Value::None, &mut io).is_err());
+}
+
 #[test]
 fn test_print_no_args() {
     let mut io = MockIo::new();
@@ -542,3 +554,244 @@ fn test_str_count() {
     let result = str_methods::str_count(&receiver, vec![substr]).unwrap();
     assert_eq!(result, Value::Int(3));
 }
+
+// ========== 숫자/객체 builtin 테스트 ==========
+
+#[test]
+fn test_abs() {
+    assert_eq!(numeric::abs(vec![Value::Int(-5)]).unwrap(), Value::Int(5));
+    assert_eq!(numeric::abs(vec![Value::Float(-1.5)]).unwrap(), Value::Float(1.5));
+    assert!(numeric::abs(vec![Value::Int(i64::MIN)]).unwrap_err().message.contains("OverflowError"));
+    assert!(numeric::abs(vec![make_string("x".into())]).is_err());
+}
+
+#[test]
+fn test_round_half_to_even() {
+    assert_eq!(numeric::round(vec![Value::Float(2.5)]).unwrap(), Value::Int(2));
+    assert_eq!(numeric::round(vec![Value::Float(3.5)]).unwrap(), Value::Int(4));
+    assert_eq!(numeric::round(vec![Value::Float(-0.5)]).unwrap(), Value::Int(0));
+    assert_eq!(
+        numeric::round(vec![Value::Float(1.23456), Value::Int(2)]).unwrap(),
+        Value::Float(1.23)
+    );
+    assert_eq!(numeric::round(vec![Value::Int(1250), Value::Int(-2)]).unwrap(), Value::Int(1200));
+    assert_eq!(numeric::round(vec![Value::Int(1350), Value::Int(-2)]).unwrap(), Value::Int(1400));
+}
+
+#[test]
+fn test_round_uses_exact_decimal_value() {
+    // 2.675는 실제로 2.67499...이므로 Python처럼 내림
+    let round2 = |f: f64| numeric::round(vec![Value::Float(f), Value::Int(2)]).unwrap();
+    assert_eq!(round2(2.675), Value::Float(2.67));
+    assert_eq!(round2(0.125), Value::Float(0.12));
+    assert_eq!(round2(0.375), Value::Float(0.38));
+    assert_eq!(round2(1e300), Value::Float(1e300));
+    assert_eq!(
+        numeric::round(vec![Value::Float(5e-324), Value::Int(400)]).unwrap(),
+        Value::Float(5e-324)
+    );
+}
+
+#[test]
+fn test_round_out_of_range() {
+    let round = |args| numeric::round(args);
+    assert_eq!(round(vec![Value::Int(12345), Value::Int(i64::MIN)]).unwrap(), Value::Int(0));
+    assert!(round(vec![Value::Int(i64::MAX), Value::Int(-1)]).unwrap_err().message.starts_with("OverflowError"));
+    assert_eq!(
+        round(vec![Value::Float(1e300)]).unwrap_err().message,
+        "OverflowError: round(1e+300) is too large for a 64-bit integer"
+    );
+    assert_eq!(round(vec![Value::Float(-9.2e18)]).unwrap(), Value::Int(-9_200_000_000_000_000_000));
+    // 단위가 float 범위를 넘으면 0 (부호 유지)
+    let Value::Float(zero) = round(vec![Value::Float(-1e308), Value::Int(-400)]).unwrap() else {
+        panic!("round(float, n) returns a float");
+    };
+    assert!(zero == 0.0 && zero.is_sign_negative());
+    assert_eq!(round(vec![Value::Float(1e308), Value::Int(-400)]).unwrap(), Value::Float(0.0));
+    assert_eq!(round(vec![Value::Float(1e308), Value::Int(-308)]).unwrap(), Value::Float(1e308));
+    assert!(round(vec![Value::Float(1.7e308), Value::Int(-308)]).unwrap_err().message.starts_with("OverflowError"));
+}
+
+#[test]
+fn test_round_negative_ndigits() {
+    let round = |f: f64, n: i64| numeric::round(vec![Value::Float(f), Value::Int(n)]).unwrap();
+    assert_eq!(round(1250.0, -2), Value::Float(1200.0));
+    assert_eq!(round(1350.0, -2), Value::Float(1400.0));
+    // 정확히 절반보다 조금 크면 올림 (1250.5는 1300)
+    assert_eq!(round(1250.5, -2), Value::Float(1300.0));
+    assert_eq!(round(25.4, -1), Value::Float(30.0));
+    assert_eq!(round(-995.0, -1), Value::Float(-1000.0));
+    assert_eq!(round(999.0, -3), Value::Float(1000.0));
+    assert_eq!(round(499.0, -3), Value::Float(0.0));
+    assert_eq!(round(123.0, -5), Value::Float(0.0));
+}
+
+#[test]
+fn test_pow_mod() {
+    assert_eq!(
+        numeric::pow_mod(&Value::Int(2), &Value::Int(10), &Value::Int(1000)).unwrap(),
+        Value::Int(24)
+    );
+    // 결과의 부호는 mod를 따름
+    assert_eq!(
+        numeric::pow_mod(&Value::Int(3), &Value::Int(4), &Value::Int(-5)).unwrap(),
+        Value::Int(-4)
+    );
+    assert!(numeric::pow_mod(&Value::Int(2), &Value::Int(3), &Value::Int(0)).is_err());
+    assert!(numeric::pow_mod(&Value::Float(2.0), &Value::Int(3), &Value::Int(5)).is_err());
+}
+
+#[test]
+fn test_divmod_matches_operators() {
+    let result = numeric::divmod(vec![Value::Int(7), Value::Int(2)]).unwrap();
+    assert_eq!(display_value(&result), "(3, 1)");
+    // 내림 나눗셈: 나머지의 부호는 나누는 수를 따름
+    let result = numeric::divmod(vec![Value::Int(-7), Value::Int(2)]).unwrap();
+    assert_eq!(display_value(&result), "(-4, 1)");
+    let result = numeric::divmod(vec![Value::Int(7), Value::Int(-2)]).unwrap();
+    assert_eq!(display_value(&result), "(-4, -1)");
+    let result = numeric::divmod(vec![Value::Float(-7.5), Value::Int(2)]).unwrap();
+    assert_eq!(display_value(&result), "(-4.0, 0.5)");
+    assert!(matches!(
+        numeric::divmod(vec![Value::Int(1), Value::Int(0)]).unwrap_err().kind,
+        VmErrorKind::ZeroDivision
+    ));
+}
+
+#[test]
+fn test_hex_bin() {
+    assert_eq!(display_value(&numeric::hex(vec![Value::Int(255)]).unwrap()), "0xff");
+    assert_eq!(display_value(&numeric::hex(vec![Value::Int(-255)]).unwrap()), "-0xff");
+    assert_eq!(display_value(&numeric::bin(vec![Value::Int(5)]).unwrap()), "0b101");
+    assert!(numeric::bin(vec![Value::Float(1.0)]).is_err());
+}
+
+#[test]
+fn test_chr_ord_roundtrip() {
+    let c = object::chr(vec![Value::Int(0xAC00)]).unwrap();
+    assert_eq!(display_value(&c), "가");
+    assert_eq!(object::ord(vec![c]).unwrap(), Value::Int(0xAC00));
+    assert!(object::chr(vec![Value::Int(-1)]).is_err());
+    assert!(object::ord(vec![make_string("ab".into())]).is_err());
+}
+
+#[test]
+fn test_hash_consistent_with_eq() {
+    let h = |v: Value| object::hash(vec![v]).unwrap();
+    assert_eq!(h(Value::Int(1)), h(Value::Float(1.0)));
+    assert_eq!(h(Value::Int(1)), h(Value::Bool(true)));
+    assert_eq!(h(make_string("a".into())), h(make_string("a".into())));
+    assert!(object::hash(vec![crate::vm::utils::make_list(vec![])]).is_err());
+}
+
+#[test]
+fn test_eq_vals_structural() {
+    use crate::vm::utils::{eq_vals, make_list, make_tuple};
+    let nested = |n: i64| make_list(vec![Value::Int(1), make_tuple(vec![Value::Int(n)])]);
+    assert!(eq_vals(&nested(2), &nested(2)).unwrap());
+    assert!(!eq_vals(&nested(2), &nested(3)).unwrap());
+    assert!(!eq_vals(&make_list(vec![]), &make_tuple(vec![])).unwrap());
+
+    // 자기 자신을 담은 list끼리 비교해도 끝나야 함
+    let (a, b) = (make_list(vec![]), make_list(vec![]));
+    list_methods::list_append(&a, vec![a.clone()]).unwrap();
+    list_methods::list_append(&b, vec![b.clone()]).unwrap();
+    assert!(eq_vals(&a, &b).unwrap());
+}
+
+#[test]
+fn test_list_repeat_and_concat() {
+    use crate::vm::utils::make_list;
+    let xs = make_list(vec![Value::Int(1), Value::Int(2)]);
+    let doubled = list_methods::list_mul(&xs, vec![Value::Int(2)]).unwrap();
+    assert_eq!(display_value(&doubled), "[1, 2, 1, 2]");
+    let empty = list_methods::list_mul(&xs, vec![Value::Int(-3)]).unwrap();
+    assert_eq!(display_value(&empty), "[]");
+    assert!(list_methods::list_mul(&xs, vec![Value::Float(2.0)]).is_err());
+    assert!(list_methods::list_add(&xs, vec![make_string("a".into())]).is_err());
+}
+
+#[test]
+fn test_set_pop_and_discard() {
+    let s = set::call(vec![crate::vm::utils::make_list(vec![Value::Int(7)])]).unwrap();
+    set_methods::set_discard(&s, vec![Value::Int(1)]).unwrap();
+    assert_eq!(set_methods::set_pop(&s, vec![]).unwrap(), Value::Int(7));
+    assert!(set_methods::set_pop(&s, vec![]).is_err());
+}
+
+#[test]
+fn test_str_char_at() {
+    use crate::vm::utils::str_char_at;
+    assert_eq!(str_char_at("abc", true, 0), Some('a'));
+    assert_eq!(str_char_at("abc", true, -1), Some('c'));
+    assert_eq!(str_char_at("abc", true, 3), None);
+    assert_eq!(str_char_at("abc", true, -4), None);
+    assert_eq!(str_char_at("가나다", false, 1), Some('나'));
+    assert_eq!(str_char_at("가나다", false, -3), Some('가'));
+    assert_eq!(str_char_at("가나다", false, -4), None);
+
+    // 플래그는 문자열 객체를 만들 때 한 번 계산됨
+    let is_ascii = |v: Value| match v {
+        Value::Object(obj) => obj.is_ascii_str(),
+        _ => unreachable!(),
+    };
+    assert!(is_ascii(make_string("abc".to_string())));
+    assert!(!is_ascii(make_string("a가".to_string())));
+    assert!(!is_ascii(crate::vm::utils::make_list(vec![])));
+}
+
+#[test]
+fn test_str_split_maxsplit() {
+    let s = make_string(" a b  c ".into());
+    let split = |args| display_value(&str_methods::str_split(&s, args).unwrap());
+    assert_eq!(split(vec![]), "['a', 'b', 'c']");
+    assert_eq!(split(vec![Value::None, Value::Int(1)]), "['a', 'b  c ']");
+    assert_eq!(split(vec![make_string(" ".into())]), "['', 'a', 'b', '', 'c', '']");
+    assert!(str_methods::str_split(&s, vec![make_string(String::new())]).is_err());
+    let rsplit = str_methods::str_rsplit(&s, vec![Value::None, Value::Int(1)]).unwrap();
+    assert_eq!(display_value(&rsplit), "[' a b', 'c']");
+}
+
+#[test]
+fn test_float_repr() {
+    use crate::vm::utils::float_repr;
+    assert_eq!(float_repr(1.0), "1.0");
+    assert_eq!(float_repr(0.1), "0.1");
+    assert_eq!(float_repr(-0.0), "-0.0");
+    assert_eq!(float_repr(1e16), "1e+16");
+    assert_eq!(float_repr(123456789012345.6), "123456789012345.6");
+    assert_eq!(float_repr(1.5e-7), "1.5e-07");
+    assert_eq!(float_repr(f64::INFINITY), "inf");
+    assert_eq!(float_repr(f64::NAN), "nan");
+}
+
+#[test]
+fn test_huge_repeat_is_an_error() {
+    let list = crate::vm::utils::make_list(vec![Value::Int(1), Value::Int(2)]);
+    let error = list_methods::list_mul(&list, vec![Value::Int(i64::MAX)]).unwrap_err();
+    assert!(error.message.starts_with("MemoryError"), "{}", error.message);
+    let tuple = crate::vm::utils::make_tuple(vec![Value::Int(1), Value::Int(2)]);
+    let error = tuple_methods::tuple_mul(&tuple, vec![Value::Int(1 << 62)]).unwrap_err();
+    assert!(error.message.starts_with("MemoryError"), "{}", error.message);
+    let error = str_methods::str_mul(&make_string("abc".into()), vec![Value::Int(i64::MAX)]).unwrap_err();
+    assert_eq!(error.message, "OverflowError: repeated string is too long");
+    let ok = list_methods::list_mul(&list, vec![Value::Int(3)]).unwrap();
+    assert_eq!(display_value(&ok), "[1, 2, 1, 2, 1, 2]");
+}
+
+#[test]
+fn test_huge_padding_is_an_error() {
+    let s = make_string("a".into());
+    let width = || vec![Value::Int(i64::MAX)];
+    for result in [
+        str_methods::str_center(&s, width()),
+        str_methods::str_ljust(&s, width()),
+        str_methods::str_rjust(&s, width()),
+        str_methods::str_zfill(&s, width()),
+    ] {
+        let error = result.unwrap_err();
+        assert!(error.message.starts_with("MemoryError"), "{}", error.message);
+    }
+    let padded = str_methods::str_center(&s, vec![Value::Int(3), make_string("*".into())]).unwrap();
+    assert_eq!(display_value(&padded), "*a*");
+}
@@ -12,6 +12,7 @@ use std::rc::Rc;
 ///
 /// treeset() - 빈 treeset 생성
 /// treeset(iterable) - iterable의 원소들로 treeset 생성
+#[allow(clippy::useless_format)]
 pub fn call(args: Vec<Value>) -> VmResult<Value> {
     if args.is_empty() {
         // 빈 treeset 생성
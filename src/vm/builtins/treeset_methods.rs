@@ -76,6 +76,7 @@ pub fn treeset_add(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
 }
 
 /// treeset.remove(item)
+#[allow(clippy::useless_format)]
 pub fn treeset_remove(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     if args.len() != 1 {
         return Err(err(
@@ -159,24 +160,7 @@ pub fn treeset_union(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::TreeSet { items } = &obj.data {
-                let other_treeset = match &args[0] {
-                    Value::Object(other_obj) => {
-                        if let ObjectData::TreeSet { items: other_items } = &other_obj.data {
-                            other_items.borrow().clone()
-                        } else {
-                            return Err(err(
-                                VmErrorKind::TypeError("treeset.union"),
-                                "union() requires a treeset argument".to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(err(
-                            VmErrorKind::TypeError("treeset.union"),
-                            "union() requires a treeset argument".to_string(),
-                        ));
-                    }
-                };
+                let other_treeset = other_set_keys(&args[0], "union", false)?;
 
                 let mut result = items.borrow().clone();
                 result.extend(other_treeset);
@@ -216,24 +200,7 @@ pub fn treeset_intersection(receiver: &Value, args: Vec<Value>) -> VmResult<Valu
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::TreeSet { items } = &obj.data {
-                let other_treeset = match &args[0] {
-                    Value::Object(other_obj) => {
-                        if let ObjectData::TreeSet { items: other_items } = &other_obj.data {
-                            other_items.borrow().clone()
-                        } else {
-                            return Err(err(
-                                VmErrorKind::TypeError("treeset.intersection"),
-                                "intersection() requires a treeset argument".to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(err(
-                            VmErrorKind::TypeError("treeset.intersection"),
-                            "intersection() requires a treeset argument".to_string(),
-                        ));
-                    }
-                };
+                let other_treeset = other_set_keys(&args[0], "intersection", false)?;
 
                 let result: BTreeSet<SetKey> = items
                     .borrow()
@@ -276,24 +243,7 @@ pub fn treeset_difference(receiver: &Value, args: Vec<Value>) -> VmResult<Value>
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::TreeSet { items } = &obj.data {
-                let other_treeset = match &args[0] {
-                    Value::Object(other_obj) => {
-                        if let ObjectData::TreeSet { items: other_items } = &other_obj.data {
-                            other_items.borrow().clone()
-                        } else {
-                            return Err(err(
-                                VmErrorKind::TypeError("treeset.difference"),
-                                "difference() requires a treeset argument".to_string(),
-                            ));
-                        }
-                    }
-                    _ => {
-                        return Err(err(
-                            VmErrorKind::TypeError("treeset.difference"),
-                            "difference() requires a treeset argument".to_string(),
-                        ));
-                    }
-                };
+                let other_treeset = other_set_keys(&args[0], "difference", false)?;
 
                 let result: BTreeSet<SetKey> = items
                     .borrow()
@@ -494,6 +444,12 @@ pub fn treeset_max(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
 
 /// treeset.__iter__()
 pub fn treeset_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    // iterator의 __iter__는 자기 자신 (`iter(iter(xs))`)
+    if let Value::Object(obj) = receiver
+        && matches!(obj.data, ObjectData::BuiltinInstance { .. })
+    {
+        return Ok(receiver.clone());
+    }
     match receiver {
         Value::Object(obj) => {
             if let ObjectData::TreeSet { items } = &obj.data {
@@ -578,3 +534,129 @@ pub fn treeset_next(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
     }
 }
 
+// ========== 집합 연산자와 추가 메서드 ==========
+
+/// treeset.symmetric_difference(other) / `a ^ b`
+pub fn treeset_symmetric_difference(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "symmetric_difference")?;
+    let other = other_set_keys(&args[0], "symmetric_difference", false)?;
+    let result = items.borrow().symmetric_difference(&other).cloned().collect();
+    Ok(make_treeset(result))
+}
+
+/// treeset.issubset(other) / `a <= b`
+pub fn treeset_issubset(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "issubset")?;
+    let other = other_set_keys(&args[0], "issubset", false)?;
+    Ok(Value::Bool(items.borrow().is_subset(&other)))
+}
+
+/// treeset.issuperset(other) / `a >= b`
+pub fn treeset_issuperset(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "issuperset")?;
+    let other = other_set_keys(&args[0], "issuperset", false)?;
+    Ok(Value::Bool(items.borrow().is_superset(&other)))
+}
+
+/// treeset.__lt__(other): 진부분집합 `a < b`
+pub fn treeset_lt(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "__lt__")?;
+    let other = other_set_keys(&args[0], "__lt__", false)?;
+    let items = items.borrow();
+    Ok(Value::Bool(items.len() < other.len() && items.is_subset(&other)))
+}
+
+/// treeset.__gt__(other): 진상위집합 `a > b`
+pub fn treeset_gt(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "__gt__")?;
+    let other = other_set_keys(&args[0], "__gt__", false)?;
+    let items = items.borrow();
+    Ok(Value::Bool(items.len() > other.len() && items.is_superset(&other)))
+}
+
+/// treeset.discard(item): remove()와 달리 원소가 없어도 에러가 아님
+pub fn treeset_discard(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "discard")?;
+    let key = value_to_set_key(&args[0])?;
+    items.borrow_mut().remove(&key);
+    Ok(Value::None)
+}
+
+/// treeset.pop(): 가장 작은 원소를 꺼냄
+pub fn treeset_pop(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    let mut items = expect_treeset(receiver, "pop")?.borrow_mut();
+    let key = items.pop_first();
+    key.map(|k| set_key_to_value(&k)).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("treeset.pop"),
+            "KeyError: 'pop from an empty treeset'".to_string(),
+        )
+    })
+}
+
+/// treeset.update(*iterables): set, treeset, list, tuple들의 원소를 모두 추가
+pub fn treeset_update(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_treeset(receiver, "update")?;
+    for arg in &args {
+        // 자기 자신으로 update해도 되도록 먼저 모아 둠
+        let other = other_set_keys(arg, "update", true)?;
+        items.borrow_mut().extend(other);
+    }
+    Ok(Value::None)
+}
+
+/// Helper: receiver가 treeset인지 확인
+fn expect_treeset<'a>(
+    receiver: &'a Value,
+    method: &'static str,
+) -> VmResult<&'a RefCell<BTreeSet<SetKey>>> {
+    if let Value::Object(obj) = receiver
+        && let ObjectData::TreeSet { items } = &obj.data
+    {
+        return Ok(items);
+    }
+    Err(err(
+        VmErrorKind::TypeError("treeset"),
+        format!("{}() requires a treeset", method),
+    ))
+}
+
+/// Helper: 다른 집합(set 또는 treeset)의 원소
+///
+/// `allow_sequences`이면 list, tuple도 받습니다 (`update`).
+fn other_set_keys(
+    value: &Value,
+    method: &'static str,
+    allow_sequences: bool,
+) -> VmResult<BTreeSet<SetKey>> {
+    if let Value::Object(obj) = value {
+        match &obj.data {
+            ObjectData::Set { items } => return Ok(items.borrow().iter().cloned().collect()),
+            ObjectData::TreeSet { items } => return Ok(items.borrow().iter().cloned().collect()),
+            ObjectData::List { items } if allow_sequences => {
+                return items.borrow().iter().map(value_to_set_key).collect();
+            }
+            ObjectData::Tuple { items } if allow_sequences => {
+                return items.iter().map(value_to_set_key).collect();
+            }
+            _ => {}
+        }
+    }
+    Err(err(
+        VmErrorKind::TypeError("treeset"),
+        format!(
+            "{}() argument must be a set, not '{}'",
+            method,
+            super::type_name(value)
+        ),
+    ))
+}
+
+fn make_treeset(items: BTreeSet<SetKey>) -> Value {
+    Value::Object(Rc::new(Object::new(
+        TYPE_TREESET,
+        ObjectData::TreeSet {
+            items: RefCell::new(items),
+        },
+    )))
+}
@@ -0,0 +1,90 @@
+//! Tuple methods implementation
+
+use super::super::bytecode::Value;
+use super::super::utils::{make_list_iterator, make_tuple, type_name};
+use super::super::value::ObjectData;
+use super::super::{VmError, VmErrorKind, VmResult, err, limits};
+
+/// tuple.__contains__(item): `item in t`
+pub fn tuple_contains(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    match receiver {
+        Value::Object(obj) => {
+            if let ObjectData::Tuple { items } = &obj.data {
+                for item in items.iter() {
+                    if super::super::utils::eq_vals(item, &args[0])? {
+                        return Ok(Value::Bool(true));
+                    }
+                }
+                Ok(Value::Bool(false))
+            } else {
+                Err(err(
+                    VmErrorKind::TypeError("tuple.__contains__"),
+                    "__contains__() requires a tuple".to_string(),
+                ))
+            }
+        }
+        _ => Err(err(
+            VmErrorKind::TypeError("tuple.__contains__"),
+            "__contains__() requires a tuple".to_string(),
+        )),
+    }
+}
+
+/// tuple.__iter__(): 원소를 차례로 순회하는 iterator
+pub fn tuple_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    match receiver {
+        Value::Object(obj) => {
+            if let ObjectData::Tuple { items } = &obj.data {
+                Ok(make_list_iterator(items.clone()))
+            } else {
+                Err(err(
+                    VmErrorKind::TypeError("tuple.__iter__"),
+                    "__iter__() requires a tuple".to_string(),
+                ))
+            }
+        }
+        _ => Err(err(
+            VmErrorKind::TypeError("tuple.__iter__"),
+            "__iter__() requires a tuple".to_string(),
+        )),
+    }
+}
+
+/// tuple.__add__(other): `(1,) + (2,)` → 새 tuple
+pub fn tuple_add(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_tuple_items(receiver, "__add__")?;
+    if let Value::Object(other) = &args[0]
+        && let ObjectData::Tuple { items: other_items } = &other.data
+    {
+        return Ok(make_tuple([items, other_items.as_slice()].concat()));
+    }
+    Err(err(
+        VmErrorKind::TypeError("tuple.__add__"),
+        format!(
+            "can only concatenate tuple (not \"{}\") to tuple",
+            type_name(&args[0])
+        ),
+    ))
+}
+
+/// tuple.__mul__(n): `(0,) * 3` → 새 tuple
+pub fn tuple_mul(receiver: &Value, args: Vec<Value>) -> VmResult<Value> {
+    let items = expect_tuple_items(receiver, "__mul__")?;
+    let times = super::list_methods::repeat_count(&args[0])?;
+    limits::reserve_array::<Value>(limits::repeat_len(items.len(), times, "tuple")?)?;
+    Ok(make_tuple(
+        std::iter::repeat_n(items.iter(), times).flatten().cloned().collect(),
+    ))
+}
+
+fn expect_tuple_items<'a>(receiver: &'a Value, method: &'static str) -> VmResult<&'a [Value]> {
+    if let Value::Object(obj) = receiver
+        && let ObjectData::Tuple { items } = &obj.data
+    {
+        return Ok(items);
+    }
+    Err(err(
+        VmErrorKind::TypeError("tuple"),
+        format!("{}() requires a tuple", method),
+    ))
+}
@@ -0,0 +1,101 @@
+//! zip() builtin function and zip iterator implementation
+
+use super::super::bytecode::{Module, Value};
+use super::super::machine::Vm;
+use super::super::utils::make_tuple;
+use super::super::value::{BuiltinInstanceData, Object, ObjectData};
+use super::super::{VmError, VmErrorKind, VmResult, err};
+use super::bool::to_bool;
+use crate::builtins::{BuiltinClassType, TYPE_ZIP};
+use crate::runtime_io::RuntimeIo;
+use std::rc::Rc;
+
+/// zip(a, b, ...) 생성자
+///
+/// 각 iterable에서 하나씩 꺼낸 tuple을 만들어내는 lazy iterator를 반환합니다.
+/// 가장 짧은 iterable이 끝나면 멈춥니다.
+///
+/// ```python
+/// for pair in zip([1, 2, 3], "ab"):
+///     print(pair)  # (1, 'a'), (2, 'b')
+/// ```
+pub fn create_zip<IO: RuntimeIo>(
+    args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let mut source_iters = Vec::with_capacity(args.len());
+    for arg in &args {
+        source_iters.push(vm.get_iter(arg, module, io)?);
+    }
+
+    Ok(Value::Object(Rc::new(Object::new(
+        TYPE_ZIP,
+        ObjectData::BuiltinInstance {
+            class_type: BuiltinClassType::Zip,
+            data: BuiltinInstanceData::ZipIterator { source_iters },
+        },
+    ))))
+}
+
+// ========== Iterator Protocol 메서드들 ==========
+
+/// zip.__iter__(): 자기 자신을 반환
+pub fn zip_iter(receiver: &Value, _args: Vec<Value>) -> VmResult<Value> {
+    Ok(receiver.clone())
+}
+
+/// zip.__has_next__(): 모든 source iterator에 다음 값이 있어야 True
+///
+/// 인자 없는 `zip()`은 바로 끝납니다.
+pub fn zip_has_next<IO: RuntimeIo>(
+    receiver: &Value,
+    _args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let source_iters = expect_zip(receiver)?;
+    if source_iters.is_empty() {
+        return Ok(Value::Bool(false));
+    }
+    for source_iter in source_iters {
+        let has_next = vm.call_method(source_iter, "__has_next__", vec![], module, io)?;
+        if !to_bool(&has_next) {
+            return Ok(Value::Bool(false));
+        }
+    }
+    Ok(Value::Bool(true))
+}
+
+/// zip.__next__(): 각 source에서 하나씩 꺼내 tuple로 묶음
+pub fn zip_next<IO: RuntimeIo>(
+    receiver: &Value,
+    _args: Vec<Value>,
+    module: &mut Module,
+    vm: &mut Vm,
+    io: &mut IO,
+) -> VmResult<Value> {
+    let source_iters = expect_zip(receiver)?;
+    let mut items = Vec::with_capacity(source_iters.len());
+    for source_iter in source_iters {
+        items.push(vm.call_method(source_iter, "__next__", vec![], module, io)?);
+    }
+    Ok(make_tuple(items))
+}
+
+fn expect_zip(receiver: &Value) -> VmResult<&[Value]> {
+    if let Value::Object(obj) = receiver
+        && let ObjectData::BuiltinInstance {
+            data: BuiltinInstanceData::ZipIterator { source_iters },
+            ..
+        } = &obj.data
+    {
+        return Ok(source_iters);
+    }
+    Err(err(
+        VmErrorKind::TypeError("zip"),
+        "expected zip object".into(),
+    ))
+}
@@ -17,29 +17,9 @@ pub enum Value {
 }
 
 impl PartialEq for Value {
+    /// `==` 연산자와 같은 구조적 비교 (`utils::eq_vals`, 너무 깊어 비교하지 못하면 다름)
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (Value::Int(a), Value::Int(b)) => a == b,
-            (Value::Bool(a), Value::Bool(b)) => a == b,
-            (Value::Float(a), Value::Float(b)) => a == b,
-            (Value::Int(a), Value::Float(b)) => *a as f64 == *b,
-            (Value::Float(a), Value::Int(b)) => *a == *b as f64,
-            (Value::None, Value::None) => true,
-            (Value::Object(a), Value::Object(b)) => {
-                if Rc::ptr_eq(a, b) {
-                    return true;
-                }
-                // String은 값 비교
-                // TODO: __eq__ 메서드 구현 필요
-                use super::value::ObjectData;
-                match (&a.data, &b.data) {
-                    (ObjectData::String(s1), ObjectData::String(s2)) => s1 == s2,
-                    // 다른 객체들은 포인터 비교만 (identity)
-                    _ => false,
-                }
-            }
-            _ => false,
-        }
+        matches!(super::utils::eq_vals(self, other), Ok(true))
     }
 }
 
@@ -68,6 +48,15 @@ pub enum Instruction {
     /// 스택 top 값을 복제
     /// Stack: value → value, value
     Dup,
+    /// top 값을 세 번째 자리로 내림 (연쇄 비교용)
+    /// Stack: a, b, c → c, a, b
+    Rot3,
+    /// 스택 top 두 값을 복제 (인덱스 복합 할당용)
+    /// Stack: a, b → a, b, a, b
+    Dup2,
+    /// 스택 top 두 값을 교환 (스택에 있는 값을 속성/인덱스에 저장할 때)
+    /// Stack: a, b → b, a
+    Swap,
 
     // locals/globals
     LoadLocal(u16),
@@ -77,13 +66,34 @@ pub enum Instruction {
 
     // arithmetic
     Add,
+    /// `+=`: 리스트는 제자리 확장, 그 외에는 Add와 동일
+    InplaceAdd,
     Sub,
+    /// `-=`: 셋은 제자리 갱신, 그 외에는 Sub와 동일
+    InplaceSub,
     Mul,
     Div,     // Floor division (//)
     TrueDiv, // True division (/)
     Mod,
     Neg,
     Pos,
+    /// 거듭제곱 (**)
+    Pow,
+
+    // bitwise
+    BitAnd,
+    BitOr,
+    BitXor,
+    /// `&=`, `|=`, `^=`: 셋은 제자리 갱신, 그 외에는 BitAnd/BitOr/BitXor와 동일
+    InplaceBitAnd,
+    InplaceBitOr,
+    InplaceBitXor,
+    /// 왼쪽 시프트 (<<)
+    Shl,
+    /// 오른쪽 시프트 (>>)
+    Shr,
+    /// 비트 반전 (~)
+    Invert,
 
     // compare/logical
     Eq,
@@ -93,6 +103,12 @@ pub enum Instruction {
     Gt,
     Ge,
     Not,
+    /// 멤버십 검사: item in container (`__contains__`)
+    /// Stack: item, container → bool
+    In,
+    /// 동일성 검사: a is b
+    /// Stack: a, b → bool
+    Is,
 
     // control flow
     Jump(i32),
@@ -104,6 +120,14 @@ pub enum Instruction {
     CallBuiltin(u8 /* builtin_id */, u8 /* argc */),
     Return,
 
+    /// 키워드 인자가 있는 builtin 호출: sorted(xs, key=f)
+    /// Stack: arg1, ..., argN, kwargs_dict → result
+    CallBuiltinKw(u8 /* builtin_id */, u8 /* positional argc */),
+
+    /// 호스트가 등록한 네이티브 함수/클래스 호출 (실행 시점에 이름으로 찾음)
+    /// Stack: arg1, ..., argN → result
+    CallNative(u16 /* name_sym */, u8 /* argc */),
+
     /// 값을 callable로 호출 (func가 스택에 있음)
     /// Stack: callable, arg1, arg2, ... → result
     CallValue(u8 /* argc */),
@@ -112,6 +136,18 @@ pub enum Instruction {
     /// Stack: receiver, arg1, arg2, ... → result
     CallMethod(u16 /* method_name_sym */, u8 /* argc */),
 
+    /// 키워드 인자가 있는 메서드 호출: receiver.method(args, name=value)
+    /// Stack: receiver, arg1, ..., argN, kwargs_dict → result
+    CallMethodKw(u16 /* method_name_sym */, u8 /* positional argc */),
+
+    /// 값 하나를 문자열로 포매팅 (f-string 필드)
+    /// Stack: value[, spec] → str
+    FormatValue(u8 /* conversion: 0=none, 1=!s, 2=!r */, bool /* has_spec */),
+
+    /// 스택의 문자열 n개를 이어 붙임
+    /// Stack: s1, s2, ..., sn → str
+    BuildString(u16 /* count */),
+
     /// Attribute 로드: obj.attr
     /// Stack: object → value
     LoadAttr(u16 /* attr_name_sym */),
@@ -120,6 +156,10 @@ pub enum Instruction {
     /// Stack: object, value →
     StoreAttr(u16 /* attr_name_sym */),
 
+    /// 내장 모듈 가져오기: import sys (같은 VM에서는 같은 모듈 객체)
+    /// Stack: → module
+    ImportModule(u16 /* module_name_sym */),
+
     /// 리스트 생성: 스택에서 n개의 값을 꺼내 리스트 생성
     /// Stack: val1, val2, ..., valn → list
     BuildList(u16 /* count */),
@@ -148,6 +188,16 @@ pub enum Instruction {
     /// Stack: object, index, value →
     StoreIndex,
 
+    /// 언패킹: `a, b = seq` (seq는 iterable이면 됨: `map(int, input().split())`)
+    /// 값이 정확히 `count`개여야 하고, 첫 대상이 top에 오도록 push
+    /// Stack: seq → b, a
+    UnpackSequence(u8 /* count */),
+
+    /// 별표 언패킹: `a, *rest, z = seq`
+    /// 앞 `before`개, 나머지를 담은 리스트, 뒤 `after`개를 첫 대상이 top에 오도록 push
+    /// Stack: seq → z, rest, a
+    UnpackEx(u8 /* before */, u8 /* after */),
+
     /// Create a closure/lambda at runtime
     /// Pops num_captures values from stack (captured variables)
     /// Pushes a callable function object
@@ -155,12 +205,29 @@ pub enum Instruction {
     MakeClosure(u16 /* func_id */, u8 /* num_captures */),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 컴파일러가 만든 암묵적 코드(함수 끝의 `return None` 등)의 소스 위치
+pub const NO_POSITION: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FunctionCode {
     pub name_sym: u16,
     pub arity: u8,
     pub num_locals: u16,
     pub code: Vec<Instruction>,
+    /// 명령어마다 그 명령어를 만든 문장의 시작 위치 (소스 바이트 오프셋, 없으면 `NO_POSITION`)
+    pub positions: Vec<u32>,
+    /// 지역 변수 슬롯 이름 (인덱스 = 슬롯 번호)
+    pub local_names: Vec<String>,
+}
+
+impl FunctionCode {
+    /// `ip`번째 명령어를 만든 문장의 소스 위치
+    pub fn position(&self, ip: usize) -> Option<usize> {
+        match self.positions.get(ip) {
+            Some(&pos) if pos != NO_POSITION => Some(pos as usize),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -218,14 +285,17 @@ impl Module {
 mod tests {
     use super::*;
     use crate::vm::type_def::*;
-    use crate::builtins::{TYPE_MAP_ITER, TYPE_FILTER_ITER};
+    use crate::builtins::{
+        TYPE_ENUMERATE, TYPE_FILTER_ITER, TYPE_MAP_ITER, TYPE_SET, TYPE_TREESET, TYPE_TUPLE,
+        TYPE_LIST_ITERATOR, TYPE_MODULE, TYPE_TEXT_IO, TYPE_TYPE, TYPE_ZIP,
+    };
 
     #[test]
     fn test_module_type_table_initialization() {
         let module = Module::new();
 
-        // 타입 테이블이 11개 (int, bool, str, NoneType, range, list, dict, float, function, map_iterator, filter_iterator) 초기화되어야 함
-        assert_eq!(module.types.len(), 11);
+        // 타입 테이블이 20개 (int, bool, str, NoneType, range, list, dict, float, function, map_iterator, filter_iterator, tuple, set, treeset, enumerate, zip, type, module, TextIOWrapper, list_iterator) 초기화되어야 함
+        assert_eq!(module.types.len(), 20);
 
         // 각 타입의 이름 확인
         assert_eq!(module.types[TYPE_INT as usize].name, "int");
@@ -239,6 +309,12 @@ mod tests {
         assert_eq!(module.types[TYPE_FUNCTION as usize].name, "function");
         assert_eq!(module.types[TYPE_MAP_ITER as usize].name, "map_iterator");
         assert_eq!(module.types[TYPE_FILTER_ITER as usize].name, "filter_iterator");
+        assert_eq!(module.types[TYPE_ENUMERATE as usize].name, "enumerate");
+        assert_eq!(module.types[TYPE_ZIP as usize].name, "zip");
+        assert_eq!(module.types[TYPE_TYPE as usize].name, "type");
+        assert_eq!(module.types[TYPE_MODULE as usize].name, "module");
+        assert_eq!(module.types[TYPE_TEXT_IO as usize].name, "TextIOWrapper");
+        assert_eq!(module.types[TYPE_LIST_ITERATOR as usize].name, "list_iterator");
     }
 
     #[test]
@@ -246,11 +322,12 @@ mod tests {
         let module = Module::new();
         let str_type = &module.types[TYPE_STR as usize];
 
-        // str 타입은 18개의 메서드를 가져야 함 (10개 일반 메서드 + 8개 매직 메서드)
-        assert_eq!(str_type.methods.len(), 18);
+        // str 타입은 43개의 메서드를 가져야 함 (32개 일반 메서드 + 11개 매직 메서드)
+        assert_eq!(str_type.methods.len(), 43);
 
         // 주요 메서드 확인
         assert!(str_type.methods.contains_key("upper"));
+        assert!(str_type.methods.contains_key("format"));
         assert!(str_type.methods.contains_key("lower"));
         assert!(str_type.methods.contains_key("strip"));
         assert!(str_type.methods.contains_key("split"));
@@ -260,6 +337,39 @@ mod tests {
         assert!(str_type.methods.contains_key("endswith"));
         assert!(str_type.methods.contains_key("find"));
         assert!(str_type.methods.contains_key("count"));
+        assert!(str_type.methods.contains_key("rsplit"));
+        assert!(str_type.methods.contains_key("partition"));
+        assert!(str_type.methods.contains_key("zfill"));
+        assert!(str_type.methods.contains_key("__contains__"));
+    }
+
+    #[test]
+    fn test_container_types_have_contains() {
+        let module = Module::new();
+
+        // `in` 연산자가 지원하는 모든 빌트인 컨테이너는 __contains__를 가져야 함
+        for type_id in [TYPE_STR, TYPE_RANGE, TYPE_LIST, TYPE_DICT, TYPE_TUPLE, TYPE_SET, TYPE_TREESET] {
+            let ty = &module.types[type_id as usize];
+            assert!(
+                ty.methods.contains_key("__contains__"),
+                "{} is missing __contains__",
+                ty.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_iterable_types_have_iter() {
+        let module = Module::new();
+
+        // for 루프와 iterable builtin(sum, sorted, zip 등)이 순회하는 모든 타입은 __iter__를 가져야 함
+        for type_id in [
+            TYPE_STR, TYPE_RANGE, TYPE_LIST, TYPE_DICT, TYPE_TUPLE, TYPE_SET, TYPE_TREESET,
+            TYPE_MAP_ITER, TYPE_FILTER_ITER, TYPE_ENUMERATE, TYPE_ZIP, TYPE_LIST_ITERATOR,
+        ] {
+            let ty = &module.types[type_id as usize];
+            assert!(ty.methods.contains_key("__iter__"), "{} is missing __iter__", ty.name);
+        }
     }
 
     #[test]
@@ -274,9 +384,9 @@ mod tests {
             panic!("upper method not found or not native");
         }
 
-        // split() - 0 또는 1개 인자
+        // split(sep=None, maxsplit=-1) - 0 ~ 2개 인자
         if let Some(MethodImpl::Native { arity, .. }) = str_type.methods.get("split") {
-            assert_eq!(*arity, Arity::Range(0, 1));
+            assert_eq!(*arity, Arity::Range(0, 2));
         } else {
             panic!("split method not found or not native");
         }
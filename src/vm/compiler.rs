@@ -1,5 +1,7 @@
-use super::bytecode::{ClassDef, FunctionCode, Instruction as I, Module};
-use crate::parser::ast::{BinaryOp, Expr, ExprS, Literal, MethodDef, Stmt, StmtS, UnaryOp};
+use super::bytecode::{ClassDef, FunctionCode, Instruction as I, Module, NO_POSITION};
+use crate::parser::ast::{
+    BinaryOp, Expr, ExprS, FStringPart, Literal, MethodDef, Stmt, StmtS, UnaryOp,
+};
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
@@ -25,14 +27,21 @@ pub struct Compiler {
     module: Module,
     symbols: std::collections::HashMap<String, u16>,
     loop_stack: Vec<LoopContext>,
+    /// 호스트가 등록한 네이티브 함수/클래스 이름 (호출을 CallNative로 컴파일)
+    natives: HashSet<String>,
+    /// 지금 컴파일 중인 문장의 시작 위치 (`FunctionCode::positions`에 기록)
+    position: u32,
 }
 
 impl Compiler {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             module: Module::default(),
             symbols: Default::default(),
             loop_stack: Vec::new(),
+            natives: HashSet::new(),
+            position: NO_POSITION,
         }
     }
 
@@ -55,9 +64,30 @@ impl Compiler {
             module,
             symbols,
             loop_stack: Vec::new(),
+            natives: HashSet::new(),
+            position: NO_POSITION,
         }
     }
 
+    /// 네이티브 함수/클래스 호출을 허용 (실행할 VM에도 같은 레지스트리를 넘겨야 함)
+    pub fn with_natives(mut self, natives: &crate::vm::native::NativeRegistry) -> Self {
+        self.natives = natives.names().map(str::to_string).collect();
+        self
+    }
+
+    /// 사용자가 같은 이름을 지역 변수, 함수, 클래스로 정의해 네이티브를 가리는지
+    ///
+    /// 여기서 모르는 전역 변수(`double = ...`)는 VM의 `CallNative`가 실행 시점에 확인합니다.
+    fn shadows_native(&self, name: &str, locals: Option<&HashMap<String, u16>>) -> bool {
+        locals.is_some_and(|l| l.contains_key(name))
+            || self.module.classes.iter().any(|c| c.name == name)
+            || self
+                .module
+                .functions
+                .iter()
+                .any(|f| self.module.symbols.get(f.name_sym as usize).is_some_and(|s| s == name))
+    }
+
     pub fn compile(mut self, program: &[StmtS]) -> Module {
         // Reserve function 0 for __main__ entry
         let main_sym = self.intern("__main__");
@@ -66,18 +96,21 @@ impl Compiler {
             arity: 0,
             num_locals: 0,
             code: vec![],
+            ..Default::default()
         });
         let mut main = FunctionCode {
             name_sym: main_sym,
             arity: 0,
             num_locals: 0,
             code: vec![],
+            ..Default::default()
         };
         for s in program {
             self.emit_stmt(s, &mut main, None);
         }
         // implicit None return
         main.code.push(I::Return);
+        finish_function(&mut main, &HashMap::new());
         // place main at index 0
         self.module.functions[0] = main;
         self.module
@@ -99,6 +132,25 @@ impl Compiler {
         stmt: &StmtS,
         fun: &mut FunctionCode,
         locals: Option<&HashMap<String, u16>>,
+    ) {
+        // 앞서 나온 명령어는 바깥 문장(if 조건, for 반복 등)의 것
+        self.mark_positions(fun);
+        let outer = std::mem::replace(&mut self.position, stmt.1.start as u32);
+        self.emit_stmt_code(stmt, fun, locals);
+        self.mark_positions(fun);
+        self.position = outer;
+    }
+
+    /// 아직 위치가 없는 명령어에 지금 문장의 위치를 기록
+    fn mark_positions(&self, fun: &mut FunctionCode) {
+        fun.positions.resize(fun.code.len(), self.position);
+    }
+
+    fn emit_stmt_code(
+        &mut self,
+        stmt: &StmtS,
+        fun: &mut FunctionCode,
+        locals: Option<&HashMap<String, u16>>,
     ) {
         match &stmt.0 {
             Stmt::Break => {
@@ -122,8 +174,21 @@ impl Compiler {
             Stmt::Pass => {
                 // Pass is a no-op, emit nothing
             }
-            Stmt::Assign { target, value } => {
-                if let Err(e) = self.emit_assign(target, value, fun, locals) {
+            Stmt::Import(name) => {
+                let name_sym = self.sym_id(name);
+                fun.code.push(I::ImportModule(name_sym));
+                let target = (Expr::Variable(name.clone()), stmt.1.clone());
+                if let Err(e) = self.emit_assign_target(&target, fun, locals) {
+                    panic!("{}", e);
+                }
+            }
+            Stmt::Assign { targets, value } => {
+                if let Err(e) = self.emit_assign(targets, value, fun, locals) {
+                    panic!("{}", e);
+                }
+            }
+            Stmt::AugAssign { target, op, value } => {
+                if let Err(e) = self.emit_aug_assign(target, op, value, fun, locals) {
                     panic!("{}", e);
                 }
             }
@@ -216,7 +281,7 @@ impl Compiler {
                 }
             }
             Stmt::For {
-                var,
+                target,
                 iterable,
                 body,
             } => {
@@ -259,17 +324,9 @@ impl Compiler {
                 let next_method = self.intern("__next__");
                 fun.code.push(I::CallMethod(next_method, 0));
 
-                // 7. 루프 변수에 할당 (local 또는 global)
-                if let Some(locals) = locals {
-                    if let Some(ix) = locals.get(var) {
-                        fun.code.push(I::StoreLocal(*ix));
-                    } else {
-                        let var_sym = self.sym_id(var);
-                        fun.code.push(I::StoreGlobal(var_sym));
-                    }
-                } else {
-                    let var_sym = self.sym_id(var);
-                    fun.code.push(I::StoreGlobal(var_sym));
+                // 7. 루프 변수에 할당 (local 또는 global, 언패킹 패턴 포함)
+                if let Err(e) = self.emit_assign_target(target, fun, locals) {
+                    panic!("{}", e);
                 }
 
                 // 8. body 실행
@@ -311,20 +368,33 @@ impl Compiler {
                     arity: params.len() as u8,
                     num_locals,
                     code: vec![I::Return],
+                    ..Default::default()
                 });
                 let mut f = FunctionCode {
                     name_sym,
                     arity: params.len() as u8,
                     num_locals,
                     code: vec![],
+                    ..Default::default()
                 };
                 for s in body {
                     self.emit_stmt(s, &mut f, Some(&local_map));
                 }
                 f.code.push(I::Return);
+                finish_function(&mut f, &local_map);
                 self.module.functions[fid] = f;
+
+                // 모듈 수준 함수는 값으로도 쓸 수 있게 전역에 함수 객체를 둠 (`key=by_len`)
+                if locals.is_none() {
+                    fun.code.push(I::MakeClosure(fid as u16, 0));
+                    fun.code.push(I::StoreGlobal(name_sym));
+                }
             }
-            Stmt::Class { name, methods, .. } => {
+            Stmt::Class {
+                name,
+                methods,
+                attributes,
+            } => {
                 // 먼저 빈 ClassDef를 module.classes에 추가
                 let class_id = self.module.classes.len();
                 let class_def = ClassDef {
@@ -346,16 +416,25 @@ impl Compiler {
                 // Phase 4: UserClass를 Object로 저장
                 let name_sym = self.intern(name);
                 let const_id = self.module.consts.len();
-                let class_obj = super::bytecode::Value::Object(Rc::new(super::value::Object::new(
+                let class_obj = super::bytecode::Value::Object(Rc::new(super::value::Object::new_with_attrs(
                     super::type_def::TYPE_USER_START + class_id as u16,
                     super::value::ObjectData::UserClass {
                         class_id: class_id as u16,
+                        name: name.as_str().into(),
                         methods: method_map,
                     },
                 )));
                 self.module.consts.push(class_obj);
                 fun.code.push(I::LoadConst(const_id as u32));
                 fun.code.push(I::StoreGlobal(name_sym));
+
+                // 클래스 속성은 클래스 객체의 속성으로 저장 (인스턴스에 없으면 여기서 찾음)
+                for (attr, value) in attributes {
+                    let attr_sym = self.intern(attr);
+                    fun.code.push(I::LoadConst(const_id as u32));
+                    self.emit_expr(value, fun, locals);
+                    fun.code.push(I::StoreAttr(attr_sym));
+                }
             }
         }
     }
@@ -371,6 +450,7 @@ impl Compiler {
             arity: method.params.len() as u8,
             num_locals,
             code: vec![I::Return],
+            ..Default::default()
         });
 
         let mut f = FunctionCode {
@@ -378,6 +458,7 @@ impl Compiler {
             arity: method.params.len() as u8,
             num_locals,
             code: vec![],
+            ..Default::default()
         };
 
         for s in &method.body {
@@ -390,6 +471,7 @@ impl Compiler {
         }
 
         f.code.push(I::Return);
+        finish_function(&mut f, &local_map);
         self.module.functions[fid] = f;
 
         Ok(fid as u16)
@@ -429,6 +511,7 @@ impl Compiler {
                     UnaryOp::Not => fun.code.push(I::Not),
                     UnaryOp::Negate => fun.code.push(I::Neg),
                     UnaryOp::Pos => fun.code.push(I::Pos),
+                    UnaryOp::Invert => fun.code.push(I::Invert),
                 }
             }
             Expr::Binary { op, left, right } => {
@@ -465,25 +548,15 @@ impl Compiler {
                     _ => {
                         self.emit_expr(left, fun, locals);
                         self.emit_expr(right, fun, locals);
-                        match op {
-                            B::Add => fun.code.push(I::Add),
-                            B::Subtract => fun.code.push(I::Sub),
-                            B::Multiply => fun.code.push(I::Mul),
-                            B::Divide => fun.code.push(I::TrueDiv),
-                            B::FloorDivide => fun.code.push(I::Div),
-                            B::Modulo => fun.code.push(I::Mod),
-                            B::Equal => fun.code.push(I::Eq),
-                            B::NotEqual => fun.code.push(I::Ne),
-                            B::Less => fun.code.push(I::Lt),
-                            B::LessEqual => fun.code.push(I::Le),
-                            B::Greater => fun.code.push(I::Gt),
-                            B::GreaterEqual => fun.code.push(I::Ge),
-                            B::And | B::Or => unreachable!(),
-                        }
+                        emit_binary_op(op, fun);
                     }
                 }
             }
-            Expr::Call { func_name, args } => {
+            Expr::Call {
+                func_name,
+                args,
+                kwargs,
+            } => {
                 // 특별 처리: func_name이 Attribute인 경우 → CallMethod 최적화
                 if let Expr::Attribute { object, attr } = &func_name.0 {
                     // 메서드 호출: obj.method(args)
@@ -492,7 +565,12 @@ impl Compiler {
                         self.emit_expr(arg, fun, locals);
                     }
                     let method_sym = self.intern(attr);
-                    fun.code.push(I::CallMethod(method_sym, args.len() as u8));
+                    if kwargs.is_empty() {
+                        fun.code.push(I::CallMethod(method_sym, args.len() as u8));
+                    } else {
+                        self.emit_kwargs_dict(kwargs, fun, locals);
+                        fun.code.push(I::CallMethodKw(method_sym, args.len() as u8));
+                    }
                     return;
                 }
 
@@ -502,7 +580,20 @@ impl Compiler {
                         for a in args {
                             self.emit_expr(a, fun, locals);
                         }
-                        fun.code.push(I::CallBuiltin(bid, args.len() as u8));
+                        if kwargs.is_empty() {
+                            fun.code.push(I::CallBuiltin(bid, args.len() as u8));
+                        } else {
+                            self.emit_kwargs_dict(kwargs, fun, locals);
+                            fun.code.push(I::CallBuiltinKw(bid, args.len() as u8));
+                        }
+                        return;
+                    }
+                    if self.natives.contains(name) && !self.shadows_native(name, locals) {
+                        for a in args {
+                            self.emit_expr(a, fun, locals);
+                        }
+                        let name_sym = self.intern(name);
+                        fun.code.push(I::CallNative(name_sym, args.len() as u8));
                         return;
                     }
                     // 클래스인지 확인
@@ -551,6 +642,71 @@ impl Compiler {
                 }
                 fun.code.push(I::CallValue(args.len() as u8));
             }
+            Expr::Compare { left, ops } => {
+                // a < b < c:
+                //   a; b; Dup; Rot3; Lt; JumpIfFalse cleanup   (스택: b)
+                //   c; Lt; Jump end
+                //   cleanup: Pop; False
+                //   end:
+                self.emit_expr(left, fun, locals);
+                let mut cleanup_jumps = Vec::with_capacity(ops.len() - 1);
+                for (op, operand) in &ops[..ops.len() - 1] {
+                    self.emit_expr(operand, fun, locals);
+                    fun.code.push(I::Dup);
+                    fun.code.push(I::Rot3);
+                    emit_binary_op(op, fun);
+                    cleanup_jumps.push(fun.code.len());
+                    fun.code.push(I::JumpIfFalse(0));
+                }
+                let (op, operand) = &ops[ops.len() - 1];
+                self.emit_expr(operand, fun, locals);
+                emit_binary_op(op, fun);
+                let j_end = fun.code.len();
+                fun.code.push(I::Jump(0));
+                let l_cleanup = fun.code.len() as i32;
+                for j in cleanup_jumps {
+                    patch_rel(&mut fun.code[j], l_cleanup - (j as i32 + 1));
+                }
+                fun.code.push(I::Pop);
+                fun.code.push(I::False);
+                let l_end = fun.code.len() as i32;
+                patch_rel(&mut fun.code[j_end], l_end - (j_end as i32 + 1));
+            }
+            Expr::FString(parts) => {
+                if parts.is_empty() {
+                    let str_id = get_or_add_string(&mut self.module, String::new());
+                    fun.code.push(I::ConstStr(str_id));
+                    return;
+                }
+                for part in parts {
+                    match part {
+                        FStringPart::Literal(text) => {
+                            let str_id = get_or_add_string(&mut self.module, text.clone());
+                            fun.code.push(I::ConstStr(str_id));
+                        }
+                        FStringPart::Expr {
+                            expr,
+                            conversion,
+                            spec,
+                        } => {
+                            self.emit_expr(expr, fun, locals);
+                            if let Some(spec) = spec {
+                                let str_id = get_or_add_string(&mut self.module, spec.clone());
+                                fun.code.push(I::ConstStr(str_id));
+                            }
+                            let conversion = match conversion {
+                                Some('s') => 1,
+                                Some('r') => 2,
+                                _ => 0,
+                            };
+                            fun.code.push(I::FormatValue(conversion, spec.is_some()));
+                        }
+                    }
+                }
+                if parts.len() > 1 {
+                    fun.code.push(I::BuildString(parts.len() as u16));
+                }
+            }
             Expr::Attribute { object, attr } => {
                 self.emit_expr(object, fun, locals);
                 let attr_sym = self.intern(attr);
@@ -604,6 +760,35 @@ impl Compiler {
                 // LoadIndex instruction
                 fun.code.push(I::LoadIndex);
             }
+            Expr::Conditional {
+                condition,
+                then_expr,
+                else_expr,
+            } => {
+                self.emit_expr(condition, fun, locals);
+                let j_else = fun.code.len();
+                fun.code.push(I::JumpIfFalse(0));
+                self.emit_expr(then_expr, fun, locals);
+                let j_end = fun.code.len();
+                fun.code.push(I::Jump(0));
+                let l_else = fun.code.len() as i32;
+                patch_rel(&mut fun.code[j_else], l_else - (j_else as i32 + 1));
+                self.emit_expr(else_expr, fun, locals);
+                let l_end = fun.code.len() as i32;
+                patch_rel(&mut fun.code[j_end], l_end - (j_end as i32 + 1));
+            }
+            Expr::NamedExpr { name, value } => {
+                // 값을 저장하고 식의 결과로도 남김
+                self.emit_expr(value, fun, locals);
+                fun.code.push(I::Dup);
+                let target = (Expr::Variable(name.clone()), expr.1.clone());
+                if let Err(e) = self.emit_assign_target(&target, fun, locals) {
+                    panic!("{}", e);
+                }
+            }
+            Expr::Starred(_) => {
+                panic!("starred expression outside assignment target (should be caught by semantic analysis)");
+            }
             Expr::Lambda { params, body } => {
                 // Lambda를 익명 함수로 컴파일 (Closure 지원)
                 // 1. 익명 함수 이름 생성
@@ -633,6 +818,7 @@ impl Compiler {
                     arity: params.len() as u8,
                     num_locals,
                     code: vec![I::Return],
+                    ..Default::default()
                 });
 
                 // 5. Lambda body 컴파일 (단일 표현식)
@@ -641,9 +827,13 @@ impl Compiler {
                     arity: params.len() as u8,
                     num_locals,
                     code: vec![],
+                    ..Default::default()
                 };
                 self.emit_expr(body, &mut lambda_fun, Some(&lambda_locals));
+                // 본문은 람다를 담은 문장의 위치
+                self.mark_positions(&mut lambda_fun);
                 lambda_fun.code.push(I::Return);
+                finish_function(&mut lambda_fun, &lambda_locals);
 
                 // 6. 컴파일된 함수 저장
                 self.module.functions[fid] = lambda_fun;
@@ -705,25 +895,42 @@ impl Compiler {
                 Ok(())
             }
             Expr::Attribute { object, attr } => {
-                // object는 이미 스택에 있어야 함 (이전에 평가됨)
-                // 하지만 여기서는 값만 스택에 있으므로, object를 먼저 평가해야 함
-                // 이 경우는 일반 assign에서 처리됨
-                Err("Attribute assignment in unpacking not yet supported".to_string())
+                // value → value, obj → obj, value
+                self.emit_expr(object, fun, locals);
+                fun.code.push(I::Swap);
+                let attr_sym = self.intern(attr);
+                fun.code.push(I::StoreAttr(attr_sym));
+                Ok(())
             }
             Expr::Index { object, index } => {
-                // object와 index는 이미 스택에 있어야 함
-                // 하지만 여기서는 값만 스택에 있으므로, 이 경우는 일반 assign에서 처리됨
-                Err("Index assignment in unpacking not yet supported".to_string())
+                // value → obj, value → obj, value, idx → obj, idx, value
+                self.emit_expr(object, fun, locals);
+                fun.code.push(I::Swap);
+                self.emit_expr(index, fun, locals);
+                fun.code.push(I::Swap);
+                fun.code.push(I::StoreIndex);
+                Ok(())
             }
-            Expr::Tuple(elements) => {
+            Expr::Tuple(elements) | Expr::List(elements)
+                if let Some(star) = elements.iter().position(|e| matches!(e.0, Expr::Starred(_))) =>
+            {
+                // 별표 언패킹: first, *rest = ... → 대상 순서대로 스택 top에 놓임
+                let after = elements.len() - star - 1;
+                fun.code.push(I::UnpackEx(star as u8, after as u8));
+                for target_elem in elements {
+                    let target_elem = match &target_elem.0 {
+                        Expr::Starred(inner) => inner.as_ref(),
+                        _ => target_elem,
+                    };
+                    self.emit_assign_target(target_elem, fun, locals)?;
+                }
+                Ok(())
+            }
+            Expr::Tuple(elements) | Expr::List(elements) => {
                 // 중첩 언패킹: (a, (b, c)) = ...
-                // 값은 이미 스택에 있음
-                for (i, target_elem) in elements.iter().enumerate().rev() {
-                    if i > 0 {
-                        fun.code.push(I::Dup);
-                    }
-                    fun.code.push(I::ConstI64(i as i64));
-                    fun.code.push(I::LoadIndex);
+                // 값은 이미 스택에 있음 → 첫 대상의 값이 top에 오도록 풀어서 차례로 저장
+                fun.code.push(I::UnpackSequence(elements.len() as u8));
+                for target_elem in elements {
                     self.emit_assign_target(target_elem, fun, locals)?;
                 }
                 Ok(())
@@ -732,14 +939,25 @@ impl Compiler {
         }
     }
 
-    /// 할당 문을 컴파일합니다. 튜플 언패킹을 지원합니다.
+    /// 할당 문을 컴파일합니다. 튜플 언패킹과 연쇄 할당을 지원합니다.
     fn emit_assign(
         &mut self,
-        target: &ExprS,
+        targets: &[ExprS],
         value: &ExprS,
         fun: &mut FunctionCode,
         locals: Option<&HashMap<String, u16>>,
     ) -> Result<(), String> {
+        let [target] = targets else {
+            // a = b = value: 값은 한 번만 평가하고 왼쪽 대상부터 차례로 저장
+            self.emit_expr(value, fun, locals);
+            for (i, target) in targets.iter().enumerate() {
+                if i + 1 < targets.len() {
+                    fun.code.push(I::Dup);
+                }
+                self.emit_assign_target(target, fun, locals)?;
+            }
+            return Ok(());
+        };
         match &target.0 {
             Expr::Variable(name) => {
                 self.emit_expr(value, fun, locals);
@@ -761,25 +979,11 @@ impl Compiler {
                 fun.code.push(I::StoreIndex);
                 Ok(())
             }
-            Expr::Tuple(elements) => {
+            Expr::Tuple(_) | Expr::List(_) => {
                 // 튜플 언패킹: a, b, c = value
-                // 1. RHS 평가 (튜플/리스트를 스택에 push)
+                // RHS를 스택에 push한 뒤 대상 패턴에 따라 (재귀적으로) 나눠 저장
                 self.emit_expr(value, fun, locals);
-                
-                // 2. 각 요소를 역순으로 처리 (스택 순서 맞추기 위해)
-                for (i, target_elem) in elements.iter().enumerate().rev() {
-                    // 마지막 요소가 아니면 튜플/리스트 복사
-                    if i > 0 {
-                        fun.code.push(I::Dup);
-                    }
-                    // 인덱스 push
-                    fun.code.push(I::ConstI64(i as i64));
-                    // 요소 가져오기
-                    fun.code.push(I::LoadIndex);
-                    // target에 할당 (재귀적으로 처리하여 중첩 언패킹 지원)
-                    self.emit_assign_target(target_elem, fun, locals)?;
-                }
-                Ok(())
+                self.emit_assign_target(target, fun, locals)
             }
             _ => {
                 Err("Invalid assignment target".to_string())
@@ -787,6 +991,64 @@ impl Compiler {
         }
     }
 
+    /// 복합 할당 `target op= value`를 컴파일합니다.
+    /// 속성/인덱스 대상의 객체와 인덱스 식은 한 번만 평가합니다.
+    fn emit_aug_assign(
+        &mut self,
+        target: &ExprS,
+        op: &BinaryOp,
+        value: &ExprS,
+        fun: &mut FunctionCode,
+        locals: Option<&HashMap<String, u16>>,
+    ) -> Result<(), String> {
+        match &target.0 {
+            Expr::Variable(_) => {
+                self.emit_expr(target, fun, locals);
+                self.emit_expr(value, fun, locals);
+                emit_aug_op(op, fun);
+                self.emit_assign_target(target, fun, locals)
+            }
+            Expr::Attribute { object, attr } => {
+                // obj → obj, obj → obj, old → obj, old, value → obj, new
+                self.emit_expr(object, fun, locals);
+                fun.code.push(I::Dup);
+                let attr_sym = self.intern(attr);
+                fun.code.push(I::LoadAttr(attr_sym));
+                self.emit_expr(value, fun, locals);
+                emit_aug_op(op, fun);
+                fun.code.push(I::StoreAttr(attr_sym));
+                Ok(())
+            }
+            Expr::Index { object, index } => {
+                // obj, idx → obj, idx, obj, idx → obj, idx, old → obj, idx, new
+                self.emit_expr(object, fun, locals);
+                self.emit_expr(index, fun, locals);
+                fun.code.push(I::Dup2);
+                fun.code.push(I::LoadIndex);
+                self.emit_expr(value, fun, locals);
+                emit_aug_op(op, fun);
+                fun.code.push(I::StoreIndex);
+                Ok(())
+            }
+            _ => Err("Invalid augmented assignment target".to_string()),
+        }
+    }
+
+    /// 키워드 인자는 dict 하나로 묶어 전달
+    fn emit_kwargs_dict(
+        &mut self,
+        kwargs: &[(String, ExprS)],
+        fun: &mut FunctionCode,
+        locals: Option<&HashMap<String, u16>>,
+    ) {
+        for (name, value) in kwargs {
+            let str_id = get_or_add_string(&mut self.module, name.clone());
+            fun.code.push(I::ConstStr(str_id));
+            self.emit_expr(value, fun, locals);
+        }
+        fun.code.push(I::BuildDict(kwargs.len() as u16));
+    }
+
     fn resolve_function_id(&mut self, name: &str) -> usize {
         // linear scan; in v0.1 functions are compiled before use in same module body order
         if name == "__main__" {
@@ -811,6 +1073,7 @@ impl Compiler {
             arity: 0,
             num_locals: 0,
             code: vec![I::Return],
+            ..Default::default()
         });
         id
     }
@@ -822,17 +1085,29 @@ fn collect_locals_from_target(target: &Expr, seen: &mut HashSet<String>) {
         Expr::Variable(name) => {
             seen.insert(name.clone());
         }
-        Expr::Tuple(elements) => {
+        Expr::Tuple(elements) | Expr::List(elements) => {
             for elem in elements {
                 collect_locals_from_target(&elem.0, seen);
             }
         }
+        Expr::Starred(inner) => collect_locals_from_target(&inner.0, seen),
         _ => {
             // 다른 표현식은 로컬 변수를 생성하지 않음
         }
     }
 }
 
+/// 함수 끝의 암묵적 코드에 위치 없음을 표시하고 지역 변수 이름을 채움
+fn finish_function(fun: &mut FunctionCode, locals: &HashMap<String, u16>) {
+    fun.positions.resize(fun.code.len(), NO_POSITION);
+    fun.local_names = vec![String::new(); fun.num_locals as usize];
+    for (name, &slot) in locals {
+        if let Some(entry) = fun.local_names.get_mut(slot as usize) {
+            *entry = name.clone();
+        }
+    }
+}
+
 fn collect_locals(params: &[String], body: &[StmtS]) -> HashMap<String, u16> {
     let mut map: HashMap<String, u16> = HashMap::new();
     for (i, p) in params.iter().enumerate() {
@@ -841,17 +1116,23 @@ fn collect_locals(params: &[String], body: &[StmtS]) -> HashMap<String, u16> {
     let mut seen: HashSet<String> = params.iter().cloned().collect();
     fn walk(body: &[StmtS], seen: &mut HashSet<String>) {
         for s in body {
+            // 대입 표현식(`n := ...`)의 이름도 로컬
+            for expr in crate::semantic::stmt_exprs(&s.0) {
+                crate::semantic::collect_named_expr_targets(expr, seen);
+            }
             match &s.0 {
-                Stmt::Assign { target, .. } => {
-                    // locals는 Variable만 추적
-                    if let Expr::Variable(name) = &target.0 {
-                        seen.insert(name.clone());
+                Stmt::Assign { targets, .. } => {
+                    for target in targets {
+                        collect_locals_from_target(&target.0, seen);
                     }
                 }
+                Stmt::AugAssign { target, .. } => {
+                    collect_locals_from_target(&target.0, seen);
+                }
                 Stmt::Def { name, .. } => {
                     seen.insert(name.clone());
                 }
-                Stmt::Class { name, .. } => {
+                Stmt::Class { name, .. } | Stmt::Import(name) => {
                     seen.insert(name.clone());
                 }
                 Stmt::If {
@@ -871,9 +1152,9 @@ fn collect_locals(params: &[String], body: &[StmtS]) -> HashMap<String, u16> {
                 Stmt::While { body, .. } => {
                     walk(body, seen);
                 }
-                Stmt::For { var, body, .. } => {
+                Stmt::For { target, body, .. } => {
                     // for문의 루프 변수도 local로 수집
-                    seen.insert(var.clone());
+                    collect_locals_from_target(&target.0, seen);
                     walk(body, seen);
                 }
                 Stmt::Break | Stmt::Continue | Stmt::Pass => {}
@@ -904,12 +1185,27 @@ fn collect_referenced_vars(expr: &ExprS, vars: &mut HashSet<String>) {
         Expr::Unary { expr: inner, .. } => {
             collect_referenced_vars(inner, vars);
         }
-        Expr::Call { func_name, args } => {
+        Expr::Compare { left, ops } => {
+            collect_referenced_vars(left, vars);
+            for (_, operand) in ops {
+                collect_referenced_vars(operand, vars);
+            }
+        }
+        Expr::Call {
+            func_name,
+            args,
+            kwargs,
+        } => {
             collect_referenced_vars(func_name, vars);
-            for arg in args {
+            for arg in args.iter().chain(kwargs.iter().map(|(_, v)| v)) {
                 collect_referenced_vars(arg, vars);
             }
         }
+        Expr::FString(parts) => {
+            for inner in crate::semantic::fstring_exprs(parts) {
+                collect_referenced_vars(inner, vars);
+            }
+        }
         Expr::Attribute { object, .. } => {
             collect_referenced_vars(object, vars);
         }
@@ -943,6 +1239,18 @@ fn collect_referenced_vars(expr: &ExprS, vars: &mut HashSet<String>) {
             collect_referenced_vars(object, vars);
             collect_referenced_vars(index, vars);
         }
+        Expr::Conditional {
+            condition,
+            then_expr,
+            else_expr,
+        } => {
+            collect_referenced_vars(condition, vars);
+            collect_referenced_vars(then_expr, vars);
+            collect_referenced_vars(else_expr, vars);
+        }
+        Expr::NamedExpr { value, .. } | Expr::Starred(value) => {
+            collect_referenced_vars(value, vars);
+        }
         Expr::Lambda { params, body } => {
             // 중첩 lambda의 body도 재귀적으로 탐색
             // (중첩 lambda가 참조하는 변수를 현재 lambda도 캡처해야 할 수 있음)
@@ -993,6 +1301,54 @@ fn collect_free_vars(
     free_vars
 }
 
+/// 복합 할당용 연산: `+=`, `-=`, `&=`, `|=`, `^=`는 제자리 버전이 따로 있습니다.
+fn emit_aug_op(op: &BinaryOp, fun: &mut FunctionCode) {
+    match op {
+        BinaryOp::Add => fun.code.push(I::InplaceAdd),
+        BinaryOp::Subtract => fun.code.push(I::InplaceSub),
+        BinaryOp::BitAnd => fun.code.push(I::InplaceBitAnd),
+        BinaryOp::BitOr => fun.code.push(I::InplaceBitOr),
+        BinaryOp::BitXor => fun.code.push(I::InplaceBitXor),
+        _ => emit_binary_op(op, fun),
+    }
+}
+
+/// 산술/비교 이항 연산자 하나를 명령어로 변환 (`and`/`or`는 제외)
+fn emit_binary_op(op: &BinaryOp, fun: &mut FunctionCode) {
+    use BinaryOp as B;
+    match op {
+        B::Add => fun.code.push(I::Add),
+        B::Subtract => fun.code.push(I::Sub),
+        B::Multiply => fun.code.push(I::Mul),
+        B::Divide => fun.code.push(I::TrueDiv),
+        B::FloorDivide => fun.code.push(I::Div),
+        B::Modulo => fun.code.push(I::Mod),
+        B::Power => fun.code.push(I::Pow),
+        B::BitAnd => fun.code.push(I::BitAnd),
+        B::BitOr => fun.code.push(I::BitOr),
+        B::BitXor => fun.code.push(I::BitXor),
+        B::LeftShift => fun.code.push(I::Shl),
+        B::RightShift => fun.code.push(I::Shr),
+        B::Equal => fun.code.push(I::Eq),
+        B::NotEqual => fun.code.push(I::Ne),
+        B::Less => fun.code.push(I::Lt),
+        B::LessEqual => fun.code.push(I::Le),
+        B::Greater => fun.code.push(I::Gt),
+        B::GreaterEqual => fun.code.push(I::Ge),
+        B::In => fun.code.push(I::In),
+        B::NotIn => {
+            fun.code.push(I::In);
+            fun.code.push(I::Not);
+        }
+        B::Is => fun.code.push(I::Is),
+        B::IsNot => {
+            fun.code.push(I::Is);
+            fun.code.push(I::Not);
+        }
+        B::And | B::Or => unreachable!("short-circuit operators are compiled with jumps"),
+    }
+}
+
 fn patch_rel(ins: &mut I, rel: i32) {
     match ins {
         I::JumpIfFalse(r) | I::JumpIfTrue(r) | I::Jump(r) => *r = rel,
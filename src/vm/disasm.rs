@@ -72,6 +72,13 @@ pub fn disassemble_function(
     Ok(())
 }
 
+/// 명령어 하나를 피연산자와 함께 포맷 (`LoadGlobal 0 ("x")`), trace에서도 사용
+pub fn disassemble_instruction_to_string(module: &Module, ins: &I) -> String {
+    let mut output = String::new();
+    let _ = disassemble_instruction(module, ins, &mut output);
+    output
+}
+
 fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt::Result {
     let ins_name = ins.to_string();
     match ins {
@@ -88,6 +95,9 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
 
         I::Pop => write!(w, "{}", ins_name),
         I::Dup => write!(w, "{}", ins_name),
+        I::Rot3 => write!(w, "{}", ins_name),
+        I::Dup2 => write!(w, "{}", ins_name),
+        I::Swap => write!(w, "{}", ins_name),
 
         I::LoadLocal(idx) => write!(w, "{} {}", ins_name, idx),
         I::StoreLocal(idx) => write!(w, "{} {}", ins_name, idx),
@@ -101,11 +111,23 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
         }
 
         I::Add => write!(w, "{}", ins_name),
+        I::InplaceAdd => write!(w, "{}", ins_name),
         I::Sub => write!(w, "{}", ins_name),
+        I::InplaceSub => write!(w, "{}", ins_name),
         I::Mul => write!(w, "{}", ins_name),
         I::Div => write!(w, "{}", ins_name),
         I::Mod => write!(w, "{}", ins_name),
         I::Neg => write!(w, "{}", ins_name),
+        I::Pow => write!(w, "{}", ins_name),
+        I::BitAnd => write!(w, "{}", ins_name),
+        I::BitOr => write!(w, "{}", ins_name),
+        I::BitXor => write!(w, "{}", ins_name),
+        I::InplaceBitAnd => write!(w, "{}", ins_name),
+        I::InplaceBitOr => write!(w, "{}", ins_name),
+        I::InplaceBitXor => write!(w, "{}", ins_name),
+        I::Shl => write!(w, "{}", ins_name),
+        I::Shr => write!(w, "{}", ins_name),
+        I::Invert => write!(w, "{}", ins_name),
         I::Pos => write!(w, "{}", ins_name),
         I::TrueDiv => write!(w, "{}", ins_name),
 
@@ -116,6 +138,8 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
         I::Gt => write!(w, "{}", ins_name),
         I::Ge => write!(w, "{}", ins_name),
         I::Not => write!(w, "{}", ins_name),
+        I::In => write!(w, "{}", ins_name),
+        I::Is => write!(w, "{}", ins_name),
 
         I::Jump(offset) => write!(w, "{} {}", ins_name, offset),
         I::JumpIfFalse(offset) => write!(w, "{} {}", ins_name, offset),
@@ -130,6 +154,13 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
             )
         }
         I::CallBuiltin(bid, argc) => write!(w, "{} {} (argc={})", ins_name, bid, argc),
+        I::CallBuiltinKw(bid, argc) => {
+            write!(w, "{} {} (argc={}, kwargs)", ins_name, bid, argc)
+        }
+        I::CallNative(name_sym, argc) => {
+            let name = &module.symbols[*name_sym as usize];
+            write!(w, "{} {} (\"{}\", argc={})", ins_name, name_sym, name, argc)
+        }
         I::CallValue(argc) => write!(w, "{} (argc={})", ins_name, argc),
         I::CallMethod(method_sym, argc) => {
             let method_name = &module.symbols[*method_sym as usize];
@@ -139,6 +170,23 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
                 ins_name, method_sym, method_name, argc
             )
         }
+        I::CallMethodKw(method_sym, argc) => {
+            let method_name = &module.symbols[*method_sym as usize];
+            write!(
+                w,
+                "{} {} (\"{}\", argc={}, kwargs)",
+                ins_name, method_sym, method_name, argc
+            )
+        }
+        I::FormatValue(conversion, has_spec) => {
+            let conv = match conversion {
+                1 => "!s",
+                2 => "!r",
+                _ => "",
+            };
+            write!(w, "{} (conv=\"{}\", spec={})", ins_name, conv, has_spec)
+        }
+        I::BuildString(count) => write!(w, "{} (count={})", ins_name, count),
         I::Return => write!(w, "{}", ins_name),
 
         I::LoadAttr(attr_sym) => {
@@ -149,6 +197,10 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
             let attr_name = &module.symbols[*attr_sym as usize];
             write!(w, "{} {} (\"{}\")", ins_name, attr_sym, attr_name)
         }
+        I::ImportModule(name_sym) => {
+            let name = &module.symbols[*name_sym as usize];
+            write!(w, "{} {} (\"{}\")", ins_name, name_sym, name)
+        }
 
         I::BuildList(count) => write!(w, "{} (count={})", ins_name, count),
         I::BuildTuple(count) => write!(w, "{} (count={})", ins_name, count),
@@ -157,6 +209,10 @@ fn disassemble_instruction(module: &Module, ins: &I, w: &mut impl Write) -> fmt:
         I::BuildTreeSet(count) => write!(w, "{} (count={})", ins_name, count),
         I::LoadIndex => write!(w, "{}", ins_name),
         I::StoreIndex => write!(w, "{}", ins_name),
+        I::UnpackSequence(count) => write!(w, "{} (count={})", ins_name, count),
+        I::UnpackEx(before, after) => {
+            write!(w, "{} (before={}, after={})", ins_name, before, after)
+        }
         I::MakeClosure(func_id, num_captures) => {
             let fname = &module.symbols[module.functions[*func_id as usize].name_sym as usize];
             write!(
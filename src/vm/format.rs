@@ -0,0 +1,898 @@
+//! 문자열 포매팅 엔진
+//!
+//! f-string, `str.format`, `%` 연산자, `format()` 빌트인이 모두 같은
+//! format spec 미니 언어를 공유합니다.
+//!
+//! ```text
+//! [[fill]align][sign][#][0][width][grouping][.precision][type]
+//!
+//! align    : '<' | '>' | '^' | '='
+//! sign     : '+' | '-' | ' '
+//! grouping : ',' | '_'
+//! type     : 'd' | 'f' | 'F' | 'e' | 'E' | 'g' | 'G' | 'x' | 'X' | 'o' | 'b' | 'c' | 's' | '%'
+//! ```
+
+use super::bytecode::Value;
+use super::utils::{display_value, repr_value, type_name};
+use super::value::{DictKey, ObjectData};
+use super::{VmErrorKind, VmResult, err};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+    /// 부호/접두사 뒤에 채움 (`+0042`)
+    AfterSign,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    /// 음수에만 `-`
+    Minus,
+    /// 항상 부호 표시
+    Plus,
+    /// 양수에는 공백
+    Space,
+}
+
+/// 파싱된 format spec
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatSpec {
+    pub fill: char,
+    pub align: Option<Align>,
+    pub sign: Sign,
+    /// `#`: 진법 접두사(`0x`, `0o`, `0b`)
+    pub alternate: bool,
+    pub width: Option<usize>,
+    pub grouping: Option<char>,
+    pub precision: Option<usize>,
+    pub ty: Option<char>,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: None,
+            sign: Sign::Minus,
+            alternate: false,
+            width: None,
+            grouping: None,
+            precision: None,
+            ty: None,
+        }
+    }
+}
+
+fn parse_align(c: char) -> Option<Align> {
+    match c {
+        '<' => Some(Align::Left),
+        '>' => Some(Align::Right),
+        '^' => Some(Align::Center),
+        '=' => Some(Align::AfterSign),
+        _ => None,
+    }
+}
+
+impl FormatSpec {
+    /// spec 문자열 파싱. 에러 메시지는 컴파일 시점 진단과 런타임 ValueError에 함께 쓰입니다.
+    pub fn parse(spec: &str) -> Result<FormatSpec, String> {
+        let chars: Vec<char> = spec.chars().collect();
+        let mut out = FormatSpec::default();
+        let mut i = 0;
+
+        // [[fill]align]
+        if chars.len() >= 2 && let Some(align) = parse_align(chars[1]) {
+            out.fill = chars[0];
+            out.align = Some(align);
+            i = 2;
+        } else if let Some(align) = chars.first().and_then(|&c| parse_align(c)) {
+            out.align = Some(align);
+            i = 1;
+        }
+
+        // [sign]
+        if let Some(&c) = chars.get(i) {
+            let sign = match c {
+                '+' => Some(Sign::Plus),
+                '-' => Some(Sign::Minus),
+                ' ' => Some(Sign::Space),
+                _ => None,
+            };
+            if let Some(sign) = sign {
+                out.sign = sign;
+                i += 1;
+            }
+        }
+
+        // [#]
+        if chars.get(i) == Some(&'#') {
+            out.alternate = true;
+            i += 1;
+        }
+
+        // [0]: 명시적 정렬이 없으면 부호 뒤를 '0'으로 채움
+        if chars.get(i) == Some(&'0') {
+            if out.align.is_none() {
+                out.fill = '0';
+                out.align = Some(Align::AfterSign);
+            }
+            i += 1;
+        }
+
+        // [width]
+        let start = i;
+        while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+            i += 1;
+        }
+        if i > start {
+            let digits: String = chars[start..i].iter().collect();
+            out.width = Some(digits.parse().map_err(|_| "Too many decimal digits in format string".to_string())?);
+        }
+
+        // [grouping]
+        if let Some(&c) = chars.get(i)
+            && (c == ',' || c == '_')
+        {
+            out.grouping = Some(c);
+            i += 1;
+            if matches!(chars.get(i), Some(',') | Some('_')) {
+                return Err("Cannot specify both ',' and '_'".to_string());
+            }
+        }
+
+        // [.precision]
+        if chars.get(i) == Some(&'.') {
+            i += 1;
+            let start = i;
+            while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            if i == start {
+                return Err("Format specifier missing precision".to_string());
+            }
+            let digits: String = chars[start..i].iter().collect();
+            out.precision = Some(digits.parse().map_err(|_| "Too many decimal digits in format string".to_string())?);
+        }
+
+        // [type]
+        if let Some(&c) = chars.get(i) {
+            if !"dfFeEgGxXobcs%".contains(c) {
+                return Err(format!("Unknown format code '{}'", c));
+            }
+            out.ty = Some(c);
+            i += 1;
+        }
+
+        if i < chars.len() {
+            return Err(format!("Invalid format specifier '{}'", spec));
+        }
+
+        if out.grouping.is_some() && matches!(out.ty, Some('c') | Some('s')) {
+            return Err(format!("Cannot specify '{}' with '{}'", out.grouping.unwrap_or(','), out.ty.unwrap_or('s')));
+        }
+
+        Ok(out)
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == FormatSpec::default()
+    }
+}
+
+/// `f"{x:>99999999999}"`, `"%.9999999999d"` 같은 너비/정밀도는 만들기 전에 검사
+/// (할당이 실패하면 프로세스가 abort되므로 MemoryError나 메모리 제한 초과로 보고)
+fn reserve_spec(spec: &FormatSpec) -> VmResult<()> {
+    let size = spec.width.unwrap_or(0).saturating_add(spec.precision.unwrap_or(0));
+    super::limits::reserve(size.saturating_mul(spec.fill.len_utf8()))
+}
+
+fn value_error(message: String) -> super::VmError {
+    err(VmErrorKind::TypeError("format"), format!("ValueError: {}", message))
+}
+
+fn type_error(message: String) -> super::VmError {
+    err(VmErrorKind::TypeError("format"), message)
+}
+
+/// `format(value, spec)` — spec 문자열을 파싱해 값을 포매팅
+pub fn format_with_spec(value: &Value, spec: &str) -> VmResult<String> {
+    let parsed = FormatSpec::parse(spec).map_err(value_error)?;
+    format_value(value, &parsed)
+}
+
+/// 파싱된 spec으로 값 포매팅
+pub fn format_value(value: &Value, spec: &FormatSpec) -> VmResult<String> {
+    reserve_spec(spec)?;
+    match value {
+        Value::Int(n) => format_int(*n, spec),
+        Value::Bool(b) if spec.ty.is_none() => Ok(pad(
+            if *b { "True" } else { "False" },
+            spec,
+            Align::Left,
+        )),
+        Value::Bool(b) => format_int(*b as i64, spec),
+        Value::Float(f) => format_float(*f, spec),
+        Value::Object(obj) if matches!(obj.data, ObjectData::String(_)) => {
+            let ObjectData::String(s) = &obj.data else { unreachable!() };
+            format_str(s, spec)
+        }
+        _ if spec.is_empty() => Ok(display_value(value)),
+        _ => Err(type_error(format!(
+            "TypeError: unsupported format string passed to {}.__format__",
+            type_name(value)
+        ))),
+    }
+}
+
+fn format_str(s: &str, spec: &FormatSpec) -> VmResult<String> {
+    if let Some(ty) = spec.ty
+        && ty != 's'
+    {
+        return Err(value_error(format!(
+            "Unknown format code '{}' for object of type 'str'",
+            ty
+        )));
+    }
+    if spec.sign != Sign::Minus {
+        return Err(value_error("Sign not allowed in string format specifier".to_string()));
+    }
+    if spec.align == Some(Align::AfterSign) {
+        return Err(value_error(
+            "'=' alignment not allowed in string format specifier".to_string(),
+        ));
+    }
+    let truncated: String = match spec.precision {
+        Some(p) => s.chars().take(p).collect(),
+        None => s.to_string(),
+    };
+    Ok(pad(&truncated, spec, Align::Left))
+}
+
+fn format_int(n: i64, spec: &FormatSpec) -> VmResult<String> {
+    let ty = spec.ty.unwrap_or('d');
+    let (prefix, digits) = match ty {
+        'd' => ("", n.unsigned_abs().to_string()),
+        'x' => ("0x", format!("{:x}", n.unsigned_abs())),
+        'X' => ("0X", format!("{:X}", n.unsigned_abs())),
+        'o' => ("0o", format!("{:o}", n.unsigned_abs())),
+        'b' => ("0b", format!("{:b}", n.unsigned_abs())),
+        'c' => {
+            if spec.sign != Sign::Minus {
+                return Err(value_error("Sign not allowed with integer format specifier 'c'".to_string()));
+            }
+            let c = u32::try_from(n)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| err(VmErrorKind::TypeError("format"), "OverflowError: %c arg not in range(0x110000)".to_string()))?;
+            return Ok(pad(&c.to_string(), spec, Align::Left));
+        }
+        'f' | 'F' | 'e' | 'E' | 'g' | 'G' | '%' => return format_float(n as f64, spec),
+        _ => {
+            return Err(value_error(format!(
+                "Unknown format code '{}' for object of type 'int'",
+                ty
+            )));
+        }
+    };
+    if spec.precision.is_some() {
+        return Err(value_error("Precision not allowed in integer format specifier".to_string()));
+    }
+
+    let digits = match spec.grouping {
+        Some(sep) if ty == 'd' => group_digits(&digits, sep, 3),
+        Some('_') => group_digits(&digits, '_', 4),
+        Some(sep) => {
+            return Err(value_error(format!("Cannot specify '{}' with '{}'", sep, ty)));
+        }
+        None => digits,
+    };
+    let prefix = if spec.alternate { prefix } else { "" };
+    Ok(pad_number(sign_str(n < 0, spec.sign), prefix, &digits, spec))
+}
+
+fn format_float(x: f64, spec: &FormatSpec) -> VmResult<String> {
+    let negative = x.is_sign_negative() && !x.is_nan();
+    let abs = x.abs();
+
+    let body = if !abs.is_finite() {
+        let s = if abs.is_nan() { "nan" } else { "inf" };
+        if matches!(spec.ty, Some('F') | Some('E') | Some('G')) {
+            s.to_uppercase()
+        } else {
+            s.to_string()
+        }
+    } else {
+        match spec.ty {
+            Some('f') | Some('F') => format!("{:.*}", spec.precision.unwrap_or(6), abs),
+            Some('e') => format_exp(abs, spec.precision.unwrap_or(6)),
+            Some('E') => format_exp(abs, spec.precision.unwrap_or(6)).to_uppercase(),
+            Some('g') => format_general(abs, spec.precision.unwrap_or(6), spec.alternate),
+            Some('G') => format_general(abs, spec.precision.unwrap_or(6), spec.alternate).to_uppercase(),
+            Some('%') => format!("{:.*}%", spec.precision.unwrap_or(6), abs * 100.0),
+            None => match spec.precision {
+                Some(p) => format_general(abs, p, spec.alternate),
+                None => display_value(&Value::Float(abs)),
+            },
+            Some(ty) => {
+                return Err(value_error(format!(
+                    "Unknown format code '{}' for object of type 'float'",
+                    ty
+                )));
+            }
+        }
+    };
+
+    // 정수부에만 자릿수 구분자 적용
+    let body = match spec.grouping {
+        Some(sep) => {
+            let end = body.find(|c: char| !c.is_ascii_digit()).unwrap_or(body.len());
+            format!("{}{}", group_digits(&body[..end], sep, 3), &body[end..])
+        }
+        None => body,
+    };
+    Ok(pad_number(sign_str(negative, spec.sign), "", &body, spec))
+}
+
+/// `1.500000e+03` 형식 (지수는 최소 두 자리)
+fn format_exp(x: f64, precision: usize) -> String {
+    let s = format!("{:.*e}", precision, x);
+    let (mantissa, exp) = s.split_once('e').unwrap_or((&s, "0"));
+    let exp: i32 = exp.parse().unwrap_or(0);
+    format!(
+        "{}e{}{:02}",
+        mantissa,
+        if exp < 0 { '-' } else { '+' },
+        exp.abs()
+    )
+}
+
+/// `g` 형식: 유효 숫자 `precision`개, 지수 크기에 따라 고정/지수 표기 선택
+fn format_general(x: f64, precision: usize, keep_zeros: bool) -> String {
+    let p = precision.max(1);
+    if x == 0.0 {
+        return if keep_zeros {
+            format!("{:.*}", p - 1, 0.0)
+        } else {
+            "0".to_string()
+        };
+    }
+    // 반올림 후의 지수를 구하기 위해 지수 표기를 먼저 계산
+    let exp_repr = format!("{:.*e}", p - 1, x);
+    let exp: i32 = exp_repr
+        .split_once('e')
+        .and_then(|(_, e)| e.parse().ok())
+        .unwrap_or(0);
+
+    if exp >= -4 && exp < p as i32 {
+        let s = format!("{:.*}", (p as i32 - 1 - exp).max(0) as usize, x);
+        if keep_zeros { s } else { strip_fraction_zeros(&s) }
+    } else {
+        let s = format_exp(x, p - 1);
+        if keep_zeros {
+            return s;
+        }
+        let (mantissa, exp) = s.split_once('e').unwrap_or((&s, ""));
+        format!("{}e{}", strip_fraction_zeros(mantissa), exp)
+    }
+}
+
+fn strip_fraction_zeros(s: &str) -> String {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+fn group_digits(digits: &str, sep: char, every: usize) -> String {
+    let len = digits.len();
+    let mut out = String::with_capacity(len + len / every);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (len - i).is_multiple_of(every) {
+            out.push(sep);
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn sign_str(negative: bool, sign: Sign) -> &'static str {
+    match (negative, sign) {
+        (true, _) => "-",
+        (false, Sign::Plus) => "+",
+        (false, Sign::Space) => " ",
+        (false, Sign::Minus) => "",
+    }
+}
+
+fn pad_number(sign: &str, prefix: &str, body: &str, spec: &FormatSpec) -> String {
+    if spec.align == Some(Align::AfterSign) {
+        let used = sign.len() + prefix.len() + body.chars().count();
+        let fill: String = std::iter::repeat_n(spec.fill, spec.width.unwrap_or(0).saturating_sub(used)).collect();
+        return format!("{}{}{}{}", sign, prefix, fill, body);
+    }
+    pad(&format!("{}{}{}", sign, prefix, body), spec, Align::Right)
+}
+
+/// 너비에 맞춰 채움 문자 삽입
+fn pad(s: &str, spec: &FormatSpec, default_align: Align) -> String {
+    let len = s.chars().count();
+    let width = spec.width.unwrap_or(0);
+    if len >= width {
+        return s.to_string();
+    }
+    let total = width - len;
+    let (left, right) = match spec.align.unwrap_or(default_align) {
+        Align::Left => (0, total),
+        Align::Right | Align::AfterSign => (total, 0),
+        Align::Center => (total / 2, total - total / 2),
+    };
+    let fill = |n| std::iter::repeat_n(spec.fill, n).collect::<String>();
+    format!("{}{}{}", fill(left), s, fill(right))
+}
+
+/// `!s` / `!r` 변환 적용
+pub fn convert(value: &Value, conversion: Option<char>) -> VmResult<Value> {
+    match conversion {
+        None => Ok(value.clone()),
+        Some('s') => Ok(super::utils::make_string(display_value(value))),
+        Some('r') => Ok(super::utils::make_string(repr_value(value))),
+        Some(c) => Err(value_error(format!(
+            "Unknown conversion specifier {}",
+            c
+        ))),
+    }
+}
+
+// ========== str.format ==========
+
+/// `"{} {name:>5}".format(...)` 템플릿 확장
+pub fn str_format(
+    template: &str,
+    args: &[Value],
+    kwargs: &HashMap<String, Value>,
+) -> VmResult<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+    // 자동 번호(`{}`)와 수동 번호(`{0}`)는 섞을 수 없음
+    let mut auto_index: Option<usize> = None;
+    let mut manual = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '}' => {
+                return Err(value_error(
+                    "Single '}' encountered in format string".to_string(),
+                ));
+            }
+            '{' => {
+                let mut field = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    match c {
+                        '}' => {
+                            closed = true;
+                            break;
+                        }
+                        '{' => {
+                            return Err(value_error(
+                                "nested replacement fields are not supported".to_string(),
+                            ));
+                        }
+                        _ => field.push(c),
+                    }
+                }
+                if !closed {
+                    return Err(value_error(
+                        "expected '}' before end of string".to_string(),
+                    ));
+                }
+
+                let (head, spec) = match field.split_once(':') {
+                    Some((head, spec)) => (head, spec),
+                    None => (field.as_str(), ""),
+                };
+                let (name, conversion) = match head.split_once('!') {
+                    Some((name, conv)) => {
+                        let mut conv_chars = conv.chars();
+                        match (conv_chars.next(), conv_chars.next()) {
+                            (Some(c), None) => (name, Some(c)),
+                            _ => {
+                                return Err(value_error(
+                                    "expected ':' after conversion specifier".to_string(),
+                                ));
+                            }
+                        }
+                    }
+                    None => (head, None),
+                };
+
+                let value = if name.is_empty() {
+                    if manual {
+                        return Err(value_error(
+                            "cannot switch from manual field specification to automatic field numbering"
+                                .to_string(),
+                        ));
+                    }
+                    let index = auto_index.map_or(0, |i| i + 1);
+                    auto_index = Some(index);
+                    positional(args, index)?
+                } else if let Ok(index) = name.parse::<usize>() {
+                    if auto_index.is_some() {
+                        return Err(value_error(
+                            "cannot switch from automatic field numbering to manual field specification"
+                                .to_string(),
+                        ));
+                    }
+                    manual = true;
+                    positional(args, index)?
+                } else if name.contains(['.', '[']) {
+                    return Err(value_error(format!(
+                        "attribute and index lookups are not supported in format fields: '{}'",
+                        name
+                    )));
+                } else {
+                    kwargs.get(name).ok_or_else(|| {
+                        err(
+                            VmErrorKind::TypeError("format"),
+                            format!("KeyError: '{}'", name),
+                        )
+                    })?
+                };
+
+                let value = convert(value, conversion)?;
+                out.push_str(&format_with_spec(&value, spec)?);
+            }
+            _ => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+fn positional(args: &[Value], index: usize) -> VmResult<&Value> {
+    args.get(index).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("format"),
+            format!(
+                "IndexError: Replacement index {} out of range for positional args tuple",
+                index
+            ),
+        )
+    })
+}
+
+/// 키워드 인자 dict(`CallMethodKw`가 넘기는 값)를 이름 → 값 맵으로 변환
+pub fn kwargs_from_dict(value: &Value) -> HashMap<String, Value> {
+    let mut out = HashMap::new();
+    if let Value::Object(obj) = value
+        && let ObjectData::Dict { map } = &obj.data
+    {
+        for (k, v) in map.borrow().iter() {
+            if let DictKey::String(name) = k {
+                out.insert(name.clone(), v.clone());
+            }
+        }
+    }
+    out
+}
+
+// ========== printf 스타일 (%) ==========
+
+/// `"%5.2f %s" % (x, y)` 확장
+///
+/// 오른쪽 피연산자가 tuple이면 각 원소를, dict이면 `%(name)s` 조회를,
+/// 그 외 값은 단일 인자로 사용합니다.
+pub fn percent_format(template: &str, operand: &Value) -> VmResult<String> {
+    let (items, mapping): (Vec<Value>, Option<&Value>) = match operand {
+        Value::Object(obj) => match &obj.data {
+            ObjectData::Tuple { items } => (items.clone(), None),
+            ObjectData::Dict { .. } => (vec![operand.clone()], Some(operand)),
+            _ => (vec![operand.clone()], None),
+        },
+        _ => (vec![operand.clone()], None),
+    };
+
+    let chars: Vec<char> = template.chars().collect();
+    let mut out = String::with_capacity(template.len());
+    let mut next = 0;
+    let mut used_mapping = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        i += 1;
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+
+        // %(name)
+        let mut key = None;
+        if chars.get(i) == Some(&'(') {
+            let close = chars[i..]
+                .iter()
+                .position(|&c| c == ')')
+                .ok_or_else(|| value_error("incomplete format key".to_string()))?;
+            key = Some(chars[i + 1..i + close].iter().collect::<String>());
+            i += close + 1;
+        }
+
+        let mut spec = FormatSpec::default();
+        let mut zero = false;
+        while let Some(&flag) = chars.get(i) {
+            match flag {
+                '-' => spec.align = Some(Align::Left),
+                '+' => spec.sign = Sign::Plus,
+                ' ' if spec.sign != Sign::Plus => spec.sign = Sign::Space,
+                ' ' => {}
+                '#' => spec.alternate = true,
+                '0' => zero = true,
+                _ => break,
+            }
+            i += 1;
+        }
+        let start = i;
+        while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+            i += 1;
+        }
+        let too_many_digits = || value_error("Too many decimal digits in format string".to_string());
+        if i > start {
+            spec.width = Some(chars[start..i].iter().collect::<String>().parse().map_err(|_| too_many_digits())?);
+        }
+        if chars.get(i) == Some(&'.') {
+            i += 1;
+            let start = i;
+            while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            let digits: String = chars[start..i].iter().collect();
+            spec.precision = Some(if digits.is_empty() { 0 } else { digits.parse().map_err(|_| too_many_digits())? });
+        }
+
+        let conv = *chars
+            .get(i)
+            .ok_or_else(|| value_error("incomplete format".to_string()))?;
+        i += 1;
+        if conv == '%' {
+            out.push('%');
+            continue;
+        }
+
+        let value = match (&key, mapping) {
+            (Some(name), Some(map)) => {
+                used_mapping = true;
+                lookup_key(map, name)?
+            }
+            (Some(_), None) => {
+                return Err(type_error("TypeError: format requires a mapping".to_string()));
+            }
+            (None, _) => {
+                let v = items.get(next).cloned().ok_or_else(|| {
+                    type_error("TypeError: not enough arguments for format string".to_string())
+                })?;
+                next += 1;
+                v
+            }
+        };
+
+        if zero && spec.align.is_none() && !matches!(conv, 's' | 'r' | 'c') {
+            spec.fill = '0';
+            spec.align = Some(Align::AfterSign);
+        }
+        out.push_str(&percent_one(conv, &value, spec)?);
+    }
+
+    if !used_mapping && next < items.len() && mapping.is_none() {
+        return Err(type_error(
+            "TypeError: not all arguments converted during string formatting".to_string(),
+        ));
+    }
+    Ok(out)
+}
+
+fn lookup_key(map: &Value, name: &str) -> VmResult<Value> {
+    if let Value::Object(obj) = map
+        && let ObjectData::Dict { map } = &obj.data
+        && let Some(v) = map.borrow().get(&DictKey::String(name.to_string()))
+    {
+        return Ok(v.clone());
+    }
+    Err(err(
+        VmErrorKind::TypeError("format"),
+        format!("KeyError: '{}'", name),
+    ))
+}
+
+fn percent_one(conv: char, value: &Value, mut spec: FormatSpec) -> VmResult<String> {
+    reserve_spec(&spec)?;
+    match conv {
+        's' | 'r' => {
+            let s = if conv == 's' { display_value(value) } else { repr_value(value) };
+            spec.sign = Sign::Minus;
+            format_str(&s, &spec)
+        }
+        'c' => match value {
+            Value::Object(obj) if matches!(&obj.data, ObjectData::String(s) if s.chars().count() == 1) => {
+                format_str(&display_value(value), &spec)
+            }
+            Value::Int(n) => {
+                spec.ty = Some('c');
+                format_int(*n, &spec)
+            }
+            _ => Err(type_error("TypeError: %c requires int or char".to_string())),
+        },
+        'd' | 'i' | 'u' | 'x' | 'X' | 'o' => {
+            let n = match value {
+                Value::Int(n) => *n,
+                Value::Bool(b) => *b as i64,
+                Value::Float(f) if conv == 'd' || conv == 'i' || conv == 'u' => f.trunc() as i64,
+                _ => {
+                    return Err(type_error(format!(
+                        "TypeError: %{} format: {} is required, not {}",
+                        conv,
+                        if matches!(conv, 'd' | 'i' | 'u') { "a real number" } else { "an integer" },
+                        type_name(value)
+                    )));
+                }
+            };
+            // %d의 정밀도는 최소 자릿수
+            let (prefix, digits) = match conv {
+                'x' => ("0x", format!("{:x}", n.unsigned_abs())),
+                'X' => ("0X", format!("{:X}", n.unsigned_abs())),
+                'o' => ("0o", format!("{:o}", n.unsigned_abs())),
+                _ => ("", n.unsigned_abs().to_string()),
+            };
+            let zeros = spec.precision.unwrap_or(0).saturating_sub(digits.len());
+            let digits = format!("{}{}", "0".repeat(zeros), digits);
+            let prefix = if spec.alternate { prefix } else { "" };
+            Ok(pad_number(sign_str(n < 0, spec.sign), prefix, &digits, &spec))
+        }
+        'e' | 'E' | 'f' | 'F' | 'g' | 'G' => {
+            let x = match value {
+                Value::Int(n) => *n as f64,
+                Value::Bool(b) => *b as i64 as f64,
+                Value::Float(f) => *f,
+                _ => {
+                    return Err(type_error(format!(
+                        "TypeError: must be real number, not {}",
+                        type_name(value)
+                    )));
+                }
+            };
+            spec.ty = Some(conv);
+            if spec.precision.is_none() {
+                spec.precision = Some(6);
+            }
+            format_float(x, &spec)
+        }
+        _ => Err(value_error(format!(
+            "unsupported format character '{}'",
+            conv
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::utils::{make_string, make_dict};
+
+    fn fmt(v: Value, spec: &str) -> String {
+        format_with_spec(&v, spec).unwrap()
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        let spec = FormatSpec::parse("*^+#010,.3f").unwrap();
+        assert_eq!(spec.fill, '*');
+        assert_eq!(spec.align, Some(Align::Center));
+        assert_eq!(spec.sign, Sign::Plus);
+        assert!(spec.alternate);
+        assert_eq!(spec.width, Some(10));
+        assert_eq!(spec.grouping, Some(','));
+        assert_eq!(spec.precision, Some(3));
+        assert_eq!(spec.ty, Some('f'));
+
+        assert!(FormatSpec::parse(".2q").unwrap_err().contains("'q'"));
+        assert!(FormatSpec::parse(".f").is_err());
+        assert!(FormatSpec::parse("5x5").is_err());
+    }
+
+    #[test]
+    fn test_format_numbers() {
+        assert_eq!(fmt(Value::Float(1.23456), ".2f"), "1.23");
+        assert_eq!(fmt(Value::Int(42), "05d"), "00042");
+        assert_eq!(fmt(Value::Int(-42), "+06d"), "-00042");
+        assert_eq!(fmt(Value::Int(255), "#x"), "0xff");
+        assert_eq!(fmt(Value::Int(5), "08b"), "00000101");
+        assert_eq!(fmt(Value::Int(1234567), ","), "1,234,567");
+        assert_eq!(fmt(Value::Float(1234.5), ",.1f"), "1,234.5");
+        assert_eq!(fmt(Value::Float(1500.0), ".2e"), "1.50e+03");
+        assert_eq!(fmt(Value::Float(0.5), ".1%"), "50.0%");
+        assert_eq!(fmt(Value::Float(0.0001234), "g"), "0.0001234");
+        assert_eq!(fmt(Value::Float(123456789.0), "g"), "1.23457e+08");
+        assert_eq!(fmt(Value::Int(7), "f"), "7.000000");
+    }
+
+    #[test]
+    fn test_format_alignment() {
+        assert_eq!(fmt(make_string("ab".into()), "5"), "ab   ");
+        assert_eq!(fmt(Value::Int(7), "5"), "    7");
+        assert_eq!(fmt(make_string("ab".into()), "*^6"), "**ab**");
+        assert_eq!(fmt(make_string("hello".into()), ".3"), "hel");
+        assert!(format_with_spec(&make_string("x".into()), "d").is_err());
+        assert!(format_with_spec(&Value::Float(1.0), "x").is_err());
+    }
+
+    #[test]
+    fn test_str_format() {
+        let args = vec![make_string("Kim".into()), Value::Float(91.256)];
+        let mut kwargs = HashMap::new();
+        kwargs.insert("unit".to_string(), make_string("pt".into()));
+        assert_eq!(
+            str_format("{}: {:.1f}{unit} {{ok}}", &args, &kwargs).unwrap(),
+            "Kim: 91.3pt {ok}"
+        );
+        assert_eq!(
+            str_format("{1:>6.2f}|{0:<5}|{0!r}", &args, &kwargs).unwrap(),
+            " 91.26|Kim  |'Kim'"
+        );
+        assert!(str_format("{} {0}", &args, &kwargs).is_err());
+        assert!(str_format("{2}", &args, &kwargs).unwrap_err().message.starts_with("IndexError"));
+        assert!(str_format("{nope}", &args, &kwargs).unwrap_err().message.starts_with("KeyError"));
+        assert!(str_format("oops }", &args, &kwargs).is_err());
+        assert!(str_format("{:{}}", &args, &kwargs).unwrap_err().message.contains("nested replacement fields"));
+    }
+
+    #[test]
+    fn test_percent_format() {
+        let tuple = Value::Object(std::rc::Rc::new(crate::vm::value::Object::new(
+            crate::builtins::TYPE_TUPLE,
+            ObjectData::Tuple {
+                items: vec![make_string("x".into()), Value::Int(5), Value::Float(2.5)],
+            },
+        )));
+        assert_eq!(percent_format("%s=%03d (%.2f) 100%%", &tuple).unwrap(), "x=005 (2.50) 100%");
+        assert_eq!(percent_format("%-4s|", &make_string("ab".into())).unwrap(), "ab  |");
+        let pair = Value::Object(std::rc::Rc::new(crate::vm::value::Object::new(
+            crate::builtins::TYPE_TUPLE,
+            ObjectData::Tuple {
+                items: vec![Value::Int(255), Value::Int(-7)],
+            },
+        )));
+        assert_eq!(percent_format("%#x|%5.3d|%+d", &pair).unwrap_err().message, "TypeError: not enough arguments for format string");
+        assert_eq!(percent_format("%#x|%5.3d", &pair).unwrap(), "0xff| -007");
+        assert_eq!(percent_format("%r", &make_string("a".into())).unwrap(), "'a'");
+
+        let mut map = HashMap::new();
+        map.insert(DictKey::String("n".into()), Value::Int(3));
+        assert_eq!(percent_format("%(n)d items", &make_dict(map)).unwrap(), "3 items");
+        assert!(percent_format("%d %d", &Value::Int(1)).is_err());
+        assert!(percent_format("x", &Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn test_huge_width_and_precision_are_errors() {
+        let message = |result: VmResult<String>| result.unwrap_err().message;
+        assert!(message(format_with_spec(&Value::Int(1), "9999999999999")).starts_with("MemoryError"));
+        assert!(message(format_with_spec(&Value::Float(1.5), ".9999999999f")).starts_with("MemoryError"));
+        assert!(message(percent_format("%.9999999999d", &Value::Int(1))).starts_with("MemoryError"));
+        assert!(message(percent_format("%9999999999999s", &make_string("a".into()))).starts_with("MemoryError"));
+        assert_eq!(
+            message(percent_format("%99999999999999999999999d", &Value::Int(1))),
+            "ValueError: Too many decimal digits in format string"
+        );
+        assert_eq!(percent_format("%.f", &Value::Float(2.5)).unwrap(), "2");
+    }
+}
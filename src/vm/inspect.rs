@@ -0,0 +1,455 @@
+//! 실행 상태 시각화 데이터 (Python Tutor 스타일)
+//!
+//! 프레임(함수 이름, 현재 줄, 지역 변수), 전역 변수, 힙 객체 그래프를 JSON으로 보낼 수 있는
+//! 구조로 만듭니다. 객체는 id로 가리키므로 웹 UI가 변수에서 객체로 화살표를 그릴 수 있습니다.
+//!
+//! ```
+//! use pyhyeon::runtime_io::BufferIo;
+//! use pyhyeon::vm::{StepMode, Vm};
+//!
+//! let source = "xs = [1, 2]\nys = xs\n";
+//! let mut module = pyhyeon::compile_to_module(&pyhyeon::parse_source(source).unwrap());
+//! let mut vm = Vm::new();
+//! let mut io = BufferIo::new();
+//! vm.step_with_io(&mut module, &mut io, StepMode::Statement).unwrap(); // 1번 줄 앞에서 멈춤
+//! vm.step_with_io(&mut module, &mut io, StepMode::Statement).unwrap();
+//! assert_eq!(vm.inspect(&module, source).frames[0].line, Some(2));
+//!
+//! vm.step_with_io(&mut module, &mut io, StepMode::Statement).unwrap();
+//! let view = vm.inspect(&module, source);
+//! assert!(view.frames.is_empty()); // 끝까지 실행함
+//! assert_eq!(view.globals[0].value, view.globals[1].value); // 같은 리스트를 가리킴
+//! assert_eq!(view.heap.len(), 1);
+//! ```
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde::Serialize;
+
+use super::bytecode::{Module, Value};
+use super::machine::Vm;
+use super::utils::repr_value;
+use super::value::{DictKey, Object, ObjectData, SetKey};
+
+/// 한 시점의 실행 상태
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExecutionView {
+    /// 모듈 프레임부터 지금 실행 중인 프레임까지
+    pub frames: Vec<FrameView>,
+    /// 값이 있는 전역 변수 (심볼 테이블 순서)
+    pub globals: Vec<Variable>,
+    /// 프레임과 전역 변수에서 닿는 객체 (id 순)
+    pub heap: Vec<HeapObject>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FrameView {
+    /// 함수 이름 (모듈 수준 코드는 `<module>`)
+    pub function: String,
+    /// 다음에 실행할 문장의 줄 번호 (1부터). 함수 끝의 암묵적 return 등에서는 없음
+    pub line: Option<usize>,
+    pub locals: Vec<Variable>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Variable {
+    pub name: String,
+    pub value: ValueView,
+}
+
+/// 변수나 원소의 값: 기본값과 문자열은 그대로, 나머지 객체는 `heap`의 id
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum ValueView {
+    None,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Ref(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HeapObject {
+    List {
+        id: usize,
+        items: Vec<ValueView>,
+    },
+    Tuple {
+        id: usize,
+        items: Vec<ValueView>,
+    },
+    /// set과 treeset (`type_name`으로 구분)
+    Set {
+        id: usize,
+        type_name: String,
+        items: Vec<ValueView>,
+    },
+    Dict {
+        id: usize,
+        entries: Vec<(ValueView, ValueView)>,
+    },
+    /// 사용자 정의 클래스의 인스턴스 (속성은 이름 순)
+    Instance {
+        id: usize,
+        class_name: String,
+        attributes: Vec<Variable>,
+    },
+    Class {
+        id: usize,
+        name: String,
+        methods: Vec<String>,
+    },
+    /// 함수와 람다 (`captures`는 람다가 잡아 둔 바깥 변수)
+    Function {
+        id: usize,
+        name: String,
+        captures: Vec<Variable>,
+    },
+    /// iterator, builtin 타입, 네이티브 객체 등은 repr만
+    Other {
+        id: usize,
+        type_name: String,
+        repr: String,
+    },
+}
+
+impl HeapObject {
+    pub fn id(&self) -> usize {
+        match self {
+            HeapObject::List { id, .. }
+            | HeapObject::Tuple { id, .. }
+            | HeapObject::Set { id, .. }
+            | HeapObject::Dict { id, .. }
+            | HeapObject::Instance { id, .. }
+            | HeapObject::Class { id, .. }
+            | HeapObject::Function { id, .. }
+            | HeapObject::Other { id, .. } => *id,
+        }
+    }
+}
+
+impl Vm {
+    /// 현재 실행 상태를 시각화용으로 변환
+    ///
+    /// `source`는 모듈을 컴파일한 소스 코드입니다 (줄 번호 계산용).
+    pub fn inspect(&self, module: &Module, source: &str) -> ExecutionView {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        let line_of = |pos: usize| line_starts.partition_point(|&start| start <= pos);
+
+        let mut heap = HeapCollector::default();
+        let frames = self
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(depth, frame)| {
+                let func = &module.functions[frame.func_id];
+                let function = if depth == 0 && frame.func_id == 0 {
+                    "<module>".to_string()
+                } else {
+                    module.symbols[func.name_sym as usize].clone()
+                };
+                let locals = frame
+                    .locals
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(slot, value)| {
+                        let name = func.local_names.get(slot)?;
+                        Some(heap.variable(name, value))
+                    })
+                    .collect();
+                FrameView {
+                    function,
+                    line: func.position(frame.ip).map(line_of),
+                    locals,
+                }
+            })
+            .collect();
+        let globals = module
+            .symbols
+            .iter()
+            .zip(&module.globals)
+            .filter(|(name, _)| !is_internal_name(name))
+            .filter_map(|(name, value)| Some(heap.variable(name, value.as_ref()?)))
+            .collect();
+
+        ExecutionView {
+            frames,
+            globals,
+            heap: heap.finish(module),
+        }
+    }
+}
+
+/// `__for_iter_12__` 같은 컴파일러가 만든 이름
+fn is_internal_name(name: &str) -> bool {
+    name.starts_with("__") && name.ends_with("__")
+}
+
+/// 객체에 처음 만난 순서대로 id를 붙이고, 목록이 빌 때까지 변환
+#[derive(Default)]
+struct HeapCollector {
+    ids: HashMap<*const Object, usize>,
+    objects: Vec<Rc<Object>>,
+}
+
+impl HeapCollector {
+    fn value(&mut self, value: &Value) -> ValueView {
+        match value {
+            Value::Int(i) => ValueView::Int(*i),
+            Value::Bool(b) => ValueView::Bool(*b),
+            Value::Float(f) => ValueView::Float(*f),
+            Value::None => ValueView::None,
+            Value::Object(obj) => {
+                if let ObjectData::String(s) = &obj.data {
+                    return ValueView::Str(s.clone());
+                }
+                let ptr = Rc::as_ptr(obj);
+                if let Some(&id) = self.ids.get(&ptr) {
+                    return ValueView::Ref(id);
+                }
+                let id = self.objects.len();
+                self.ids.insert(ptr, id);
+                self.objects.push(obj.clone());
+                ValueView::Ref(id)
+            }
+        }
+    }
+
+    fn values(&mut self, values: &[Value]) -> Vec<ValueView> {
+        values.iter().map(|v| self.value(v)).collect()
+    }
+
+    fn variable(&mut self, name: &str, value: &Value) -> Variable {
+        Variable {
+            name: name.to_string(),
+            value: self.value(value),
+        }
+    }
+
+    fn finish(mut self, module: &Module) -> Vec<HeapObject> {
+        let mut heap = Vec::with_capacity(self.objects.len());
+        while heap.len() < self.objects.len() {
+            let obj = self.objects[heap.len()].clone();
+            heap.push(self.object(heap.len(), &obj, module));
+        }
+        heap
+    }
+
+    fn object(&mut self, id: usize, obj: &Rc<Object>, module: &Module) -> HeapObject {
+        match &obj.data {
+            ObjectData::List { items } => HeapObject::List {
+                id,
+                items: self.values(&items.borrow()),
+            },
+            ObjectData::Tuple { items } => HeapObject::Tuple {
+                id,
+                items: self.values(items),
+            },
+            ObjectData::Set { items } => HeapObject::Set {
+                id,
+                type_name: "set".to_string(),
+                items: items.borrow().iter().map(set_key).collect(),
+            },
+            ObjectData::TreeSet { items } => HeapObject::Set {
+                id,
+                type_name: "treeset".to_string(),
+                items: items.borrow().iter().map(set_key).collect(),
+            },
+            ObjectData::Dict { map } => HeapObject::Dict {
+                id,
+                entries: map
+                    .borrow()
+                    .iter()
+                    .map(|(k, v)| (dict_key(k), self.value(v)))
+                    .collect(),
+            },
+            ObjectData::UserInstance { class_name, .. } => {
+                let mut attributes: Vec<(String, Value)> = obj
+                    .attributes
+                    .as_ref()
+                    .map(|attrs| attrs.borrow().iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                    .unwrap_or_default();
+                attributes.sort_by(|a, b| a.0.cmp(&b.0));
+                HeapObject::Instance {
+                    id,
+                    class_name: class_name.to_string(),
+                    attributes: attributes
+                        .iter()
+                        .map(|(name, value)| self.variable(name, value))
+                        .collect(),
+                }
+            }
+            ObjectData::UserClass { name, methods, .. } => {
+                let mut methods: Vec<String> = methods.keys().cloned().collect();
+                methods.sort();
+                HeapObject::Class {
+                    id,
+                    name: name.to_string(),
+                    methods,
+                }
+            }
+            ObjectData::UserFunction { func_id, captures, .. } => {
+                let func = &module.functions[*func_id as usize];
+                // 람다 locals 레이아웃: [params..., captures...]
+                let names = func.local_names.iter().skip(func.arity as usize);
+                HeapObject::Function {
+                    id,
+                    name: module.symbols[func.name_sym as usize].clone(),
+                    captures: names
+                        .zip(captures)
+                        .map(|(name, value)| self.variable(name, value))
+                        .collect(),
+                }
+            }
+            _ => {
+                let value = Value::Object(obj.clone());
+                HeapObject::Other {
+                    id,
+                    type_name: super::utils::type_name(&value).to_string(),
+                    repr: repr_value(&value),
+                }
+            }
+        }
+    }
+}
+
+fn set_key(key: &SetKey) -> ValueView {
+    match key {
+        SetKey::Int(i) => ValueView::Int(*i),
+        SetKey::String(s) => ValueView::Str(s.clone()),
+        SetKey::Bool(b) => ValueView::Bool(*b),
+    }
+}
+
+fn dict_key(key: &DictKey) -> ValueView {
+    match key {
+        DictKey::Int(i) => ValueView::Int(*i),
+        DictKey::String(s) => ValueView::Str(s.clone()),
+        DictKey::Bool(b) => ValueView::Bool(*b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime_io::BufferIo;
+    use crate::vm::machine::{StepMode, VmState};
+
+    fn start(source: &str) -> (Vm, Module) {
+        let program = crate::parse_source(source).unwrap();
+        crate::analyze(&program).unwrap();
+        (Vm::new(), crate::compile_to_module(&program))
+    }
+
+    /// 문장 단위로 끝까지 실행하며 (함수 이름, 줄)을 모음
+    fn trace(source: &str) -> Vec<(String, Option<usize>)> {
+        let (mut vm, mut module) = start(source);
+        let mut io = BufferIo::new();
+        let mut lines = vec![];
+        loop {
+            vm.step_with_io(&mut module, &mut io, StepMode::Statement).unwrap();
+            if vm.get_state() != VmState::Running {
+                return lines;
+            }
+            let view = vm.inspect(&module, source);
+            let top = view.frames.last().unwrap();
+            lines.push((top.function.clone(), top.line));
+        }
+    }
+
+    #[test]
+    fn test_statement_steps_follow_lines_and_calls() {
+        let source = "def double(x):\n  y = x * 2\n  return y\n\nn = double(3)\nfor i in range(2):\n  n += i\n";
+        let module = |line| ("<module>".to_string(), Some(line));
+        let double = |line| ("double".to_string(), Some(line));
+        assert_eq!(
+            trace(source),
+            [
+                module(1),
+                module(5),
+                double(2),
+                double(3),
+                module(5),
+                module(6),
+                module(7),
+                module(6),
+                module(7),
+                module(6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frames_locals_and_heap_graph() {
+        let source = concat!(
+            "class P:\n",
+            "  def __init__(self, v):\n",
+            "    self.v = v\n",
+            "    self.xs = [v, 'a']\n",
+            "def f(p):\n",
+            "  d = {'p': p}\n",
+            "  return d\n",
+            "p = P(1)\n",
+            "f(p)\n",
+        );
+        let (mut vm, mut module) = start(source);
+        let mut io = BufferIo::new();
+        // `return d` 앞까지
+        let mut view = vm.inspect(&module, source);
+        while view.frames.last().map(|f| (f.function.as_str(), f.line)) != Some(("f", Some(7))) {
+            vm.step_with_io(&mut module, &mut io, StepMode::Statement).unwrap();
+            view = vm.inspect(&module, source);
+        }
+
+        assert_eq!(view.frames.len(), 2);
+        let globals: Vec<&str> = view.globals.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(globals, ["P", "f", "p"]);
+        let object = |value: &ValueView| match value {
+            ValueView::Ref(id) => view.heap[*id].clone(),
+            other => panic!("not a reference: {:?}", other),
+        };
+
+        let locals = &view.frames[1].locals;
+        assert_eq!(locals[0].name, "p");
+        assert_eq!(locals[0].value, view.globals[2].value);
+        let HeapObject::Dict { entries, .. } = object(&locals[1].value) else { panic!() };
+        assert_eq!(entries[0].0, ValueView::Str("p".into()));
+        assert_eq!(entries[0].1, locals[0].value);
+
+        let HeapObject::Instance { class_name, attributes, .. } = object(&locals[0].value) else {
+            panic!()
+        };
+        assert_eq!(class_name, "P");
+        assert_eq!(attributes[0], Variable { name: "v".into(), value: ValueView::Int(1) });
+        let HeapObject::List { items, .. } = object(&attributes[1].value) else { panic!() };
+        assert_eq!(items, [ValueView::Int(1), ValueView::Str("a".into())]);
+    }
+
+    #[test]
+    fn test_instruction_steps_and_lambda_captures() {
+        let source = "def make(n):\n  return lambda x: x + n\nadd = make(5)\n";
+        let (mut vm, mut module) = start(source);
+        let mut io = BufferIo::new();
+        let mut steps = 0;
+        while vm.get_state() == VmState::Running {
+            vm.step_with_io(&mut module, &mut io, StepMode::Instruction).unwrap();
+            steps += 1;
+        }
+        // 한 번에 명령어 하나씩
+        let instructions: usize = module.functions.iter().map(|f| f.code.len()).sum();
+        assert!(steps > 5 && steps <= instructions + 2, "{}", steps);
+
+        let view = vm.inspect(&module, source);
+        assert!(view.frames.is_empty());
+        let add = view.globals.iter().find(|g| g.name == "add").unwrap();
+        let ValueView::Ref(id) = add.value else { panic!() };
+        let HeapObject::Function { name, captures, .. } = &view.heap[id] else { panic!() };
+        assert!(name.starts_with("<lambda"));
+        assert_eq!(captures, &[Variable { name: "n".into(), value: ValueView::Int(5) }]);
+    }
+}
@@ -0,0 +1,274 @@
+//! 실행 제한 (온라인 저지 모드의 시간/메모리 제한)
+//!
+//! `Vm::set_limits`로 정하면 명령어 [`CHECK_INTERVAL`]개마다 검사해서, 넘으면
+//! `VmErrorKind::TimeLimitExceeded` / `VmErrorKind::MemoryLimitExceeded`로 실행을 멈춥니다.
+//!
+//! 메모리는 [`CountingAlloc`]를 전역 할당자로 설치한 프로그램에서만 잴 수 있습니다
+//! (`pyhc`는 설치함). 설치하지 않으면 명령어 사이의 메모리 검사는 하지 않습니다.
+//! 설치해도 [`enable_counting`]을 부르기 전에는 세지 않고 바로 `System`에 넘깁니다
+//! (메모리 제한을 건 `set_limits`와 `judge`가 부름).
+//!
+//! 명령어 사이의 검사로는 `[0] * (10 ** 11)`처럼 한 번에 크게 할당하는 연산을 막을 수 없어
+//! (할당이 실패하면 프로세스가 abort됨), 큰 list/str을 만드는 연산은 할당 전에 [`reserve`]로
+//! 크기를 검사합니다. 메모리 제한이 없으면 대신 [`MAX_ALLOCATION`]보다 큰 할당을
+//! `MemoryError`로 거부합니다 (README에 적어 둠).
+//!
+//! 시간은 유닉스에서는 실행 중인 스레드의 CPU 시간으로 잽니다 ([`CpuTimer`]).
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::time::Duration;
+
+use super::{VmErrorKind, VmResult, err};
+
+/// 제한을 검사하는 간격 (명령어 수)
+pub const CHECK_INTERVAL: u32 = 1024;
+
+/// 실행 제한. 둘 다 `set_limits`를 부른 시점부터 잽니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// 실행 시간
+    pub time: Option<Duration>,
+    /// 실행 중에 늘어난 힙 메모리 (바이트)
+    pub memory: Option<usize>,
+}
+
+/// `Vm`이 들고 있는 검사 상태
+///
+/// 살아 있는 동안 이 스레드의 [`reserve`] 상한을 정하고, 없어지면 이전 상한으로 되돌립니다.
+#[derive(Debug)]
+pub(crate) struct LimitState {
+    pub(crate) limits: Limits,
+    pub(crate) timer: CpuTimer,
+    /// 시작할 때 이미 할당되어 있던 바이트
+    pub(crate) memory_base: usize,
+    pub(crate) counter: u32,
+    previous_cap: Option<usize>,
+}
+
+impl LimitState {
+    pub(crate) fn new(limits: Limits) -> Self {
+        if limits.memory.is_some() {
+            enable_counting();
+        }
+        let memory_base = allocated_bytes();
+        let cap = limits.memory.map(|memory| memory_base.saturating_add(memory));
+        Self {
+            limits,
+            timer: CpuTimer::start(),
+            memory_base,
+            counter: 0,
+            previous_cap: MEMORY_CAP.replace(cap),
+        }
+    }
+}
+
+impl Drop for LimitState {
+    fn drop(&mut self) {
+        MEMORY_CAP.set(self.previous_cap);
+    }
+}
+
+thread_local! {
+    /// `reserve`가 허용하는 할당량의 상한 (`allocated_bytes` 기준, 제한이 없으면 `None`)
+    static MEMORY_CAP: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// 메모리 제한이 없을 때 한 번에 할당할 수 있는 최대 바이트 (넘으면 `MemoryError`)
+pub const MAX_ALLOCATION: usize = 1 << 30;
+
+/// `bytes`만큼 새로 할당해도 되는지 할당 전에 검사
+///
+/// 메모리 제한을 건 VM이 실행 중이면 제한을 넘을 때 `MemoryLimitExceeded`입니다.
+/// `CountingAlloc`이 없어도 한 번의 할당이 제한보다 큰지는 검사합니다. 제한이 없으면
+/// `MAX_ALLOCATION`보다 클 때 `MemoryError`입니다.
+pub fn reserve(bytes: usize) -> VmResult<()> {
+    if let Some(cap) = MEMORY_CAP.get() {
+        if allocated_bytes().saturating_add(bytes) > cap {
+            return Err(err(
+                VmErrorKind::MemoryLimitExceeded,
+                format!("memory limit exceeded (cannot allocate {} bytes)", bytes),
+            ));
+        }
+        return Ok(());
+    }
+    if bytes > MAX_ALLOCATION {
+        return Err(err(
+            VmErrorKind::TypeError("memory"),
+            format!("MemoryError: cannot allocate {} bytes", bytes),
+        ));
+    }
+    Ok(())
+}
+
+/// 원소 `count`개짜리 배열을 `reserve` (바이트 수가 넘치면 `MemoryError`)
+pub fn reserve_array<T>(count: usize) -> VmResult<()> {
+    match count.checked_mul(std::mem::size_of::<T>()) {
+        Some(bytes) => reserve(bytes),
+        None => Err(err(
+            VmErrorKind::TypeError("memory"),
+            format!("MemoryError: cannot allocate {} items", count),
+        )),
+    }
+}
+
+/// `len`개짜리 시퀀스를 `times`번 반복한 길이 (`[1, 2] * n`, `"ab" * n`)
+///
+/// 길이가 `usize`를 넘으면 `OverflowError`입니다. 할당 전에 결과로 `reserve`해야 합니다.
+pub fn repeat_len(len: usize, times: usize, kind: &str) -> VmResult<usize> {
+    len.checked_mul(times).ok_or_else(|| {
+        err(
+            VmErrorKind::TypeError("repeat"),
+            format!("OverflowError: repeated {} is too long", kind),
+        )
+    })
+}
+
+/// 실행 시간을 재는 시계
+///
+/// 유닉스에서는 이 스레드의 CPU 시간을 재므로, 다른 프로세스나 스레드 때문에 느려져도
+/// 시간 제한을 넘지 않습니다. 그 외(wasm 등)에서는 경과 시간입니다.
+#[derive(Debug, Clone, Copy)]
+pub struct CpuTimer {
+    #[cfg(unix)]
+    start: Duration,
+    #[cfg(not(unix))]
+    start: instant::Instant,
+}
+
+impl CpuTimer {
+    /// 지금부터 재기 시작
+    pub fn start() -> Self {
+        Self {
+            #[cfg(unix)]
+            start: thread_cpu_time(),
+            #[cfg(not(unix))]
+            start: instant::Instant::now(),
+        }
+    }
+
+    /// `start` 이후 쓴 시간
+    pub fn elapsed(&self) -> Duration {
+        #[cfg(unix)]
+        return thread_cpu_time().saturating_sub(self.start);
+        #[cfg(not(unix))]
+        return self.start.elapsed();
+    }
+}
+
+#[cfg(unix)]
+fn thread_cpu_time() -> Duration {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // 유닉스는 CLOCK_THREAD_CPUTIME_ID를 항상 지원하므로 실패하지 않음
+    unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) };
+    Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32)
+}
+
+/// `CountingAlloc`이 할당을 세는지 (`enable_counting`)
+static COUNTING: AtomicBool = AtomicBool::new(false);
+/// `enable_counting`이 `CountingAlloc`이 전역 할당자임을 확인함
+static TRACKING: AtomicBool = AtomicBool::new(false);
+/// 세기 시작한 뒤 늘어난 바이트. 세기 전에 할당한 메모리를 해제하면 음수가 될 수 있음
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// 할당한 바이트를 세는 전역 할당자 (실제 할당은 `System`)
+///
+/// [`enable_counting`] 전에는 세지 않으므로 다른 할당자와 거의 같은 속도입니다.
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: pyhyeon::vm::limits::CountingAlloc = pyhyeon::vm::limits::CountingAlloc;
+/// ```
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn grow(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            let now = ALLOCATED.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+            PEAK.fetch_max(now, Ordering::Relaxed);
+        }
+    }
+
+    fn shrink(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            ALLOCATED.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            if new_size >= layout.size() {
+                Self::grow(new_size - layout.size());
+            } else {
+                Self::shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// `CountingAlloc`이 할당을 세기 시작 (이미 세고 있으면 아무것도 안 함)
+///
+/// 그 뒤로는 끄지 않습니다. `CountingAlloc`이 전역 할당자라서 메모리를 잴 수 있으면 `true`.
+pub fn enable_counting() -> bool {
+    if !COUNTING.swap(true, Ordering::Relaxed) {
+        // 할당 하나가 세어지는지로 `CountingAlloc`이 설치되었는지 확인
+        let before = ALLOCATED.load(Ordering::Relaxed);
+        let probe = std::hint::black_box(Box::new([0u8; 64]));
+        let counted = ALLOCATED.load(Ordering::Relaxed) != before;
+        drop(probe);
+        TRACKING.store(counted, Ordering::Relaxed);
+    }
+    memory_tracking()
+}
+
+/// 세기 시작한 뒤 늘어난 바이트 (`CountingAlloc`이 없거나 세지 않으면 0)
+///
+/// 두 시점의 차이로 사용량을 잽니다.
+pub fn allocated_bytes() -> usize {
+    ALLOCATED.load(Ordering::Relaxed).max(0) as usize
+}
+
+/// `reset_peak` 이후 가장 많이 할당되어 있던 바이트
+pub fn peak_bytes() -> usize {
+    PEAK.load(Ordering::Relaxed).max(0) as usize
+}
+
+/// 최대 사용량을 지금 사용량으로 되돌림
+pub fn reset_peak() {
+    PEAK.store(ALLOCATED.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+/// `enable_counting`을 불렀고 `CountingAlloc`이 전역 할당자라서 메모리를 잴 수 있는지
+pub fn memory_tracking() -> bool {
+    TRACKING.load(Ordering::Relaxed)
+}
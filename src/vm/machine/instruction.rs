@@ -1,8 +1,21 @@
-use super::{Vm, VmErrorKind, VmResult, eq_vals, err};
+use super::{Vm, VmError, VmErrorKind, VmResult, eq_vals, err};
 use crate::runtime_io::RuntimeIo;
 use crate::vm::bytecode::{Instruction as I, Module, Value};
 use crate::vm::type_def::{BuiltinClassType, MethodImpl};
+use crate::vm::builtins::{float, int};
+use crate::vm::limits;
+use crate::vm::native::{HostRequest, HostValue, NativeResult};
 use crate::vm::value::ObjectData;
+use std::rc::Rc;
+
+/// 비트 연산 피연산자로 쓸 수 있는 정수 값 (Bool은 0/1)
+fn int_like(v: &Value) -> Option<i64> {
+    match v {
+        Value::Int(n) => Some(*n),
+        Value::Bool(b) => Some(*b as i64),
+        _ => None,
+    }
+}
 
 /// 명령어 실행 결과
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,7 +23,8 @@ pub enum ExecutionFlow {
     /// 다음 명령어 계속 실행
     Continue,
     /// 입력 대기 (input() builtin)
-    WaitingForInput,
+    /// 네이티브 함수가 호스트의 응답을 기다림 (Vm::host_request)
+    WaitingForHost,
     /// 함수 리턴 (프로그램 종료 가능)
     Return(Option<Value>),
 }
@@ -36,6 +50,9 @@ impl Vm {
             // ===== 스택 연산 =====
             I::Pop => self.handle_pop(),
             I::Dup => self.handle_dup(),
+            I::Rot3 => self.handle_rot3(),
+            I::Dup2 => self.handle_dup2(),
+            I::Swap => self.handle_swap(),
 
             // ===== 로컬/글로벌 변수 =====
             I::LoadLocal(ix) => self.handle_load_local(*ix),
@@ -45,13 +62,25 @@ impl Vm {
 
             // ===== 산술 연산 =====
             I::Add => self.handle_add(module, io),
+            I::InplaceAdd => self.handle_inplace_add(module, io),
             I::Sub => self.handle_sub(module, io),
+            I::InplaceSub => self.handle_inplace_set_op(&I::Sub, module, io),
             I::Mul => self.handle_mul(module, io),
             I::Div => self.handle_div(module, io),
             I::TrueDiv => self.handle_truediv(module, io),
             I::Mod => self.handle_mod(module, io),
             I::Neg => self.handle_neg(module, io),
             I::Pos => self.handle_pos(module, io),
+            I::Pow => self.handle_pow(module, io),
+
+            // ===== 비트 연산 =====
+            I::BitAnd | I::BitOr | I::BitXor => self.handle_bitwise(ins, module, io),
+            I::InplaceBitAnd => self.handle_inplace_set_op(&I::BitAnd, module, io),
+            I::InplaceBitOr => self.handle_inplace_set_op(&I::BitOr, module, io),
+            I::InplaceBitXor => self.handle_inplace_set_op(&I::BitXor, module, io),
+            I::Shl => self.handle_shift(true, module, io),
+            I::Shr => self.handle_shift(false, module, io),
+            I::Invert => self.handle_invert(module, io),
 
             // ===== 비교/논리 연산 =====
             I::Eq => self.handle_eq(module, io),
@@ -61,6 +90,8 @@ impl Vm {
             I::Gt => self.handle_gt(module, io),
             I::Ge => self.handle_ge(module, io),
             I::Not => self.handle_not(),
+            I::In => self.handle_in(module, io),
+            I::Is => self.handle_is(),
 
             // ===== 제어 흐름 =====
             I::Jump(off) => self.handle_jump(*off),
@@ -68,15 +99,28 @@ impl Vm {
             I::JumpIfTrue(off) => self.handle_jump_if_true(*off),
             I::Call(fid, argc) => self.handle_call(*fid, *argc, module),
             I::CallBuiltin(bid, argc) => self.handle_call_builtin(*bid, *argc, module, io),
+            I::CallBuiltinKw(bid, argc) => self.handle_call_builtin_kw(*bid, *argc, module, io),
+            I::CallNative(name_sym, argc) => self.handle_call_native(*name_sym, *argc, module, io),
             I::CallValue(argc) => self.handle_call_value(*argc, module, io),
             I::CallMethod(method_sym, argc) => {
                 self.handle_call_method_dispatch(*method_sym, *argc, module, io)
             }
+            I::CallMethodKw(method_sym, argc) => {
+                self.handle_call_method_kw(*method_sym, *argc as usize, module, io)?;
+                Ok(ExecutionFlow::Continue)
+            }
             I::Return => self.handle_return(),
 
+            // ===== 문자열 포매팅 =====
+            I::FormatValue(conversion, has_spec) => {
+                self.handle_format_value(*conversion, *has_spec)
+            }
+            I::BuildString(count) => self.handle_build_string(*count),
+
             // ===== 속성 접근 =====
             I::LoadAttr(attr_sym) => self.handle_load_attr(*attr_sym, module),
             I::StoreAttr(attr_sym) => self.handle_store_attr(*attr_sym, module),
+            I::ImportModule(name_sym) => self.handle_import_module(*name_sym, module),
 
             // ===== 컬렉션 =====
             I::BuildList(count) => self.handle_build_list(*count),
@@ -86,6 +130,8 @@ impl Vm {
             I::BuildTreeSet(count) => self.handle_build_treeset(*count),
             I::LoadIndex => self.handle_load_index(),
             I::StoreIndex => self.handle_store_index(),
+            I::UnpackSequence(count) => self.handle_unpack_sequence(*count, module, io),
+            I::UnpackEx(before, after) => self.handle_unpack_ex(*before, *after, module, io),
 
             // ===== Lambda/Closure =====
             I::MakeClosure(func_id, num_captures) => {
@@ -142,6 +188,32 @@ impl Vm {
         Ok(ExecutionFlow::Continue)
     }
 
+    /// a, b → a, b, a, b (인덱스 복합 할당에서 객체와 인덱스 재사용)
+    fn handle_dup2(&mut self) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        self.push(a.clone())?;
+        self.push(b.clone())?;
+        self.push(a)?;
+        self.push(b)?;
+        Ok(ExecutionFlow::Continue)
+    }
+
+    fn handle_swap(&mut self) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        self.push(b)?;
+        self.push(a)?;
+        Ok(ExecutionFlow::Continue)
+    }
+
+    /// a, b, c → c, a, b (연쇄 비교에서 중간 피연산자 보존)
+    fn handle_rot3(&mut self) -> VmResult<ExecutionFlow> {
+        let (c, b, a) = (self.pop()?, self.pop()?, self.pop()?);
+        self.push(c)?;
+        self.push(a)?;
+        self.push(b)?;
+        Ok(ExecutionFlow::Continue)
+    }
+
     fn handle_pop(&mut self) -> VmResult<ExecutionFlow> {
         self.pop()?;
         Ok(ExecutionFlow::Continue)
@@ -166,6 +238,23 @@ impl Vm {
             .globals
             .get(ix as usize)
             .and_then(|o| o.clone())
+            // 정의되지 않은 전역이 builtin 타입 이름이면 타입 객체 (`isinstance(x, int)`),
+            // builtin 함수 이름이면 함수 객체 (`key=len`), 네이티브 이름이면 네이티브 객체 (`f = double`)
+            .or_else(|| {
+                let name = module.symbols.get(ix as usize)?;
+                crate::builtins::BuiltinClassType::from_type_name(name)
+                    .map(super::super::utils::make_builtin_class)
+                    .or_else(|| {
+                        crate::builtins::lookup(name)
+                            .map(|b| super::super::utils::make_builtin_function(b.builtin_id))
+                    })
+                    .or_else(|| {
+                        self.natives.contains(name).then(|| {
+                            let is_class = self.natives.class(name).is_some();
+                            super::super::utils::make_native_function(name, is_class)
+                        })
+                    })
+            })
             .ok_or_else(|| {
                 err(
                     VmErrorKind::UndefinedGlobal(ix),
@@ -190,6 +279,72 @@ impl Vm {
 
     // ==================== 산술 연산 핸들러 ====================
 
+    /// `a += b`: 리스트끼리는 `a`를 제자리에서 확장해 별칭(`b = a`)에도 반영되게 합니다.
+    /// 그 외의 조합은 일반 덧셈과 같습니다.
+    fn handle_inplace_add<IO: RuntimeIo>(
+        &mut self,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        if let (Value::Object(lhs), Value::Object(rhs)) = (&a, &b)
+            && let ObjectData::List { items } = &lhs.data
+            && let ObjectData::List { items: other } = &rhs.data
+        {
+            // `a += a`도 안전하도록 먼저 복사
+            let extra = other.borrow().clone();
+            items.borrow_mut().extend(extra);
+            self.push(a)?;
+            return Ok(ExecutionFlow::Continue);
+        }
+        self.push(a)?;
+        self.push(b)?;
+        self.handle_add(module, io)
+    }
+
+    /// `a -= b`, `a &= b`, `a |= b`, `a ^= b`: 셋/트리셋은 연산 결과를 `a`에 제자리로 옮겨
+    /// 별칭에도 반영되게 합니다. 그 외의 조합은 일반 연산(`op`)과 같습니다.
+    fn handle_inplace_set_op<IO: RuntimeIo>(
+        &mut self,
+        op: &I,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        self.push(a.clone())?;
+        self.push(b)?;
+        // 연산자 검사와 에러 메시지는 일반 연산과 같게
+        let flow = match op {
+            I::Sub => self.handle_sub(module, io)?,
+            _ => self.handle_bitwise(op, module, io)?,
+        };
+        let Value::Object(lhs) = &a else {
+            return Ok(flow);
+        };
+        if !matches!(lhs.data, ObjectData::Set { .. } | ObjectData::TreeSet { .. }) {
+            return Ok(flow);
+        }
+        // 셋의 연산자는 네이티브이므로 결과가 바로 스택에 있음
+        let result = self.pop()?;
+        if let Value::Object(res) = &result {
+            match (&lhs.data, &res.data) {
+                (ObjectData::Set { items }, ObjectData::Set { items: new }) => {
+                    *items.borrow_mut() = new.take();
+                    self.push(a)?;
+                    return Ok(ExecutionFlow::Continue);
+                }
+                (ObjectData::TreeSet { items }, ObjectData::TreeSet { items: new }) => {
+                    *items.borrow_mut() = new.take();
+                    self.push(a)?;
+                    return Ok(ExecutionFlow::Continue);
+                }
+                _ => {}
+            }
+        }
+        self.push(result)?;
+        Ok(ExecutionFlow::Continue)
+    }
+
     fn handle_add<IO: RuntimeIo>(
         &mut self,
         module: &mut Module,
@@ -367,6 +522,7 @@ impl Vm {
             let result = if n < 0 {
                 String::new()
             } else {
+                limits::reserve(limits::repeat_len(s.len(), n as usize, "string")?)?;
                 s.repeat(n as usize)
             };
             self.push(super::super::utils::make_string(result))?;
@@ -381,6 +537,7 @@ impl Vm {
             let result = if n < 0 {
                 String::new()
             } else {
+                limits::reserve(limits::repeat_len(s.len(), n as usize, "string")?)?;
                 s.repeat(n as usize)
             };
             self.push(super::super::utils::make_string(result))?;
@@ -405,6 +562,16 @@ impl Vm {
             return Ok(ExecutionFlow::Continue);
         }
 
+        // Int * List/Tuple: 반복 횟수가 왼쪽에 와도 시퀀스의 __mul__로 처리
+        let (a, b) = if int_like(&a).is_some()
+            && let Value::Object(obj) = &b
+            && matches!(obj.data, ObjectData::List { .. } | ObjectData::Tuple { .. })
+        {
+            (b, a)
+        } else {
+            (a, b)
+        };
+
         // Slow path: __mul__ 메서드 조회
         match self.lookup_method(&a, "__mul__", module) {
             Ok(method_impl) => {
@@ -460,7 +627,7 @@ impl Vm {
                     "integer division by zero".into(),
                 ));
             }
-            self.push(Value::Int(x / y))?;
+            self.push(Value::Int(int::floor_div(*x, *y)))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -555,7 +722,7 @@ impl Vm {
                     "integer modulo by zero".into(),
                 ));
             }
-            self.push(Value::Int(x % y))?;
+            self.push(Value::Int(int::floor_mod(*x, *y)))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -567,7 +734,7 @@ impl Vm {
                     "float modulo by zero".into(),
                 ));
             }
-            self.push(Value::Float(x % y))?;
+            self.push(Value::Float(float::floor_mod(*x, *y)))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -579,7 +746,7 @@ impl Vm {
                     "float modulo by zero".into(),
                 ));
             }
-            self.push(Value::Float((*x as f64) % y))?;
+            self.push(Value::Float(float::floor_mod(*x as f64, *y)))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -591,7 +758,7 @@ impl Vm {
                     "float modulo by zero".into(),
                 ));
             }
-            self.push(Value::Float(x % (*y as f64)))?;
+            self.push(Value::Float(float::floor_mod(*x, *y as f64)))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -635,6 +802,188 @@ impl Vm {
         }
     }
 
+    fn handle_pow<IO: RuntimeIo>(
+        &mut self,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+
+        // Fast path 1: Int ** Int (음수 지수는 Float)
+        if let (Value::Int(x), Value::Int(y)) = (&a, &b) {
+            self.push(int::pow_ints(*x, *y)?)?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
+        // Fast path 2: Float/Int 혼합
+        let as_float = |v: &Value| match v {
+            Value::Int(n) => Some(*n as f64),
+            Value::Float(f) => Some(*f),
+            _ => None,
+        };
+        if let (Some(x), Some(y)) = (as_float(&a), as_float(&b)) {
+            self.push(Value::Float(float::pow_floats(x, y)?))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
+        self.call_binary_magic(a, b, "__pow__", "**", module, io)
+    }
+
+    /// &, |, ^: Int/Bool 피연산자 (Bool끼리는 Bool 결과)
+    fn handle_bitwise<IO: RuntimeIo>(
+        &mut self,
+        ins: &I,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        let (method, symbol, apply): (&str, &str, fn(i64, i64) -> i64) = match ins {
+            I::BitAnd => ("__and__", "&", |x, y| x & y),
+            I::BitOr => ("__or__", "|", |x, y| x | y),
+            _ => ("__xor__", "^", |x, y| x ^ y),
+        };
+
+        // Fast path 1: Bool op Bool
+        if let (Value::Bool(x), Value::Bool(y)) = (&a, &b) {
+            self.push(Value::Bool(apply(*x as i64, *y as i64) != 0))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
+        // Fast path 2: Int/Bool 혼합
+        if let (Some(x), Some(y)) = (int_like(&a), int_like(&b)) {
+            self.push(Value::Int(apply(x, y)))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
+        self.call_binary_magic(a, b, method, symbol, module, io)
+    }
+
+    /// <<, >>
+    fn handle_shift<IO: RuntimeIo>(
+        &mut self,
+        left: bool,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+
+        // Fast path: Int/Bool 피연산자
+        if let (Some(x), Some(y)) = (int_like(&a), int_like(&b)) {
+            let shifted = if left {
+                int::shift_left(x, y)?
+            } else {
+                int::shift_right(x, y)?
+            };
+            self.push(Value::Int(shifted))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
+        if left {
+            self.call_binary_magic(a, b, "__lshift__", "<<", module, io)
+        } else {
+            self.call_binary_magic(a, b, "__rshift__", ">>", module, io)
+        }
+    }
+
+    fn handle_invert<IO: RuntimeIo>(
+        &mut self,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let a = self.pop()?;
+
+        // Fast path: ~Int, ~Bool
+        if let Some(x) = int_like(&a) {
+            self.push(Value::Int(!x))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
+        // Slow path: __invert__ 메서드 조회
+        match self.lookup_method(&a, "__invert__", module) {
+            Ok(method_impl) => {
+                match self.call_method_impl(method_impl, &a, vec![], module, io)? {
+                    Some(result) => {
+                        self.push(result)?;
+                        Ok(ExecutionFlow::Continue)
+                    }
+                    None => {
+                        // UserDefined 메서드: 스택 기반 메서드 호출
+                        self.push(a)?;
+                        let method_sym = module
+                            .symbols
+                            .iter()
+                            .position(|s| s == "__invert__")
+                            .ok_or_else(|| {
+                                err(
+                                    VmErrorKind::TypeError("method"),
+                                    "__invert__ symbol not found".into(),
+                                )
+                            })? as u16;
+                        self.handle_call_method(method_sym, 0, module, io)?;
+                        Ok(ExecutionFlow::Continue)
+                    }
+                }
+            }
+            Err(_) => Err(err(
+                VmErrorKind::TypeError("invert"),
+                format!(
+                    "bad operand type for unary ~: '{}'",
+                    self.get_type_name(&a, module)
+                        .unwrap_or_else(|_| "unknown".to_string())
+                ),
+            )),
+        }
+    }
+
+    /// 이항 연산 slow path: `a.method(b)` (Native는 즉시, UserDefined는 스택 기반 호출)
+    fn call_binary_magic<IO: RuntimeIo>(
+        &mut self,
+        a: Value,
+        b: Value,
+        method: &str,
+        symbol: &str,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        match self.lookup_method(&a, method, module) {
+            Ok(method_impl) => {
+                match self.call_method_impl(method_impl, &a, vec![b.clone()], module, io)? {
+                    Some(result) => {
+                        self.push(result)?;
+                        Ok(ExecutionFlow::Continue)
+                    }
+                    None => {
+                        self.push(a)?;
+                        self.push(b)?;
+                        let method_sym = module
+                            .symbols
+                            .iter()
+                            .position(|s| s == method)
+                            .ok_or_else(|| {
+                                err(
+                                    VmErrorKind::TypeError("method"),
+                                    format!("{} symbol not found", method),
+                                )
+                            })? as u16;
+                        self.handle_call_method(method_sym, 1, module, io)?;
+                        Ok(ExecutionFlow::Continue)
+                    }
+                }
+            }
+            Err(_) => Err(err(
+                VmErrorKind::TypeError("operator"),
+                format!(
+                    "unsupported operand types for {}: '{}' and '{}'",
+                    symbol,
+                    self.get_type_name(&a, module)
+                        .unwrap_or_else(|_| "unknown".to_string()),
+                    self.get_type_name(&b, module)
+                        .unwrap_or_else(|_| "unknown".to_string())
+                ),
+            )),
+        }
+    }
+
     fn handle_truediv<IO: RuntimeIo>(
         &mut self,
         module: &mut Module,
@@ -694,7 +1043,7 @@ impl Vm {
 
         // Fast path 1: -Int
         if let Value::Int(x) = a {
-            self.push(Value::Int(-x))?;
+            self.push(Value::Int(x.wrapping_neg()))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -816,7 +1165,7 @@ impl Vm {
                 | (Value::Bool(_), Value::Bool(_))
                 | (Value::None, Value::None)
         ) {
-            self.push(Value::Bool(eq_vals(&a, &b)))?;
+            self.push(Value::Bool(eq_vals(&a, &b)?))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -857,7 +1206,7 @@ impl Vm {
             }
             Err(_) => {
                 // __eq__가 없으면 기본 동작 (객체 identity 비교)
-                self.push(Value::Bool(eq_vals(&a, &b)))?;
+                self.push(Value::Bool(eq_vals(&a, &b)?))?;
                 Ok(ExecutionFlow::Continue)
             }
         }
@@ -880,7 +1229,7 @@ impl Vm {
                 | (Value::Bool(_), Value::Bool(_))
                 | (Value::None, Value::None)
         ) {
-            self.push(Value::Bool(!eq_vals(&a, &b)))?;
+            self.push(Value::Bool(!eq_vals(&a, &b)?))?;
             return Ok(ExecutionFlow::Continue);
         }
 
@@ -921,12 +1270,33 @@ impl Vm {
             }
             Err(_) => {
                 // __ne__가 없으면 기본 동작 (객체 identity 비교)
-                self.push(Value::Bool(!eq_vals(&a, &b)))?;
+                self.push(Value::Bool(!eq_vals(&a, &b)?))?;
                 Ok(ExecutionFlow::Continue)
             }
         }
     }
 
+    /// `'<' not supported between instances of 'int' and 'str'`
+    pub(super) fn unsupported_comparison(
+        &self,
+        op: &str,
+        a: &Value,
+        b: &Value,
+        module: &Module,
+    ) -> VmError {
+        err(
+            VmErrorKind::TypeError("comparison"),
+            format!(
+                "'{}' not supported between instances of '{}' and '{}'",
+                op,
+                self.get_type_name(a, module)
+                    .unwrap_or_else(|_| "unknown".to_string()),
+                self.get_type_name(b, module)
+                    .unwrap_or_else(|_| "unknown".to_string())
+            ),
+        )
+    }
+
     fn handle_lt<IO: RuntimeIo>(
         &mut self,
         module: &mut Module,
@@ -965,10 +1335,21 @@ impl Vm {
             return Ok(ExecutionFlow::Continue);
         }
 
+        // list/tuple: 사전식 비교
+        if let Some((xs, ys)) = super::method_dispatch::sequence_pair(&a, &b) {
+            let result = self.compare_sequences(&I::Lt, &xs, &ys, module, io)?;
+            self.push(Value::Bool(result))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
         // Slow path: __lt__ 메서드 조회
         match self.lookup_method(&a, "__lt__", module) {
             Ok(method_impl) => {
-                match self.call_method_impl(method_impl, &a, vec![b.clone()], module, io)? {
+                // 내장 타입의 비교 메서드는 다른 타입과 비교하면 실패함
+                let result = self
+                    .call_method_impl(method_impl, &a, vec![b.clone()], module, io)
+                    .map_err(|_| self.unsupported_comparison("<", &a, &b, module))?;
+                match result {
                     Some(result) => {
                         self.push(result)?;
                         Ok(ExecutionFlow::Continue)
@@ -992,16 +1373,7 @@ impl Vm {
                     }
                 }
             }
-            Err(_) => Err(err(
-                VmErrorKind::TypeError("comparison"),
-                format!(
-                    "'<' not supported between instances of '{}' and '{}'",
-                    self.get_type_name(&a, module)
-                        .unwrap_or_else(|_| "unknown".to_string()),
-                    self.get_type_name(&b, module)
-                        .unwrap_or_else(|_| "unknown".to_string())
-                ),
-            )),
+            Err(_) => Err(self.unsupported_comparison("<", &a, &b, module)),
         }
     }
 
@@ -1043,10 +1415,21 @@ impl Vm {
             return Ok(ExecutionFlow::Continue);
         }
 
+        // list/tuple: 사전식 비교
+        if let Some((xs, ys)) = super::method_dispatch::sequence_pair(&a, &b) {
+            let result = self.compare_sequences(&I::Le, &xs, &ys, module, io)?;
+            self.push(Value::Bool(result))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
         // Slow path: __le__ 메서드 조회
         match self.lookup_method(&a, "__le__", module) {
             Ok(method_impl) => {
-                match self.call_method_impl(method_impl, &a, vec![b.clone()], module, io)? {
+                // 내장 타입의 비교 메서드는 다른 타입과 비교하면 실패함
+                let result = self
+                    .call_method_impl(method_impl, &a, vec![b.clone()], module, io)
+                    .map_err(|_| self.unsupported_comparison("<=", &a, &b, module))?;
+                match result {
                     Some(result) => {
                         self.push(result)?;
                         Ok(ExecutionFlow::Continue)
@@ -1070,16 +1453,7 @@ impl Vm {
                     }
                 }
             }
-            Err(_) => Err(err(
-                VmErrorKind::TypeError("comparison"),
-                format!(
-                    "'<=' not supported between instances of '{}' and '{}'",
-                    self.get_type_name(&a, module)
-                        .unwrap_or_else(|_| "unknown".to_string()),
-                    self.get_type_name(&b, module)
-                        .unwrap_or_else(|_| "unknown".to_string())
-                ),
-            )),
+            Err(_) => Err(self.unsupported_comparison("<=", &a, &b, module)),
         }
     }
 
@@ -1121,10 +1495,21 @@ impl Vm {
             return Ok(ExecutionFlow::Continue);
         }
 
+        // list/tuple: 사전식 비교
+        if let Some((xs, ys)) = super::method_dispatch::sequence_pair(&a, &b) {
+            let result = self.compare_sequences(&I::Gt, &xs, &ys, module, io)?;
+            self.push(Value::Bool(result))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
         // Slow path: __gt__ 메서드 조회
         match self.lookup_method(&a, "__gt__", module) {
             Ok(method_impl) => {
-                match self.call_method_impl(method_impl, &a, vec![b.clone()], module, io)? {
+                // 내장 타입의 비교 메서드는 다른 타입과 비교하면 실패함
+                let result = self
+                    .call_method_impl(method_impl, &a, vec![b.clone()], module, io)
+                    .map_err(|_| self.unsupported_comparison(">", &a, &b, module))?;
+                match result {
                     Some(result) => {
                         self.push(result)?;
                         Ok(ExecutionFlow::Continue)
@@ -1148,16 +1533,7 @@ impl Vm {
                     }
                 }
             }
-            Err(_) => Err(err(
-                VmErrorKind::TypeError("comparison"),
-                format!(
-                    "'>' not supported between instances of '{}' and '{}'",
-                    self.get_type_name(&a, module)
-                        .unwrap_or_else(|_| "unknown".to_string()),
-                    self.get_type_name(&b, module)
-                        .unwrap_or_else(|_| "unknown".to_string())
-                ),
-            )),
+            Err(_) => Err(self.unsupported_comparison(">", &a, &b, module)),
         }
     }
 
@@ -1199,10 +1575,21 @@ impl Vm {
             return Ok(ExecutionFlow::Continue);
         }
 
+        // list/tuple: 사전식 비교
+        if let Some((xs, ys)) = super::method_dispatch::sequence_pair(&a, &b) {
+            let result = self.compare_sequences(&I::Ge, &xs, &ys, module, io)?;
+            self.push(Value::Bool(result))?;
+            return Ok(ExecutionFlow::Continue);
+        }
+
         // Slow path: __ge__ 메서드 조회
         match self.lookup_method(&a, "__ge__", module) {
             Ok(method_impl) => {
-                match self.call_method_impl(method_impl, &a, vec![b.clone()], module, io)? {
+                // 내장 타입의 비교 메서드는 다른 타입과 비교하면 실패함
+                let result = self
+                    .call_method_impl(method_impl, &a, vec![b.clone()], module, io)
+                    .map_err(|_| self.unsupported_comparison(">=", &a, &b, module))?;
+                match result {
                     Some(result) => {
                         self.push(result)?;
                         Ok(ExecutionFlow::Continue)
@@ -1226,22 +1613,93 @@ impl Vm {
                     }
                 }
             }
+            Err(_) => Err(self.unsupported_comparison(">=", &a, &b, module)),
+        }
+    }
+
+    fn handle_not(&mut self) -> VmResult<ExecutionFlow> {
+        let a = self.pop_bool()?;
+        self.push(Value::Bool(!a))?;
+        Ok(ExecutionFlow::Continue)
+    }
+
+    /// `item in container` → container.__contains__(item)
+    fn handle_in<IO: RuntimeIo>(
+        &mut self,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let (container, item) = (self.pop()?, self.pop()?);
+
+        match self.lookup_method(&container, "__contains__", module) {
+            Ok(method_impl) => {
+                match self.call_method_impl(method_impl, &container, vec![item.clone()], module, io)? {
+                    Some(result) => {
+                        self.push(result)?;
+                        Ok(ExecutionFlow::Continue)
+                    }
+                    None => {
+                        // UserDefined 메서드: 스택 기반 메서드 호출
+                        self.push(container)?;
+                        self.push(item)?;
+                        let method_sym = module
+                            .symbols
+                            .iter()
+                            .position(|s| s == "__contains__")
+                            .ok_or_else(|| {
+                                err(
+                                    VmErrorKind::TypeError("method"),
+                                    "__contains__ symbol not found".into(),
+                                )
+                            })? as u16;
+                        self.handle_call_method(method_sym, 1, module, io)?;
+                        Ok(ExecutionFlow::Continue)
+                    }
+                }
+            }
             Err(_) => Err(err(
-                VmErrorKind::TypeError("comparison"),
+                VmErrorKind::TypeError("in"),
                 format!(
-                    "'>=' not supported between instances of '{}' and '{}'",
-                    self.get_type_name(&a, module)
-                        .unwrap_or_else(|_| "unknown".to_string()),
-                    self.get_type_name(&b, module)
+                    "argument of type '{}' is not iterable",
+                    self.get_type_name(&container, module)
                         .unwrap_or_else(|_| "unknown".to_string())
                 ),
             )),
         }
     }
 
-    fn handle_not(&mut self) -> VmResult<ExecutionFlow> {
-        let a = self.pop_bool()?;
-        self.push(Value::Bool(!a))?;
+    /// `a is b`: 객체는 포인터 동일성, 원시 값은 같은 타입의 같은 값
+    ///
+    /// builtin 함수와 타입, 네이티브 이름은 읽을 때마다 새 객체를 만들므로 가리키는 대상으로
+    /// 비교합니다 (`print is print`, `type(1) is int`).
+    fn handle_is(&mut self) -> VmResult<ExecutionFlow> {
+        let (b, a) = (self.pop()?, self.pop()?);
+        let same = match (&a, &b) {
+            (Value::Object(x), Value::Object(y)) => {
+                Rc::ptr_eq(x, y)
+                    || match (&x.data, &y.data) {
+                        (
+                            ObjectData::BuiltinFunction { builtin_id: f1 },
+                            ObjectData::BuiltinFunction { builtin_id: f2 },
+                        ) => f1 == f2,
+                        (
+                            ObjectData::BuiltinClass { class_type: t1 },
+                            ObjectData::BuiltinClass { class_type: t2 },
+                        ) => t1 == t2,
+                        (
+                            ObjectData::NativeFunction { name: n1, .. },
+                            ObjectData::NativeFunction { name: n2, .. },
+                        ) => n1 == n2,
+                        _ => false,
+                    }
+            }
+            (Value::Int(x), Value::Int(y)) => x == y,
+            (Value::Float(x), Value::Float(y)) => x.to_bits() == y.to_bits(),
+            (Value::Bool(x), Value::Bool(y)) => x == y,
+            (Value::None, Value::None) => true,
+            _ => false,
+        };
+        self.push(Value::Bool(same))?;
         Ok(ExecutionFlow::Continue)
     }
 
@@ -1274,12 +1732,12 @@ impl Vm {
         Ok(ExecutionFlow::Continue)
     }
 
-    /// Builtin 함수 호출 (print, input, int, bool, str, len, range)
+    /// Builtin 함수 호출 (print, input, int, bool, str, len, range, ...)
     fn handle_call_builtin<IO: RuntimeIo>(
         &mut self,
         bid: u8,
         argc: u8,
-        module: &Module,
+        module: &mut Module,
         io: &mut IO,
     ) -> VmResult<ExecutionFlow> {
         let argc = argc as usize;
@@ -1296,7 +1754,75 @@ impl Vm {
         }
         args.reverse(); // 스택에서 꺼낸 순서를 역순으로
 
-        let result = crate::vm::builtins::call_builtin(bid, args, io)?;
+        let result = crate::vm::builtins::call_builtin_with_vm(bid, args, module, self, io)?;
+
+        self.push(result)?;
+        Ok(ExecutionFlow::Continue)
+    }
+
+    /// 네이티브 함수 호출 또는 네이티브 클래스 인스턴스 생성
+    ///
+    /// 같은 이름의 전역(`def double(x): ...`, `double = ...`)이 있으면 그쪽을 호출합니다.
+    fn handle_call_native<IO: RuntimeIo>(
+        &mut self,
+        name_sym: u16,
+        argc: u8,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let mut args = Vec::with_capacity(argc as usize);
+        for _ in 0..argc {
+            args.push(self.pop()?);
+        }
+        args.reverse();
+
+        if let Some(Some(user)) = module.globals.get(name_sym as usize) {
+            self.push(user.clone())?;
+            for arg in args {
+                self.push(arg)?;
+            }
+            return self.handle_call_value(argc, module, io);
+        }
+
+        let name = &module.symbols[name_sym as usize];
+        let result = self.natives.call(name, &args)?;
+        self.push_native_result(result)
+    }
+
+    /// 네이티브 호출 결과를 스택에 넣거나, 호스트 요청이면 VM을 멈춤
+    fn push_native_result(&mut self, result: NativeResult) -> VmResult<ExecutionFlow> {
+        match result {
+            NativeResult::Ready(result) => {
+                self.push(result)?;
+                Ok(ExecutionFlow::Continue)
+            }
+            // 결과는 resume_host가 스택에 넣음 (IP는 이미 다음 명령어)
+            NativeResult::Pending(request) => {
+                self.host_request = Some(request);
+                Ok(ExecutionFlow::WaitingForHost)
+            }
+        }
+    }
+
+    /// 키워드 인자가 있는 builtin 호출
+    ///
+    /// 키워드 인자는 컴파일러가 dict로 묶어 스택 맨 위에 둡니다.
+    fn handle_call_builtin_kw<IO: RuntimeIo>(
+        &mut self,
+        bid: u8,
+        argc: u8,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let kwargs = crate::vm::format::kwargs_from_dict(&self.pop()?);
+
+        let mut args = Vec::with_capacity(argc as usize);
+        for _ in 0..argc {
+            args.push(self.pop()?);
+        }
+        args.reverse();
+
+        let result = crate::vm::builtins::call_builtin_kw(bid, args, kwargs, module, self, io)?;
 
         self.push(result)?;
         Ok(ExecutionFlow::Continue)
@@ -1322,43 +1848,32 @@ impl Vm {
             ));
         }
 
-        // Get prompt if provided (peek to keep on stack for retry)
-        let prompt_str = if argc == 1 {
-            let prompt = self
-                .stack
-                .last()
-                .ok_or_else(|| err(VmErrorKind::StackUnderflow, "stack underflow".into()))?;
-            // Object에서 String 프롬프트 추출
-            if self.is_string_object(prompt) {
-                Some(super::super::utils::expect_string(prompt)?)
-            } else {
+        let prompt = if argc == 1 { Some(self.pop()?) } else { None };
+        let prompt_str = match &prompt {
+            Some(p) if self.is_string_object(p) => Some(super::super::utils::expect_string(p)?),
+            Some(_) => {
                 return Err(err(
                     VmErrorKind::TypeError("input"),
                     "prompt must be a string".to_string(),
                 ));
             }
-        } else {
-            None
+            None => None,
         };
 
         use crate::runtime_io::ReadResult;
-        // Use read_line_with_prompt which handles prompt deduplication internally
         match io.read_line_with_prompt(prompt_str) {
             ReadResult::Ok(line) => {
-                // Pop the prompt if it exists
-                if argc == 1 {
-                    self.pop()?;
-                }
-                // input 결과를 String Object로 반환
-                self.push(super::super::utils::make_string(line.trim().to_string()))?;
+                self.push(input_line(line))?;
                 Ok(ExecutionFlow::Continue)
             }
+            // 다른 호스트 요청처럼 멈추고, 호스트가 읽은 줄을 resume_host로 넘기면 결과가 됨
             ReadResult::WaitingForInput => {
-                // Decrement IP so we retry this instruction when input arrives
-                if let Some(f) = self.frames.last_mut() {
-                    f.ip -= 1;
-                }
-                Ok(ExecutionFlow::WaitingForInput)
+                let payload = prompt_str.map_or(HostValue::None, |p| HostValue::Str(p.to_string()));
+                self.host_request = Some(HostRequest {
+                    kind: HostRequest::INPUT.to_string(),
+                    payload,
+                });
+                Ok(ExecutionFlow::WaitingForHost)
             }
             ReadResult::Error(e) => Err(err(VmErrorKind::TypeError("io"), e)),
         }
@@ -1389,9 +1904,14 @@ impl Vm {
             Value::Object(obj) => {
                 match &obj.data {
                     // 사용자 정의 클래스 호출
-                    ObjectData::UserClass { class_id, methods } => {
+                    ObjectData::UserClass {
+                        class_id,
+                        name,
+                        methods,
+                    } => {
                         // 인스턴스 생성
-                        let instance_value = super::super::utils::make_user_instance(*class_id);
+                        let instance_value =
+                            super::super::utils::make_user_instance(*class_id, name.clone());
 
                         // __init__ 메서드가 있으면 호출
                         if let Some(&init_func_id) = methods.get("__init__") {
@@ -1422,52 +1942,23 @@ impl Vm {
 
                         self.push(instance_value)?;
                     }
-                    // Builtin 클래스 호출 (range 등)
+                    // Builtin 타입 객체 호출 (`t = int; t("3")`): 대응하는 builtin 함수 실행
                     ObjectData::BuiltinClass { class_type } => {
-                        use crate::builtins::BuiltinClassType;
-                        let result = match class_type {
-                            BuiltinClassType::Range => {
-                                crate::vm::builtins::range::create_range(args)?
-                            }
-                            BuiltinClassType::List => {
-                                // TODO: list() 생성자는 나중에 구현
-                                return Err(err(
-                                    VmErrorKind::TypeError("list constructor"),
-                                    "list() constructor not yet implemented".to_string(),
-                                ));
-                            }
-                            BuiltinClassType::Dict => {
-                                // TODO: dict() 생성자는 나중에 구현
-                                return Err(err(
-                                    VmErrorKind::TypeError("dict constructor"),
-                                    "dict() constructor not yet implemented".to_string(),
-                                ));
-                            }
-                            BuiltinClassType::Tuple => {
-                                // TODO: tuple() 생성자는 나중에 구현
-                                return Err(err(
-                                    VmErrorKind::TypeError("tuple constructor"),
-                                    "tuple() constructor not yet implemented".to_string(),
-                                ));
-                            }
-                            BuiltinClassType::MapIter | BuiltinClassType::FilterIter => {
-                                // map/filter는 직접 호출할 수 없음 (builtin 함수로만 생성 가능)
-                                return Err(err(
-                                    VmErrorKind::TypeError("iterator constructor"),
-                                    format!("{} cannot be called directly", class_type.name()),
-                                ));
-                            }
-                            BuiltinClassType::Set => {
-                                crate::vm::builtins::set::call(args)?
-                            }
-                            BuiltinClassType::TreeSet => {
-                                crate::vm::builtins::treeset::call(args)?
-                            }
-                        };
+                        let result = self.call_builtin_class(*class_type, args, module, io)?;
                         self.push(result)?;
                     }
+                    // 값으로 꺼낸 builtin 함수 호출 (`f = abs; f(-1)`)
+                    ObjectData::BuiltinFunction { builtin_id } => {
+                        let result = self.call_builtin_value(*builtin_id, args, module, io)?;
+                        self.push(result)?;
+                    }
+                    // 값으로 꺼낸 네이티브 함수/클래스 호출 (`f = double; f(2)`)
+                    ObjectData::NativeFunction { name, .. } => {
+                        return self.push_native_result(self.natives.call(name, &args)?);
+                    }
                     // User-defined function/lambda 호출 (Closure 지원)
-                    ObjectData::UserFunction { func_id, captures } => {
+                    ObjectData::UserFunction { func_id, captures, .. } => {
+                        Self::check_func_arity(module, *func_id as usize, argc)?;
                         // 인자들을 스택에 push
                         for arg in args {
                             self.push(arg)?;
@@ -1516,6 +2007,43 @@ impl Vm {
         Ok(ExecutionFlow::Continue)
     }
 
+    // ===== 문자열 포매팅 핸들러 =====
+
+    fn handle_format_value(&mut self, conversion: u8, has_spec: bool) -> VmResult<ExecutionFlow> {
+        use crate::vm::format;
+
+        let spec = if has_spec {
+            Some(crate::vm::utils::expect_string(&self.pop()?)?.to_string())
+        } else {
+            None
+        };
+        let value = self.pop()?;
+        let value = format::convert(
+            &value,
+            match conversion {
+                1 => Some('s'),
+                2 => Some('r'),
+                _ => None,
+            },
+        )?;
+        let s = format::format_with_spec(&value, spec.as_deref().unwrap_or(""))?;
+        self.push(crate::vm::utils::make_string(s))?;
+        Ok(ExecutionFlow::Continue)
+    }
+
+    fn handle_build_string(&mut self, count: u16) -> VmResult<ExecutionFlow> {
+        let mut parts = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            parts.push(self.pop()?);
+        }
+        let mut s = String::new();
+        for part in parts.iter().rev() {
+            s.push_str(crate::vm::utils::expect_string(part)?);
+        }
+        self.push(crate::vm::utils::make_string(s))?;
+        Ok(ExecutionFlow::Continue)
+    }
+
     fn handle_return(&mut self) -> VmResult<ExecutionFlow> {
         let ret = self.leave_frame()?;
         if self.frames.is_empty() {
@@ -1537,12 +2065,15 @@ impl Vm {
 
         // Phase 4: 모든 Object에서 속성 로드 가능
         let value = match &obj_value {
-            Value::Object(obj) => obj.get_attr(attr_name).ok_or_else(|| {
-                err(
-                    VmErrorKind::TypeError("attribute"),
-                    format!("Object has no attribute '{}'", attr_name),
-                )
-            })?,
+            Value::Object(obj) => obj
+                .get_attr(attr_name)
+                .or_else(|| class_attribute(obj, attr_name, module))
+                .ok_or_else(|| {
+                    err(
+                        VmErrorKind::TypeError("attribute"),
+                        format!("Object has no attribute '{}'", attr_name),
+                    )
+                })?,
             _ => {
                 return Err(err(
                     VmErrorKind::TypeError("attribute access"),
@@ -1588,6 +2119,28 @@ impl Vm {
         Ok(ExecutionFlow::Continue)
     }
 
+    fn handle_import_module(&mut self, name_sym: u16, module: &Module) -> VmResult<ExecutionFlow> {
+        let name = &module.symbols[name_sym as usize];
+        let value = match self.modules.get(name) {
+            Some(value) => value.clone(),
+            None => {
+                let value = match name.as_str() {
+                    "sys" => super::super::builtins::sys::make_module(&self.argv),
+                    _ => {
+                        return Err(err(
+                            VmErrorKind::TypeError("import"),
+                            format!("No module named '{}'", name),
+                        ));
+                    }
+                };
+                self.modules.insert(name.clone(), value.clone());
+                value
+            }
+        };
+        self.push(value)?;
+        Ok(ExecutionFlow::Continue)
+    }
+
     // ==================== 컬렉션 핸들러 ====================
 
     fn handle_build_list(&mut self, count: u16) -> VmResult<ExecutionFlow> {
@@ -1847,6 +2400,24 @@ impl Vm {
                         let value = items[actual_idx].clone();
                         self.push(value)?;
                     }
+                    ObjectData::String(s) => {
+                        let idx = match index {
+                            Value::Int(i) => i,
+                            _ => {
+                                return Err(err(
+                                    VmErrorKind::TypeError("string index"),
+                                    "String indices must be integers".to_string(),
+                                ));
+                            }
+                        };
+                        let ch = super::super::utils::str_char_at(s, o.is_ascii_str(), idx).ok_or_else(|| {
+                            err(
+                                VmErrorKind::TypeError("string index"),
+                                format!("IndexError: string index out of range: {}", idx),
+                            )
+                        })?;
+                        self.push(super::super::utils::make_string(ch.to_string()))?;
+                    }
                     ObjectData::Dict { map } => {
                         // key를 DictKey로 변환
                         use crate::vm::value::DictKey;
@@ -1989,13 +2560,74 @@ impl Vm {
         Ok(ExecutionFlow::Continue)
     }
 
+    /// `a, b = seq`: 원소가 정확히 `count`개인지 확인하고 첫 대상이 top에 오도록 push
+    fn handle_unpack_sequence<IO: RuntimeIo>(
+        &mut self,
+        count: u8,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let seq = self.pop()?;
+        let items = self.collect_iterable(&seq, module, io)?;
+        let count = count as usize;
+        if items.len() != count {
+            let message = if items.len() > count {
+                format!("ValueError: too many values to unpack (expected {})", count)
+            } else {
+                format!(
+                    "ValueError: not enough values to unpack (expected {}, got {})",
+                    count,
+                    items.len()
+                )
+            };
+            return Err(err(VmErrorKind::TypeError("unpack"), message));
+        }
+        for item in items.into_iter().rev() {
+            self.push(item)?;
+        }
+        Ok(ExecutionFlow::Continue)
+    }
+
+    /// `a, *rest, z = seq`: 앞/뒤 원소와 나머지 리스트를 첫 대상이 top에 오도록 push
+    fn handle_unpack_ex<IO: RuntimeIo>(
+        &mut self,
+        before: u8,
+        after: u8,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<ExecutionFlow> {
+        let seq = self.pop()?;
+        let mut items = self.collect_iterable(&seq, module, io)?;
+        let (before, after) = (before as usize, after as usize);
+        if items.len() < before + after {
+            return Err(err(
+                VmErrorKind::TypeError("unpack"),
+                format!(
+                    "ValueError: not enough values to unpack (expected at least {}, got {})",
+                    before + after,
+                    items.len()
+                ),
+            ));
+        }
+        let tail = items.split_off(items.len() - after);
+        let rest = items.split_off(before);
+        for item in tail.into_iter().rev() {
+            self.push(item)?;
+        }
+        self.push(crate::vm::utils::make_list(rest))?;
+        for item in items.into_iter().rev() {
+            self.push(item)?;
+        }
+        Ok(ExecutionFlow::Continue)
+    }
+
     // ==================== Lambda/Closure 핸들러 ====================
 
     fn handle_make_closure(
         &mut self,
         func_id: u16,
         num_captures: u8,
-        _module: &Module,
+        module: &Module,
     ) -> VmResult<ExecutionFlow> {
         use crate::builtins::TYPE_FUNCTION;
         use crate::vm::value::{Object, ObjectData};
@@ -2009,12 +2641,46 @@ impl Vm {
         captures.reverse(); // 스택에서 역순으로 pop되므로 뒤집기
 
         // Create UserFunction object
+        let name = module.symbols[module.functions[func_id as usize].name_sym as usize].clone();
         let func_obj = Value::Object(Rc::new(Object::new(
             TYPE_FUNCTION,
-            ObjectData::UserFunction { func_id, captures },
+            ObjectData::UserFunction {
+                func_id,
+                name,
+                captures,
+            },
         )));
 
         self.push(func_obj)?;
         Ok(ExecutionFlow::Continue)
     }
 }
+
+/// 인스턴스에 없는 속성은 클래스 본문에서 정의한 클래스 속성에서 찾음 (`count = 0`)
+///
+/// 클래스 객체는 클래스 정의가 만든 상수이므로 모듈 상수에서 `class_id`로 찾습니다.
+fn class_attribute(obj: &crate::vm::value::Object, attr_name: &str, module: &Module) -> Option<Value> {
+    let ObjectData::UserInstance { class_id, .. } = &obj.data else {
+        return None;
+    };
+    module.consts.iter().find_map(|value| match value {
+        Value::Object(class) => match &class.data {
+            ObjectData::UserClass { class_id: id, .. } if id == class_id => {
+                class.get_attr(attr_name)
+            }
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// `input()`의 결과: 앞뒤 공백과 줄바꿈을 뺀 문자열 (이미 다듬어진 줄은 그대로 씀)
+pub(super) fn input_line(line: String) -> Value {
+    let trimmed = line.trim();
+    let line = if trimmed.len() == line.len() {
+        line
+    } else {
+        trimmed.to_string()
+    };
+    super::super::utils::make_string(line)
+}
@@ -1,11 +1,14 @@
-use super::super::bytecode::{Module, Value};
+use super::super::bytecode::{Instruction, Module, Value};
 use super::super::type_def::{Arity, MethodImpl};
 use super::super::utils::expect_string;
-use super::super::value::ObjectData;
+use super::super::value::{BuiltinInstanceData, ObjectData};
 use super::{Frame, Vm, VmErrorKind, VmResult, err};
-use crate::builtins::{TYPE_BOOL, TYPE_FLOAT, TYPE_INT, TYPE_NONE, TYPE_STR};
+use crate::builtins::{BuiltinClassType, TYPE_BOOL, TYPE_FLOAT, TYPE_INT, TYPE_NONE, TYPE_STR};
 use crate::runtime_io::RuntimeIo;
 use crate::vm::builtins::float;
+use crate::vm::limits;
+use crate::vm::native::{HostRequest, NativeResult};
+use std::collections::HashMap;
 
 impl Vm {
     // ========== 통일된 메서드 조회 시스템 ==========
@@ -56,7 +59,7 @@ impl Vm {
     ) -> VmResult<MethodImpl> {
         // UserInstance는 별도 처리 (클래스 테이블 사용)
         if let Value::Object(obj) = value
-            && let ObjectData::UserInstance { class_id } = &obj.data
+            && let ObjectData::UserInstance { class_id, .. } = &obj.data
         {
             let class_def = &module.classes[*class_id as usize];
 
@@ -75,6 +78,21 @@ impl Vm {
             return Ok(MethodImpl::UserDefined { func_id: *func_id });
         }
 
+        // 네이티브 인스턴스의 메서드는 타입 테이블에 없음 (CallMethod가 직접 호출)
+        if let Value::Object(obj) = value
+            && let ObjectData::NativeInstance { class, .. } = &obj.data
+        {
+            let message = if class.method_arity(method_name).is_some() {
+                format!(
+                    "{}.{}() does not accept keyword arguments",
+                    class.name, method_name
+                )
+            } else {
+                format!("'{}' object has no method '{}'", class.name, method_name)
+            };
+            return Err(err(VmErrorKind::TypeError("method"), message));
+        }
+
         // 1. 값의 타입 ID 가져오기
         let type_id = self.get_type_id(value)?;
 
@@ -179,7 +197,7 @@ impl Vm {
 
         // Phase 4: UserInstance 메서드는 별도 처리 (타입 테이블이 아닌 클래스 테이블 사용)
         if let Value::Object(obj) = &receiver
-            && let ObjectData::UserInstance { class_id } = &obj.data
+            && let ObjectData::UserInstance { class_id, .. } = &obj.data
         {
             let class_def = &module.classes[*class_id as usize];
 
@@ -202,6 +220,15 @@ impl Vm {
             return Ok(());
         }
 
+        // 네이티브 인스턴스: 호스트가 등록한 메서드를 바로 실행
+        if let Value::Object(obj) = &receiver
+            && let ObjectData::NativeInstance { class, state } = &obj.data
+        {
+            let result = class.call_method(state, method_name, &args)?;
+            self.push(result)?;
+            return Ok(());
+        }
+
         // 4. 메서드 조회 (통일된 방식!)
         let method_impl = self.lookup_method(&receiver, method_name, module)?;
 
@@ -247,10 +274,89 @@ impl Vm {
         Ok(())
     }
 
+    /// CallMethodKw 명령어 핸들러
+    ///
+    /// 키워드 인자는 컴파일러가 dict로 묶어 스택 맨 위에 둡니다.
+    /// 키워드를 받는 Native 메서드(`str.format`)만 허용합니다.
+    pub(super) fn handle_call_method_kw<IO: RuntimeIo>(
+        &mut self,
+        method_sym: u16,
+        argc: usize,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<()> {
+        let kwargs = super::super::format::kwargs_from_dict(&self.pop()?);
+
+        let mut args = Vec::with_capacity(argc);
+        for _ in 0..argc {
+            args.push(self.pop()?);
+        }
+        args.reverse();
+        let receiver = self.pop()?;
+        let method_name = module.symbols[method_sym as usize].clone();
+
+        let method_impl = self.lookup_method(&receiver, &method_name, module)?;
+        match method_impl {
+            MethodImpl::Native { func, .. } => {
+                if let Some(name) = kwargs.keys().find(|name| !func.accepts_keyword(name)) {
+                    return Err(err(
+                        VmErrorKind::TypeError("method"),
+                        format!(
+                            "{}.{}() got an unexpected keyword argument '{}'",
+                            self.get_type_name(&receiver, module)?,
+                            method_name,
+                            name
+                        ),
+                    ));
+                }
+                let result = match func {
+                    super::super::type_def::NativeMethod::StrFormat => {
+                        let template = expect_string(&receiver)?;
+                        super::super::utils::make_string(super::super::format::str_format(
+                            template, &args, &kwargs,
+                        )?)
+                    }
+                    super::super::type_def::NativeMethod::ListSort => {
+                        super::super::builtins::list_methods::list_sort(
+                            &receiver, &kwargs, module, self, io,
+                        )?
+                    }
+                    _ => self.call_native_method_dispatch(func, &receiver, args, module, io)?,
+                };
+                self.push(result)?;
+                Ok(())
+            }
+            MethodImpl::UserDefined { .. } => Err(err(
+                VmErrorKind::TypeError("method"),
+                format!("{}() does not accept keyword arguments", method_name),
+            )),
+        }
+    }
+
     /// 타입 이름 가져오기 (에러 메시지용)
+    ///
+    /// 사용자 클래스 인스턴스(type_id >= 100)는 타입 테이블에 없으므로 `module.classes`에서 찾고,
+    /// 그래도 모르면 `"object"`
     pub(super) fn get_type_name(&self, value: &Value, module: &Module) -> VmResult<String> {
+        if let Value::Object(obj) = value {
+            match &obj.data {
+                ObjectData::NativeInstance { class, .. } => return Ok(class.name.clone()),
+                ObjectData::UserInstance { class_id, .. } => {
+                    return Ok(module
+                        .classes
+                        .get(*class_id as usize)
+                        .map_or("object", |class| class.name.as_str())
+                        .to_string());
+                }
+                _ => {}
+            }
+        }
         let type_id = self.get_type_id(value)?;
-        Ok(module.types[type_id as usize].name.clone())
+        Ok(module
+            .types
+            .get(type_id as usize)
+            .map_or("object", |ty| ty.name.as_str())
+            .to_string())
     }
 
     /// Native 메서드 디스패처
@@ -264,7 +370,7 @@ impl Vm {
         module: &mut Module,
         io: &mut IO,
     ) -> VmResult<Value> {
-        use super::super::builtins::{dict_methods, filter, int, list_methods, map, range, set_methods, str_methods, treeset_methods};
+        use super::super::builtins::{dict_methods, enumerate, filter, int, list_methods, map, range, set_methods, str_methods, treeset_methods, tuple_methods, zip};
         use super::super::type_def::NativeMethod as NM;
 
         // builtins 모듈에서 직접 호출
@@ -285,6 +391,13 @@ impl Vm {
             NM::IntGe => int::int_ge(receiver, args),
             NM::IntEq => int::int_eq(receiver, args),
             NM::IntNe => int::int_ne(receiver, args),
+            NM::IntPow => int::int_pow(receiver, args),
+            NM::IntAnd => int::int_and(receiver, args),
+            NM::IntOr => int::int_or(receiver, args),
+            NM::IntXor => int::int_xor(receiver, args),
+            NM::IntLShift => int::int_lshift(receiver, args),
+            NM::IntRShift => int::int_rshift(receiver, args),
+            NM::IntInvert => int::int_invert(receiver, args),
 
             // Float 매직 메서드들
             NM::FloatAdd => float::float_add(receiver, args),
@@ -301,6 +414,7 @@ impl Vm {
             NM::FloatGe => float::float_ge(receiver, args),
             NM::FloatEq => float::float_eq(receiver, args),
             NM::FloatNe => float::float_ne(receiver, args),
+            NM::FloatPow => float::float_pow(receiver, args),
 
             // String 매직 메서드들
             NM::StrAdd => str_methods::str_add(receiver, args),
@@ -323,11 +437,37 @@ impl Vm {
             NM::StrEndsWith => str_methods::str_ends_with(receiver, args),
             NM::StrFind => str_methods::str_find(receiver, args),
             NM::StrCount => str_methods::str_count(receiver, args),
+            NM::StrLStrip => str_methods::str_lstrip(receiver, args),
+            NM::StrRStrip => str_methods::str_rstrip(receiver, args),
+            NM::StrRSplit => str_methods::str_rsplit(receiver, args),
+            NM::StrSplitLines => str_methods::str_splitlines(receiver, args),
+            NM::StrRFind => str_methods::str_rfind(receiver, args),
+            NM::StrIndex => str_methods::str_index(receiver, args),
+            NM::StrRIndex => str_methods::str_rindex(receiver, args),
+            NM::StrPartition => str_methods::str_partition(receiver, args),
+            NM::StrRPartition => str_methods::str_rpartition(receiver, args),
+            NM::StrIsDigit => str_methods::str_isdigit(receiver, args),
+            NM::StrIsAlpha => str_methods::str_isalpha(receiver, args),
+            NM::StrIsAlnum => str_methods::str_isalnum(receiver, args),
+            NM::StrIsSpace => str_methods::str_isspace(receiver, args),
+            NM::StrIsUpper => str_methods::str_isupper(receiver, args),
+            NM::StrIsLower => str_methods::str_islower(receiver, args),
+            NM::StrTitle => str_methods::str_title(receiver, args),
+            NM::StrCapitalize => str_methods::str_capitalize(receiver, args),
+            NM::StrCenter => str_methods::str_center(receiver, args),
+            NM::StrLJust => str_methods::str_ljust(receiver, args),
+            NM::StrRJust => str_methods::str_rjust(receiver, args),
+            NM::StrZFill => str_methods::str_zfill(receiver, args),
+            NM::StrFormat => str_methods::str_format(receiver, args),
+            NM::StrMod => str_methods::str_mod(receiver, args),
+            NM::StrContains => str_methods::str_contains(receiver, args),
+            NM::StrIter => str_methods::str_iter(receiver, args),
 
             // Range 메서드들
             NM::RangeIter => range::range_iter(receiver, args),
             NM::RangeHasNext => range::range_has_next(receiver, args),
             NM::RangeNext => range::range_next(receiver, args),
+            NM::RangeContains => range::range_contains(receiver, args),
 
             // List 메서드들
             NM::ListAppend => list_methods::list_append(receiver, args),
@@ -336,13 +476,16 @@ impl Vm {
             NM::ListInsert => list_methods::list_insert(receiver, args),
             NM::ListRemove => list_methods::list_remove(receiver, args),
             NM::ListReverse => list_methods::list_reverse(receiver, args),
-            NM::ListSort => list_methods::list_sort(receiver, args),
+            NM::ListSort => list_methods::list_sort(receiver, &HashMap::new(), module, self, io),
             NM::ListClear => list_methods::list_clear(receiver, args),
             NM::ListIndex => list_methods::list_index(receiver, args),
             NM::ListCount => list_methods::list_count(receiver, args),
             NM::ListIter => list_methods::list_iter(receiver, args),
             NM::ListHasNext => list_methods::list_has_next(receiver, args),
             NM::ListNext => list_methods::list_next(receiver, args),
+            NM::ListContains => list_methods::list_contains(receiver, args),
+            NM::ListAdd => list_methods::list_add(receiver, args),
+            NM::ListMul => list_methods::list_mul(receiver, args),
 
             // Dict 메서드들
             NM::DictGet => dict_methods::dict_get(receiver, args),
@@ -355,6 +498,7 @@ impl Vm {
             NM::DictIter => dict_methods::dict_iter(receiver, args),
             NM::DictHasNext => dict_methods::dict_has_next(receiver, args),
             NM::DictNext => dict_methods::dict_next(receiver, args),
+            NM::DictContains => dict_methods::dict_contains(receiver, args),
 
             // Map Iterator 메서드들
             NM::MapIter => map::map_iter(receiver, args),
@@ -366,6 +510,22 @@ impl Vm {
             NM::FilterHasNext => filter::filter_has_next(receiver, args, module, self, io),
             NM::FilterNext => filter::filter_next(receiver, args, module, self, io),
 
+            // Enumerate Iterator 메서드들
+            NM::EnumerateIter => enumerate::enumerate_iter(receiver, args),
+            NM::EnumerateHasNext => enumerate::enumerate_has_next(receiver, args, module, self, io),
+            NM::EnumerateNext => enumerate::enumerate_next(receiver, args, module, self, io),
+
+            // Zip Iterator 메서드들
+            NM::ZipIter => zip::zip_iter(receiver, args),
+            NM::ZipHasNext => zip::zip_has_next(receiver, args, module, self, io),
+            NM::ZipNext => zip::zip_next(receiver, args, module, self, io),
+
+            // Tuple 메서드들
+            NM::TupleContains => tuple_methods::tuple_contains(receiver, args),
+            NM::TupleIter => tuple_methods::tuple_iter(receiver, args),
+            NM::TupleAdd => tuple_methods::tuple_add(receiver, args),
+            NM::TupleMul => tuple_methods::tuple_mul(receiver, args),
+
             // Set 메서드들
             NM::SetAdd => set_methods::set_add(receiver, args),
             NM::SetRemove => set_methods::set_remove(receiver, args),
@@ -373,6 +533,14 @@ impl Vm {
             NM::SetUnion => set_methods::set_union(receiver, args),
             NM::SetIntersection => set_methods::set_intersection(receiver, args),
             NM::SetDifference => set_methods::set_difference(receiver, args),
+            NM::SetSymmetricDifference => set_methods::set_symmetric_difference(receiver, args),
+            NM::SetIsSubset => set_methods::set_issubset(receiver, args),
+            NM::SetIsSuperset => set_methods::set_issuperset(receiver, args),
+            NM::SetLt => set_methods::set_lt(receiver, args),
+            NM::SetGt => set_methods::set_gt(receiver, args),
+            NM::SetDiscard => set_methods::set_discard(receiver, args),
+            NM::SetPop => set_methods::set_pop(receiver, args),
+            NM::SetUpdate => set_methods::set_update(receiver, args),
             NM::SetClear => set_methods::set_clear(receiver, args),
             NM::SetCopy => set_methods::set_copy(receiver, args),
             NM::SetIter => set_methods::set_iter(receiver, args),
@@ -386,6 +554,14 @@ impl Vm {
             NM::TreeSetUnion => treeset_methods::treeset_union(receiver, args),
             NM::TreeSetIntersection => treeset_methods::treeset_intersection(receiver, args),
             NM::TreeSetDifference => treeset_methods::treeset_difference(receiver, args),
+            NM::TreeSetSymmetricDifference => treeset_methods::treeset_symmetric_difference(receiver, args),
+            NM::TreeSetIsSubset => treeset_methods::treeset_issubset(receiver, args),
+            NM::TreeSetIsSuperset => treeset_methods::treeset_issuperset(receiver, args),
+            NM::TreeSetLt => treeset_methods::treeset_lt(receiver, args),
+            NM::TreeSetGt => treeset_methods::treeset_gt(receiver, args),
+            NM::TreeSetDiscard => treeset_methods::treeset_discard(receiver, args),
+            NM::TreeSetPop => treeset_methods::treeset_pop(receiver, args),
+            NM::TreeSetUpdate => treeset_methods::treeset_update(receiver, args),
             NM::TreeSetClear => treeset_methods::treeset_clear(receiver, args),
             NM::TreeSetCopy => treeset_methods::treeset_copy(receiver, args),
             NM::TreeSetLowerBound => treeset_methods::treeset_lower_bound(receiver, args),
@@ -395,6 +571,16 @@ impl Vm {
             NM::TreeSetIter => treeset_methods::treeset_iter(receiver, args),
             NM::TreeSetHasNext => treeset_methods::treeset_has_next(receiver, args),
             NM::TreeSetNext => treeset_methods::treeset_next(receiver, args),
+
+            // 내장 모듈 함수들
+            NM::SysExit => super::super::builtins::sys::sys_exit(args),
+
+            // sys.stdin / sys.stdout 메서드들
+            NM::StreamRead => super::super::builtins::sys::stream_read(receiver, io),
+            NM::StreamReadline => super::super::builtins::sys::stream_readline(receiver, io),
+            NM::StreamReadlines => super::super::builtins::sys::stream_readlines(receiver, io),
+            NM::StreamWrite => super::super::builtins::sys::stream_write(receiver, args, io),
+            NM::StreamFlush => super::super::builtins::sys::stream_flush(receiver, io),
         }
     }
 
@@ -422,17 +608,24 @@ impl Vm {
         // 메서드 조회
         let method_impl = self.lookup_method(receiver, method_name, module)?;
 
+        // UserDefined 메서드: self + args를 푸시하고 프레임을 끝까지 실행
+        if let MethodImpl::UserDefined { func_id } = method_impl {
+            let argc = args.len();
+            self.push(receiver.clone())?;
+            for arg in args {
+                self.push(arg)?;
+            }
+            self.enter_func(module, func_id as usize, argc + 1)?;
+            return self.run_function(module, io);
+        }
+
         // 메서드 호출
         match self.call_method_impl(method_impl, receiver, args, module, io)? {
             Some(result) => Ok(result),
-            None => {
-                // UserDefined 메서드는 동기적으로 호출할 수 없음
-                // 이 경우는 현재 구조상 발생하지 않아야 함
-                Err(err(
-                    VmErrorKind::TypeError("method"),
-                    "cannot call user-defined method synchronously from builtin".into(),
-                ))
-            }
+            None => Err(err(
+                VmErrorKind::TypeError("method"),
+                "cannot call user-defined method synchronously from builtin".into(),
+            )),
         }
     }
 
@@ -448,7 +641,8 @@ impl Vm {
     ) -> VmResult<Value> {
         match func {
             Value::Object(obj) => match &obj.data {
-                ObjectData::UserFunction { func_id, captures } => {
+                ObjectData::UserFunction { func_id, captures, .. } => {
+                    Self::check_func_arity(module, *func_id as usize, args.len())?;
                     // 인자들을 스택에 푸시
                     for arg in args.iter() {
                         self.push(arg.clone())?;
@@ -467,6 +661,25 @@ impl Vm {
 
                     Ok(result)
                 }
+                // `map(int, ...)`처럼 타입 객체를 함수로 넘긴 경우
+                ObjectData::BuiltinClass { class_type } => {
+                    self.call_builtin_class(*class_type, args, module, io)
+                }
+                // `xs.sort(key=len)`처럼 builtin 함수를 넘긴 경우
+                ObjectData::BuiltinFunction { builtin_id } => {
+                    self.call_builtin_value(*builtin_id, args, module, io)
+                }
+                // `map(double, xs)`처럼 네이티브 함수를 넘긴 경우
+                ObjectData::NativeFunction { name, .. } => match self.natives.call(name, &args)? {
+                    NativeResult::Ready(result) => Ok(result),
+                    NativeResult::Pending(request) => Err(err(
+                        VmErrorKind::HostError,
+                        format!(
+                            "cannot wait for host request '{}' in builtin context",
+                            request.kind
+                        ),
+                    )),
+                },
                 _ => Err(err(
                     VmErrorKind::TypeError("function"),
                     format!("'{}' object is not callable", super::super::utils::type_name(func)),
@@ -479,6 +692,195 @@ impl Vm {
         }
     }
 
+    /// Builtin 타입 객체 호출 (`t = int; t("3")`): 대응하는 builtin 함수 실행
+    pub(crate) fn call_builtin_class<IO: RuntimeIo>(
+        &mut self,
+        class_type: BuiltinClassType,
+        args: Vec<Value>,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<Value> {
+        let builtin_id = class_type.constructor_id().ok_or_else(|| {
+            err(
+                VmErrorKind::TypeError("callable"),
+                format!("cannot create '{}' instances", class_type.name()),
+            )
+        })?;
+        self.call_builtin_value(builtin_id, args, module, io)
+    }
+
+    /// 값으로 쓴 builtin 함수 호출: 인자 개수를 확인하고 실행
+    pub(crate) fn call_builtin_value<IO: RuntimeIo>(
+        &mut self,
+        builtin_id: u8,
+        args: Vec<Value>,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<Value> {
+        let builtin = crate::builtins::lookup_by_id(builtin_id).ok_or_else(|| {
+            err(
+                VmErrorKind::TypeError("callable"),
+                format!("unknown builtin function {}", builtin_id),
+            )
+        })?;
+        if !builtin.check_arity(args.len()) {
+            return Err(err(
+                VmErrorKind::ArityError {
+                    expected: 0,
+                    got: args.len(),
+                },
+                format!(
+                    "{}() takes {} argument(s) but {} given",
+                    builtin.name,
+                    builtin.arity.description(),
+                    args.len()
+                ),
+            ));
+        }
+        crate::vm::builtins::call_builtin_with_vm(builtin.builtin_id, args, module, self, io)
+    }
+
+    /// 에러 메시지용 타입 이름 (사용자 클래스 인스턴스는 클래스 이름)
+    pub fn type_name_of(&self, value: &Value, module: &Module) -> String {
+        if let Value::Object(obj) = value
+            && let ObjectData::UserInstance { class_id, .. } = &obj.data
+        {
+            return module.classes[*class_id as usize].name.clone();
+        }
+        super::super::utils::type_name(value).to_string()
+    }
+
+    /// iterable에서 iterator 얻기 (`iter(x)`, `for x in ...`와 같은 규칙)
+    pub fn get_iter<IO: RuntimeIo>(
+        &mut self,
+        value: &Value,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<Value> {
+        if self.lookup_method(value, "__iter__", module).is_err() {
+            return Err(err(
+                VmErrorKind::TypeError("iter"),
+                format!("'{}' object is not iterable", self.type_name_of(value, module)),
+            ));
+        }
+        self.call_method(value, "__iter__", vec![], module, io)
+    }
+
+    /// iterator에서 다음 값 꺼내기 (`__has_next__` → `__next__`)
+    ///
+    /// 끝에 도달하면 `None`을 반환합니다.
+    pub fn next_item<IO: RuntimeIo>(
+        &mut self,
+        iter: &Value,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<Option<Value>> {
+        let has_next = self.call_method(iter, "__has_next__", vec![], module, io)?;
+        if !crate::vm::builtins::bool::to_bool(&has_next) {
+            return Ok(None);
+        }
+        self.call_method(iter, "__next__", vec![], module, io).map(Some)
+    }
+
+    /// iterable의 모든 원소를 모음 (list/tuple은 iterator 없이 바로 복사)
+    pub fn collect_iterable<IO: RuntimeIo>(
+        &mut self,
+        value: &Value,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<Vec<Value>> {
+        if let Value::Object(obj) = value {
+            match &obj.data {
+                ObjectData::List { items } => return Ok(items.borrow().clone()),
+                ObjectData::Tuple { items } => return Ok(items.clone()),
+                // `list(range(10 ** 11))`은 순회하기 전에 메모리 제한 검사
+                ObjectData::BuiltinInstance {
+                    data: BuiltinInstanceData::Range { current, stop, step },
+                    ..
+                } => {
+                    let len = crate::vm::builtins::range::remaining(*current.borrow(), *stop, *step);
+                    limits::reserve_array::<Value>(len)?;
+                }
+                _ => {}
+            }
+        }
+        let iter = self.get_iter(value, module, io)?;
+        let mut items = Vec::new();
+        while let Some(item) = self.next_item(&iter, module, io)? {
+            // 순회하는 동안은 명령어 사이의 검사가 없으므로 늘릴 때마다 검사
+            if items.len() == items.capacity() {
+                limits::reserve_array::<Value>(items.capacity().max(4))?;
+            }
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    /// 이항 연산을 동기적으로 평가 (builtin 함수에서 사용)
+    ///
+    /// 연산자 핸들러를 그대로 실행하므로 fast path, 매직 메서드, 에러 메시지가
+    /// 바이트코드의 `a + b`, `a < b`와 같습니다.
+    pub fn binary_op<IO: RuntimeIo>(
+        &mut self,
+        op: &Instruction,
+        a: Value,
+        b: Value,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<Value> {
+        let depth = self.frames.len();
+        self.push(a)?;
+        self.push(b)?;
+        self.execute_instruction(op, module, io)?;
+
+        // 사용자 정의 매직 메서드는 프레임만 만들어지므로 끝까지 실행
+        if self.frames.len() > depth {
+            self.run_function(module, io)
+        } else {
+            self.pop()
+        }
+    }
+
+    /// `a < b`를 평가하여 bool로 반환 (min, max, sorted에서 사용)
+    pub fn less_than<IO: RuntimeIo>(
+        &mut self,
+        a: &Value,
+        b: &Value,
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<bool> {
+        let result = self.binary_op(&Instruction::Lt, a.clone(), b.clone(), module, io)?;
+        Ok(crate::vm::builtins::bool::to_bool(&result))
+    }
+
+    /// list/tuple 사전식 비교
+    ///
+    /// 처음으로 다른(`==`가 아닌) 원소 쌍을 `op`로 비교하고, 한쪽이 다른 쪽의
+    /// 앞부분이면 길이로 비교합니다: `[1, 2] < [1, 2, 0]`.
+    pub(super) fn compare_sequences<IO: RuntimeIo>(
+        &mut self,
+        op: &Instruction,
+        xs: &[Value],
+        ys: &[Value],
+        module: &mut Module,
+        io: &mut IO,
+    ) -> VmResult<bool> {
+        for (x, y) in xs.iter().zip(ys) {
+            if !super::super::utils::eq_vals(x, y)? {
+                let result = self.binary_op(op, x.clone(), y.clone(), module, io)?;
+                return Ok(crate::vm::builtins::bool::to_bool(&result));
+            }
+        }
+        let (n, m) = (xs.len(), ys.len());
+        Ok(match op {
+            Instruction::Lt => n < m,
+            Instruction::Le => n <= m,
+            Instruction::Gt => n > m,
+            Instruction::Ge => n >= m,
+            _ => unreachable!("not an ordering operator: {:?}", op),
+        })
+    }
+
     /// 함수를 끝까지 실행하고 반환값을 얻음 (동기적 실행)
     fn run_function<IO: RuntimeIo>(
         &mut self,
@@ -502,6 +904,10 @@ impl Vm {
 
             // inst를 복사하여 mutable borrow 문제 해결
             let inst = module.functions[func_id].code[ip].clone();
+            if let Some(tracer) = self.tracer.as_mut() {
+                tracer.trace(module, func_id, ip, &inst, self.stack.last());
+            }
+            self.check_limits()?;
 
             // IP 증가
             self.frames[frame_idx].ip += 1;
@@ -517,14 +923,37 @@ impl Vm {
                 super::instruction::ExecutionFlow::Return(None) => {
                     return Ok(Value::None);
                 }
-                super::instruction::ExecutionFlow::WaitingForInput => {
-                    // 동기적 실행 중에는 input()을 사용할 수 없음
+                super::instruction::ExecutionFlow::WaitingForHost => {
+                    let kind = self.host_request.take().map(|r| r.kind).unwrap_or_default();
+                    if kind == HostRequest::INPUT {
+                        // 동기적 실행 중에는 input()을 기다릴 수 없음
+                        return Err(err(
+                            VmErrorKind::TypeError("input"),
+                            "cannot use input() in builtin context".into(),
+                        ));
+                    }
                     return Err(err(
-                        VmErrorKind::TypeError("input"),
-                        "cannot use input() in builtin context".into(),
+                        VmErrorKind::HostError,
+                        format!("cannot wait for host request '{}' in builtin context", kind),
                     ));
                 }
             }
         }
     }
 }
+
+/// 둘 다 tuple이거나 둘 다 list이면 원소 snapshot 쌍
+pub(super) fn sequence_pair(a: &Value, b: &Value) -> Option<(Vec<Value>, Vec<Value>)> {
+    let (Value::Object(x), Value::Object(y)) = (a, b) else {
+        return None;
+    };
+    match (&x.data, &y.data) {
+        (ObjectData::Tuple { items: xs }, ObjectData::Tuple { items: ys }) => {
+            Some((xs.clone(), ys.clone()))
+        }
+        (ObjectData::List { items: xs }, ObjectData::List { items: ys }) => {
+            Some((xs.borrow().clone(), ys.borrow().clone()))
+        }
+        _ => None,
+    }
+}
@@ -5,14 +5,20 @@
 use crate::builtins::{BuiltinClassType, TYPE_RANGE, TYPE_STR};
 use crate::runtime_io::RuntimeIo;
 use crate::vm::bytecode::{ClassDef, Instruction as I, Module, Value};
+use crate::vm::native::{HostRequest, NativeRegistry};
+use crate::vm::limits::{self, LimitState, Limits};
+use crate::vm::trace::Tracer;
 use crate::vm::utils::{make_builtin_class, make_string, make_user_class, make_user_instance};
 use crate::vm::value::{BuiltinInstanceData, Object, ObjectData};
+use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 // 서브모듈
 mod instruction;
 mod method_dispatch;
+mod snapshot;
 
 #[cfg(test)]
 mod tests;
@@ -27,7 +33,21 @@ pub enum VmErrorKind {
     UndefinedGlobal(u16),
     StackUnderflow,
     StackOverflow,
+    /// 너무 깊이 중첩된 컨테이너를 비교함 (`utils::eq_vals`)
+    RecursionError,
     AssertionError,
+    /// 호스트가 요청을 처리하지 못함 (Vm::resume_host에 Err를 넘김)
+    HostError,
+    /// 스냅샷을 만들거나 복원할 수 없음 (Vm::snapshot/Vm::restore)
+    Snapshot,
+    /// builtin과 같은 이름의 네이티브를 등록하려 함 (NativeRegistry::register_*)
+    ReservedName,
+    /// `sys.exit(code)`: 실행 중인 프레임을 모두 빠져나감 (VM이 정상 종료로 바꿈)
+    Exit(i32),
+    /// `set_limits`의 시간 제한을 넘김
+    TimeLimitExceeded,
+    /// `set_limits`의 메모리 제한을 넘김
+    MemoryLimitExceeded,
 }
 
 #[derive(Debug)]
@@ -39,14 +59,24 @@ pub struct VmError {
 pub type VmResult<T> = Result<T, VmError>;
 
 /// VM execution state
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VmState {
     Running,
-    WaitingForInput,
+    /// 네이티브 함수나 `input()`의 요청(`host_request`)에 대한 호스트의 응답을 기다림
+    WaitingForHost,
     Finished,
     Error,
 }
 
+/// `Vm::step_with_io`가 한 번에 실행하는 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepMode {
+    /// 명령어 하나
+    Instruction,
+    /// 다른 문장으로 넘어가거나 함수에 들어가고 나올 때까지 (소스를 한 줄씩 따라가기)
+    Statement,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Frame {
     pub ip: usize,
@@ -61,6 +91,22 @@ pub struct Vm {
     pub max_stack: usize,
     pub max_frames: usize,
     pub state: VmState,
+    /// 호스트가 등록한 네이티브 함수/클래스 (`CallNative`가 이름으로 찾음)
+    pub natives: NativeRegistry,
+    /// WaitingForHost 상태에서 호스트가 처리할 요청
+    host_request: Option<HostRequest>,
+    /// 호스트가 요청 실패를 알림: 다음 실행에서 런타임 에러로 보고
+    host_error: Option<VmError>,
+    /// 실행하는 명령어를 기록 (`set_tracer`)
+    tracer: Option<Tracer>,
+    /// `sys.argv` (`set_argv`)
+    argv: Vec<String>,
+    /// `import`한 내장 모듈 (다시 import하면 같은 객체)
+    modules: HashMap<String, Value>,
+    /// `sys.exit()`로 끝났을 때의 (종료 코드, 메시지)
+    exit: Option<(i32, Option<String>)>,
+    /// 시간/메모리 제한 (`set_limits`)
+    limits: Option<LimitState>,
 }
 
 // ========== 유틸리티 함수 ==========
@@ -85,6 +131,7 @@ fn jump_rel(ip: &mut usize, off: i32) {
 // ========== VM 구현 ==========
 
 impl Vm {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             stack: Vec::with_capacity(128),
@@ -92,6 +139,24 @@ impl Vm {
             max_stack: 1024,
             max_frames: 256,
             state: VmState::Running,
+            natives: NativeRegistry::default(),
+            host_request: None,
+            host_error: None,
+            tracer: None,
+            argv: Vec::new(),
+            modules: HashMap::new(),
+            exit: None,
+            limits: None,
+        }
+    }
+
+    /// 네이티브 함수/클래스를 호출할 수 있는 VM
+    ///
+    /// 컴파일할 때 쓴 것과 같은 레지스트리를 넘겨야 합니다.
+    pub fn with_natives(natives: NativeRegistry) -> Self {
+        Self {
+            natives,
+            ..Self::new()
         }
     }
 
@@ -99,14 +164,110 @@ impl Vm {
         self.state.clone()
     }
 
-    pub fn resume(&mut self) {
-        if self.state == VmState::WaitingForInput {
-            self.state = VmState::Running;
+    /// `input()`이 읽을 줄을 기다리는 중인지 (`host_request`의 kind가 `HostRequest::INPUT`)
+    pub fn is_waiting_for_input(&self) -> bool {
+        self.host_request.as_ref().is_some_and(HostRequest::is_input)
+    }
+
+    /// 이후 실행하는 명령어를 `tracer`로 기록
+    pub fn set_tracer(&mut self, tracer: Tracer) {
+        self.tracer = Some(tracer);
+    }
+
+    pub fn tracer(&self) -> Option<&Tracer> {
+        self.tracer.as_ref()
+    }
+
+    /// trace를 끄고 tracer를 돌려받음
+    pub fn take_tracer(&mut self) -> Option<Tracer> {
+        self.tracer.take()
+    }
+
+    /// 프로그램이 `sys.argv`로 볼 인자 (첫 번째는 스크립트 이름). `import sys` 전에 정해야 합니다.
+    pub fn set_argv(&mut self, argv: Vec<String>) {
+        self.argv = argv;
+    }
+
+    /// 지금부터 실행 시간과 늘어난 메모리를 제한 (넘으면 `TimeLimitExceeded`/`MemoryLimitExceeded` 에러)
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = Some(LimitState::new(limits));
+    }
+
+    /// 명령어 `CHECK_INTERVAL`개마다 제한을 검사
+    fn check_limits(&mut self) -> VmResult<()> {
+        let Some(state) = self.limits.as_mut() else {
+            return Ok(());
+        };
+        state.counter = state.counter.wrapping_add(1);
+        if state.counter % limits::CHECK_INTERVAL != 0 {
+            return Ok(());
+        }
+        if let Some(time) = state.limits.time
+            && state.timer.elapsed() > time
+        {
+            return Err(err(
+                VmErrorKind::TimeLimitExceeded,
+                format!("time limit exceeded ({:.2}s)", time.as_secs_f64()),
+            ));
         }
+        if let Some(memory) = state.limits.memory
+            && limits::allocated_bytes().saturating_sub(state.memory_base) > memory
+        {
+            return Err(err(
+                VmErrorKind::MemoryLimitExceeded,
+                format!("memory limit exceeded ({} bytes)", memory),
+            ));
+        }
+        Ok(())
     }
 
-    pub fn is_waiting_for_input(&self) -> bool {
-        self.state == VmState::WaitingForInput
+    /// `sys.exit(n)`으로 끝났으면 종료 코드 (`sys.exit()`, `sys.exit(None)`은 0, 문자열은 1)
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit.as_ref().map(|(code, _)| *code)
+    }
+
+    /// `sys.exit("message")`의 메시지 (Python은 표준 에러로 출력)
+    pub fn exit_message(&self) -> Option<&str> {
+        self.exit.as_ref().and_then(|(_, message)| message.as_deref())
+    }
+
+    /// 호스트가 처리할 요청 (WaitingForHost 상태일 때만 있음)
+    pub fn host_request(&self) -> Option<&HostRequest> {
+        self.host_request.as_ref()
+    }
+
+    /// 호스트 요청에 응답하고 실행을 재개할 수 있게 함
+    ///
+    /// `Ok(value)`는 멈췄던 네이티브 호출의 결과가 되고, `Err(message)`는 다음 `run`에서
+    /// 런타임 에러가 됩니다. `input()` 요청에는 읽은 줄을 문자열로 넘깁니다.
+    /// 기다리는 요청이 없으면 아무 일도 하지 않습니다.
+    pub fn resume_host(&mut self, response: Result<Value, String>) -> VmResult<()> {
+        if self.state != VmState::WaitingForHost {
+            return Ok(());
+        }
+        let Some(request) = self.host_request.take() else {
+            return Ok(());
+        };
+        match response {
+            Ok(value) if request.is_input() => match value {
+                Value::Object(ref obj) if let ObjectData::String(line) = &obj.data => {
+                    self.push(instruction::input_line(line.clone()))?
+                }
+                other => {
+                    self.host_error = Some(err(
+                        VmErrorKind::HostError,
+                        format!(
+                            "input() expects a line of text from the host, got '{}'",
+                            super::utils::type_name(&other)
+                        ),
+                    ))
+                }
+            },
+            Ok(value) => self.push(value)?,
+            Err(message) => self.host_error = Some(err(VmErrorKind::HostError, message)),
+        }
+        self.state = VmState::Running;
+        Ok(())
     }
 
     pub fn is_finished(&self) -> bool {
@@ -114,7 +275,7 @@ impl Vm {
     }
 
     pub fn run(&mut self, module: &mut Module) -> VmResult<Option<Value>> {
-        let mut stdio = crate::runtime_io::StdIo;
+        let mut stdio = crate::runtime_io::StdIo::new();
         self.run_with_io(module, &mut stdio)
     }
 
@@ -122,13 +283,79 @@ impl Vm {
         &mut self,
         module: &mut Module,
         io: &mut IO,
+    ) -> VmResult<Option<Value>> {
+        let result = self.run_until(module, io, |_, _| false);
+        io.flush();
+        result
+    }
+
+    /// 조금만 실행하고 멈춤 (시각화/디버깅용)
+    ///
+    /// 처음 호출하면 첫 문장 앞에서 멈춥니다. 멈춘 뒤에도 상태는 `Running`이고,
+    /// 입력/호스트 응답을 기다리거나 끝나면 `run_with_io`와 같은 상태가 됩니다.
+    pub fn step_with_io<IO: RuntimeIo>(
+        &mut self,
+        module: &mut Module,
+        io: &mut IO,
+        mode: StepMode,
+    ) -> VmResult<Option<Value>> {
+        if self.frames.is_empty() {
+            if self.state == VmState::Running && !module.functions.is_empty() {
+                self.enter_func(module, 0, 0)?;
+            }
+            return Ok(None);
+        }
+        let start = self.location(module);
+        let result = self.run_until(module, io, |vm, module| match mode {
+            StepMode::Instruction => true,
+            StepMode::Statement => {
+                let here = vm.location(module);
+                here.1.is_some() && here != start
+            }
+        });
+        io.flush();
+        result
+    }
+
+    /// 다음에 실행할 명령어의 (프레임 깊이, 문장 위치)
+    fn location(&self, module: &Module) -> (usize, Option<usize>) {
+        let position = self
+            .frames
+            .last()
+            .and_then(|f| module.functions[f.func_id].position(f.ip));
+        (self.frames.len(), position)
+    }
+
+    /// 가장 안쪽 프레임에서 마지막으로 실행한 명령어를 만든 문장의 소스 위치
+    ///
+    /// 런타임 에러 뒤에는 프레임이 남아 있으므로 에러가 난 문장을, 입력이나 호스트 응답을
+    /// 기다리는 중이면 멈춘 `input()`/네이티브 호출 문장을 가리킵니다.
+    pub fn last_position(&self, module: &Module) -> Option<usize> {
+        let frame = self.frames.last()?;
+        module.functions[frame.func_id].position(frame.ip.checked_sub(1)?)
+    }
+
+    /// 명령어를 실행하다가 `pause`가 참이면 다음 명령어 앞에서 멈춤 (첫 명령어는 항상 실행)
+    fn run_until<IO: RuntimeIo>(
+        &mut self,
+        module: &mut Module,
+        io: &mut IO,
+        mut pause: impl FnMut(&Vm, &Module) -> bool,
     ) -> VmResult<Option<Value>> {
         if module.functions.is_empty() {
             return Ok(None);
         }
+        if self.state == VmState::WaitingForHost {
+            return Ok(None);
+        }
+        if let Some(error) = self.host_error.take() {
+            self.state = VmState::Error;
+            return Err(error);
+        }
         if self.frames.is_empty() {
             self.enter_func(module, 0, 0)?;
         }
+        let mut first = true;
         loop {
             let (func_id, ip, code_len) = {
                 let f = match self.frames.last() {
@@ -138,6 +365,10 @@ impl Vm {
                 let func = &module.functions[f.func_id];
                 (f.func_id, f.ip, func.code.len())
             };
+            if !first && pause(self, module) {
+                return Ok(None);
+            }
+            first = false;
             if ip >= code_len {
                 let ret = self.leave_frame()?;
                 if self.frames.is_empty() {
@@ -152,15 +383,33 @@ impl Vm {
                 continue;
             }
             let ins = &module.functions[func_id].code[ip].clone();
+            if let Some(tracer) = self.tracer.as_mut() {
+                tracer.trace(module, func_id, ip, ins, self.stack.last());
+            }
+            self.check_limits()?;
             if let Some(f) = self.frames.last_mut() {
                 f.ip = ip + 1;
             }
 
             use instruction::ExecutionFlow;
-            match self.execute_instruction(ins, module, io)? {
+            let flow = match self.execute_instruction(ins, module, io) {
+                Err(VmError {
+                    kind: VmErrorKind::Exit(code),
+                    message,
+                }) => {
+                    // sys.exit(): 남은 프레임을 버리고 정상 종료
+                    self.frames.clear();
+                    self.stack.clear();
+                    self.exit = Some((code, Some(message).filter(|m| !m.is_empty())));
+                    self.state = VmState::Finished;
+                    return Ok(None);
+                }
+                flow => flow?,
+            };
+            match flow {
                 ExecutionFlow::Continue => {}
-                ExecutionFlow::WaitingForInput => {
-                    self.state = VmState::WaitingForInput;
+                ExecutionFlow::WaitingForHost => {
+                    self.state = VmState::WaitingForHost;
                     return Ok(None);
                 }
                 ExecutionFlow::Return(ret) => {
@@ -200,12 +449,32 @@ impl Vm {
         match self.pop()? {
             Value::Bool(b) => Ok(b),
             Value::Int(i) => Ok(i != 0),
+            // `if node:` / `while cur:` - None은 거짓, 사용자 클래스 인스턴스는 참
+            Value::None => Ok(false),
+            Value::Object(obj) if matches!(obj.data, ObjectData::UserInstance { .. }) => Ok(true),
             _ => Err(err(VmErrorKind::TypeError("bool"), "expected Bool".into())),
         }
     }
 
     // ========== 프레임 관리 ==========
 
+    /// 값으로 호출하는 사용자 함수의 인자 개수 검사 (직접 호출은 의미 분석에서 검사됨)
+    pub(super) fn check_func_arity(module: &Module, func_id: usize, argc: usize) -> VmResult<()> {
+        let func = &module.functions[func_id];
+        if func.arity as usize == argc {
+            return Ok(());
+        }
+        let name = &module.symbols[func.name_sym as usize];
+        let name = if name.starts_with("<lambda#") { "<lambda>" } else { name.as_str() };
+        Err(err(
+            VmErrorKind::ArityError {
+                expected: func.arity as usize,
+                got: argc,
+            },
+            format!("{}() takes {} argument(s) but {} given", name, func.arity, argc),
+        ))
+    }
+
     pub(super) fn enter_func(&mut self, module: &Module, func_id: usize, argc: usize) -> VmResult<()> {
         // 캡처 없는 함수 호출 (호환성)
         self.enter_func_with_captures(module, func_id, argc, vec![])
@@ -0,0 +1,790 @@
+// snapshot 모듈 - 실행 중인 VM과 모듈 상태를 바이트로 저장/복원
+//
+// 힙 객체(`Rc<Object>`)는 처음 만난 순서대로 id를 붙여 그래프로 저장합니다.
+// 같은 객체를 가리키던 값들은 복원 후에도 같은 객체를 가리키고, 순환 참조도 그대로 복원됩니다.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Frame, Vm, VmErrorKind, VmResult, VmState, err};
+use crate::builtins::BuiltinClassType;
+use crate::vm::bytecode::{ClassDef, FunctionCode, Module, Value};
+use crate::vm::native::{FromValue, HostRequest, HostValue, IntoValue};
+use crate::vm::value::{BuiltinInstanceData, DictKey, Object, ObjectData, SetKey, StreamKind};
+
+/// 스냅샷 바이트의 앞 4바이트
+const MAGIC: &[u8; 4] = b"PYHS";
+/// 저장 형식이 바뀌면 올림 (다른 버전의 스냅샷은 복원 거부)
+const VERSION: u32 = 4;
+
+/// 힙 참조를 id로 바꾼 값
+#[derive(Serialize, Deserialize)]
+enum SnapValue {
+    Int(i64),
+    Bool(bool),
+    Float(f64),
+    None,
+    Ref(u32),
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapObject {
+    type_id: u16,
+    data: SnapData,
+    attributes: Option<Vec<(String, SnapValue)>>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum SnapData {
+    String(String),
+    List(Vec<SnapValue>),
+    Tuple(Vec<SnapValue>),
+    Dict(Vec<(DictKey, SnapValue)>),
+    Set(Vec<SetKey>),
+    TreeSet(Vec<SetKey>),
+    UserClass {
+        class_id: u16,
+        name: String,
+        methods: Vec<(String, u16)>,
+    },
+    UserInstance {
+        class_id: u16,
+        class_name: String,
+    },
+    BuiltinClass(BuiltinClassType),
+    BuiltinInstance {
+        class_type: BuiltinClassType,
+        data: SnapIter,
+    },
+    UserFunction {
+        func_id: u16,
+        name: String,
+        captures: Vec<SnapValue>,
+    },
+    Module(String),
+    Stream(StreamKind),
+    BuiltinFunction(u8),
+    NativeFunction { name: String, is_class: bool },
+}
+
+/// `BuiltinInstanceData` (iterator 상태)
+#[derive(Serialize, Deserialize)]
+enum SnapIter {
+    Range { current: i64, stop: i64, step: i64 },
+    List { items: Vec<SnapValue>, current: usize },
+    Dict { keys: Vec<DictKey>, current: usize },
+    Map { func: SnapValue, source: SnapValue },
+    Filter {
+        func: SnapValue,
+        source: SnapValue,
+        peeked: Option<SnapValue>,
+    },
+    Enumerate { source: SnapValue, index: i64 },
+    Zip { sources: Vec<SnapValue> },
+    Set { keys: Vec<SetKey>, current: usize },
+    TreeSet { keys: Vec<SetKey>, current: usize },
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapFrame {
+    ip: usize,
+    func_id: usize,
+    ret_stack_size: usize,
+    locals: Vec<SnapValue>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    version: u32,
+    heap: Vec<SnapObject>,
+    // 모듈
+    consts: Vec<SnapValue>,
+    string_pool: Vec<String>,
+    globals: Vec<Option<SnapValue>>,
+    symbols: Vec<String>,
+    functions: Vec<FunctionCode>,
+    classes: Vec<ClassDef>,
+    // VM
+    stack: Vec<SnapValue>,
+    frames: Vec<SnapFrame>,
+    max_stack: usize,
+    max_frames: usize,
+    state: VmState,
+    host_request: Option<(String, SnapValue)>,
+    host_error: Option<String>,
+}
+
+fn snapshot_error(message: impl Into<String>) -> super::VmError {
+    err(VmErrorKind::Snapshot, message.into())
+}
+
+fn corrupt() -> super::VmError {
+    snapshot_error("corrupt snapshot")
+}
+
+impl Vm {
+    /// VM 실행 상태(스택, 프레임, 상태)와 모듈(코드, 상수, 전역 변수)을 바이트로 저장
+    ///
+    /// 입력을 기다리는 중이나 호스트 요청을 기다리는 중에도 저장할 수 있습니다.
+    /// 네이티브 클래스 인스턴스는 상태를 알 수 없으므로 저장할 수 없습니다.
+    pub fn snapshot(&self, module: &Module) -> VmResult<Vec<u8>> {
+        let mut heap = HeapWriter::default();
+        let consts = module.consts.iter().map(|v| heap.value(v)).collect();
+        let globals = module
+            .globals
+            .iter()
+            .map(|g| g.as_ref().map(|v| heap.value(v)))
+            .collect();
+        let stack = self.stack.iter().map(|v| heap.value(v)).collect();
+        let frames = self
+            .frames
+            .iter()
+            .map(|f| SnapFrame {
+                ip: f.ip,
+                func_id: f.func_id,
+                ret_stack_size: f.ret_stack_size,
+                locals: f.locals.iter().map(|v| heap.value(v)).collect(),
+            })
+            .collect();
+        let host_request = self
+            .host_request
+            .as_ref()
+            .map(|r| (r.kind.clone(), heap.value(&r.payload.clone().into_value())));
+
+        let snapshot = Snapshot {
+            version: VERSION,
+            heap: heap.finish()?,
+            consts,
+            string_pool: module.string_pool.clone(),
+            globals,
+            symbols: module.symbols.clone(),
+            functions: module.functions.clone(),
+            classes: module.classes.clone(),
+            stack,
+            frames,
+            max_stack: self.max_stack,
+            max_frames: self.max_frames,
+            state: self.state.clone(),
+            host_request,
+            host_error: self.host_error.as_ref().map(|e| e.message.clone()),
+        };
+
+        let mut bytes = MAGIC.to_vec();
+        bincode::serde::encode_into_std_write(&snapshot, &mut bytes, bincode::config::standard())
+            .map_err(|e| snapshot_error(format!("cannot encode snapshot: {}", e)))?;
+        Ok(bytes)
+    }
+
+    /// `snapshot`으로 저장한 VM과 모듈 복원
+    ///
+    /// 네이티브 레지스트리는 저장되지 않으므로, 네이티브를 쓰는 프로그램이면
+    /// 복원한 VM의 `natives`에 같은 레지스트리를 다시 넣어야 합니다.
+    /// 같은 바이트에서 여러 번 복원하면 서로 독립된 실행을 얻습니다.
+    pub fn restore(bytes: &[u8]) -> VmResult<(Vm, Module)> {
+        let body = bytes
+            .strip_prefix(MAGIC.as_slice())
+            .ok_or_else(|| snapshot_error("not a pyhyeon snapshot"))?;
+        let (snapshot, _): (Snapshot, usize) =
+            bincode::serde::decode_from_slice(body, bincode::config::standard())
+                .map_err(|_| corrupt())?;
+        if snapshot.version != VERSION {
+            return Err(snapshot_error(format!(
+                "unsupported snapshot version {} (expected {})",
+                snapshot.version, VERSION
+            )));
+        }
+
+        let objects = restore_heap(&snapshot.heap)?;
+        let value = |v: &SnapValue| resolve(v, &objects);
+        let values = |vs: &[SnapValue]| vs.iter().map(value).collect::<VmResult<Vec<_>>>();
+
+        let mut module = Module::new();
+        module.consts = values(&snapshot.consts)?;
+        module.string_pool = snapshot.string_pool;
+        module.globals = snapshot
+            .globals
+            .iter()
+            .map(|g| g.as_ref().map(value).transpose())
+            .collect::<VmResult<_>>()?;
+        module.symbols = snapshot.symbols;
+        module.functions = snapshot.functions;
+        module.classes = snapshot.classes;
+
+        let frames = snapshot
+            .frames
+            .iter()
+            .map(|f| {
+                if f.func_id >= module.functions.len() {
+                    return Err(corrupt());
+                }
+                Ok(Frame {
+                    ip: f.ip,
+                    func_id: f.func_id,
+                    ret_stack_size: f.ret_stack_size,
+                    locals: values(&f.locals)?,
+                })
+            })
+            .collect::<VmResult<_>>()?;
+        let host_request = match &snapshot.host_request {
+            Some((kind, payload)) => Some(HostRequest {
+                kind: kind.clone(),
+                payload: HostValue::from_value(&value(payload)?)?,
+            }),
+            None => None,
+        };
+
+        // 다시 import해도 같은 모듈 객체가 되도록 캐시를 복원
+        let modules = objects
+            .iter()
+            .filter_map(|obj| match &obj.data {
+                ObjectData::Module { name } => Some((name.clone(), Value::Object(obj.clone()))),
+                _ => None,
+            })
+            .collect();
+        let vm = Vm {
+            stack: values(&snapshot.stack)?,
+            modules,
+            frames,
+            max_stack: snapshot.max_stack,
+            max_frames: snapshot.max_frames,
+            state: snapshot.state,
+            host_request,
+            host_error: snapshot
+                .host_error
+                .map(|message| err(VmErrorKind::HostError, message)),
+            ..Vm::new()
+        };
+        Ok((vm, module))
+    }
+}
+
+// ========== 저장: 힙 그래프 → id ==========
+
+#[derive(Default)]
+struct HeapWriter {
+    ids: HashMap<*const Object, u32>,
+    /// id 순서대로 만난 객체 (아직 변환하지 않은 것 포함)
+    objects: Vec<Rc<Object>>,
+}
+
+impl HeapWriter {
+    /// 값을 변환하고, 처음 보는 객체면 id를 붙여 나중에 변환할 목록에 넣음
+    fn value(&mut self, value: &Value) -> SnapValue {
+        match value {
+            Value::Int(i) => SnapValue::Int(*i),
+            Value::Bool(b) => SnapValue::Bool(*b),
+            Value::Float(f) => SnapValue::Float(*f),
+            Value::None => SnapValue::None,
+            Value::Object(obj) => {
+                let ptr = Rc::as_ptr(obj);
+                if let Some(&id) = self.ids.get(&ptr) {
+                    return SnapValue::Ref(id);
+                }
+                let id = self.objects.len() as u32;
+                self.ids.insert(ptr, id);
+                self.objects.push(obj.clone());
+                SnapValue::Ref(id)
+            }
+        }
+    }
+
+    fn values(&mut self, values: &[Value]) -> Vec<SnapValue> {
+        values.iter().map(|v| self.value(v)).collect()
+    }
+
+    /// 목록이 빌 때까지 객체를 변환 (재귀 대신 목록을 써서 깊은 구조도 안전)
+    fn finish(mut self) -> VmResult<Vec<SnapObject>> {
+        let mut heap = Vec::with_capacity(self.objects.len());
+        while heap.len() < self.objects.len() {
+            let obj = self.objects[heap.len()].clone();
+            heap.push(self.object(&obj)?);
+        }
+        Ok(heap)
+    }
+
+    fn object(&mut self, obj: &Object) -> VmResult<SnapObject> {
+        let data = match &obj.data {
+            ObjectData::String(s) => SnapData::String(s.clone()),
+            ObjectData::List { items } => SnapData::List(self.values(&items.borrow())),
+            ObjectData::Tuple { items } => SnapData::Tuple(self.values(items)),
+            ObjectData::Dict { map } => SnapData::Dict(
+                map.borrow()
+                    .iter()
+                    .map(|(k, v)| (k.clone(), self.value(v)))
+                    .collect(),
+            ),
+            ObjectData::Set { items } => SnapData::Set(items.borrow().iter().cloned().collect()),
+            ObjectData::TreeSet { items } => {
+                SnapData::TreeSet(items.borrow().iter().cloned().collect())
+            }
+            ObjectData::UserClass {
+                class_id,
+                name,
+                methods,
+            } => SnapData::UserClass {
+                class_id: *class_id,
+                name: name.to_string(),
+                methods: methods.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            },
+            ObjectData::UserInstance {
+                class_id,
+                class_name,
+            } => SnapData::UserInstance {
+                class_id: *class_id,
+                class_name: class_name.to_string(),
+            },
+            ObjectData::BuiltinClass { class_type } => SnapData::BuiltinClass(*class_type),
+            ObjectData::BuiltinInstance { class_type, data } => SnapData::BuiltinInstance {
+                class_type: *class_type,
+                data: self.iterator(data),
+            },
+            ObjectData::UserFunction {
+                func_id,
+                name,
+                captures,
+            } => SnapData::UserFunction {
+                func_id: *func_id,
+                name: name.clone(),
+                captures: self.values(captures),
+            },
+            ObjectData::Module { name } => SnapData::Module(name.clone()),
+            ObjectData::Stream { kind } => SnapData::Stream(*kind),
+            ObjectData::BuiltinFunction { builtin_id } => SnapData::BuiltinFunction(*builtin_id),
+            ObjectData::NativeFunction { name, is_class } => SnapData::NativeFunction {
+                name: name.clone(),
+                is_class: *is_class,
+            },
+            ObjectData::NativeInstance { class, .. } => {
+                return Err(snapshot_error(format!(
+                    "cannot snapshot native '{}' object",
+                    class.name
+                )));
+            }
+        };
+        let attributes = obj.attributes.as_ref().map(|attrs| {
+            attrs
+                .borrow()
+                .iter()
+                .map(|(k, v)| (k.clone(), self.value(v)))
+                .collect()
+        });
+        Ok(SnapObject {
+            type_id: obj.type_id,
+            data,
+            attributes,
+        })
+    }
+
+    fn iterator(&mut self, data: &BuiltinInstanceData) -> SnapIter {
+        match data {
+            BuiltinInstanceData::Range {
+                current,
+                stop,
+                step,
+            } => SnapIter::Range {
+                current: *current.borrow(),
+                stop: *stop,
+                step: *step,
+            },
+            BuiltinInstanceData::ListIterator { items, current } => SnapIter::List {
+                items: self.values(&items.borrow()),
+                current: *current.borrow(),
+            },
+            BuiltinInstanceData::DictIterator { keys, current } => SnapIter::Dict {
+                keys: keys.clone(),
+                current: *current.borrow(),
+            },
+            BuiltinInstanceData::MapIterator { func, source_iter } => SnapIter::Map {
+                func: self.value(func),
+                source: self.value(source_iter),
+            },
+            BuiltinInstanceData::FilterIterator {
+                func,
+                source_iter,
+                peeked,
+            } => SnapIter::Filter {
+                func: self.value(func),
+                source: self.value(source_iter),
+                peeked: peeked.borrow().as_ref().map(|v| self.value(v)),
+            },
+            BuiltinInstanceData::EnumerateIterator { source_iter, index } => SnapIter::Enumerate {
+                source: self.value(source_iter),
+                index: *index.borrow(),
+            },
+            BuiltinInstanceData::ZipIterator { source_iters } => SnapIter::Zip {
+                sources: self.values(source_iters),
+            },
+            BuiltinInstanceData::SetIterator { keys, current } => SnapIter::Set {
+                keys: keys.clone(),
+                current: *current.borrow(),
+            },
+            BuiltinInstanceData::TreeSetIterator { keys, current } => SnapIter::TreeSet {
+                keys: keys.clone(),
+                current: *current.borrow(),
+            },
+        }
+    }
+}
+
+// ========== 복원: id → 힙 그래프 ==========
+
+/// 모든 객체를 만들고 연결
+///
+/// 1. 자식을 `RefCell` 안에만 두는 객체(list, dict, 인스턴스 등)는 빈 껍데기로 먼저 만듦
+/// 2. 자식을 생성 시점에 받아야 하는 불변 객체(tuple, 클로저, map/zip 등)는 자식부터 만듦
+/// 3. 껍데기를 채움
+///
+/// 순환은 항상 가변 객체를 거치므로(불변 객체는 만들 때 이미 있던 값만 담음) 이 순서로 모두 복원됩니다.
+fn restore_heap(heap: &[SnapObject]) -> VmResult<Vec<Rc<Object>>> {
+    let mut reader = HeapReader {
+        heap,
+        objects: heap.iter().map(|snap| shell(snap).map(Rc::new)).collect(),
+        building: vec![false; heap.len()],
+    };
+    for id in 0..heap.len() {
+        reader.object(id as u32)?;
+    }
+    let objects: Vec<Rc<Object>> = reader.objects.into_iter().flatten().collect();
+
+    for (snap, obj) in heap.iter().zip(&objects) {
+        fill(snap, obj, &objects)?;
+    }
+    Ok(objects)
+}
+
+fn resolve(value: &SnapValue, objects: &[Rc<Object>]) -> VmResult<Value> {
+    Ok(match value {
+        SnapValue::Int(i) => Value::Int(*i),
+        SnapValue::Bool(b) => Value::Bool(*b),
+        SnapValue::Float(f) => Value::Float(*f),
+        SnapValue::None => Value::None,
+        SnapValue::Ref(id) => Value::Object(objects.get(*id as usize).ok_or_else(corrupt)?.clone()),
+    })
+}
+
+fn resolve_all(values: &[SnapValue], objects: &[Rc<Object>]) -> VmResult<Vec<Value>> {
+    values.iter().map(|v| resolve(v, objects)).collect()
+}
+
+fn new_object(snap: &SnapObject, data: ObjectData) -> Object {
+    Object {
+        type_id: snap.type_id,
+        ascii: Object::ascii_flag(&data),
+        data,
+        attributes: snap.attributes.as_ref().map(|_| RefCell::new(HashMap::new())),
+    }
+}
+
+/// 1단계: 다른 객체 없이 만들 수 있는 객체 (불변 객체면 None)
+fn shell(snap: &SnapObject) -> Option<Object> {
+    let data = match &snap.data {
+        SnapData::String(s) => ObjectData::String(s.clone()),
+        SnapData::List(_) => ObjectData::List {
+            items: RefCell::new(Vec::new()),
+        },
+        SnapData::Dict(_) => ObjectData::Dict {
+            map: RefCell::new(HashMap::new()),
+        },
+        SnapData::Set(keys) => ObjectData::Set {
+            items: RefCell::new(keys.iter().cloned().collect()),
+        },
+        SnapData::TreeSet(keys) => ObjectData::TreeSet {
+            items: RefCell::new(keys.iter().cloned().collect()),
+        },
+        SnapData::UserClass {
+            class_id,
+            name,
+            methods,
+        } => ObjectData::UserClass {
+            class_id: *class_id,
+            name: name.as_str().into(),
+            methods: methods.iter().cloned().collect(),
+        },
+        SnapData::UserInstance {
+            class_id,
+            class_name,
+        } => ObjectData::UserInstance {
+            class_id: *class_id,
+            class_name: class_name.as_str().into(),
+        },
+        SnapData::BuiltinClass(class_type) => ObjectData::BuiltinClass {
+            class_type: *class_type,
+        },
+        SnapData::Module(name) => ObjectData::Module { name: name.clone() },
+        SnapData::Stream(kind) => ObjectData::Stream { kind: *kind },
+        SnapData::BuiltinFunction(builtin_id) => ObjectData::BuiltinFunction {
+            builtin_id: *builtin_id,
+        },
+        SnapData::NativeFunction { name, is_class } => ObjectData::NativeFunction {
+            name: name.clone(),
+            is_class: *is_class,
+        },
+        SnapData::BuiltinInstance { class_type, data } => {
+            let data = match data {
+                SnapIter::Range {
+                    current,
+                    stop,
+                    step,
+                } => BuiltinInstanceData::Range {
+                    current: RefCell::new(*current),
+                    stop: *stop,
+                    step: *step,
+                },
+                SnapIter::List { current, .. } => BuiltinInstanceData::ListIterator {
+                    items: Rc::new(RefCell::new(Vec::new())),
+                    current: RefCell::new(*current),
+                },
+                SnapIter::Dict { keys, current } => BuiltinInstanceData::DictIterator {
+                    keys: keys.clone(),
+                    current: RefCell::new(*current),
+                },
+                SnapIter::Set { keys, current } => BuiltinInstanceData::SetIterator {
+                    keys: keys.clone(),
+                    current: RefCell::new(*current),
+                },
+                SnapIter::TreeSet { keys, current } => BuiltinInstanceData::TreeSetIterator {
+                    keys: keys.clone(),
+                    current: RefCell::new(*current),
+                },
+                SnapIter::Map { .. }
+                | SnapIter::Filter { .. }
+                | SnapIter::Enumerate { .. }
+                | SnapIter::Zip { .. } => return None,
+            };
+            ObjectData::BuiltinInstance {
+                class_type: *class_type,
+                data,
+            }
+        }
+        SnapData::Tuple(_) | SnapData::UserFunction { .. } => return None,
+    };
+    Some(new_object(snap, data))
+}
+
+struct HeapReader<'a> {
+    heap: &'a [SnapObject],
+    objects: Vec<Option<Rc<Object>>>,
+    /// 2단계에서 만드는 중인 객체 (불변 객체끼리의 순환은 손상된 스냅샷)
+    building: Vec<bool>,
+}
+
+impl HeapReader<'_> {
+    /// 2단계: 불변 객체를 자식부터 만듦
+    fn object(&mut self, id: u32) -> VmResult<Rc<Object>> {
+        let index = id as usize;
+        if let Some(obj) = self.objects.get(index).ok_or_else(corrupt)? {
+            return Ok(obj.clone());
+        }
+        if self.building[index] {
+            return Err(corrupt());
+        }
+        self.building[index] = true;
+
+        let snap = &self.heap[index];
+        let data = match &snap.data {
+            SnapData::Tuple(items) => ObjectData::Tuple {
+                items: self.values(items)?,
+            },
+            SnapData::UserFunction {
+                func_id,
+                name,
+                captures,
+            } => ObjectData::UserFunction {
+                func_id: *func_id,
+                name: name.clone(),
+                captures: self.values(captures)?,
+            },
+            SnapData::BuiltinInstance { class_type, data } => ObjectData::BuiltinInstance {
+                class_type: *class_type,
+                data: match data {
+                    SnapIter::Map { func, source } => BuiltinInstanceData::MapIterator {
+                        func: Box::new(self.value(func)?),
+                        source_iter: Box::new(self.value(source)?),
+                    },
+                    SnapIter::Filter { func, source, .. } => BuiltinInstanceData::FilterIterator {
+                        func: Box::new(self.value(func)?),
+                        source_iter: Box::new(self.value(source)?),
+                        peeked: RefCell::new(None),
+                    },
+                    SnapIter::Enumerate { source, index } => {
+                        BuiltinInstanceData::EnumerateIterator {
+                            source_iter: Box::new(self.value(source)?),
+                            index: RefCell::new(*index),
+                        }
+                    }
+                    SnapIter::Zip { sources } => BuiltinInstanceData::ZipIterator {
+                        source_iters: self.values(sources)?,
+                    },
+                    _ => unreachable!("other iterators are created as shells"),
+                },
+            },
+            _ => unreachable!("mutable objects are created as shells"),
+        };
+        let obj = Rc::new(new_object(snap, data));
+        self.objects[index] = Some(obj.clone());
+        Ok(obj)
+    }
+
+    fn value(&mut self, value: &SnapValue) -> VmResult<Value> {
+        match value {
+            SnapValue::Ref(id) => Ok(Value::Object(self.object(*id)?)),
+            other => resolve(other, &[]),
+        }
+    }
+
+    fn values(&mut self, values: &[SnapValue]) -> VmResult<Vec<Value>> {
+        values.iter().map(|v| self.value(v)).collect()
+    }
+}
+
+/// 3단계: 껍데기의 내용과 속성 채우기
+fn fill(snap: &SnapObject, obj: &Object, objects: &[Rc<Object>]) -> VmResult<()> {
+    match (&snap.data, &obj.data) {
+        (SnapData::List(items), ObjectData::List { items: cell }) => {
+            *cell.borrow_mut() = resolve_all(items, objects)?;
+        }
+        (SnapData::Dict(entries), ObjectData::Dict { map }) => {
+            let mut map = map.borrow_mut();
+            for (key, value) in entries {
+                map.insert(key.clone(), resolve(value, objects)?);
+            }
+        }
+        (
+            SnapData::BuiltinInstance {
+                data: SnapIter::List { items, .. },
+                ..
+            },
+            ObjectData::BuiltinInstance {
+                data: BuiltinInstanceData::ListIterator { items: cell, .. },
+                ..
+            },
+        ) => {
+            *cell.borrow_mut() = resolve_all(items, objects)?;
+        }
+        (
+            SnapData::BuiltinInstance {
+                data:
+                    SnapIter::Filter {
+                        peeked: Some(value),
+                        ..
+                    },
+                ..
+            },
+            ObjectData::BuiltinInstance {
+                data: BuiltinInstanceData::FilterIterator { peeked, .. },
+                ..
+            },
+        ) => {
+            *peeked.borrow_mut() = Some(resolve(value, objects)?);
+        }
+        _ => {}
+    }
+    if let (Some(entries), Some(attrs)) = (&snap.attributes, &obj.attributes) {
+        let mut attrs = attrs.borrow_mut();
+        for (name, value) in entries {
+            attrs.insert(name.clone(), resolve(value, objects)?);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime_io::BufferIo;
+    use crate::vm::utils::make_string;
+
+    fn compile(source: &str) -> Module {
+        let program = crate::parse_source(source).unwrap();
+        crate::analyze(&program).unwrap();
+        crate::compile_to_module(&program)
+    }
+
+    fn global(module: &Module, name: &str) -> Value {
+        let idx = module.symbols.iter().position(|s| s == name).unwrap();
+        module.globals[idx].clone().unwrap()
+    }
+
+    fn list_items(value: &Value) -> Vec<Value> {
+        match value {
+            Value::Object(obj) => match &obj.data {
+                ObjectData::List { items } => items.borrow().clone(),
+                _ => panic!("not a list"),
+            },
+            _ => panic!("not an object"),
+        }
+    }
+
+    fn same_object(a: &Value, b: &Value) -> bool {
+        matches!((a, b), (Value::Object(a), Value::Object(b)) if Rc::ptr_eq(a, b))
+    }
+
+    #[test]
+    fn test_sharing_and_cycles_survive() {
+        let mut module = compile(concat!(
+            "a = [1]\n",
+            "b = [a, a, (a, 'x')]\n",
+            "a.append(a)\n",
+            "class Node:\n",
+            "  def __init__(self):\n",
+            "    self.next = self\n",
+            "n = Node()\n",
+        ));
+        let mut vm = Vm::new();
+        vm.run_with_io(&mut module, &mut BufferIo::new()).unwrap();
+
+        let bytes = vm.snapshot(&module).unwrap();
+        let (_, restored) = Vm::restore(&bytes).unwrap();
+
+        let a = global(&restored, "a");
+        let b = list_items(&global(&restored, "b"));
+        assert!(same_object(&b[0], &a) && same_object(&b[1], &a));
+        assert!(same_object(&list_items(&a)[1], &a));
+        let Value::Object(tuple) = &b[2] else { panic!() };
+        let ObjectData::Tuple { items } = &tuple.data else { panic!() };
+        assert!(same_object(&items[0], &a));
+
+        let n = global(&restored, "n");
+        let Value::Object(node) = &n else { panic!() };
+        assert!(same_object(&node.get_attr("next").unwrap(), &n));
+        // 원래 힙과는 독립
+        assert!(!same_object(&a, &global(&module, "a")));
+    }
+
+    #[test]
+    fn test_resume_from_snapshot_mid_loop() {
+        let mut module = compile(concat!(
+            "total = 0\n",
+            "for i, x in enumerate(map(lambda v: v * 10, [1, 2, 3])):\n",
+            "  total += int(input()) * x\n",
+            "print(total)\n",
+        ));
+        let mut vm = Vm::new();
+        let mut io = BufferIo::new();
+        io.push_input_line("1");
+        vm.run_with_io(&mut module, &mut io).unwrap();
+        assert!(vm.is_waiting_for_input());
+        let bytes = vm.snapshot(&module).unwrap();
+
+        for (inputs, expected) in [(["2", "3"], "140\n"), (["0", "0"], "10\n")] {
+            let (mut vm, mut module) = Vm::restore(&bytes).unwrap();
+            // 첫 줄은 멈춘 input()에 대한 응답, 다음 줄은 평소처럼 입력 버퍼에서
+            vm.resume_host(Ok(make_string(inputs[0].to_string()))).unwrap();
+            let mut io = BufferIo::new();
+            io.push_input_line(inputs[1]);
+            vm.run_with_io(&mut module, &mut io).unwrap();
+            assert_eq!(io.take_output(), expected);
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_bytes() {
+        let error = |bytes: &[u8]| Vm::restore(bytes).map(|_| ()).unwrap_err().message;
+        assert_eq!(error(b"nope"), "not a pyhyeon snapshot");
+        let bytes = Vm::new().snapshot(&Module::new()).unwrap();
+        assert_eq!(error(&bytes[..bytes.len() - 1]), "corrupt snapshot");
+    }
+}
@@ -50,6 +50,7 @@ fn test_const_instructions() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(42)],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -67,6 +68,7 @@ fn test_arithmetic_add() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(10), I::ConstI64(32), I::Add],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -83,6 +85,7 @@ fn test_arithmetic_sub() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(50), I::ConstI64(8), I::Sub],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -99,6 +102,7 @@ fn test_arithmetic_mul() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(6), I::ConstI64(7), I::Mul],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -115,6 +119,7 @@ fn test_arithmetic_div() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(84), I::ConstI64(2), I::Div],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -131,6 +136,7 @@ fn test_arithmetic_mod() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(42), I::ConstI64(10), I::Mod],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -147,6 +153,7 @@ fn test_arithmetic_neg() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(42), I::Neg],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -163,6 +170,7 @@ fn test_comparison_eq() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(42), I::ConstI64(42), I::Eq],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -179,6 +187,7 @@ fn test_comparison_lt() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(10), I::ConstI64(42), I::Lt],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -187,6 +196,43 @@ fn test_comparison_lt() {
     assert_eq!(result, Some(Value::Bool(true)));
 }
 
+#[test]
+fn test_comparison_sequences() {
+    // [1, 2] == [1, 2] 와 (1, 2) < (1, 3)
+    let mut module = make_test_module();
+    module.functions.push(FunctionCode {
+        name_sym: 0,
+        arity: 0,
+        num_locals: 0,
+        code: vec![
+            I::ConstI64(1),
+            I::ConstI64(2),
+            I::BuildList(2),
+            I::ConstI64(1),
+            I::ConstI64(2),
+            I::BuildList(2),
+            I::Eq,
+            I::ConstI64(1),
+            I::ConstI64(2),
+            I::BuildTuple(2),
+            I::ConstI64(1),
+            I::ConstI64(3),
+            I::BuildTuple(2),
+            I::Lt,
+            I::BuildTuple(2),
+        ],
+        ..Default::default()
+    });
+
+    let mut vm = Vm::new();
+    let result = vm.run(&mut module).ok().flatten();
+
+    assert_eq!(
+        result,
+        Some(crate::vm::utils::make_tuple(vec![Value::Bool(true), Value::Bool(true)]))
+    );
+}
+
 #[test]
 fn test_logical_not() {
     let mut module = make_test_module();
@@ -195,6 +241,7 @@ fn test_logical_not() {
         arity: 0,
         num_locals: 0,
         code: vec![I::True, I::Not],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -216,6 +263,7 @@ fn test_jump() {
             I::ConstI64(3),
             I::ConstI64(4),
         ],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -239,6 +287,7 @@ fn test_jump_if_false() {
             I::ConstI64(2),
             I::ConstI64(3),
         ],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -264,6 +313,7 @@ fn test_local_variables() {
             I::LoadLocal(1),
             I::Add,
         ],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -287,6 +337,7 @@ fn test_function_call_no_args() {
         code: vec![
             I::Call(1, 0), // Call function 1 with 0 args
         ],
+        ..Default::default()
     });
 
     // Function 1: returns 42
@@ -295,6 +346,7 @@ fn test_function_call_no_args() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(42), I::Return],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -317,6 +369,7 @@ fn test_function_call_with_args() {
             I::ConstI64(32),
             I::Call(1, 2), // Call function 1 with 2 args
         ],
+        ..Default::default()
     });
 
     // Function 1: add(a, b) -> a + b
@@ -330,6 +383,7 @@ fn test_function_call_with_args() {
             I::Add,
             I::Return,
         ],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -348,6 +402,7 @@ fn test_recursive_function() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(5), I::Call(1, 1)],
+        ..Default::default()
     });
 
     // Function 1: factorial(n)
@@ -373,6 +428,7 @@ fn test_recursive_function() {
             I::Mul,        // n * factorial(n-1)
             I::Return,
         ],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -390,6 +446,7 @@ fn test_zero_division_error() {
         arity: 0,
         num_locals: 0,
         code: vec![I::ConstI64(42), I::ConstI64(0), I::Div],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -412,6 +469,7 @@ fn test_type_error() {
             I::ConstI64(42),
             I::Add, // Can't add bool + int
         ],
+        ..Default::default()
     });
 
     let mut vm = Vm::new();
@@ -421,3 +479,124 @@ fn test_type_error() {
         assert!(matches!(e.kind, VmErrorKind::TypeError(_)));
     }
 }
+
+fn run_source(src: &str, argv: &[&str]) -> (Vm, VmResult<Option<Value>>, String) {
+    let program = crate::parse_source(src).unwrap();
+    let mut module = crate::compile_to_module(&program);
+    let mut vm = Vm::new();
+    vm.set_argv(argv.iter().map(|s| s.to_string()).collect());
+    let mut io = crate::runtime_io::BufferIo::new();
+    let result = vm.run_with_io(&mut module, &mut io);
+    (vm, result, io.take_output())
+}
+
+#[test]
+fn test_sys_argv_and_module_identity() {
+    // 다시 import해도 같은 모듈 객체
+    let src = "import sys\nsys.argv.append('x')\ndef again():\n  import sys\n  return sys\nprint(again() is sys, again().argv, sys)\n";
+    let (vm, result, output) = run_source(src, &["prog.pyh", "1"]);
+    assert!(result.is_ok());
+    assert_eq!(output, "True ['prog.pyh', '1', 'x'] <module 'sys'>\n");
+    assert_eq!(vm.exit_code(), None);
+}
+
+#[test]
+fn test_sys_exit_unwinds_nested_calls() {
+    // builtin(sorted)이 부른 함수 안에서 끝내도 남은 코드는 실행하지 않음
+    let src = "import sys\ndef key(x):\n  print('key', x)\n  sys.exit(3)\nprint(sorted([2, 1], key=key))\nprint('unreachable')\n";
+    let (vm, result, output) = run_source(src, &[]);
+    assert_eq!(result.unwrap(), None);
+    assert_eq!(output, "key 2\n");
+    assert_eq!(vm.get_state(), VmState::Finished);
+    assert!(vm.frames.is_empty() && vm.stack.is_empty());
+    assert_eq!((vm.exit_code(), vm.exit_message()), (Some(3), None));
+}
+
+#[test]
+fn test_sys_exit_status_values() {
+    let status = |arg: &str| {
+        let (vm, result, _) = run_source(&format!("import sys\nsys.exit({})\n", arg), &[]);
+        result.map(|_| (vm.exit_code(), vm.exit_message().map(str::to_string)))
+    };
+    assert_eq!(status("").unwrap(), (Some(0), None));
+    assert_eq!(status("None").unwrap(), (Some(0), None));
+    assert_eq!(status("True").unwrap(), (Some(1), None));
+    assert_eq!(status("42").unwrap(), (Some(42), None));
+    assert_eq!(status("'bad input'").unwrap(), (Some(1), Some("bad input".to_string())));
+    assert!(matches!(status("[1]").unwrap_err().kind, VmErrorKind::TypeError(_)));
+}
+
+#[test]
+fn test_sys_streams_and_iterator_unpacking() {
+    let src = "import sys\na, b = map(int, '3 4'.split())\nsys.stdout.write(str(a * b))\nprint(sys.stdout.write('!'), sys.stdin.readlines())\nprint(sys.stdin, type(sys.stdout))\n";
+    let (_, result, output) = run_source(src, &[]);
+    assert!(result.is_ok());
+    assert_eq!(
+        output,
+        "12!1 []\n<_io.TextIOWrapper name='<stdin>'> <class 'TextIOWrapper'>\n"
+    );
+
+    let error = |src: &str| run_source(src, &[]).1.unwrap_err().message;
+    assert_eq!(error("import sys\nsys.stdout.read()\n"), "UnsupportedOperation: not readable");
+    assert_eq!(error("import sys\nsys.stdin.write('x')\n"), "UnsupportedOperation: not writable");
+    assert_eq!(error("a, b = [1, 2, 3]\n"), "ValueError: too many values to unpack (expected 2)");
+    assert_eq!(
+        error("a, b, c = map(int, ['1'])\n"),
+        "ValueError: not enough values to unpack (expected 3, got 1)"
+    );
+}
+
+#[test]
+fn test_time_limit_stops_loops_and_callbacks() {
+    use crate::vm::Limits;
+    use std::time::Duration;
+
+    let run = |src: &str| {
+        let program = crate::parse_source(src).unwrap();
+        let mut module = crate::compile_to_module(&program);
+        let mut vm = Vm::new();
+        vm.set_limits(Limits {
+            time: Some(Duration::from_millis(20)),
+            memory: None,
+        });
+        vm.run_with_io(&mut module, &mut crate::runtime_io::BufferIo::new())
+    };
+    let error = run("n = 0\nwhile True:\n  n += 1\n").unwrap_err();
+    assert!(matches!(error.kind, VmErrorKind::TimeLimitExceeded));
+    assert_eq!(error.message, "time limit exceeded (0.02s)");
+    // 내장 함수가 부르는 람다 안에서도 멈춤
+    let error = run("def spin(x):\n  while True:\n    pass\nsorted([2, 1], key=spin)\n").unwrap_err();
+    assert!(matches!(error.kind, VmErrorKind::TimeLimitExceeded));
+    assert!(run("print(sum(range(100)))\n").is_ok());
+}
+
+#[test]
+fn test_allocation_cap_only_without_memory_limit() {
+    use crate::vm::Limits;
+
+    let too_big = limits::MAX_ALLOCATION + 1;
+    let error = limits::reserve(too_big).unwrap_err();
+    assert!(error.message.starts_with("MemoryError"), "{}", error.message);
+
+    // 메모리 제한이 있으면 고정 상한 대신 제한으로 검사
+    let mut vm = Vm::new();
+    vm.set_limits(Limits {
+        time: None,
+        memory: Some(4 * limits::MAX_ALLOCATION),
+    });
+    assert!(limits::reserve(too_big).is_ok());
+    let error = limits::reserve(5 * limits::MAX_ALLOCATION).unwrap_err();
+    assert!(matches!(error.kind, VmErrorKind::MemoryLimitExceeded));
+    drop(vm);
+    assert!(limits::reserve(too_big).is_err());
+}
+
+#[test]
+fn test_deeply_nested_comparison_raises_recursion_error() {
+    let src = "a = []\nb = []\nx = a\ny = b\nfor i in range(1500):\n  x.append([])\n  y.append([])\n  x = x[0]\n  y = y[0]\nprint([[1]] == [[1]], [1, [2]] != [1, [3]])\nprint(a == b)\n";
+    let (_, result, output) = run_source(src, &[]);
+    let error = result.unwrap_err();
+    assert!(matches!(error.kind, VmErrorKind::RecursionError));
+    assert_eq!(error.message, "RecursionError: maximum recursion depth exceeded in comparison");
+    assert_eq!(output, "True True\n");
+}
@@ -6,7 +6,12 @@ pub mod builtins; // builtin 함수/클래스 통합 모듈
 pub mod bytecode;
 pub mod compiler;
 pub mod disasm; // 디스어셈블러
+pub mod format; // format spec 미니 언어 (f-string, str.format, %)
+pub mod inspect; // 실행 상태 시각화 (프레임, 전역, 힙 그래프)
+pub mod limits; // 시간/메모리 제한 (pyhc judge)
 pub mod machine; // machine/ 디렉토리
+pub mod native; // 호스트가 등록하는 네이티브 함수/클래스
+pub mod trace; // 실행 trace (--trace)
 
 pub mod type_def;
 pub mod utils;
@@ -14,7 +19,11 @@ pub mod value; // 유틸리티 함수
 
 pub use bytecode::{FunctionCode, Instruction, Module, Value};
 pub use compiler::Compiler;
-pub use machine::{Vm, VmError, VmErrorKind, VmResult, err};
+pub use limits::Limits;
+pub use machine::{StepMode, Vm, VmError, VmErrorKind, VmResult, err};
+pub use native::{
+    FromValue, HostRequest, HostValue, IntoValue, NativeClassBuilder, NativeRegistry, NativeResult,
+};
 
 pub use crate::builtins::{
     BuiltinClassType, TYPE_BOOL, TYPE_DICT, TYPE_FLOAT, TYPE_FUNCTION, TYPE_INT, TYPE_LIST,
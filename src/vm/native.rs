@@ -0,0 +1,773 @@
+//! 호스트(pyhyeon을 임베딩하는 Rust 애플리케이션)가 등록하는 네이티브 함수와 클래스
+//!
+//! 등록된 이름은 의미 분석에서 builtin처럼 보이고(이름 해석, 인자 개수 검사),
+//! 컴파일러는 호출을 `CallNative`로 내보내며, VM은 실행 시점에 이름으로 찾아 호출합니다.
+//! 사용자가 같은 이름을 정의하면 사용자 정의가 우선이고, 이름만 꺼내면 호출 가능한 값이 됩니다.
+//!
+//! ```
+//! use pyhyeon::vm::native::{NativeClassBuilder, NativeRegistry, arg};
+//! use pyhyeon::vm::{Arity, Value};
+//!
+//! struct Counter {
+//!     count: i64,
+//! }
+//!
+//! let mut natives = NativeRegistry::new();
+//! natives
+//!     .register_fn("double", Arity::Exact(1), |args| {
+//!         let n: i64 = arg(args, 0)?;
+//!         Ok(Value::Int(n * 2))
+//!     })?
+//!     .register_class(
+//!         NativeClassBuilder::new("Counter", Arity::Exact(1), |args| {
+//!             Ok(Counter { count: arg(args, 0)? })
+//!         })
+//!         .method("incr", Arity::Exact(0), |this: &mut Counter, _| {
+//!             this.count += 1;
+//!             Ok(Value::Int(this.count))
+//!         }),
+//!     )?;
+//! # Ok::<(), pyhyeon::vm::VmError>(())
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use super::bytecode::Value;
+use super::builtins::dict_methods::value_to_dict_key;
+use super::type_def::Arity;
+use super::utils::{make_dict, make_list, make_string, type_name};
+use super::value::{DictKey, Object, ObjectData};
+use super::{VmErrorKind, VmResult, err};
+use crate::builtins::TYPE_NATIVE;
+
+type NativeFnImpl = dyn Fn(&[Value]) -> VmResult<NativeResult>;
+type NativeInitImpl = dyn Fn(&[Value]) -> VmResult<Box<dyn Any>>;
+type NativeMethodImpl = dyn Fn(&mut dyn Any, &[Value]) -> VmResult<Value>;
+type TypedInitImpl<T> = dyn Fn(&[Value]) -> VmResult<T>;
+
+/// 스크립트에서 `name(args...)`로 호출하는 네이티브 함수
+pub struct NativeFunction {
+    pub name: String,
+    pub arity: Arity,
+    func: Rc<NativeFnImpl>,
+}
+
+/// 네이티브 함수의 결과: 바로 값을 돌려주거나, 호스트의 응답을 기다리며 VM을 멈춤
+#[derive(Debug, Clone)]
+pub enum NativeResult {
+    Ready(Value),
+    /// VM은 `VmState::WaitingForHost`로 멈추고, 호스트가 `Vm::resume_host`로 결과를 넘기면
+    /// 그 값이 이 호출의 결과가 됩니다.
+    Pending(HostRequest),
+}
+
+impl NativeResult {
+    pub fn pending(kind: impl Into<String>, payload: HostValue) -> Self {
+        NativeResult::Pending(HostRequest {
+            kind: kind.into(),
+            payload,
+        })
+    }
+}
+
+/// VM이 멈춘 이유: 호스트가 처리할 요청 (`sleep`, 리소스 읽기, 클릭 대기 등)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HostRequest {
+    /// 요청 종류. `register_host_request`로 만든 함수는 함수 이름
+    pub kind: String,
+    pub payload: HostValue,
+}
+
+impl HostRequest {
+    /// 읽을 입력이 없을 때 `input()`이 보내는 요청의 kind. payload는 프롬프트(없으면 None)이고,
+    /// 호스트는 읽은 줄을 문자열로 응답합니다.
+    pub const INPUT: &'static str = "input";
+
+    pub fn is_input(&self) -> bool {
+        self.kind == Self::INPUT
+    }
+}
+
+/// 호스트와 주고받는 값 (JSON과 같은 모양이라 wasm에서 JS 값으로 바로 변환됨)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum HostValue {
+    None,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    List(Vec<HostValue>),
+    Dict(BTreeMap<String, HostValue>),
+}
+
+impl NativeFunction {
+    pub fn call(&self, args: &[Value]) -> VmResult<NativeResult> {
+        check_arity(&self.name, self.arity, args.len())?;
+        (self.func)(args)
+    }
+}
+
+impl fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native function {}>", self.name)
+    }
+}
+
+/// 스크립트에서 `Name(args...)`로 인스턴스를 만들고 `obj.method(args...)`로 쓰는 네이티브 클래스
+///
+/// 인스턴스는 Rust 값(`T`)을 그대로 담고, 메서드는 그 값을 `&mut T`로 받습니다.
+pub struct NativeClass {
+    pub name: String,
+    pub init_arity: Arity,
+    init: Rc<NativeInitImpl>,
+    methods: HashMap<String, (Arity, Rc<NativeMethodImpl>)>,
+}
+
+impl NativeClass {
+    /// 메서드의 인자 개수 (self 제외). 없는 메서드면 None
+    pub fn method_arity(&self, name: &str) -> Option<Arity> {
+        self.methods.get(name).map(|(arity, _)| *arity)
+    }
+
+    pub fn method_names(&self) -> impl Iterator<Item = &str> {
+        self.methods.keys().map(String::as_str)
+    }
+
+    /// 생성자를 실행해 인스턴스 값을 만듭니다.
+    pub fn instantiate(self: &Rc<Self>, args: &[Value]) -> VmResult<Value> {
+        check_arity(&self.name, self.init_arity, args.len())?;
+        let state = (self.init)(args)?;
+        Ok(Value::Object(Rc::new(Object::new(
+            TYPE_NATIVE,
+            ObjectData::NativeInstance {
+                class: Rc::clone(self),
+                state: NativeState(Rc::new(RefCell::new(state))),
+            },
+        ))))
+    }
+
+    /// 인스턴스의 메서드 호출
+    pub fn call_method(&self, state: &NativeState, name: &str, args: &[Value]) -> VmResult<Value> {
+        let (arity, method) = self.methods.get(name).ok_or_else(|| {
+            err(
+                VmErrorKind::TypeError("method"),
+                format!("'{}' object has no method '{}'", self.name, name),
+            )
+        })?;
+        check_arity(&format!("{}.{}", self.name, name), *arity, args.len())?;
+        let mut state = state.0.try_borrow_mut().map_err(|_| {
+            err(
+                VmErrorKind::TypeError("native"),
+                format!("'{}' object is already in use", self.name),
+            )
+        })?;
+        method(state.as_mut(), args)
+    }
+}
+
+impl fmt::Debug for NativeClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native class {}>", self.name)
+    }
+}
+
+/// 네이티브 인스턴스가 담은 Rust 값 (복제하면 같은 값을 공유)
+#[derive(Clone)]
+pub struct NativeState(Rc<RefCell<Box<dyn Any>>>);
+
+impl fmt::Debug for NativeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<native state>")
+    }
+}
+
+/// 타입이 정해진 네이티브 클래스를 만드는 빌더
+pub struct NativeClassBuilder<T> {
+    name: String,
+    init_arity: Arity,
+    init: Rc<TypedInitImpl<T>>,
+    methods: HashMap<String, (Arity, Rc<NativeMethodImpl>)>,
+}
+
+impl<T: 'static> NativeClassBuilder<T> {
+    /// `init`은 생성자 인자로 인스턴스가 담을 Rust 값을 만듭니다.
+    pub fn new(
+        name: impl Into<String>,
+        init_arity: Arity,
+        init: impl Fn(&[Value]) -> VmResult<T> + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            init_arity,
+            init: Rc::new(init),
+            methods: HashMap::new(),
+        }
+    }
+
+    pub fn method(
+        mut self,
+        name: impl Into<String>,
+        arity: Arity,
+        method: impl Fn(&mut T, &[Value]) -> VmResult<Value> + 'static,
+    ) -> Self {
+        let class_name = self.name.clone();
+        let erased = move |state: &mut dyn Any, args: &[Value]| match state.downcast_mut::<T>() {
+            Some(this) => method(this, args),
+            None => Err(err(
+                VmErrorKind::TypeError("native"),
+                format!("'{}' object has an unexpected native type", class_name),
+            )),
+        };
+        self.methods.insert(name.into(), (arity, Rc::new(erased)));
+        self
+    }
+
+    fn build(self) -> NativeClass {
+        let init = self.init;
+        NativeClass {
+            name: self.name,
+            init_arity: self.init_arity,
+            init: Rc::new(move |args| Ok(Box::new(init(args)?) as Box<dyn Any>)),
+            methods: self.methods,
+        }
+    }
+}
+
+/// 컴파일과 실행에 함께 넘기는 네이티브 함수/클래스 목록
+///
+/// 복제 비용이 작아(`Rc` 공유) 의미 분석, 컴파일러, VM이 각자 하나씩 가집니다.
+/// builtin과 같은 이름은 등록할 수 없습니다.
+#[derive(Debug, Clone, Default)]
+pub struct NativeRegistry {
+    functions: Vec<Rc<NativeFunction>>,
+    classes: Vec<Rc<NativeClass>>,
+}
+
+impl NativeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 네이티브 함수 등록. 같은 이름이 이미 있으면 교체합니다.
+    ///
+    /// # Errors
+    ///
+    /// `name`이 builtin 함수 이름이면 `VmErrorKind::ReservedName` 에러를 반환하고 아무것도 바꾸지 않습니다.
+    pub fn register_fn(
+        &mut self,
+        name: impl Into<String>,
+        arity: Arity,
+        func: impl Fn(&[Value]) -> VmResult<Value> + 'static,
+    ) -> VmResult<&mut Self> {
+        self.register_host_fn(name, arity, move |args| func(args).map(NativeResult::Ready))
+    }
+
+    /// VM을 멈추고 호스트에 요청할 수 있는 네이티브 함수 등록
+    ///
+    /// `NativeResult::Pending`을 반환하면 실행이 멈추고 `Vm::host_request`에 요청이 남습니다.
+    /// `sorted`의 `key`처럼 builtin이 대신 부르는 함수 안에서는 멈출 수 없어 에러가 됩니다.
+    /// builtin 이름이면 `register_fn`처럼 에러입니다.
+    pub fn register_host_fn(
+        &mut self,
+        name: impl Into<String>,
+        arity: Arity,
+        func: impl Fn(&[Value]) -> VmResult<NativeResult> + 'static,
+    ) -> VmResult<&mut Self> {
+        let name = name.into();
+        self.remove(&name)?;
+        self.functions.push(Rc::new(NativeFunction {
+            name,
+            arity,
+            func: Rc::new(func),
+        }));
+        Ok(self)
+    }
+
+    /// 호출되면 항상 멈추는 함수: 요청 종류는 함수 이름, payload는 인자 목록
+    ///
+    /// 호스트 쪽(예: 웹 playground의 JS)이 요청을 처리하는 `sleep(ms)`, `fetch(path)` 같은 함수용입니다.
+    pub fn register_host_request(
+        &mut self,
+        name: impl Into<String>,
+        arity: Arity,
+    ) -> VmResult<&mut Self> {
+        let name = name.into();
+        let kind = name.clone();
+        self.register_host_fn(name, arity, move |args| {
+            let payload = args.iter().map(HostValue::from_value).collect::<VmResult<_>>()?;
+            Ok(NativeResult::pending(kind.clone(), HostValue::List(payload)))
+        })
+    }
+
+    /// 네이티브 클래스 등록. 같은 이름이 이미 있으면 교체합니다.
+    ///
+    /// # Errors
+    ///
+    /// 클래스 이름이 builtin 함수 이름이면 `VmErrorKind::ReservedName` 에러를 반환합니다.
+    pub fn register_class<T: 'static>(&mut self, class: NativeClassBuilder<T>) -> VmResult<&mut Self> {
+        let class = class.build();
+        self.remove(&class.name)?;
+        self.classes.push(Rc::new(class));
+        Ok(self)
+    }
+
+    /// 새로 등록할 이름의 기존 항목을 지움 (builtin 이름은 가릴 수 없으므로 에러)
+    fn remove(&mut self, name: &str) -> VmResult<()> {
+        if crate::builtins::lookup(name).is_some() {
+            return Err(err(
+                VmErrorKind::ReservedName,
+                format!(
+                    "cannot register native '{}': the name is already taken by the builtin function {}()",
+                    name, name
+                ),
+            ));
+        }
+        self.functions.retain(|f| f.name != name);
+        self.classes.retain(|c| c.name != name);
+        Ok(())
+    }
+
+    pub fn function(&self, name: &str) -> Option<&Rc<NativeFunction>> {
+        self.functions.iter().find(|f| f.name == name)
+    }
+
+    pub fn class(&self, name: &str) -> Option<&Rc<NativeClass>> {
+        self.classes.iter().find(|c| c.name == name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.function(name).is_some() || self.class(name).is_some()
+    }
+
+    /// 등록된 모든 함수와 클래스 이름
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.functions
+            .iter()
+            .map(|f| f.name.as_str())
+            .chain(self.classes.iter().map(|c| c.name.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty() && self.classes.is_empty()
+    }
+
+    /// 이름으로 호출: 함수면 실행하고, 클래스면 인스턴스를 만듭니다.
+    pub fn call(&self, name: &str, args: &[Value]) -> VmResult<NativeResult> {
+        if let Some(function) = self.function(name) {
+            return function.call(args);
+        }
+        if let Some(class) = self.class(name) {
+            return class.instantiate(args).map(NativeResult::Ready);
+        }
+        Err(err(
+            VmErrorKind::TypeError("native"),
+            format!("NameError: native '{}' is not registered", name),
+        ))
+    }
+}
+
+fn check_arity(name: &str, arity: Arity, got: usize) -> VmResult<()> {
+    if arity.check(got) {
+        return Ok(());
+    }
+    Err(err(
+        VmErrorKind::ArityError {
+            expected: match arity {
+                Arity::Exact(n) => n,
+                _ => 0,
+            },
+            got,
+        },
+        format!(
+            "{}() takes {} argument(s) but {} given",
+            name,
+            arity.description(),
+            got
+        ),
+    ))
+}
+
+/// 네이티브 인스턴스 값에서 Rust 값을 꺼내 `f`에 빌려줍니다.
+pub fn with_native_instance<T: 'static, R>(value: &Value, f: impl FnOnce(&mut T) -> R) -> VmResult<R> {
+    if let Value::Object(obj) = value
+        && let ObjectData::NativeInstance { state, .. } = &obj.data
+        && let Ok(mut state) = state.0.try_borrow_mut()
+        && let Some(this) = state.downcast_mut::<T>()
+    {
+        return Ok(f(this));
+    }
+    Err(conversion_error(std::any::type_name::<T>(), value))
+}
+
+// ========== Value <-> Rust 변환 ==========
+
+/// 스크립트 값 → Rust 값
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> VmResult<Self>;
+}
+
+/// Rust 값 → 스크립트 값
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+/// 인자 목록에서 `index`번째 인자를 변환합니다.
+pub fn arg<T: FromValue>(args: &[Value], index: usize) -> VmResult<T> {
+    let value = args.get(index).ok_or_else(|| {
+        err(
+            VmErrorKind::ArityError {
+                expected: index + 1,
+                got: args.len(),
+            },
+            format!("missing argument {}", index + 1),
+        )
+    })?;
+    T::from_value(value)
+}
+
+fn conversion_error(expected: &str, value: &Value) -> super::VmError {
+    err(
+        VmErrorKind::TypeError("native"),
+        format!("TypeError: expected {}, got '{}'", expected, type_name(value)),
+    )
+}
+
+impl FromValue for Value {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl IntoValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        match value {
+            Value::Int(i) => Ok(*i),
+            Value::Bool(b) => Ok(*b as i64),
+            _ => Err(conversion_error("int", value)),
+        }
+    }
+}
+
+impl IntoValue for i64 {
+    fn into_value(self) -> Value {
+        Value::Int(self)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        match value {
+            Value::Float(f) => Ok(*f),
+            Value::Int(i) => Ok(*i as f64),
+            _ => Err(conversion_error("float", value)),
+        }
+    }
+}
+
+impl IntoValue for f64 {
+    fn into_value(self) -> Value {
+        Value::Float(self)
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        match value {
+            Value::Bool(b) => Ok(*b),
+            _ => Err(conversion_error("bool", value)),
+        }
+    }
+}
+
+impl IntoValue for bool {
+    fn into_value(self) -> Value {
+        Value::Bool(self)
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        match value {
+            Value::Object(obj) => match &obj.data {
+                ObjectData::String(s) => Ok(s.clone()),
+                _ => Err(conversion_error("str", value)),
+            },
+            _ => Err(conversion_error("str", value)),
+        }
+    }
+}
+
+impl IntoValue for String {
+    fn into_value(self) -> Value {
+        make_string(self)
+    }
+}
+
+impl IntoValue for &str {
+    fn into_value(self) -> Value {
+        make_string(self.to_string())
+    }
+}
+
+impl IntoValue for () {
+    fn into_value(self) -> Value {
+        Value::None
+    }
+}
+
+/// `None` ↔ `None`, 그 외에는 `T`로 변환
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        match value {
+            Value::None => Ok(None),
+            other => T::from_value(other).map(Some),
+        }
+    }
+}
+
+impl<T: IntoValue> IntoValue for Option<T> {
+    fn into_value(self) -> Value {
+        self.map_or(Value::None, IntoValue::into_value)
+    }
+}
+
+/// list 또는 tuple → `Vec<T>`
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        if let Value::Object(obj) = value {
+            match &obj.data {
+                ObjectData::List { items } => {
+                    return items.borrow().iter().map(T::from_value).collect();
+                }
+                ObjectData::Tuple { items } => return items.iter().map(T::from_value).collect(),
+                _ => {}
+            }
+        }
+        Err(conversion_error("list", value))
+    }
+}
+
+/// `Vec<T>` → list
+impl<T: IntoValue> IntoValue for Vec<T> {
+    fn into_value(self) -> Value {
+        make_list(self.into_iter().map(IntoValue::into_value).collect())
+    }
+}
+
+/// dict 키로 쓸 수 있는 Rust 타입 (`str`, `int`, `bool`)
+pub trait DictKeyValue: Sized + Eq + Hash {
+    fn from_key(key: &DictKey) -> Option<Self>;
+    fn into_key(self) -> DictKey;
+}
+
+impl DictKeyValue for String {
+    fn from_key(key: &DictKey) -> Option<Self> {
+        match key {
+            DictKey::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    fn into_key(self) -> DictKey {
+        DictKey::String(self)
+    }
+}
+
+impl DictKeyValue for i64 {
+    fn from_key(key: &DictKey) -> Option<Self> {
+        match key {
+            DictKey::Int(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    fn into_key(self) -> DictKey {
+        DictKey::Int(self)
+    }
+}
+
+impl DictKeyValue for bool {
+    fn from_key(key: &DictKey) -> Option<Self> {
+        match key {
+            DictKey::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    fn into_key(self) -> DictKey {
+        DictKey::Bool(self)
+    }
+}
+
+impl<K: DictKeyValue, V: FromValue> FromValue for HashMap<K, V> {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        if let Value::Object(obj) = value
+            && let ObjectData::Dict { map } = &obj.data
+        {
+            return map
+                .borrow()
+                .iter()
+                .map(|(key, v)| {
+                    let key = K::from_key(key).ok_or_else(|| {
+                        err(
+                            VmErrorKind::TypeError("native"),
+                            format!(
+                                "TypeError: unexpected dict key type for {}",
+                                std::any::type_name::<K>()
+                            ),
+                        )
+                    })?;
+                    Ok((key, V::from_value(v)?))
+                })
+                .collect();
+        }
+        Err(conversion_error("dict", value))
+    }
+}
+
+impl<K: DictKeyValue, V: IntoValue> IntoValue for HashMap<K, V> {
+    fn into_value(self) -> Value {
+        make_dict(
+            self.into_iter()
+                .map(|(k, v)| (k.into_key(), v.into_value()))
+                .collect(),
+        )
+    }
+}
+
+/// 스크립트 값 → 호스트 값. set은 list가 되고, dict 키는 문자열이어야 함
+impl FromValue for HostValue {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        Ok(match value {
+            Value::None => HostValue::None,
+            Value::Bool(b) => HostValue::Bool(*b),
+            Value::Int(i) => HostValue::Int(*i),
+            Value::Float(f) => HostValue::Float(*f),
+            Value::Object(obj) => match &obj.data {
+                ObjectData::String(s) => HostValue::Str(s.clone()),
+                ObjectData::List { items } => HostValue::List(
+                    items.borrow().iter().map(HostValue::from_value).collect::<VmResult<_>>()?,
+                ),
+                ObjectData::Tuple { items } => HostValue::List(
+                    items.iter().map(HostValue::from_value).collect::<VmResult<_>>()?,
+                ),
+                ObjectData::Dict { .. } => HostValue::Dict(FromValue::from_value(value)?),
+                _ => return Err(conversion_error("a host value", value)),
+            },
+        })
+    }
+}
+
+impl IntoValue for HostValue {
+    fn into_value(self) -> Value {
+        match self {
+            HostValue::None => Value::None,
+            HostValue::Bool(b) => Value::Bool(b),
+            HostValue::Int(i) => Value::Int(i),
+            HostValue::Float(f) => Value::Float(f),
+            HostValue::Str(s) => make_string(s),
+            HostValue::List(items) => items.into_value(),
+            HostValue::Dict(map) => map.into_iter().collect::<HashMap<_, _>>().into_value(),
+        }
+    }
+}
+
+impl<V: FromValue> FromValue for BTreeMap<String, V> {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        HashMap::<String, V>::from_value(value).map(|map| map.into_iter().collect())
+    }
+}
+
+/// 임의의 값을 dict 키로 변환 (호스트가 `Value` 키를 직접 다룰 때)
+pub fn dict_key(value: &Value) -> VmResult<DictKey> {
+    value_to_dict_key(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions_round_trip() {
+        let value = vec![1i64, 2, 3].into_value();
+        assert_eq!(Vec::<i64>::from_value(&value).unwrap(), vec![1, 2, 3]);
+
+        let mut map = HashMap::new();
+        map.insert("a".to_string(), 1.5f64);
+        let value = map.clone().into_value();
+        assert_eq!(HashMap::<String, f64>::from_value(&value).unwrap(), map);
+
+        assert_eq!(f64::from_value(&Value::Int(2)).unwrap(), 2.0);
+        assert_eq!(Option::<i64>::from_value(&Value::None).unwrap(), None);
+        assert_eq!(String::from_value(&"hi".into_value()).unwrap(), "hi");
+
+        let error = i64::from_value(&"x".into_value()).unwrap_err();
+        assert_eq!(error.message, "TypeError: expected int, got 'str'");
+    }
+
+    #[test]
+    fn test_native_class_instances() {
+        let mut natives = NativeRegistry::new();
+        natives
+            .register_class(
+                NativeClassBuilder::new("Acc", Arity::Exact(0), |_| Ok(Vec::<i64>::new())).method(
+                    "push",
+                    Arity::Exact(1),
+                    |items: &mut Vec<i64>, args| {
+                        items.push(arg(args, 0)?);
+                        Ok(Value::Int(items.len() as i64))
+                    },
+                ),
+            )
+            .unwrap();
+        let Ok(NativeResult::Ready(acc)) = natives.call("Acc", &[]) else {
+            panic!("expected an instance");
+        };
+        let Value::Object(obj) = &acc else {
+            panic!("expected an object");
+        };
+        let ObjectData::NativeInstance { class, state } = &obj.data else {
+            panic!("expected a native instance");
+        };
+        assert!(class.call_method(state, "push", &[Value::Int(7)]).is_ok());
+        assert!(class.call_method(state, "push", &[]).is_err());
+        let items = with_native_instance(&acc, |items: &mut Vec<i64>| items.clone()).unwrap();
+        assert_eq!(items, vec![7]);
+        assert!(natives.call("Missing", &[]).is_err());
+    }
+
+    #[test]
+    fn test_builtin_names_are_reserved() {
+        let mut natives = NativeRegistry::new();
+        natives.register_fn("clamp", Arity::Exact(1), |_| Ok(Value::None)).unwrap();
+        let error = natives
+            .register_fn("len", Arity::Exact(1), |_| Ok(Value::None))
+            .unwrap_err();
+        assert!(matches!(error.kind, VmErrorKind::ReservedName));
+        assert_eq!(
+            error.message,
+            "cannot register native 'len': the name is already taken by the builtin function len()"
+        );
+        let error = natives
+            .register_class(NativeClassBuilder::new("print", Arity::Exact(0), |_| Ok(())))
+            .unwrap_err();
+        assert!(matches!(error.kind, VmErrorKind::ReservedName));
+        assert!(natives.register_host_request("input", Arity::Exact(0)).is_err());
+        // 실패한 등록은 기존 항목을 건드리지 않음
+        assert_eq!(natives.names().collect::<Vec<_>>(), ["clamp"]);
+    }
+}
@@ -0,0 +1,237 @@
+//! 실행 trace: 실행하는 명령어마다 함수, ip, 줄, 명령어, 스택 top을 기록
+//!
+//! `Vm::set_tracer`로 켭니다. 기록은 `TraceSink`로 가므로 프로그램 출력(`RuntimeIo`)과 섞이지 않습니다.
+//!
+//! ```
+//! use pyhyeon::vm::trace::{TraceBuffer, Tracer};
+//!
+//! let source = "x = 1 + 2\n";
+//! let mut module = pyhyeon::compile_to_module(&pyhyeon::parse_source(source).unwrap());
+//! let buffer = TraceBuffer::new();
+//! let mut vm = pyhyeon::Vm::new();
+//! vm.set_tracer(Tracer::new(buffer.clone()).with_source(source).max_lines(2));
+//! vm.run(&mut module).unwrap();
+//! assert_eq!(buffer.lines(), ["<module>:0 line 1 ConstI64 1 | top: -", "<module>:1 line 1 ConstI64 2 | top: 1"]);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+use super::bytecode::{Instruction, Module, Value};
+use super::disasm::disassemble_instruction_to_string;
+use super::utils::display_value;
+
+/// 명령어 하나를 실행하기 직전의 상태
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEvent {
+    /// 함수 이름 (모듈 수준 코드는 `<module>`)
+    pub function: String,
+    pub ip: usize,
+    /// 소스 줄 번호 (소스를 모르거나 암묵적 코드면 없음)
+    pub line: Option<usize>,
+    /// 피연산자를 포함한 명령어 (디스어셈블러와 같은 형식)
+    pub instruction: String,
+    /// 스택 맨 위 값 (`display_value`), 스택이 비었으면 없음
+    pub stack_top: Option<String>,
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.function, self.ip)?;
+        match self.line {
+            Some(line) => write!(f, " line {}", line)?,
+            None => write!(f, " line -")?,
+        }
+        write!(
+            f,
+            " {} | top: {}",
+            self.instruction,
+            self.stack_top.as_deref().unwrap_or("-")
+        )
+    }
+}
+
+/// trace 기록을 받는 곳
+pub trait TraceSink {
+    fn record(&mut self, event: TraceEvent);
+}
+
+/// 메모리에 모으는 sink (테스트용). clone한 핸들끼리 같은 기록을 공유합니다.
+#[derive(Debug, Clone, Default)]
+pub struct TraceBuffer(Rc<RefCell<Vec<TraceEvent>>>);
+
+impl TraceBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.0.borrow().clone()
+    }
+
+    /// 한 줄씩 포맷한 기록 (`--trace` 출력과 같은 형식)
+    pub fn lines(&self) -> Vec<String> {
+        self.0.borrow().iter().map(|e| e.to_string()).collect()
+    }
+}
+
+impl TraceSink for TraceBuffer {
+    fn record(&mut self, event: TraceEvent) {
+        self.0.borrow_mut().push(event);
+    }
+}
+
+/// 표준 에러로 한 줄씩 출력 (CLI `--trace`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StderrSink;
+
+impl TraceSink for StderrSink {
+    fn record(&mut self, event: TraceEvent) {
+        eprintln!("[trace] {}", event);
+    }
+}
+
+/// 어떤 명령어를 얼마나 기록할지 정하고 sink로 보냄
+pub struct Tracer {
+    sink: Box<dyn TraceSink>,
+    /// 소스 각 줄의 시작 바이트 오프셋
+    line_starts: Vec<usize>,
+    /// 이 함수들에서 실행한 명령어만 기록 (None이면 전부)
+    functions: Option<HashSet<String>>,
+    max_lines: Option<usize>,
+    recorded: usize,
+    dropped: usize,
+}
+
+impl Tracer {
+    pub fn new(sink: impl TraceSink + 'static) -> Self {
+        Self {
+            sink: Box::new(sink),
+            line_starts: Vec::new(),
+            functions: None,
+            max_lines: None,
+            recorded: 0,
+            dropped: 0,
+        }
+    }
+
+    /// 줄 번호를 계산할 소스 (모듈을 컴파일한 소스와 같아야 함)
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        self
+    }
+
+    /// 이 이름의 함수에서 실행한 명령어만 기록 (여러 번 부르면 합쳐짐, 모듈 수준은 `<module>`)
+    pub fn only_function(mut self, name: impl Into<String>) -> Self {
+        self.functions
+            .get_or_insert_with(HashSet::new)
+            .insert(name.into());
+        self
+    }
+
+    /// 최대 기록 줄 수. 넘는 명령어는 세기만 함 (`dropped`)
+    pub fn max_lines(mut self, max: usize) -> Self {
+        self.max_lines = Some(max);
+        self
+    }
+
+    /// 기록한 줄 수
+    pub fn recorded(&self) -> usize {
+        self.recorded
+    }
+
+    /// `max_lines`를 넘어서 기록하지 않은 명령어 수
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    pub(super) fn trace(
+        &mut self,
+        module: &Module,
+        func_id: usize,
+        ip: usize,
+        instruction: &Instruction,
+        stack_top: Option<&Value>,
+    ) {
+        let function = if func_id == 0 {
+            "<module>"
+        } else {
+            module.symbols[module.functions[func_id].name_sym as usize].as_str()
+        };
+        if let Some(functions) = &self.functions
+            && !functions.contains(function)
+        {
+            return;
+        }
+        if self.max_lines.is_some_and(|max| self.recorded >= max) {
+            self.dropped += 1;
+            return;
+        }
+        self.recorded += 1;
+
+        let line = match module.functions[func_id].position(ip) {
+            Some(pos) if !self.line_starts.is_empty() => {
+                Some(self.line_starts.partition_point(|&start| start <= pos))
+            }
+            _ => None,
+        };
+        self.sink.record(TraceEvent {
+            function: function.to_string(),
+            ip,
+            line,
+            instruction: disassemble_instruction_to_string(module, instruction),
+            stack_top: stack_top.map(display_value),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime_io::BufferIo;
+    use crate::vm::Vm;
+
+    fn run_traced(source: &str, tracer: impl FnOnce(Tracer) -> Tracer) -> (TraceBuffer, Vm) {
+        let program = crate::parse_source(source).unwrap();
+        let mut module = crate::compile_to_module(&program);
+        let buffer = TraceBuffer::new();
+        let mut vm = Vm::new();
+        vm.set_tracer(tracer(Tracer::new(buffer.clone()).with_source(source)));
+        let mut io = BufferIo::new();
+        vm.run_with_io(&mut module, &mut io).unwrap();
+        assert_eq!(io.take_output(), "6\n", "trace does not touch program output");
+        (buffer, vm)
+    }
+
+    #[test]
+    fn test_traces_every_instruction_with_lines_and_stack() {
+        let source = "def f(x):\n  return x * 2\nprint(f(3))\n";
+        let (buffer, _) = run_traced(source, |t| t);
+        let events = buffer.events();
+        let in_f: Vec<&TraceEvent> = events.iter().filter(|e| e.function == "f").collect();
+        assert_eq!(in_f.len(), 4);
+        assert!(in_f.iter().all(|e| e.line == Some(2)));
+        assert_eq!(in_f[0].to_string(), "f:0 line 2 LoadLocal 0 | top: -");
+        assert_eq!(in_f[3].to_string(), "f:3 line 2 Return | top: 6");
+        assert!(events.iter().any(|e| e.function == "<module>" && e.line == Some(3)));
+        // 피연산자는 디스어셈블러처럼 이름까지 풀어서 보여줌
+        let call = "<module>:3 line 3 Call 1 (func #1 \"f\", argc=1) | top: 3";
+        assert!(buffer.lines().iter().any(|l| l == call), "{:?}", buffer.lines());
+    }
+
+    #[test]
+    fn test_function_filter_and_max_lines() {
+        let source = "def f(x):\n  return x * 2\nprint(sorted([3], key=f)[0] * 2)\n";
+        let (buffer, vm) = run_traced(source, |t| t.only_function("f").max_lines(3));
+        // builtin이 부른 함수도 기록됨
+        let lines = buffer.lines();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|l| l.starts_with("f:")), "{:?}", lines);
+        let tracer = vm.tracer().unwrap();
+        assert_eq!((tracer.recorded(), tracer.dropped()), (3, 1));
+    }
+}
@@ -75,6 +75,13 @@ pub enum NativeMethod {
     IntGe,
     IntEq,
     IntNe,
+    IntPow,
+    IntAnd,
+    IntOr,
+    IntXor,
+    IntLShift,
+    IntRShift,
+    IntInvert,
 
     // ========== Float 매직 메서드들 ==========
     FloatAdd,
@@ -91,6 +98,7 @@ pub enum NativeMethod {
     FloatGe,
     FloatEq,
     FloatNe,
+    FloatPow,
 
     // ========== String 매직 메서드들 ==========
     StrAdd, // concatenation
@@ -113,11 +121,37 @@ pub enum NativeMethod {
     StrEndsWith,
     StrFind,
     StrCount,
+    StrLStrip,
+    StrRStrip,
+    StrRSplit,
+    StrSplitLines,
+    StrRFind,
+    StrIndex,
+    StrRIndex,
+    StrPartition,
+    StrRPartition,
+    StrIsDigit,
+    StrIsAlpha,
+    StrIsAlnum,
+    StrIsSpace,
+    StrIsUpper,
+    StrIsLower,
+    StrTitle,
+    StrCapitalize,
+    StrCenter,
+    StrLJust,
+    StrRJust,
+    StrZFill,
+    StrFormat,
+    StrMod, // printf 스타일 포매팅 ("%d" % x)
+    StrContains,
+    StrIter,
 
     // ========== Range 메서드들 ==========
     RangeIter,
     RangeHasNext,
     RangeNext,
+    RangeContains,
 
     // ========== List 메서드들 ==========
     ListAppend,
@@ -133,6 +167,9 @@ pub enum NativeMethod {
     ListIter,
     ListHasNext,
     ListNext,
+    ListContains,
+    ListAdd,
+    ListMul,
 
     // ========== Dict 메서드들 ==========
     DictGet,
@@ -145,6 +182,7 @@ pub enum NativeMethod {
     DictIter,
     DictHasNext,
     DictNext,
+    DictContains,
 
     // ========== Map Iterator 메서드들 ==========
     MapIter,
@@ -156,6 +194,22 @@ pub enum NativeMethod {
     FilterHasNext,
     FilterNext,
 
+    // ========== Enumerate Iterator 메서드들 ==========
+    EnumerateIter,
+    EnumerateHasNext,
+    EnumerateNext,
+
+    // ========== Zip Iterator 메서드들 ==========
+    ZipIter,
+    ZipHasNext,
+    ZipNext,
+
+    // ========== Tuple 메서드들 ==========
+    TupleContains,
+    TupleIter,
+    TupleAdd,
+    TupleMul,
+
     // ========== Set 메서드들 ==========
     SetAdd,
     SetRemove,
@@ -163,6 +217,14 @@ pub enum NativeMethod {
     SetUnion,
     SetIntersection,
     SetDifference,
+    SetSymmetricDifference,
+    SetIsSubset,
+    SetIsSuperset,
+    SetLt,
+    SetGt,
+    SetDiscard,
+    SetPop,
+    SetUpdate,
     SetClear,
     SetCopy,
     SetIter,
@@ -176,6 +238,14 @@ pub enum NativeMethod {
     TreeSetUnion,
     TreeSetIntersection,
     TreeSetDifference,
+    TreeSetSymmetricDifference,
+    TreeSetIsSubset,
+    TreeSetIsSuperset,
+    TreeSetLt,
+    TreeSetGt,
+    TreeSetDiscard,
+    TreeSetPop,
+    TreeSetUpdate,
     TreeSetClear,
     TreeSetCopy,
     TreeSetLowerBound,
@@ -185,6 +255,16 @@ pub enum NativeMethod {
     TreeSetIter,
     TreeSetHasNext,
     TreeSetNext,
+
+    // ========== 내장 모듈 함수들 ==========
+    SysExit,
+
+    // ========== sys.stdin / sys.stdout 메서드들 ==========
+    StreamRead,
+    StreamReadline,
+    StreamReadlines,
+    StreamWrite,
+    StreamFlush,
 }
 
 impl NativeMethod {
@@ -206,6 +286,13 @@ impl NativeMethod {
             Self::IntGe => "__ge__",
             Self::IntEq => "__eq__",
             Self::IntNe => "__ne__",
+            Self::IntPow => "__pow__",
+            Self::IntAnd => "__and__",
+            Self::IntOr => "__or__",
+            Self::IntXor => "__xor__",
+            Self::IntLShift => "__lshift__",
+            Self::IntRShift => "__rshift__",
+            Self::IntInvert => "__invert__",
 
             // Float 매직 메서드
             Self::FloatAdd => "__add__",
@@ -222,6 +309,7 @@ impl NativeMethod {
             Self::FloatGe => "__ge__",
             Self::FloatEq => "__eq__",
             Self::FloatNe => "__ne__",
+            Self::FloatPow => "__pow__",
 
             // String 매직 메서드
             Self::StrAdd => "__add__",
@@ -244,11 +332,37 @@ impl NativeMethod {
             Self::StrEndsWith => "endswith",
             Self::StrFind => "find",
             Self::StrCount => "count",
+            Self::StrLStrip => "lstrip",
+            Self::StrRStrip => "rstrip",
+            Self::StrRSplit => "rsplit",
+            Self::StrSplitLines => "splitlines",
+            Self::StrRFind => "rfind",
+            Self::StrIndex => "index",
+            Self::StrRIndex => "rindex",
+            Self::StrPartition => "partition",
+            Self::StrRPartition => "rpartition",
+            Self::StrIsDigit => "isdigit",
+            Self::StrIsAlpha => "isalpha",
+            Self::StrIsAlnum => "isalnum",
+            Self::StrIsSpace => "isspace",
+            Self::StrIsUpper => "isupper",
+            Self::StrIsLower => "islower",
+            Self::StrTitle => "title",
+            Self::StrCapitalize => "capitalize",
+            Self::StrCenter => "center",
+            Self::StrLJust => "ljust",
+            Self::StrRJust => "rjust",
+            Self::StrZFill => "zfill",
+            Self::StrFormat => "format",
+            Self::StrMod => "__mod__",
+            Self::StrContains => "__contains__",
+            Self::StrIter => "__iter__",
 
             // Range 메서드
             Self::RangeIter => "__iter__",
             Self::RangeHasNext => "__has_next__",
             Self::RangeNext => "__next__",
+            Self::RangeContains => "__contains__",
 
             // List 메서드
             Self::ListAppend => "append",
@@ -264,6 +378,9 @@ impl NativeMethod {
             Self::ListIter => "__iter__",
             Self::ListHasNext => "__has_next__",
             Self::ListNext => "__next__",
+            Self::ListContains => "__contains__",
+            Self::ListAdd => "__add__",
+            Self::ListMul => "__mul__",
 
             // Dict 메서드
             Self::DictGet => "get",
@@ -276,6 +393,7 @@ impl NativeMethod {
             Self::DictIter => "__iter__",
             Self::DictHasNext => "__has_next__",
             Self::DictNext => "__next__",
+            Self::DictContains => "__contains__",
 
             // Map Iterator 메서드
             Self::MapIter => "__iter__",
@@ -287,6 +405,22 @@ impl NativeMethod {
             Self::FilterHasNext => "__has_next__",
             Self::FilterNext => "__next__",
 
+            // Enumerate Iterator 메서드
+            Self::EnumerateIter => "__iter__",
+            Self::EnumerateHasNext => "__has_next__",
+            Self::EnumerateNext => "__next__",
+
+            // Zip Iterator 메서드
+            Self::ZipIter => "__iter__",
+            Self::ZipHasNext => "__has_next__",
+            Self::ZipNext => "__next__",
+
+            // Tuple 메서드
+            Self::TupleContains => "__contains__",
+            Self::TupleIter => "__iter__",
+            Self::TupleAdd => "__add__",
+            Self::TupleMul => "__mul__",
+
             // Set 메서드
             Self::SetAdd => "add",
             Self::SetRemove => "remove",
@@ -294,6 +428,14 @@ impl NativeMethod {
             Self::SetUnion => "union",
             Self::SetIntersection => "intersection",
             Self::SetDifference => "difference",
+            Self::SetSymmetricDifference => "symmetric_difference",
+            Self::SetIsSubset => "issubset",
+            Self::SetIsSuperset => "issuperset",
+            Self::SetLt => "__lt__",
+            Self::SetGt => "__gt__",
+            Self::SetDiscard => "discard",
+            Self::SetPop => "pop",
+            Self::SetUpdate => "update",
             Self::SetClear => "clear",
             Self::SetCopy => "copy",
             Self::SetIter => "__iter__",
@@ -307,6 +449,14 @@ impl NativeMethod {
             Self::TreeSetUnion => "union",
             Self::TreeSetIntersection => "intersection",
             Self::TreeSetDifference => "difference",
+            Self::TreeSetSymmetricDifference => "symmetric_difference",
+            Self::TreeSetIsSubset => "issubset",
+            Self::TreeSetIsSuperset => "issuperset",
+            Self::TreeSetLt => "__lt__",
+            Self::TreeSetGt => "__gt__",
+            Self::TreeSetDiscard => "discard",
+            Self::TreeSetPop => "pop",
+            Self::TreeSetUpdate => "update",
             Self::TreeSetClear => "clear",
             Self::TreeSetCopy => "copy",
             Self::TreeSetLowerBound => "lower_bound",
@@ -316,6 +466,27 @@ impl NativeMethod {
             Self::TreeSetIter => "__iter__",
             Self::TreeSetHasNext => "__has_next__",
             Self::TreeSetNext => "__next__",
+
+            // 내장 모듈 함수
+            Self::SysExit => "exit",
+
+            // sys.stdin / sys.stdout 메서드
+            Self::StreamRead => "read",
+            Self::StreamReadline => "readline",
+            Self::StreamReadlines => "readlines",
+            Self::StreamWrite => "write",
+            Self::StreamFlush => "flush",
+        }
+    }
+}
+
+impl NativeMethod {
+    /// 키워드 인자를 받는 메서드인지 (`"{x}".format(x=1)`, `xs.sort(key=f)`)
+    pub fn accepts_keyword(&self, name: &str) -> bool {
+        match self {
+            Self::StrFormat => true,
+            Self::ListSort => matches!(name, "key" | "reverse"),
+            _ => false,
         }
     }
 }
@@ -471,6 +642,55 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "__pow__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntPow,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__and__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntAnd,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__or__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntOr,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__xor__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntXor,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__lshift__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntLShift,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__rshift__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntRShift,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__invert__",
+                MethodImpl::Native {
+                    func: NativeMethod::IntInvert,
+                    arity: Arity::Exact(0),
+                },
+            ),
         ]),
         // TYPE_BOOL (1)
         TypeDef::new("bool", TypeFlags::IMMUTABLE),
@@ -552,14 +772,14 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                 "strip",
                 MethodImpl::Native {
                     func: NativeMethod::StrStrip,
-                    arity: Arity::Exact(0),
+                    arity: Arity::Range(0, 1),
                 },
             ),
             (
                 "split",
                 MethodImpl::Native {
                     func: NativeMethod::StrSplit,
-                    arity: Arity::Range(0, 1),
+                    arity: Arity::Range(0, 2),
                 },
             ),
             (
@@ -604,6 +824,181 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "lstrip",
+                MethodImpl::Native {
+                    func: NativeMethod::StrLStrip,
+                    arity: Arity::Range(0, 1),
+                },
+            ),
+            (
+                "rstrip",
+                MethodImpl::Native {
+                    func: NativeMethod::StrRStrip,
+                    arity: Arity::Range(0, 1),
+                },
+            ),
+            (
+                "rsplit",
+                MethodImpl::Native {
+                    func: NativeMethod::StrRSplit,
+                    arity: Arity::Range(0, 2),
+                },
+            ),
+            (
+                "splitlines",
+                MethodImpl::Native {
+                    func: NativeMethod::StrSplitLines,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "rfind",
+                MethodImpl::Native {
+                    func: NativeMethod::StrRFind,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "index",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIndex,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "rindex",
+                MethodImpl::Native {
+                    func: NativeMethod::StrRIndex,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "partition",
+                MethodImpl::Native {
+                    func: NativeMethod::StrPartition,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "rpartition",
+                MethodImpl::Native {
+                    func: NativeMethod::StrRPartition,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "isdigit",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIsDigit,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "isalpha",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIsAlpha,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "isalnum",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIsAlnum,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "isspace",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIsSpace,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "isupper",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIsUpper,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "islower",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIsLower,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "title",
+                MethodImpl::Native {
+                    func: NativeMethod::StrTitle,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "capitalize",
+                MethodImpl::Native {
+                    func: NativeMethod::StrCapitalize,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "center",
+                MethodImpl::Native {
+                    func: NativeMethod::StrCenter,
+                    arity: Arity::Range(1, 2),
+                },
+            ),
+            (
+                "ljust",
+                MethodImpl::Native {
+                    func: NativeMethod::StrLJust,
+                    arity: Arity::Range(1, 2),
+                },
+            ),
+            (
+                "rjust",
+                MethodImpl::Native {
+                    func: NativeMethod::StrRJust,
+                    arity: Arity::Range(1, 2),
+                },
+            ),
+            (
+                "zfill",
+                MethodImpl::Native {
+                    func: NativeMethod::StrZFill,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "format",
+                MethodImpl::Native {
+                    func: NativeMethod::StrFormat,
+                    arity: Arity::Variadic,
+                },
+            ),
+            (
+                "__mod__",
+                MethodImpl::Native {
+                    func: NativeMethod::StrMod,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__contains__",
+                MethodImpl::Native {
+                    func: NativeMethod::StrContains,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__iter__",
+                MethodImpl::Native {
+                    func: NativeMethod::StrIter,
+                    arity: Arity::Exact(0),
+                },
+            ),
         ]),
         // TYPE_NONE (3)
         TypeDef::new("NoneType", TypeFlags::IMMUTABLE),
@@ -630,6 +1025,13 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(0),
                 },
             ),
+            (
+                "__contains__",
+                MethodImpl::Native {
+                    func: NativeMethod::RangeContains,
+                    arity: Arity::Exact(1),
+                },
+            ),
         ]),
         // TYPE_LIST (5)
         TypeDef::new("list", TypeFlags::ITERABLE).with_methods(vec![
@@ -711,17 +1113,24 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                 },
             ),
             (
-                "__has_next__",
+                "__contains__",
                 MethodImpl::Native {
-                    func: NativeMethod::ListHasNext,
-                    arity: Arity::Exact(0),
+                    func: NativeMethod::ListContains,
+                    arity: Arity::Exact(1),
                 },
             ),
             (
-                "__next__",
+                "__add__",
                 MethodImpl::Native {
-                    func: NativeMethod::ListNext,
-                    arity: Arity::Exact(0),
+                    func: NativeMethod::ListAdd,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__mul__",
+                MethodImpl::Native {
+                    func: NativeMethod::ListMul,
+                    arity: Arity::Exact(1),
                 },
             ),
         ]),
@@ -797,6 +1206,13 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(0),
                 },
             ),
+            (
+                "__contains__",
+                MethodImpl::Native {
+                    func: NativeMethod::DictContains,
+                    arity: Arity::Exact(1),
+                },
+            ),
         ]),
         // TYPE_FLOAT (7)
         TypeDef::new("float", TypeFlags::IMMUTABLE).with_methods(vec![
@@ -898,6 +1314,13 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "__pow__",
+                MethodImpl::Native {
+                    func: NativeMethod::FloatPow,
+                    arity: Arity::Exact(1),
+                },
+            ),
         ]),
         // TYPE_FUNCTION (8)
         TypeDef::new("function", TypeFlags::CALLABLE),
@@ -950,7 +1373,36 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
             ),
         ]),
         // TYPE_TUPLE (11)
-        TypeDef::new("tuple", TypeFlags::IMMUTABLE | TypeFlags::ITERABLE),
+        TypeDef::new("tuple", TypeFlags::IMMUTABLE | TypeFlags::ITERABLE).with_methods(vec![
+            (
+                "__contains__",
+                MethodImpl::Native {
+                    func: NativeMethod::TupleContains,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__iter__",
+                MethodImpl::Native {
+                    func: NativeMethod::TupleIter,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__add__",
+                MethodImpl::Native {
+                    func: NativeMethod::TupleAdd,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__mul__",
+                MethodImpl::Native {
+                    func: NativeMethod::TupleMul,
+                    arity: Arity::Exact(1),
+                },
+            ),
+        ]),
         // TYPE_SET (12)
         TypeDef::new("set", TypeFlags::ITERABLE).with_methods(vec![
             (
@@ -974,6 +1426,13 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "__contains__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetContains,
+                    arity: Arity::Exact(1),
+                },
+            ),
             (
                 "union",
                 MethodImpl::Native {
@@ -995,6 +1454,104 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "symmetric_difference",
+                MethodImpl::Native {
+                    func: NativeMethod::SetSymmetricDifference,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "issubset",
+                MethodImpl::Native {
+                    func: NativeMethod::SetIsSubset,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "issuperset",
+                MethodImpl::Native {
+                    func: NativeMethod::SetIsSuperset,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__lt__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetLt,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__gt__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetGt,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "discard",
+                MethodImpl::Native {
+                    func: NativeMethod::SetDiscard,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "pop",
+                MethodImpl::Native {
+                    func: NativeMethod::SetPop,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "update",
+                MethodImpl::Native {
+                    func: NativeMethod::SetUpdate,
+                    arity: Arity::Variadic,
+                },
+            ),
+            (
+                "__or__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetUnion,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__and__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetIntersection,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__sub__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetDifference,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__xor__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetSymmetricDifference,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__le__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetIsSubset,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__ge__",
+                MethodImpl::Native {
+                    func: NativeMethod::SetIsSuperset,
+                    arity: Arity::Exact(1),
+                },
+            ),
             (
                 "clear",
                 MethodImpl::Native {
@@ -1054,6 +1611,13 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "__contains__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetContains,
+                    arity: Arity::Exact(1),
+                },
+            ),
             (
                 "union",
                 MethodImpl::Native {
@@ -1075,6 +1639,104 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                     arity: Arity::Exact(1),
                 },
             ),
+            (
+                "symmetric_difference",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetSymmetricDifference,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "issubset",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetIsSubset,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "issuperset",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetIsSuperset,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__lt__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetLt,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__gt__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetGt,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "discard",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetDiscard,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "pop",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetPop,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "update",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetUpdate,
+                    arity: Arity::Variadic,
+                },
+            ),
+            (
+                "__or__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetUnion,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__and__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetIntersection,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__sub__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetDifference,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__xor__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetSymmetricDifference,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__le__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetIsSubset,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "__ge__",
+                MethodImpl::Native {
+                    func: NativeMethod::TreeSetIsSuperset,
+                    arity: Arity::Exact(1),
+                },
+            ),
             (
                 "clear",
                 MethodImpl::Native {
@@ -1139,6 +1801,126 @@ pub fn init_builtin_types() -> Vec<TypeDef> {
                 },
             ),
         ]),
+        // TYPE_ENUMERATE (14)
+        TypeDef::new("enumerate", TypeFlags::ITERABLE).with_methods(vec![
+            (
+                "__iter__",
+                MethodImpl::Native {
+                    func: NativeMethod::EnumerateIter,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__has_next__",
+                MethodImpl::Native {
+                    func: NativeMethod::EnumerateHasNext,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__next__",
+                MethodImpl::Native {
+                    func: NativeMethod::EnumerateNext,
+                    arity: Arity::Exact(0),
+                },
+            ),
+        ]),
+        // TYPE_ZIP (15)
+        TypeDef::new("zip", TypeFlags::ITERABLE).with_methods(vec![
+            (
+                "__iter__",
+                MethodImpl::Native {
+                    func: NativeMethod::ZipIter,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__has_next__",
+                MethodImpl::Native {
+                    func: NativeMethod::ZipHasNext,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__next__",
+                MethodImpl::Native {
+                    func: NativeMethod::ZipNext,
+                    arity: Arity::Exact(0),
+                },
+            ),
+        ]),
+        // TYPE_TYPE (16)
+        TypeDef::new("type", TypeFlags::CALLABLE),
+        // TYPE_MODULE (17): 모든 내장 모듈의 함수
+        TypeDef::new("module", TypeFlags::empty()).with_methods(vec![(
+            "exit",
+            MethodImpl::Native {
+                func: NativeMethod::SysExit,
+                arity: Arity::Range(0, 1),
+            },
+        )]),
+        // TYPE_TEXT_IO (18): sys.stdin / sys.stdout
+        TypeDef::new("TextIOWrapper", TypeFlags::empty()).with_methods(vec![
+            (
+                "read",
+                MethodImpl::Native {
+                    func: NativeMethod::StreamRead,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "readline",
+                MethodImpl::Native {
+                    func: NativeMethod::StreamReadline,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "readlines",
+                MethodImpl::Native {
+                    func: NativeMethod::StreamReadlines,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "write",
+                MethodImpl::Native {
+                    func: NativeMethod::StreamWrite,
+                    arity: Arity::Exact(1),
+                },
+            ),
+            (
+                "flush",
+                MethodImpl::Native {
+                    func: NativeMethod::StreamFlush,
+                    arity: Arity::Exact(0),
+                },
+            ),
+        ]),
+        // TYPE_LIST_ITERATOR (19)
+        TypeDef::new("list_iterator", TypeFlags::ITERABLE).with_methods(vec![
+            (
+                "__iter__",
+                MethodImpl::Native {
+                    func: NativeMethod::ListIter,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__has_next__",
+                MethodImpl::Native {
+                    func: NativeMethod::ListHasNext,
+                    arity: Arity::Exact(0),
+                },
+            ),
+            (
+                "__next__",
+                MethodImpl::Native {
+                    func: NativeMethod::ListNext,
+                    arity: Arity::Exact(0),
+                },
+            ),
+        ]),
     ]
 }
 
@@ -6,84 +6,189 @@ use super::bytecode::Value;
 use super::type_def::TYPE_USER_START;
 use super::value::{BuiltinInstanceData, DictKey, Object, ObjectData, SetKey};
 use super::{VmError, VmErrorKind, VmResult, err};
-use crate::builtins::{BuiltinClassType, TYPE_DICT, TYPE_FILTER_ITER, TYPE_LIST, TYPE_MAP_ITER, TYPE_RANGE, TYPE_SET, TYPE_STR, TYPE_TREESET, TYPE_TUPLE};
+use crate::builtins::{
+    BuiltinClassType, TYPE_DICT, TYPE_FUNCTION, TYPE_LIST, TYPE_LIST_ITERATOR, TYPE_RANGE, TYPE_STR,
+    TYPE_TUPLE, TYPE_TYPE,
+};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
 /// Value를 출력 가능한 문자열로 변환
 ///
-/// Python의 `str()` 표현과 유사하게 값을 문자열로 변환합니다.
+/// Python의 `str()`과 같습니다: 최상위 문자열만 그대로 출력하고, 그 외 값은
+/// `repr_value`를 사용합니다. 그래서 컨테이너 안의 문자열은 따옴표로 감싸집니다.
 ///
 /// # Examples
 ///
 /// ```ignore
-/// display_value(&Value::Int(42))        // → "42"
-/// display_value(&Value::Bool(true))     // → "True"
-/// display_value(&Value::None)           // → "None"
+/// display_value(&Value::Int(42))            // → "42"
+/// display_value(&Value::Float(1.0))         // → "1.0"
+/// display_value(&make_string("hi".into()))  // → "hi"
+/// display_value(&make_list(vec![make_string("hi".into())]))  // → "['hi']"
 /// ```
 pub fn display_value(v: &Value) -> String {
-    match v {
-        Value::Int(i) => i.to_string(),
-        Value::Float(f) => f.to_string(),
-        Value::Bool(b) => if *b { "True" } else { "False" }.to_string(),
-        Value::None => "None".to_string(),
-        Value::Object(obj) => match &obj.data {
-            ObjectData::String(s) => s.clone(),
-            ObjectData::List { items } => {
-                let items_ref = items.borrow();
-                let contents: Vec<String> = items_ref.iter().map(display_value).collect();
-                format!("[{}]", contents.join(", "))
-            }
-            ObjectData::Tuple { items } => {
-                let contents: Vec<String> = items.iter().map(display_value).collect();
-                if contents.len() == 1 {
-                    format!("({},)", contents[0])
-                } else {
-                    format!("({})", contents.join(", "))
-                }
-            }
-            ObjectData::Dict { map } => {
-                use super::value::DictKey;
-                let map_ref = map.borrow();
-                let contents: Vec<String> = map_ref
-                    .iter()
-                    .map(|(k, v)| {
-                        let key_str = match k {
-                            DictKey::Int(i) => i.to_string(),
-                            DictKey::String(s) => format!("\"{}\"", s),
-                            DictKey::Bool(b) => if *b { "True" } else { "False" }.to_string(),
-                        };
-                        format!("{}: {}", key_str, display_value(v))
-                    })
-                    .collect();
-                format!("{{{}}}", contents.join(", "))
-            }
-            ObjectData::Set { items } => {
-                let items_ref = items.borrow();
-                let contents: Vec<String> = items_ref.iter().map(|k| display_set_key(k)).collect();
-                format!("{{{}}}", contents.join(", "))
-            }
-            ObjectData::TreeSet { items } => {
-                let items_ref = items.borrow();
-                let contents: Vec<String> = items_ref.iter().map(|k| display_set_key(k)).collect();
-                format!("t{{{}}}", contents.join(", "))
+    if let Value::Object(obj) = v
+        && let ObjectData::String(s) = &obj.data
+    {
+        return s.clone();
+    }
+    repr_value(v)
+}
+
+/// Value의 `repr()` 표현
+///
+/// 문자열은 따옴표로 감싸고 제어 문자를 이스케이프하며, 컨테이너는 원소마다 repr을 씁니다.
+/// 자기 자신을 담은 list, dict는 Python처럼 `[...]`, `{...}`로 표시합니다.
+///
+/// # Examples
+///
+/// ```ignore
+/// repr_value(&make_string("hi".into()))   // → "'hi'"
+/// repr_value(&Value::Float(1e20))         // → "1e+20"
+/// ```
+pub fn repr_value(v: &Value) -> String {
+    repr_inner(v, &mut Vec::new())
+}
+
+/// `in_progress`: 지금 출력 중인 컨테이너들 (순환 참조 감지용)
+fn repr_inner(v: &Value, in_progress: &mut Vec<*const Object>) -> String {
+    let obj = match v {
+        Value::Int(i) => return i.to_string(),
+        Value::Float(f) => return float_repr(*f),
+        Value::Bool(b) => return if *b { "True" } else { "False" }.to_string(),
+        Value::None => return "None".to_string(),
+        Value::Object(obj) => obj,
+    };
+    let ptr = Rc::as_ptr(obj);
+    match &obj.data {
+        ObjectData::String(s) => repr_str(s),
+        ObjectData::List { items } => {
+            if in_progress.contains(&ptr) {
+                return "[...]".to_string();
             }
-            ObjectData::UserClass { class_id, .. } => format!("<class user_{}>", class_id),
-            ObjectData::UserInstance { class_id } => {
-                format!("<instance of user_{}>", class_id)
+            in_progress.push(ptr);
+            let contents: Vec<String> = items
+                .borrow()
+                .iter()
+                .map(|item| repr_inner(item, in_progress))
+                .collect();
+            in_progress.pop();
+            format!("[{}]", contents.join(", "))
+        }
+        ObjectData::Tuple { items } => {
+            let contents: Vec<String> = items
+                .iter()
+                .map(|item| repr_inner(item, in_progress))
+                .collect();
+            if contents.len() == 1 {
+                format!("({},)", contents[0])
+            } else {
+                format!("({})", contents.join(", "))
             }
-            ObjectData::BuiltinClass { class_type } => {
-                format!("<class '{}'>", class_type.name())
+        }
+        ObjectData::Dict { map } => {
+            if in_progress.contains(&ptr) {
+                return "{...}".to_string();
             }
-            ObjectData::BuiltinInstance { class_type, .. } => {
-                format!("<{} object>", class_type.name())
+            in_progress.push(ptr);
+            let contents: Vec<String> = map
+                .borrow()
+                .iter()
+                .map(|(k, v)| format!("{}: {}", repr_dict_key(k), repr_inner(v, in_progress)))
+                .collect();
+            in_progress.pop();
+            format!("{{{}}}", contents.join(", "))
+        }
+        ObjectData::Set { items } => {
+            let items_ref = items.borrow();
+            if items_ref.is_empty() {
+                return "set()".to_string();
             }
-            ObjectData::UserFunction { func_id, .. } => {
+            let contents: Vec<String> = items_ref.iter().map(repr_set_key).collect();
+            format!("{{{}}}", contents.join(", "))
+        }
+        ObjectData::TreeSet { items } => {
+            let items_ref = items.borrow();
+            let contents: Vec<String> = items_ref.iter().map(repr_set_key).collect();
+            format!("t{{{}}}", contents.join(", "))
+        }
+        ObjectData::UserClass { name, .. } => format!("<class '{}'>", name),
+        ObjectData::UserInstance { class_name, .. } => format!("<{} object>", class_name),
+        ObjectData::Module { name } => format!("<module '{}'>", name),
+        ObjectData::Stream { kind } => format!("<_io.TextIOWrapper name='{}'>", kind.name()),
+        ObjectData::BuiltinClass { class_type } => {
+            format!("<class '{}'>", class_type.name())
+        }
+        ObjectData::BuiltinFunction { builtin_id } => format!(
+            "<built-in function {}>",
+            crate::builtins::lookup_by_id(*builtin_id).map_or("?", |b| b.name)
+        ),
+        ObjectData::NativeFunction { name, is_class: true } => format!("<class '{}'>", name),
+        ObjectData::NativeFunction { name, .. } => format!("<built-in function {}>", name),
+        ObjectData::BuiltinInstance { class_type, .. } => {
+            format!("<{} object>", class_type.name())
+        }
+        ObjectData::UserFunction { func_id, name, .. } => {
+            if name.starts_with("<lambda#") {
                 format!("<function lambda#{}>", func_id)
+            } else {
+                format!("<function {}>", name)
             }
-        },
+        }
+        ObjectData::NativeInstance { class, .. } => format!("<{} object>", class.name),
+    }
+}
+
+/// Python과 같은 float 표현: `1.0`, `0.1`, `1e+20`, `1e-05`, `inf`, `nan`
+///
+/// 가장 짧은 왕복 표현을 쓰고, 지수가 -4 미만이거나 16 이상이면 지수 표기로 바꿉니다.
+pub fn float_repr(f: f64) -> String {
+    if f.is_nan() {
+        return "nan".to_string();
+    }
+    if f.is_infinite() {
+        return if f > 0.0 { "inf" } else { "-inf" }.to_string();
+    }
+    let sci = format!("{:e}", f);
+    let (mantissa, exp) = sci.split_once('e').unwrap_or((&sci, "0"));
+    let exp: i32 = exp.parse().unwrap_or(0);
+    if (-4..16).contains(&exp) {
+        let positional = f.to_string();
+        if positional.contains('.') {
+            positional
+        } else {
+            positional + ".0"
+        }
+    } else {
+        let sign = if exp < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", mantissa, sign, exp.unsigned_abs())
+    }
+}
+
+/// Python과 같은 규칙으로 문자열 리터럴 표현 생성
+///
+/// 작은따옴표를 기본으로 쓰고, 문자열에 `'`만 있고 `"`가 없으면 큰따옴표를 씁니다.
+fn repr_str(s: &str) -> String {
+    let quote = if s.contains('\'') && !s.contains('"') { '"' } else { '\'' };
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push(quote);
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if c.is_control() => out.push_str(&format!("\\x{:02x}", c as u32)),
+            c => out.push(c),
+        }
     }
+    out.push(quote);
+    out
 }
 
 /// Value의 타입 이름 반환
@@ -113,8 +218,14 @@ pub fn type_name(v: &Value) -> &'static str {
             ObjectData::UserClass { .. } => "type",
             ObjectData::UserInstance { .. } => "instance",
             ObjectData::BuiltinClass { .. } => "type",
+            ObjectData::BuiltinFunction { .. } => "builtin_function_or_method",
+            ObjectData::NativeFunction { is_class: true, .. } => "type",
+            ObjectData::NativeFunction { .. } => "builtin_function_or_method",
             ObjectData::BuiltinInstance { class_type, .. } => class_type.name(),
             ObjectData::UserFunction { .. } => "function",
+            ObjectData::Module { .. } => "module",
+            ObjectData::Stream { .. } => "TextIOWrapper",
+            ObjectData::NativeInstance { .. } => "instance",
         },
     }
 }
@@ -127,27 +238,123 @@ pub fn type_name(v: &Value) -> &'static str {
 ///
 /// - 동일 타입 primitive 값: 값 비교
 /// - String 객체: 문자열 내용 비교
+/// - list, tuple: 길이와 원소를 차례로 비교
+/// - dict: 같은 키 집합과 각 키의 값 비교
+/// - set, treeset: 같은 원소 집합이면 같음 (set과 treeset끼리도 비교 가능)
+/// - 타입 객체: 같은 타입을 가리키면 같음 (`type(1) == int`)
 /// - 다른 객체: 포인터 비교 (identity)
 /// - 서로 다른 타입: `false`
-pub fn eq_vals(a: &Value, b: &Value) -> bool {
-    match (a, b) {
-        (Value::Int(x), Value::Int(y)) => x == y,
-        (Value::Float(x), Value::Float(y)) => x == y,
-        (Value::Int(x), Value::Float(y)) => (*x as f64) == *y,
-        (Value::Float(x), Value::Int(y)) => *x == (*y as f64),
-        (Value::Bool(x), Value::Bool(y)) => x == y,
-        (Value::None, Value::None) => true,
-        (Value::Object(x), Value::Object(y)) => {
-            if Rc::ptr_eq(x, y) {
-                return true;
-            }
-            match (&x.data, &y.data) {
-                (ObjectData::String(s1), ObjectData::String(s2)) => s1 == s2,
-                _ => false,
-            }
+///
+/// 자기 자신을 담은 컨테이너도 끝나도록, 이미 비교 중인 객체 쌍은 같다고 봅니다.
+/// 너무 깊이 중첩된 컨테이너는 Python처럼 `RecursionError`로 멈춥니다.
+pub fn eq_vals(a: &Value, b: &Value) -> VmResult<bool> {
+    eq_vals_inner(a, b, 0, &mut HashSet::new())
+}
+
+/// 컨테이너 비교가 들어갈 수 있는 최대 깊이 (Python의 기본 재귀 제한과 같음)
+const MAX_EQ_DEPTH: usize = 1000;
+
+/// 비교 중인 객체 쌍 (순환 참조 감지용)
+type EqPairs = HashSet<(*const Object, *const Object)>;
+
+fn eq_vals_inner(a: &Value, b: &Value, depth: usize, in_progress: &mut EqPairs) -> VmResult<bool> {
+    let (x, y) = match (a, b) {
+        (Value::Int(x), Value::Int(y)) => return Ok(x == y),
+        (Value::Float(x), Value::Float(y)) => return Ok(x == y),
+        (Value::Int(x), Value::Float(y)) => return Ok((*x as f64) == *y),
+        (Value::Float(x), Value::Int(y)) => return Ok(*x == (*y as f64)),
+        (Value::Bool(x), Value::Bool(y)) => return Ok(x == y),
+        (Value::None, Value::None) => return Ok(true),
+        (Value::Object(x), Value::Object(y)) => (x, y),
+        _ => return Ok(false),
+    };
+    if Rc::ptr_eq(x, y) {
+        return Ok(true);
+    }
+    if depth >= MAX_EQ_DEPTH {
+        return Err(err(
+            VmErrorKind::RecursionError,
+            "RecursionError: maximum recursion depth exceeded in comparison".into(),
+        ));
+    }
+    let pair = (Rc::as_ptr(x), Rc::as_ptr(y));
+    if !in_progress.insert(pair) {
+        return Ok(true);
+    }
+    let depth = depth + 1;
+    let result = match (&x.data, &y.data) {
+        (ObjectData::String(s1), ObjectData::String(s2)) => Ok(s1 == s2),
+        (ObjectData::List { items: xs }, ObjectData::List { items: ys }) => {
+            eq_slices(&xs.borrow(), &ys.borrow(), depth, in_progress)
+        }
+        (ObjectData::Tuple { items: xs }, ObjectData::Tuple { items: ys }) => {
+            eq_slices(xs, ys, depth, in_progress)
+        }
+        (ObjectData::Dict { map: m1 }, ObjectData::Dict { map: m2 }) => {
+            let (m1, m2) = (m1.borrow(), m2.borrow());
+            eq_dicts(&m1, &m2, depth, in_progress)
+        }
+        (ObjectData::Set { items: s1 }, ObjectData::Set { items: s2 }) => {
+            Ok(*s1.borrow() == *s2.borrow())
+        }
+        (ObjectData::TreeSet { items: s1 }, ObjectData::TreeSet { items: s2 }) => {
+            Ok(*s1.borrow() == *s2.borrow())
+        }
+        (ObjectData::Set { items: s1 }, ObjectData::TreeSet { items: s2 })
+        | (ObjectData::TreeSet { items: s2 }, ObjectData::Set { items: s1 }) => {
+            let (s1, s2) = (s1.borrow(), s2.borrow());
+            Ok(s1.len() == s2.len() && s2.iter().all(|k| s1.contains(k)))
+        }
+        (
+            ObjectData::BuiltinClass { class_type: t1 },
+            ObjectData::BuiltinClass { class_type: t2 },
+        ) => Ok(t1 == t2),
+        (
+            ObjectData::BuiltinFunction { builtin_id: f1 },
+            ObjectData::BuiltinFunction { builtin_id: f2 },
+        ) => Ok(f1 == f2),
+        (
+            ObjectData::NativeFunction { name: n1, .. },
+            ObjectData::NativeFunction { name: n2, .. },
+        ) => Ok(n1 == n2),
+        (
+            ObjectData::UserClass { class_id: c1, .. },
+            ObjectData::UserClass { class_id: c2, .. },
+        ) => Ok(c1 == c2),
+        _ => Ok(false),
+    };
+    in_progress.remove(&pair);
+    result
+}
+
+fn eq_slices(xs: &[Value], ys: &[Value], depth: usize, in_progress: &mut EqPairs) -> VmResult<bool> {
+    if xs.len() != ys.len() {
+        return Ok(false);
+    }
+    for (x, y) in xs.iter().zip(ys) {
+        if !eq_vals_inner(x, y, depth, in_progress)? {
+            return Ok(false);
         }
-        _ => false,
     }
+    Ok(true)
+}
+
+fn eq_dicts(
+    m1: &HashMap<DictKey, Value>,
+    m2: &HashMap<DictKey, Value>,
+    depth: usize,
+    in_progress: &mut EqPairs,
+) -> VmResult<bool> {
+    if m1.len() != m2.len() {
+        return Ok(false);
+    }
+    for (k, v1) in m1 {
+        match m2.get(k) {
+            Some(v2) if eq_vals_inner(v1, v2, depth, in_progress)? => {}
+            _ => return Ok(false),
+        }
+    }
+    Ok(true)
 }
 
 // ========== Object 생성 헬퍼 함수들 (make_*) ==========
@@ -167,6 +374,25 @@ pub fn make_list(items: Vec<Value>) -> Value {
     )))
 }
 
+/// Tuple 객체 생성
+pub fn make_tuple(items: Vec<Value>) -> Value {
+    Value::Object(Rc::new(Object::new(TYPE_TUPLE, ObjectData::Tuple { items })))
+}
+
+/// 값 목록을 순회하는 list iterator 생성 (str, tuple, reversed 등의 `__iter__`)
+pub fn make_list_iterator(items: Vec<Value>) -> Value {
+    Value::Object(Rc::new(Object::new(
+        TYPE_LIST_ITERATOR,
+        ObjectData::BuiltinInstance {
+            class_type: BuiltinClassType::ListIterator,
+            data: BuiltinInstanceData::ListIterator {
+                items: Rc::new(RefCell::new(items)),
+                current: RefCell::new(0),
+            },
+        },
+    )))
+}
+
 /// Range 객체 생성
 pub fn make_range(current: i64, stop: i64, step: i64) -> Value {
     Value::Object(Rc::new(Object::new(
@@ -192,49 +418,76 @@ pub fn make_dict(map: HashMap<DictKey, Value>) -> Value {
     )))
 }
 
-/// SetKey를 문자열로 표시
-fn display_set_key(key: &SetKey) -> String {
+/// SetKey의 repr 표현
+fn repr_set_key(key: &SetKey) -> String {
     match key {
         SetKey::Int(i) => i.to_string(),
-        SetKey::String(s) => format!("\"{}\"", s),
+        SetKey::String(s) => repr_str(s),
         SetKey::Bool(b) => if *b { "True" } else { "False" }.to_string(),
     }
 }
 
+/// DictKey의 repr 표현
+fn repr_dict_key(key: &DictKey) -> String {
+    match key {
+        DictKey::Int(i) => i.to_string(),
+        DictKey::String(s) => repr_str(s),
+        DictKey::Bool(b) => if *b { "True" } else { "False" }.to_string(),
+    }
+}
+
 /// 사용자 정의 클래스 객체 생성
-pub fn make_user_class(class_id: u16, methods: HashMap<String, u16>) -> Value {
+pub fn make_user_class(class_id: u16, name: Rc<str>, methods: HashMap<String, u16>) -> Value {
     Value::Object(Rc::new(Object::new(
         TYPE_USER_START + class_id,
-        ObjectData::UserClass { class_id, methods },
+        ObjectData::UserClass {
+            class_id,
+            name,
+            methods,
+        },
     )))
 }
 
 /// 사용자 정의 인스턴스 객체 생성
-pub fn make_user_instance(class_id: u16) -> Value {
+pub fn make_user_instance(class_id: u16, class_name: Rc<str>) -> Value {
     Value::Object(Rc::new(Object::new_with_attrs(
         TYPE_USER_START + class_id,
-        ObjectData::UserInstance { class_id },
+        ObjectData::UserInstance {
+            class_id,
+            class_name,
+        },
     )))
 }
 
 /// Builtin 클래스 객체 생성
+///
+/// 타입 객체 자체의 타입은 모두 `type`입니다 (`type(int) == type`).
 pub fn make_builtin_class(class_type: BuiltinClassType) -> Value {
-    let type_id = match class_type {
-        BuiltinClassType::Range => TYPE_RANGE,
-        BuiltinClassType::List => TYPE_LIST,
-        BuiltinClassType::Dict => TYPE_DICT,
-        BuiltinClassType::Tuple => TYPE_TUPLE,
-        BuiltinClassType::MapIter => TYPE_MAP_ITER,
-        BuiltinClassType::FilterIter => TYPE_FILTER_ITER,
-        BuiltinClassType::Set => TYPE_SET,
-        BuiltinClassType::TreeSet => TYPE_TREESET,
-    };
     Value::Object(Rc::new(Object::new(
-        type_id,
+        TYPE_TYPE,
         ObjectData::BuiltinClass { class_type },
     )))
 }
 
+/// 값으로 쓴 builtin 함수 객체 (`key=len`)
+pub fn make_builtin_function(builtin_id: u8) -> Value {
+    Value::Object(Rc::new(Object::new(
+        TYPE_FUNCTION,
+        ObjectData::BuiltinFunction { builtin_id },
+    )))
+}
+
+/// 값으로 쓴 네이티브 함수/클래스 객체 (`f = double`)
+pub fn make_native_function(name: &str, is_class: bool) -> Value {
+    Value::Object(Rc::new(Object::new(
+        if is_class { TYPE_TYPE } else { TYPE_FUNCTION },
+        ObjectData::NativeFunction {
+            name: name.to_string(),
+            is_class,
+        },
+    )))
+}
+
 // ========== 타입 추출 헬퍼 함수들 (expect_*) ==========
 
 /// Value에서 int 추출
@@ -273,6 +526,25 @@ pub fn expect_string(v: &Value) -> VmResult<&str> {
     }
 }
 
+/// 문자열의 i번째 문자 (음수 인덱스 지원)
+///
+/// ASCII 문자열(`ascii`는 `Object::is_ascii_str`)은 바이트 위치가 곧 문자 위치이므로
+/// 디코딩 없이 O(1)로 꺼냅니다.
+pub fn str_char_at(s: &str, ascii: bool, idx: i64) -> Option<char> {
+    if ascii {
+        let len = s.len() as i64;
+        let i = if idx < 0 { idx + len } else { idx };
+        return (0..len)
+            .contains(&i)
+            .then(|| s.as_bytes()[i as usize] as char);
+    }
+    if idx < 0 {
+        s.chars().rev().nth((-(idx + 1)) as usize)
+    } else {
+        s.chars().nth(idx as usize)
+    }
+}
+
 /// Value에서 bool 추출
 pub fn expect_bool(v: &Value) -> VmResult<bool> {
     match v {
@@ -8,6 +8,8 @@ use std::cell::RefCell;
 use std::collections::{HashMap, HashSet, BTreeSet};
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::builtins::BuiltinClassType;
 
 /// 통일된 런타임 객체
@@ -17,6 +19,7 @@ use crate::builtins::BuiltinClassType;
 /// # 메모리 레이아웃
 ///
 /// - `type_id`: 2 bytes (타입 테이블 인덱스)
+/// - `ascii`: 1 byte (`type_id` 옆 패딩에 들어감)
 /// - `data`: 8-24 bytes (ObjectData enum)
 /// - `attributes`: 16 bytes (Option<RefCell<HashMap>>)
 ///
@@ -27,6 +30,10 @@ pub struct Object {
     /// 예: TYPE_STR (2), TYPE_RANGE (4), 또는 사용자 정의 타입 (100+)
     pub type_id: u16,
 
+    /// 문자열 객체가 ASCII로만 되어 있는지. 만들 때 한 번 계산해 두고
+    /// 인덱싱/`len`에서 바이트 위치를 바로 문자 위치로 씀 (문자열은 불변)
+    pub(crate) ascii: bool,
+
     /// 객체의 실제 데이터
     pub data: ObjectData,
 
@@ -39,6 +46,7 @@ impl Object {
     pub fn new(type_id: u16, data: ObjectData) -> Self {
         Self {
             type_id,
+            ascii: Self::ascii_flag(&data),
             data,
             attributes: None,
         }
@@ -48,11 +56,21 @@ impl Object {
     pub fn new_with_attrs(type_id: u16, data: ObjectData) -> Self {
         Self {
             type_id,
+            ascii: Self::ascii_flag(&data),
             data,
             attributes: Some(RefCell::new(HashMap::new())),
         }
     }
 
+    pub(crate) fn ascii_flag(data: &ObjectData) -> bool {
+        matches!(data, ObjectData::String(s) if s.is_ascii())
+    }
+
+    /// ASCII로만 된 문자열 객체인지 (만들 때 계산한 값)
+    pub fn is_ascii_str(&self) -> bool {
+        self.ascii
+    }
+
     pub fn get_attr(&self, name: &str) -> Option<crate::vm::bytecode::Value> {
         self.attributes
             .as_ref()
@@ -101,12 +119,14 @@ pub enum ObjectData {
     /// 사용자 정의 클래스
     UserClass {
         class_id: u16,
+        name: Rc<str>, // repr용 클래스 이름
         methods: HashMap<String, u16>, // method_name -> func_id
     },
 
     /// 사용자 정의 객체 인스턴스
     UserInstance {
         class_id: u16,
+        class_name: Rc<str>,
     },
 
     /// Builtin 클래스
@@ -123,13 +143,60 @@ pub enum ObjectData {
     /// User-defined function/lambda (closure)
     UserFunction {
         func_id: u16,
+        /// `def` 이름 (람다는 `<lambda#N>`), repr용
+        name: String,
         captures: Vec<crate::vm::bytecode::Value>,
     },
+
+    /// 값으로 쓴 builtin 함수 (`xs.sort(key=len)`, `f = abs`)
+    BuiltinFunction {
+        builtin_id: u8,
+    },
+
+    /// 값으로 쓴 네이티브 함수/클래스 (`f = double`, `map(double, xs)`).
+    /// 호출할 때 VM의 레지스트리에서 이름으로 찾습니다.
+    NativeFunction {
+        name: String,
+        is_class: bool,
+    },
+
+    /// `import`한 내장 모듈 (값은 속성에, 함수는 TYPE_MODULE 메서드)
+    Module {
+        name: String,
+    },
+
+    /// `sys.stdin` / `sys.stdout` (실제 읽기/쓰기는 RuntimeIo가 함)
+    Stream {
+        kind: StreamKind,
+    },
+
+    /// 호스트가 등록한 네이티브 클래스의 인스턴스
+    NativeInstance {
+        class: Rc<crate::vm::native::NativeClass>,
+        state: crate::vm::native::NativeState,
+    },
+}
+
+/// 표준 스트림 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamKind {
+    Stdin,
+    Stdout,
+}
+
+impl StreamKind {
+    /// Python과 같은 스트림 이름 (`<stdin>`)
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Stdin => "<stdin>",
+            Self::Stdout => "<stdout>",
+        }
+    }
 }
 
 /// Dict key wrapper (hashable types only)
 /// TODO: __hash__ 메서드 구현 시 가능하도록 수정 필요
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub enum DictKey {
     Int(i64),
     String(String),
@@ -138,7 +205,7 @@ pub enum DictKey {
 
 /// Set key wrapper (hashable and orderable types)
 /// Used for both HashSet and BTreeSet
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
 pub enum SetKey {
     Int(i64),
     String(String),
@@ -180,6 +247,18 @@ pub enum BuiltinInstanceData {
         peeked: std::cell::RefCell<Option<crate::vm::bytecode::Value>>,
     },
 
+    /// Enumerate iterator 상태
+    EnumerateIterator {
+        source_iter: Box<crate::vm::bytecode::Value>,
+        /// 다음에 돌려줄 인덱스
+        index: RefCell<i64>,
+    },
+
+    /// Zip iterator 상태 (가장 짧은 iterator가 끝나면 종료)
+    ZipIterator {
+        source_iters: Vec<crate::vm::bytecode::Value>,
+    },
+
     /// Set iterator 상태
     SetIterator {
         keys: Vec<SetKey>,
@@ -209,7 +288,10 @@ mod tests {
     fn test_object_with_attributes() {
         use crate::builtins::TYPE_STR;
 
-        let mut obj = Object::new_with_attrs(1, ObjectData::UserInstance { class_id: 0 });
+        let mut obj = Object::new_with_attrs(1, ObjectData::UserInstance {
+                class_id: 0,
+                class_name: "Point".into(),
+            });
         assert!(obj.attributes.is_some());
 
         // 속성 설정
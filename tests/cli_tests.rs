@@ -0,0 +1,208 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// `pyhc` 바이너리를 실행하고 (종료 코드, stdout, stderr)를 돌려줌
+fn pyhc(args: &[&str], stdin: &str) -> (i32, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pyhc"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to start pyhc");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let Output {
+        status,
+        stdout,
+        stderr,
+    } = child.wait_with_output().unwrap();
+    (
+        status.code().expect("terminated by signal"),
+        String::from_utf8(stdout).unwrap(),
+        String::from_utf8(stderr).unwrap(),
+    )
+}
+
+#[test]
+fn test_run_code_and_stdin_with_argv() {
+    let (status, out, _) = pyhc(&["run", "-c", "import sys\nprint(sys.argv)", "a", "b"], "");
+    assert_eq!((status, out.as_str()), (0, "['-c', 'a', 'b']\n"));
+
+    let src = "import sys\nprint(sys.argv)\n";
+    let (status, out, _) = pyhc(&["run", "-", "x", "--", "-v"], src);
+    assert_eq!((status, out.as_str()), (0, "['-', 'x', '-v']\n"));
+
+    let (status, out, _) = pyhc(&["run", "tests/programs/basics/arith.pyh"], "");
+    assert_eq!(status, 0);
+    assert!(out.starts_with("7\n"));
+}
+
+#[test]
+fn test_exit_codes() {
+    let (status, out, _) = pyhc(&["run", "-c", "import sys\nprint(1)\nsys.exit(7)\nprint(2)"], "");
+    assert_eq!((status, out.as_str()), (7, "1\n"));
+
+    // 셸처럼 하위 8비트만 남음
+    for (code, expected) in [(-1, 255), (256, 0), (300, 44)] {
+        let (status, _, _) = pyhc(&["run", "-c", &format!("import sys\nsys.exit({})", code)], "");
+        assert_eq!(status, expected, "sys.exit({})", code);
+    }
+
+    let (status, _, err) = pyhc(&["run", "-c", "import sys\nsys.exit('bad input')"], "");
+    assert_eq!((status, err.as_str()), (1, "bad input\n"));
+
+    let (status, _, err) = pyhc(&["run", "-c", "print(1 // 0)"], "");
+    assert_eq!(status, pyhyeon::EXIT_RUNTIME_ERROR);
+    assert!(err.contains("ZeroDivision"), "{}", err);
+
+    let (status, out, err) = pyhc(&["run", "-c", "print(1)\nprint(undefined)"], "");
+    assert_eq!((status, out.as_str()), (pyhyeon::EXIT_COMPILE_ERROR, ""));
+    assert!(err.contains("E0201"), "{}", err);
+
+    let (status, _, err) = pyhc(&["run", "does/not/exist.pyh"], "");
+    assert_eq!(status, pyhyeon::EXIT_NO_INPUT);
+    assert!(err.contains("cannot read does/not/exist.pyh"), "{}", err);
+
+    let (status, _, _) = pyhc(&["run"], "");
+    assert_eq!(status, 2, "missing FILE is a usage error");
+}
+
+#[test]
+fn test_help_and_version() {
+    let (status, out, _) = pyhc(&["--version"], "");
+    assert_eq!(status, 0);
+    assert_eq!(out, format!("pyhc {}\n", env!("CARGO_PKG_VERSION")));
+
+    let (status, out, _) = pyhc(&["run", "--help"], "");
+    assert_eq!(status, 0);
+    assert!(out.contains("Exit status:") && out.contains("sys.exit(n)"), "{}", out);
+}
+
+#[test]
+fn test_max_errors_limits_reported_diagnostics() {
+    let src = "print(a)\nprint(b)\nprint(c)\n";
+    let (status, _, err) = pyhc(&["run", "-c", src, "--max-errors", "2"], "");
+    assert_eq!(status, pyhyeon::EXIT_COMPILE_ERROR);
+    assert_eq!(err.matches("Undefined variable").count(), 2, "{}", err);
+
+    // 0이면 아무것도 보고하지 않고 실패하게 되므로 인자 오류
+    let (status, _, err) = pyhc(&["run", "-c", src, "--max-errors", "0"], "");
+    assert_eq!(status, 2);
+    assert!(err.contains("must be at least 1"), "{}", err);
+}
+
+#[test]
+fn test_test_command_reports_results() {
+    let (status, out, _) = pyhc(&["test", "tests/testing/passing"], "");
+    assert_eq!(status, 0, "{}", out);
+    assert!(out.contains("PASS  tests/testing/passing/test_echo.pyh::<module>"), "{}", out);
+    assert!(out.contains("PASS  tests/testing/passing/test_stack.pyh::test_reads_input"), "{}", out);
+    assert!(out.contains("\n5 passed, 0 failed, 0 errors in "), "{}", out);
+
+    let junit = std::env::temp_dir().join(format!("pyhc-junit-{}.xml", std::process::id()));
+    let junit_arg = junit.to_str().unwrap();
+    let (status, out, _) = pyhc(&["test", "tests/testing/failing", "--junit", junit_arg], "");
+    assert_eq!(status, 1);
+    assert!(out.contains("FAIL  tests/testing/failing/test_failing.pyh::test_add ("), "{}", out);
+    let location = "tests/testing/failing/test_failing.pyh:5:3: AssertionError: 2 + 2 should be 4\n";
+    assert!(out.contains(location), "{}", out);
+    assert!(out.contains("ERROR tests/testing/failing/test_failing.pyh::test_crash ("), "{}", out);
+    assert!(out.contains("output differs from .out at line 1"), "{}", out);
+    assert!(out.contains("\n1 passed, 2 failed, 1 error in "), "{}", out);
+
+    let xml = std::fs::read_to_string(&junit).unwrap();
+    let _ = std::fs::remove_file(&junit);
+    assert!(xml.contains("tests=\"4\" failures=\"2\" errors=\"1\""), "{}", xml);
+    assert!(xml.contains("<system-out>about to divide\n</system-out>"), "{}", xml);
+
+    let (status, _, err) = pyhc(&["test", "tests/testing/missing"], "");
+    assert_eq!(status, pyhyeon::EXIT_NO_INPUT);
+    assert!(err.contains("cannot read test paths"), "{}", err);
+}
+
+#[test]
+fn test_judge_verdicts() {
+    let judge = |solution: &str| {
+        let path = format!("tests/judge/{}.pyh", solution);
+        pyhc(&["judge", &path, "tests/judge/sum", "-t", "0.2", "-m", "16"], "")
+    };
+    let (status, out, _) = judge("sum_ok");
+    assert_eq!(status, 0, "{}", out);
+    assert!(out.starts_with("Test  Verdict       Time     Memory\n1     AC  "), "{}", out);
+    assert!(out.contains("\n\nAC  3/3 passed  (max time "), "{}", out);
+    // judge는 할당을 세기 시작하므로 메모리 사용량을 보여 줌
+    assert!(out.contains(", max memory ") && !out.contains("max memory -"), "{}", out);
+
+    let verdict = |solution: &str| {
+        let (status, out, _) = judge(solution);
+        let rows: Vec<String> = out
+            .lines()
+            .skip(1)
+            .take(3)
+            .map(|row| row.split_whitespace().take(2).collect::<Vec<_>>().join(" "))
+            .collect();
+        let summary = out.lines().last().unwrap_or("").to_string();
+        (status, rows.join(", "), summary)
+    };
+    let (status, rows, summary) = verdict("sum_wa");
+    assert_eq!((status, rows.as_str()), (1, "1 AC, 2 WA, 10 AC"));
+    assert!(summary.starts_with("WA  2/3 passed"), "{}", summary);
+    assert_eq!(verdict("sum_tle").1, "1 AC, 2 TLE, 10 AC");
+    assert_eq!(verdict("sum_mle").1, "1 AC, 2 AC, 10 MLE");
+    assert_eq!(verdict("sum_re").1, "1 AC, 2 RE, 10 WA");
+    let (status, rows, _) = verdict("sum_ce");
+    assert_eq!((status, rows.as_str()), (pyhyeon::EXIT_COMPILE_ERROR, "1 CE, 2 CE, 10 CE"));
+
+    let (status, _, err) = pyhc(&["judge", "tests/judge/sum_ok.pyh", "tests/judge"], "");
+    assert_eq!(status, pyhyeon::EXIT_NO_INPUT);
+    assert!(err.contains("no NAME.in/NAME.out pairs"), "{}", err);
+}
+
+#[test]
+fn test_buffered_output_and_bulk_stdin() {
+    let src = "import sys\nn = int(input('n? '))\nrows = sys.stdin.read().split('\\n')\nfor i in range(n):\n  a, b = map(int, rows[i].split())\n  print(a + b)\nsys.stdout.write('done')\nsys.exit(3)\n";
+    let (status, out, _) = pyhc(&["run", "-c", src], "2\n1 2\n30 40\n");
+    assert_eq!((status, out.as_str()), (3, "n? 3\n70\ndone"));
+
+    // 버퍼에 남은 출력도 에러 메시지 전에 모두 나감
+    let lines: String = (0..5000).map(|i| format!("{}\n", i)).collect();
+    let src = "n = 0\nwhile n < 5000:\n  print(input())\n  n += 1\nprint(1 // 0)\n";
+    let (status, out, err) = pyhc(&["run", "-c", src], &lines);
+    assert_eq!((status, out), (pyhyeon::EXIT_RUNTIME_ERROR, lines));
+    assert!(err.contains("ZeroDivision"), "{}", err);
+}
+
+#[test]
+fn test_output_is_flushed_before_reading_a_pipe() {
+    use std::io::{BufRead, BufReader};
+
+    // 인터랙티브 문제처럼 질문을 읽어야 답을 보내는 상대: 입력 전에 출력이 나오지 않으면 멈춤
+    let src = "for _ in range(3):\n  print('? ' + str(int(input('> ')) * 2))\nprint('!')\n";
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pyhc"))
+        .args(["run", "-c", src])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to start pyhc");
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut seen = vec![];
+    for n in 1..=3 {
+        writeln!(stdin, "{}", n).unwrap();
+        stdin.flush().unwrap();
+        let mut line = String::new();
+        stdout.read_line(&mut line).unwrap();
+        seen.push(line);
+    }
+    drop(stdin);
+    let mut rest = String::new();
+    stdout.read_line(&mut rest).unwrap();
+    assert!(child.wait().unwrap().success());
+    assert_eq!(seen, ["> ? 2\n", "> ? 4\n", "> ? 6\n"]);
+    assert_eq!(rest, "!\n");
+}
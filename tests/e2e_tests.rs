@@ -33,26 +33,31 @@ fn get_test_programs() -> Vec<PathBuf> {
 
 fn run_test_program_with_input(path: &PathBuf, inputs: &[&str]) -> Result<String, String> {
     let source = fs::read_to_string(path).map_err(|e| e.to_string())?;
-    let path_str = path.to_string_lossy().to_string();
+    run_source_with_input(&path.to_string_lossy(), &source, inputs)
+}
 
+fn run_source_with_input(path_str: &str, source: &str, inputs: &[&str]) -> Result<String, String> {
     // Parse
-    let program = pyhyeon::parse_source(&source).map_err(|diagnostics| {
+    let program = pyhyeon::parse_source(source).map_err(|diagnostics| {
         format!(
             "Parse error in {}: {}",
             path_str,
             diagnostics
                 .iter()
-                .map(|d| d.format(&path_str, &source, "Parse Error", 0))
+                .map(|d| d.format(path_str, source))
                 .collect::<String>()
         )
     })?;
 
     // Analyze
-    pyhyeon::analyze(&program).map_err(|diag| {
+    pyhyeon::analyze(&program).map_err(|diagnostics| {
         format!(
             "Semantic error in {}: {}",
             path_str,
-            diag.format(&path_str, &source, "Semantic Error", 0)
+            diagnostics
+                .iter()
+                .map(|d| d.format(path_str, source))
+                .collect::<String>()
         )
     })?;
 
@@ -310,7 +315,11 @@ macro_rules! assert_program {
 // Basic Tests - 기본 기능 (산술, 분기, 재귀, short-circuit 등)
 // ============================================================================
 
-assert_program!(test_basics_arithmetic, "basics/arith.pyh", expects = ["7"]);
+assert_program!(
+    test_basics_arithmetic,
+    "basics/arith.pyh",
+    expects = ["7", "-9223372036854775808 True"]
+);
 assert_program!(
     test_basics_branching,
     "basics/branch.pyh",
@@ -333,6 +342,69 @@ assert_program!(
     expects = ["3.14", "42", "2.5"]
 );
 assert_program!(test_basics_edge_cases, "basics/edge_case_comprehensive.pyh");
+assert_program!(
+    test_basics_operators,
+    "basics/operators.pyh",
+    expects = [
+        "3",
+        "1024 512 -4 0.5 1.4142135623730951 -8",
+        "2 7 5 -6 1024 -4 False 3",
+        "24 3 True",
+        "8.0",
+        "30",
+        "[10, 25, 30] 1",
+        "{'a': 7}",
+        "10",
+        "ababab",
+        "5"
+    ]
+);
+assert_program!(
+    test_basics_membership,
+    "basics/membership.pyh",
+    expects = [
+        "True", "True", "True", "False", "True", "True", "True", "True", "False", "True",
+        "True", "True", "True", "False", "True", "True", "True", "1", "False", "2", "True"
+    ]
+);
+assert_program!(
+    test_basics_assignment_forms,
+    "basics/assignment_forms.pyh",
+    expects = [
+        "neg zero pos",
+        "10 4",
+        "2 10 [3, 1]",
+        "([3, 4, 3], 0)",
+        "20",
+        "[1] [1] True",
+        "7 7 [0, 7, 0]",
+        "1 [2, 3, 4]",
+        "[1, 2] 3",
+        "h ['e', 'l', 'l'] o",
+        "1 [] 2",
+        "0 [1, 2]",
+        "(9, 3, 5)",
+        "1 a",
+        "2 b",
+        "0 x [1, 2]",
+        "1 y []",
+        "[1, 2] True",
+        "[1, 2, 1, 2]",
+        "[0, 5] [[0, 5], [1]]",
+        "[3]",
+        "[1, 2] True",
+        "[2, 3, 4]",
+        "[2, 3] True",
+        "t{1, 3, 5} True",
+        "abc ab",
+        "2 1"
+    ]
+);
+assert_program!(
+    test_basics_line_continuation,
+    "basics/line_continuation.pyh",
+    expects = ["3 [7, 8, 9]", "pyhyeon (1, 2)", "3 0 2", "6", "A", "B"]
+);
 
 // ============================================================================
 // Loop Tests - 반복문 (for, while, break, continue, 중첩)
@@ -391,6 +463,55 @@ assert_program!(
     "collections/collections_complex.pyh",
     expects = ["[[1, 2], [3, 4]]", "Alice", "Bob"]
 );
+assert_program!(
+    test_collections_sorting,
+    "collections/sorting.pyh",
+    expects = [
+        "['Apple', 'banana', 'cherry', 'date', 'fig']",
+        "['fig', 'date', 'Apple', 'banana', 'cherry']",
+        "['banana', 'cherry', 'Apple', 'date', 'fig']",
+        "['Apple', 'banana', 'cherry', 'date', 'fig']",
+        "[-7.25, 1, 2, 3.5]",
+        "[3.5, 2, 1, -7.25]",
+        "[(1, 'c'), (1, 'z'), (2, 'a'), (2, 'b')]",
+        "[(2, 'a'), (2, 'b'), (1, 'c'), (1, 'z')]",
+        "1 2 3",
+        "['al', 'bo', 'cy']"
+    ]
+);
+assert_program!(
+    test_collections_equality,
+    "collections/equality.pyh",
+    expects = [
+        "True True True",
+        "True True",
+        "True False",
+        "True True True",
+        "True True True True False",
+        "True True",
+        "1 1 True True False",
+        "[[1], (2, 3)]",
+        "True"
+    ]
+);
+assert_program!(
+    test_collections_container_ops,
+    "collections/container_ops.pyh",
+    expects = [
+        "[1, 2, 3] [1, 2] [0, 0, 0] ['x', 'x'] [] []",
+        "(1, 2, 3) (0, 0) ('a', 1, 'a', 1, 'a', 1)",
+        "[[0, 0], [0, 0]]",
+        "[1, 2, 3, 4] [3] [1, 2] [1, 2, 4]",
+        "True True False True True False",
+        "True False [1, 2, 4]",
+        "[2, 3]",
+        "[2, 3, 4, 5, 6]",
+        "True 4",
+        "t{1, 2, 3, 5} t{1, 5} t{1, 5} t{3, 5, 9}",
+        "True True True",
+        "0 t{1, 5, 7}"
+    ]
+);
 assert_program!(
     test_collections_list_comprehension_alt,
     "collections/list_comprehension_alt.pyh",
@@ -413,6 +534,21 @@ assert_program!(
     "classes/method_chaining.pyh",
     expects = ["Hello World"]
 );
+assert_program!(
+    test_classes_attributes,
+    "classes/class_attributes.pyh",
+    expects = ["Kim", "150", "True", "joint", "2", "1"]
+);
+assert_program!(
+    test_classes_body_attributes,
+    "classes/class_body_attributes.pyh",
+    expects = ["2 c11/2 c12/2", "own1/2 c1"]
+);
+assert_program!(
+    test_classes_instance_operators,
+    "classes/instance_operators.pyh",
+    expects = ["[1, 2, 3]", "1", "True", "truthy", "3", "True"]
+);
 
 // ============================================================================
 // I/O Tests - 입출력 (input/output)
@@ -453,6 +589,13 @@ assert_program!(
     expects = ["Hello, Alice", "Hello, Bob", "Hello, Charlie"]
 );
 
+assert_program!(
+    test_io_stdin_bulk,
+    "io/stdin_bulk.pyh",
+    inputs = ["2", "1 2", "3 4"],
+    expects = ["pairs: 2\ntotal: 14\nrest: ''\n"]
+);
+
 // ============================================================================
 // String Tests - 문자열 조작
 // ============================================================================
@@ -467,8 +610,136 @@ assert_program!(
     "strings/string_advanced.pyh",
     expects = ["42", "5", "hello world"]
 );
+assert_program!(
+    test_strings_literals,
+    "strings/literals.pyh",
+    expects = [
+        "255 15 10 255 1000000 240",
+        "1000000000.0 0.5 1.0 0.0025 1000.0005 116.0",
+        "== menu ==",
+        "  \"quoted\" and 'single'",
+        "end",
+        "['a', 'bc'] 2",
+        "\\d+\\.\\d* 8",
+        "C:\\new\\table raw\\t",
+        "Abé😀A",
+        "5 True • item — done",
+        "10000 → 20",
+    ]
+);
 assert_program!(
     test_strings_methods,
     "strings/string_methods.pyh",
     expects = ["HELLO WORLD", "hello world", "spaces"]
 );
+assert_program!(
+    test_strings_indexing,
+    "strings/string_indexing.pyh",
+    expects = [
+        "h é o",
+        "['h', 'é', 'l', 'l', 'o'] 5",
+        "['a', 'b', 'c'] ['a', 'b,,c'] ['a b', 'c']",
+        "['x', 'y', 'z'] hi hi hi",
+        "True False True True True",
+        "Hello World Hello",
+        "[  ab ] ab-- ..ab -00042",
+        "2 3 4",
+        "name Kim=Lee ('a.b', '.', 'c')"
+    ]
+);
+assert_program!(
+    test_strings_formatting,
+    "strings/string_formatting.pyh",
+    expects = [
+        "Kim: 91.26",
+        "pi    |     3.1",
+        "Kim scored 91.3 (A)",
+        "Kim has 42 points (87.5%)",
+        "  3.50   |"
+    ]
+);
+
+// ============================================================================
+// Builtin Tests - 표준 builtin 함수 (min/max/sum/sorted, enumerate/zip, type 등)
+// ============================================================================
+
+assert_program!(
+    test_builtins_standard,
+    "builtins/builtins.pyh",
+    expects = [
+        "1 3 2 b",
+        "6 3.5 20",
+        "[1, 2, 3] ['a', 'b', 'c'] [3, 1, 2]",
+        "[2, 1, 3] [7, 4, 1] ['c', 'b', 'a']",
+        "(1, 'a')",
+        "[(1, 'a'), (2, 'b')]",
+        "True False False True",
+        "2 4 3.14 1200",
+        "2.67 0.12 (-4, 1) (-4, -1) -4 1 0.5",
+        "1024 24 -4 (3, 1)",
+        "A 65 0xff -0b101",
+        "<class 'int'> <class 'str'> True True False",
+        "'hi' True True",
+        "(1, 2) ('a', 'b') {'a': 1} [] [0, 1, 2]",
+        "1 2 done",
+        "<class 'list_iterator'> <class 'list_iterator'> False True <list_iterator object>",
+        "10 [1, 2, 3] 5 True True",
+        "True 1",
+        "43",
+        "(1, 3) [(2, 4)] []",
+        "<class 'enumerate'> <class 'zip'> <class 'type'> <class 'NoneType'> <class 'function'>",
+        "[1, 2, 3.5]"
+    ]
+);
+assert_program!(
+    test_builtins_repr,
+    "builtins/repr.pyh",
+    expects = [
+        "['a', \"it's\"] ('x',) {'k': 'v'} {'s'}",
+        "1.0 2.5 1e+20 1e-05 0.0001 -0.0 1e+16 1000000000000000.0",
+        "inf -inf nan",
+        "'a\\tb' 1.0 q ['q']",
+        "<class 'Point'> <Point object> <class 'Point'>",
+        "[1, [...]] {'self': {...}} [[1, [...]]]",
+        "1.0 [1.5, 'a'] 'b'",
+        "<function square> [<function square>] True"
+    ]
+);
+assert_program!(
+    test_builtins_sys_module,
+    "builtins/sys_module.pyh",
+    expects = ["<module 'sys'>", "stop at 5"]
+);
+
+assert_program!(
+    test_builtins_builtin_values,
+    "builtins/builtin_values.pyh",
+    expects = [
+        "['a', 'bb', 'ccc']\n['ccc', 'bb', 'a']\n[1, 2, 3]\n['x']",
+        "<built-in function abs>",
+        "True True True True False"
+    ]
+);
+
+// ============================================================================
+// Runtime Error Tests - 실행 중 에러 메시지
+// ============================================================================
+
+#[test]
+fn test_user_instance_operand_errors() {
+    // __lt__ 등이 없는 사용자 클래스: VM이 죽지 않고 클래스 이름으로 TypeError
+    let prelude = "class P:\n  def __init__(self):\n    self.x = 1\ndef f(p):\n  return ";
+    let cases = [
+        ("sorted([p, p])", "'<' not supported between instances of 'P' and 'P'"),
+        ("p < p", "'<' not supported between instances of 'P' and 'P'"),
+        ("max(p, p)", "not supported between instances of 'P' and 'P'"),
+        ("p in p", "argument of type 'P' is not iterable"),
+        ("p ** 2", "unsupported operand types for **: 'P' and 'int'"),
+        ("p & 1", "unsupported operand types for &: 'P' and 'int'"),
+    ];
+    for (expr, message) in cases {
+        let source = format!("{}{}\nf(P())\n", prelude, expr);
+        let err = run_source_with_input("<test>", &source, &[]).unwrap_err();
+        assert!(err.contains("TypeError") && err.contains(message), "{}: {}", expr, err);
+    }
+}
@@ -0,0 +1,363 @@
+use pyhyeon::runtime_io::BufferIo;
+use pyhyeon::vm::native::{NativeClassBuilder, NativeRegistry, arg};
+use pyhyeon::vm::{Arity, HostRequest, HostValue, IntoValue, NativeResult, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// 임베딩 테스트: 호스트가 등록한 네이티브 함수/클래스를 스크립트에서 호출합니다.
+fn natives() -> NativeRegistry {
+    struct Account {
+        balance: i64,
+        history: Vec<i64>,
+    }
+
+    let mut natives = NativeRegistry::new();
+    natives
+        .register_fn("clamp", Arity::Exact(3), |args| {
+            let (x, lo, hi): (f64, f64, f64) = (arg(args, 0)?, arg(args, 1)?, arg(args, 2)?);
+            Ok(x.clamp(lo, hi).into_value())
+        })
+        .unwrap()
+        .register_fn("word_counts", Arity::Exact(1), |args| {
+            let words: Vec<String> = arg(args, 0)?;
+            let mut counts: HashMap<String, i64> = HashMap::new();
+            for word in words {
+                *counts.entry(word).or_default() += 1;
+            }
+            Ok(counts.into_value())
+        })
+        .unwrap()
+        .register_fn("total", Arity::Exact(1), |args| {
+            let prices: HashMap<String, i64> = arg(args, 0)?;
+            Ok(prices.values().sum::<i64>().into_value())
+        })
+        .unwrap()
+        .register_class(
+            NativeClassBuilder::new("Account", Arity::Range(0, 1), |args| {
+                let balance: Option<i64> = if args.is_empty() { None } else { arg(args, 0)? };
+                Ok(Account {
+                    balance: balance.unwrap_or(0),
+                    history: Vec::new(),
+                })
+            })
+            .method("deposit", Arity::Exact(1), |account: &mut Account, args| {
+                let amount: i64 = arg(args, 0)?;
+                account.balance += amount;
+                account.history.push(amount);
+                Ok(Value::None)
+            })
+            .method("balance", Arity::Exact(0), |account: &mut Account, _| {
+                Ok(account.balance.into_value())
+            })
+            .method("history", Arity::Exact(0), |account: &mut Account, _| {
+                Ok(account.history.clone().into_value())
+            }),
+        )
+        .unwrap();
+    natives
+}
+
+fn run(source: &str) -> Result<String, String> {
+    let natives = natives();
+    let program = pyhyeon::parse_source(source).map_err(|d| d[0].message.clone())?;
+    pyhyeon::analyze_with_natives(&program, &natives).map_err(|d| d[0].message.clone())?;
+    let mut module = pyhyeon::compile_with_natives(&program, &natives);
+    let mut vm = pyhyeon::Vm::with_natives(natives);
+    let mut io = BufferIo::new();
+    vm.run_with_io(&mut module, &mut io).map_err(|e| e.message)?;
+    Ok(io.take_output())
+}
+
+#[test]
+fn test_native_functions_and_conversions() {
+    let output = run(concat!(
+        "print(clamp(15, 0, 10), clamp(-0.5, 0, 1))\n",
+        "counts = word_counts(['a', 'b', 'a'])\n",
+        "print(counts['a'], counts['b'])\n",
+        "print(total({'x': 3, 'y': 4}))\n",
+        "def twice(x):\n",
+        "  return clamp(x * 2, 0, 100)\n",
+        "print(twice(70))\n",
+    ))
+    .unwrap();
+    assert_eq!(output, "10.0 0.0\n2 1\n7\n100.0\n");
+}
+
+#[test]
+fn test_native_class_instances() {
+    let output = run(concat!(
+        "acc = Account(10)\n",
+        "acc.deposit(5)\n",
+        "acc.deposit(7)\n",
+        "print(acc.balance(), acc.history(), Account().balance())\n",
+        "print(acc)\n",
+    ))
+    .unwrap();
+    assert_eq!(output, "22 [5, 7] 0\n<Account object>\n");
+}
+
+#[test]
+fn test_natives_are_checked_before_running() {
+    assert_eq!(
+        run("clamp(1, 2)").unwrap_err(),
+        "ArityError: clamp() takes 3 argument(s) but 2 given"
+    );
+    assert_eq!(
+        run("Account(1).deposit()").unwrap_err(),
+        "ArityError: Account.deposit() takes 1 argument(s) but 0 given"
+    );
+    assert_eq!(
+        run("Account().withdraw(1)").unwrap_err(),
+        "AttributeError: 'Account' object has no attribute 'withdraw'"
+    );
+    assert_eq!(
+        run("clamp(1, 2, lo=3)").unwrap_err(),
+        "TypeError: clamp() got an unexpected keyword argument 'lo'"
+    );
+}
+
+#[test]
+fn test_native_conversion_errors_are_runtime_errors() {
+    assert_eq!(
+        run("print(clamp('a', 0, 1))").unwrap_err(),
+        "TypeError: expected float, got 'str'"
+    );
+    assert_eq!(
+        run("word_counts([1])").unwrap_err(),
+        "TypeError: expected str, got 'int'"
+    );
+}
+
+#[test]
+fn test_natives_are_unknown_without_registration() {
+    let program = pyhyeon::parse_source("clamp(1, 2, 3)").unwrap();
+    assert!(pyhyeon::analyze(&program).is_err());
+}
+
+#[test]
+fn test_interpreter_keeps_state_and_natives() {
+    let mut interp = pyhyeon::Interpreter::with_io(BufferIo::new()).with_natives(natives());
+    interp.exec("acc = Account(1)\ndef add(n):\n  acc.deposit(n)\n  return acc.balance()\n").unwrap();
+    assert_eq!(interp.call("add", vec![Value::Int(4)]).unwrap(), Value::Int(5));
+    assert_eq!(interp.eval("add(5)").unwrap(), Value::Int(10));
+    // 값으로 호출해도 인자 개수를 검사하고, 실패한 뒤에도 계속 쓸 수 있음
+    for args in [vec![], vec![Value::Int(1), Value::Int(2)]] {
+        let error = interp.call("add", args.clone()).unwrap_err();
+        assert!(
+            error.to_string().contains(&format!("add() takes 1 argument(s) but {} given", args.len())),
+            "{error}"
+        );
+    }
+    let error = interp.exec("f = add\nf(1, 2)").unwrap_err();
+    assert!(error.to_string().contains("add() takes 1 argument(s) but 2 given"), "{error}");
+    let error = interp.exec("print(list(map(lambda a, b: a, [1])))").unwrap_err();
+    assert!(error.to_string().contains("<lambda>() takes 2 argument(s) but 1 given"), "{error}");
+    assert_eq!(interp.call("add", vec![Value::Int(0)]).unwrap(), Value::Int(10));
+    interp.set_global("limit", 3);
+    interp.exec("print(clamp(add(0), 0, limit))").unwrap();
+    assert_eq!(interp.io_mut().drain_output(), "3.0\n");
+
+    let error = interp.exec("clamp(1)").unwrap_err();
+    assert_eq!(error.diagnostics()[0].message, "ArityError: clamp() takes 3 argument(s) but 1 given");
+}
+
+#[test]
+fn test_natives_are_values_and_user_definitions_shadow_them() {
+    let output = run(concat!(
+        "f = clamp\n",
+        "A = Account\n",
+        "print(f(15, 0, 10), A(5).balance(), list(map(total, [{'x': 1}, {'y': 2}])))\n",
+        "print(clamp, Account, type(clamp), type(Account), f == clamp)\n",
+    ))
+    .unwrap();
+    assert_eq!(
+        output,
+        "10.0 5 [1, 2]\n<built-in function clamp> <class 'Account'> <class 'builtin_function_or_method'> <class 'type'> True\n"
+    );
+
+    // 사용자 정의가 네이티브보다 우선: 함수(뒤에 정의해도), 변수, 매개변수, 클래스
+    let output = run(concat!(
+        "def early():\n",
+        "  return clamp(1, 2, 3)\n",
+        "def clamp(x, lo, hi):\n",
+        "  return 'mine'\n",
+        "print(early(), clamp(1, 2, 3), [clamp][0](1, 2, 3))\n",
+        "total = lambda prices: -1\n",
+        "print(total({'x': 3}))\n",
+        "def apply(word_counts):\n",
+        "  return word_counts(2)\n",
+        "print(apply(abs))\n",
+        "class Account:\n",
+        "  def __init__(self):\n",
+        "    self.owner = 'me'\n",
+        "print(Account().owner)\n",
+    ))
+    .unwrap();
+    assert_eq!(output, "mine mine mine\n-1\n2\nme\n");
+
+    // 앞선 exec에서 정의한 함수도 네이티브를 가림 (호출만 한 이름은 가리지 않음)
+    let mut interp = pyhyeon::Interpreter::with_io(BufferIo::new()).with_natives(natives());
+    assert_eq!(interp.eval("clamp(15, 0, 10)").unwrap(), Value::Float(10.0));
+    assert!(interp.exec("clamp(1)").is_err());
+    interp.exec("def clamp(x):\n  return x * 2\n").unwrap();
+    assert_eq!(interp.eval("clamp(4)").unwrap(), Value::Int(8));
+
+    // 값으로 꺼낸 호스트 요청 함수도 VM을 멈춤
+    let mut interp = pyhyeon::Interpreter::with_io(BufferIo::new()).with_natives(host_natives());
+    interp.exec("wait = sleep\nprint(wait(3))").unwrap();
+    assert_eq!(interp.pending_request().unwrap().kind, "sleep");
+    interp.resume(Ok(Value::Int(7))).unwrap();
+    assert_eq!(interp.io_mut().drain_output(), "7\n");
+}
+
+fn host_natives() -> NativeRegistry {
+    let mut natives = NativeRegistry::new();
+    natives
+        .register_host_request("sleep", Arity::Exact(1))
+        .unwrap()
+        .register_host_fn("fetch", Arity::Exact(1), |args| {
+            let path: String = arg(args, 0)?;
+            // 캐시에 있으면 바로 반환, 없으면 호스트에 요청
+            if path == "cached" {
+                return Ok(NativeResult::Ready("hit".into_value()));
+            }
+            let mut payload = BTreeMap::new();
+            payload.insert("path".to_string(), HostValue::Str(path));
+            Ok(NativeResult::pending("fetch", HostValue::Dict(payload)))
+        })
+        .unwrap();
+    natives
+}
+
+#[test]
+fn test_host_requests_suspend_and_resume() {
+    let mut interp = pyhyeon::Interpreter::with_io(BufferIo::new()).with_natives(host_natives());
+    interp
+        .exec("total = 0\nfor i in range(2):\n  total += sleep(i * 10)\nprint(total, fetch('cached'), fetch('a.txt'))")
+        .unwrap();
+
+    let mut requests = vec![];
+    while let Some(request) = interp.pending_request() {
+        requests.push(request.clone());
+        let response = match request.kind.as_str() {
+            "sleep" => Value::Int(1),
+            _ => "body".into_value(),
+        };
+        interp.resume(Ok(response)).unwrap();
+    }
+    assert_eq!(
+        requests,
+        [
+            HostRequest { kind: "sleep".into(), payload: HostValue::List(vec![HostValue::Int(0)]) },
+            HostRequest { kind: "sleep".into(), payload: HostValue::List(vec![HostValue::Int(10)]) },
+            HostRequest {
+                kind: "fetch".into(),
+                payload: HostValue::Dict([("path".to_string(), HostValue::Str("a.txt".into()))].into()),
+            },
+        ]
+    );
+    assert_eq!(interp.io_mut().drain_output(), "2 hit body\n");
+
+    // eval이 멈췄다가 끝나면 그 값을 돌려받음
+    assert_eq!(interp.eval("sleep(5) + 1").unwrap(), Value::None);
+    assert_eq!(interp.resume(Ok(Value::Int(41))).unwrap(), Value::Int(42));
+    assert_eq!(interp.resume(Ok(Value::Int(0))).unwrap(), Value::None);
+}
+
+#[test]
+fn test_host_errors_and_builtin_contexts() {
+    let mut interp = pyhyeon::Interpreter::with_io(BufferIo::new()).with_natives(host_natives());
+    interp.exec("print(fetch('missing.txt'))").unwrap();
+    let error = interp.resume(Err("FileNotFoundError: missing.txt".into())).unwrap_err();
+    assert_eq!(error.to_string(), "Runtime Error: FileNotFoundError: missing.txt");
+    assert!(interp.pending_request().is_none());
+
+    let error = interp.exec("sorted([2, 1], key=lambda x: sleep(x))").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Runtime Error: cannot wait for host request 'sleep' in builtin context"
+    );
+}
+
+#[test]
+fn test_snapshot_forks_a_suspended_program() {
+    let source = "xs = []\nfor i in range(3):\n  xs.append(sleep(i))\nprint(xs, input())\n";
+    let natives = host_natives();
+    let program = pyhyeon::parse_source(source).unwrap();
+    pyhyeon::analyze_with_natives(&program, &natives).unwrap();
+    let mut module = pyhyeon::compile_with_natives(&program, &natives);
+    let mut vm = pyhyeon::Vm::with_natives(natives.clone());
+    vm.run_with_io(&mut module, &mut BufferIo::new()).unwrap();
+    vm.resume_host(Ok(Value::Int(7))).unwrap();
+    vm.run_with_io(&mut module, &mut BufferIo::new()).unwrap();
+    // 두 번째 sleep에서 멈춘 상태를 저장하고 서로 다른 응답으로 이어감
+    let bytes = vm.snapshot(&module).unwrap();
+
+    let mut outputs = vec![];
+    for answer in [1, 2] {
+        let (mut vm, mut module) = pyhyeon::Vm::restore(&bytes).unwrap();
+        vm.natives = natives.clone();
+        assert_eq!(
+            vm.host_request(),
+            Some(&HostRequest { kind: "sleep".into(), payload: HostValue::List(vec![HostValue::Int(1)]) })
+        );
+        let mut io = BufferIo::new();
+        io.push_input_line(format!("in{}", answer));
+        vm.resume_host(Ok(Value::Int(answer))).unwrap();
+        vm.run_with_io(&mut module, &mut io).unwrap();
+        vm.resume_host(Ok(Value::Int(0))).unwrap();
+        vm.run_with_io(&mut module, &mut io).unwrap();
+        outputs.push(io.take_output());
+    }
+    assert_eq!(outputs, ["[7, 1, 0] in1\n", "[7, 2, 0] in2\n"]);
+}
+
+#[test]
+fn test_input_without_queued_lines_is_a_host_request() {
+    let source = "total = 0\nfor _ in range(2):\n  total += int(input(\"> \"))\nprint(total, input())\n";
+    let program = pyhyeon::parse_source(source).unwrap();
+    let mut module = pyhyeon::compile_to_module(&program);
+    let mut vm = pyhyeon::Vm::new();
+    let mut io = BufferIo::new();
+
+    let mut requests = vec![];
+    for line in [" 3\n", "4", "done"] {
+        vm.run_with_io(&mut module, &mut io).unwrap();
+        assert!(vm.is_waiting_for_input());
+        requests.push(vm.host_request().cloned().unwrap());
+        // 호스트의 응답도 input()처럼 앞뒤 공백이 빠짐
+        vm.resume_host(Ok(line.into_value())).unwrap();
+    }
+    vm.run_with_io(&mut module, &mut io).unwrap();
+    assert_eq!(
+        requests,
+        [
+            HostRequest { kind: HostRequest::INPUT.into(), payload: HostValue::Str("> ".into()) },
+            HostRequest { kind: HostRequest::INPUT.into(), payload: HostValue::Str("> ".into()) },
+            HostRequest { kind: HostRequest::INPUT.into(), payload: HostValue::None },
+        ]
+    );
+    // 프롬프트는 기다릴 때마다 한 번씩 출력
+    assert_eq!(io.take_output(), "> > 7 done\n");
+
+    let mut vm = pyhyeon::Vm::new();
+    let mut module = pyhyeon::compile_to_module(&pyhyeon::parse_source("x = input()\n").unwrap());
+    vm.run_with_io(&mut module, &mut BufferIo::new()).unwrap();
+    vm.resume_host(Ok(Value::Int(1))).unwrap();
+    assert_eq!(
+        vm.run_with_io(&mut module, &mut BufferIo::new()).unwrap_err().message,
+        "input() expects a line of text from the host, got 'int'"
+    );
+}
+
+#[test]
+fn test_native_objects_cannot_be_snapshotted() {
+    let natives = natives();
+    let program = pyhyeon::parse_source("acc = Account(1)\n").unwrap();
+    let mut module = pyhyeon::compile_with_natives(&program, &natives);
+    let mut vm = pyhyeon::Vm::with_natives(natives);
+    vm.run_with_io(&mut module, &mut BufferIo::new()).unwrap();
+    assert_eq!(
+        vm.snapshot(&module).unwrap_err().message,
+        "cannot snapshot native 'Account' object"
+    );
+}
@@ -180,11 +180,12 @@ for double in map(lambda x: x * 2, nums):
       const diags: any[] = (wasmAnalyze as any)(src) || []
       const markers = diags.map((d: any) => ({
         message: d.message,
+        code: d.code,
         startLineNumber: (d.start_line ?? 0) + 1,
         startColumn: (d.start_char ?? 0) + 1,
         endLineNumber: (d.end_line ?? 0) + 1,
         endColumn: (d.end_char ?? 0) + 1,
-        severity: monaco.MarkerSeverity.Error,
+        severity: (d.severity as monaco.MarkerSeverity) ?? monaco.MarkerSeverity.Error,
       }))
       monaco.editor.setModelMarkers(model, 'pyh', markers)
     } catch (e) {
@@ -265,7 +266,7 @@ for double in map(lambda x: x * 2, nums):
       monaco.languages.setMonarchTokensProvider('pyh', {
         // 현재 pyhyeon에 구현된 키워드들
         keywords: [
-          'if', 'elif', 'else', 'while', 'for', 'in', 'def', 'return',
+          'if', 'elif', 'else', 'while', 'for', 'in', 'is', 'def', 'return',
           'and', 'or', 'not', 'class', 'break', 'continue', 'pass'
         ],
 
@@ -276,12 +277,14 @@ for double in map(lambda x: x * 2, nums):
         special: ['self'],
 
         // 내장 함수들
-        builtins: ['print', 'input', 'int', 'bool', 'str', 'len', 'range', 'list', 'dict', 'float'],
+        builtins: ['print', 'input', 'int', 'bool', 'str', 'len', 'range', 'list', 'dict', 'float', 'format', 'tuple', 'min', 'max', 'sum', 'sorted', 'reversed', 'enumerate', 'zip', 'any', 'all', 'abs', 'round', 'pow', 'divmod', 'chr', 'ord', 'hex', 'bin', 'type', 'repr', 'hash', 'id', 'isinstance', 'iter', 'next'],
         
         // 연산자들
         operators: [
           '=', '==', '!=', '<', '<=', '>', '>=',
-          '+', '-', '*', '//', '%'
+          '+', '-', '*', '**', '/', '//', '%',
+          '&', '|', '^', '~', '<<', '>>',
+          '+=', '-=', '*=', '/=', '//=', '%=', '**=', '&=', '|=', '^=', '<<=', '>>='
         ],
         
         // 구분자들
@@ -295,6 +298,7 @@ for double in map(lambda x: x * 2, nums):
             // 문자열 리터럴 (큰따옴표, 작은따옴표)
             [/"([^"\\]|\\.)*$/, 'string.invalid'],  // 닫히지 않은 문자열
             [/'([^'\\]|\\.)*$/, 'string.invalid'],  // 닫히지 않은 문자열
+            [/[fF](?=["'])/, 'string'],  // f-string 접두사
             [/"/, 'string', '@string_double'],
             [/'/, 'string', '@string_single'],
             
@@ -319,7 +323,7 @@ for double in map(lambda x: x * 2, nums):
             }],
             
             // 연산자
-            [/==|!=|<=|>=|\/\/|[+\-*%<>=]/, 'operator'],
+            [/\*\*=?|\/\/=?|<<=?|>>=?|[+\-*\/%&|^=!<>]=|[+\-*\/%&|^~<>=]/, 'operator'],
             
             // 구분자
             [/[()\[\]{}\:,;.]/, 'delimiter'],
@@ -1,12 +1,67 @@
 import { useReducer, useCallback } from 'react'
-import { start_program, provide_input, stop_program } from '@pkg/pyhyeon'
+import {
+  start_program,
+  provide_input,
+  resume_host,
+  fail_host,
+  stop_program,
+  snapshot_program,
+  restore_program,
+  start_stepping,
+  step_line,
+  step_instruction
+} from '@pkg/pyhyeon'
 
-type VmState = 'idle' | 'running' | 'waiting_for_input' | 'finished' | 'error'
+type VmState = 'idle' | 'running' | 'waiting_for_input' | 'waiting_for_host' | 'finished' | 'error'
+
+/** register_host_function으로 등록한 함수가 호출되면 VM이 멈추고 보내는 요청 */
+export interface HostRequest {
+  kind: string
+  payload: unknown
+}
+
+/** 변수/원소 값: 기본값과 문자열은 그대로, 나머지는 heap 객체 id */
+export type ValueView =
+  | { kind: 'none' }
+  | { kind: 'bool'; value: boolean }
+  | { kind: 'int'; value: number }
+  | { kind: 'float'; value: number }
+  | { kind: 'str'; value: string }
+  | { kind: 'ref'; value: number }
+
+export interface Variable {
+  name: string
+  value: ValueView
+}
+
+export interface FrameView {
+  function: string
+  line: number | null
+  locals: Variable[]
+}
+
+export type HeapObject =
+  | { type: 'list' | 'tuple'; id: number; items: ValueView[] }
+  | { type: 'set'; id: number; type_name: string; items: ValueView[] }
+  | { type: 'dict'; id: number; entries: [ValueView, ValueView][] }
+  | { type: 'instance'; id: number; class_name: string; attributes: Variable[] }
+  | { type: 'class'; id: number; name: string; methods: string[] }
+  | { type: 'function'; id: number; name: string; captures: Variable[] }
+  | { type: 'other'; id: number; type_name: string; repr: string }
+
+/** start_stepping으로 시작한 실행의 한 시점 (프레임은 바깥부터) */
+export interface ExecutionView {
+  frames: FrameView[]
+  globals: Variable[]
+  heap: HeapObject[]
+}
 
 interface VmStateInfo {
   state: string
   output: string
   execution_time_ms?: number
+  request?: HostRequest
+  view?: ExecutionView
 }
 
 interface VmExecutionState {
@@ -14,6 +69,8 @@ interface VmExecutionState {
   output: string
   executionTime: number | null
   inputValue: string
+  hostRequest: HostRequest | null
+  view: ExecutionView | null
 }
 
 type VmAction = 
@@ -30,7 +87,9 @@ const initialState: VmExecutionState = {
   vmState: 'idle',
   output: '',
   executionTime: null,
-  inputValue: ''
+  inputValue: '',
+  hostRequest: null,
+  view: null
 }
 
 function vmExecutionReducer(state: VmExecutionState, action: VmAction): VmExecutionState {
@@ -49,7 +108,9 @@ function vmExecutionReducer(state: VmExecutionState, action: VmAction): VmExecut
         ...state,
         output: action.payload.output,
         vmState: action.payload.state as VmState,
-        executionTime: action.payload.execution_time_ms ?? null
+        executionTime: action.payload.execution_time_ms ?? null,
+        hostRequest: action.payload.request ?? null,
+        view: action.payload.view ?? null
       }
     
     case 'STOP_PROGRAM':
@@ -72,7 +133,9 @@ function vmExecutionReducer(state: VmExecutionState, action: VmAction): VmExecut
         output: state.output + action.payload.output,
         vmState: action.payload.state as VmState,
         executionTime: action.payload.execution_time_ms ?? state.executionTime,
-        inputValue: ''
+        inputValue: '',
+        hostRequest: action.payload.request ?? null,
+        view: action.payload.view ?? null
       }
     
     case 'SET_INPUT_VALUE':
@@ -113,6 +176,35 @@ export function useVmExecution() {
     }
   }, [])
 
+  // 첫 줄 앞에서 멈춘 채로 시작 (이후 stepLine/stepInstruction으로 진행)
+  const startStepping = useCallback((code: string) => {
+    dispatch({ type: 'START_PROGRAM' })
+
+    try {
+      const result = start_stepping(code) as VmStateInfo
+      dispatch({ type: 'PROGRAM_RESULT', payload: result })
+    } catch (e) {
+      const errorMessage = `\x1b[31mError: ${e}\x1b[0m`
+      dispatch({ type: 'ERROR', payload: errorMessage })
+      console.error('Run error:', e)
+    }
+  }, [])
+
+  const step = useCallback((byInstruction: boolean) => {
+    if (state.vmState !== 'running' || state.view === null) {
+      return
+    }
+
+    try {
+      const result = (byInstruction ? step_instruction() : step_line()) as VmStateInfo
+      dispatch({ type: 'INPUT_RESULT', payload: result })
+    } catch (e) {
+      const errorMessage = `\x1b[31mError: ${e}\x1b[0m`
+      dispatch({ type: 'ERROR', payload: errorMessage })
+      console.error('Step error:', e)
+    }
+  }, [state.vmState, state.view])
+
   const stopProgram = useCallback(() => {
     try {
       stop_program()
@@ -138,6 +230,44 @@ export function useVmExecution() {
     }
   }, [state.vmState])
 
+  // 호스트 요청에 응답 (value는 JSON으로 표현 가능한 값), error가 있으면 런타임 에러로 종료
+  const answerHost = useCallback((value: unknown, error?: string) => {
+    if (state.vmState !== 'waiting_for_host') {
+      return
+    }
+
+    try {
+      const result = (error === undefined ? resume_host(value) : fail_host(error)) as VmStateInfo
+      dispatch({ type: 'INPUT_RESULT', payload: result })
+    } catch (e) {
+      const errorMessage = `\x1b[31mError: ${e}\x1b[0m`
+      dispatch({ type: 'ERROR', payload: errorMessage })
+      console.error('Host response error:', e)
+    }
+  }, [state.vmState])
+
+  // 실행 중인 프로그램 상태 저장 (입력/호스트 응답을 기다릴 때), 실패하면 null
+  const checkpoint = useCallback((): Uint8Array | null => {
+    try {
+      return snapshot_program()
+    } catch (e) {
+      console.error('Snapshot error:', e)
+      return null
+    }
+  }, [])
+
+  // checkpoint로 저장한 상태로 되돌림 (출력은 비어 있는 상태로 시작)
+  const restoreCheckpoint = useCallback((bytes: Uint8Array) => {
+    try {
+      const result = restore_program(bytes) as VmStateInfo
+      dispatch({ type: 'PROGRAM_RESULT', payload: result })
+    } catch (e) {
+      const errorMessage = `\x1b[31mError: ${e}\x1b[0m`
+      dispatch({ type: 'ERROR', payload: errorMessage })
+      console.error('Restore error:', e)
+    }
+  }, [])
+
   const setInputValue = useCallback((value: string) => {
     dispatch({ type: 'SET_INPUT_VALUE', payload: value })
   }, [])
@@ -151,9 +281,17 @@ export function useVmExecution() {
     output: state.output,
     executionTime: state.executionTime,
     inputValue: state.inputValue,
+    hostRequest: state.hostRequest,
+    view: state.view,
     startProgram,
+    startStepping,
+    stepLine: () => step(false),
+    stepInstruction: () => step(true),
     stopProgram,
     sendInput,
+    answerHost,
+    checkpoint,
+    restoreCheckpoint,
     setInputValue,
     resetVm
   }